@@ -0,0 +1,51 @@
+#ifndef PROOF_OF_SQL_FFI_H
+#define PROOF_OF_SQL_FFI_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The result was verified and the result table was written to the output buffer. */
+#define POSQL_OK 0
+/* A required pointer was null, or the query or schema was not valid UTF-8. */
+#define POSQL_INVALID_ARGUMENT 1
+/* The commitments could not be deserialized. */
+#define POSQL_INVALID_COMMITMENTS 2
+/* The verifiable result could not be deserialized. */
+#define POSQL_INVALID_VERIFIABLE_RESULT 3
+/* The verifier setup could not be deserialized. */
+#define POSQL_INVALID_VERIFIER_SETUP 4
+/* The query could not be parsed or planned against the commitments. */
+#define POSQL_INVALID_QUERY 5
+/* The proof did not verify, or the verified result could not be postprocessed. */
+#define POSQL_VERIFICATION_FAILED 6
+/* The output buffer is too small. The required size was written to out_len. */
+#define POSQL_BUFFER_TOO_SMALL 7
+/* The verifier panicked, e.g. on a malformed proof. The panic was caught at the FFI boundary. */
+#define POSQL_PANICKED 8
+
+/*
+ * Verifies a result of `query` that was proven with the Dynamic Dory commitment scheme.
+ *
+ * All buffers are postcard serialized. On POSQL_OK, the serialized result table is written to
+ * `out` and its length to `out_len`. On POSQL_BUFFER_TOO_SMALL, only the required length is
+ * written to `out_len`.
+ *
+ * The caller owns every buffer and string, including `out`, and must free them. The library
+ * never allocates memory for the caller and does not keep any pointer after returning.
+ */
+int32_t posql_verify_dynamic_dory(
+    const uint8_t *commitments, size_t commitments_len,
+    const uint8_t *verifiable_result, size_t verifiable_result_len,
+    const uint8_t *verifier_setup, size_t verifier_setup_len,
+    const char *query, const char *default_schema,
+    uint8_t *out, size_t out_capacity, size_t *out_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* PROOF_OF_SQL_FFI_H */
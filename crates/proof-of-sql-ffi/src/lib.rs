@@ -0,0 +1,36 @@
+#![cfg_attr(test, allow(clippy::missing_panics_doc))]
+//! C bindings for verifying Proof of SQL results.
+//!
+//! This crate exposes [`posql_verify_dynamic_dory`], which verifies a
+//! [`VerifiableQueryResult`](proof_of_sql::sql::proof::VerifiableQueryResult) created with the
+//! Dynamic Dory commitment scheme. The matching C declarations are in
+//! `include/proof_of_sql_ffi.h`.
+//!
+//! # Serialization
+//!
+//! Every byte buffer is serialized with [`postcard`]:
+//! - the commitments are a
+//!   [`QueryCommitments<DynamicDoryCommitment>`](proof_of_sql::base::commitment::QueryCommitments)
+//!   covering the tables that the query reads,
+//! - the verifiable result is a
+//!   [`VerifiableQueryResult<DynamicDoryEvaluationProof>`](proof_of_sql::sql::proof::VerifiableQueryResult),
+//! - the verifier setup is a [`VerifierSetup`](proof_of_sql::proof_primitive::dory::VerifierSetup),
+//! - the verified result table written to the output buffer is an
+//!   [`OwnedTable<DoryScalar>`](proof_of_sql::base::database::OwnedTable), with any
+//!   postprocessing of the query already applied.
+//!
+//! # Memory ownership
+//!
+//! The caller owns every buffer and string passed to [`posql_verify_dynamic_dory`], including the
+//! output buffer, and is responsible for freeing them. The library only reads the inputs and writes
+//! to the output buffer for the duration of the call. It never allocates memory that is handed to
+//! the caller and never keeps any of the pointers after returning.
+
+mod verify;
+pub use verify::{
+    posql_verify_dynamic_dory, POSQL_BUFFER_TOO_SMALL, POSQL_INVALID_ARGUMENT,
+    POSQL_INVALID_COMMITMENTS, POSQL_INVALID_QUERY, POSQL_INVALID_VERIFIABLE_RESULT,
+    POSQL_INVALID_VERIFIER_SETUP, POSQL_OK, POSQL_PANICKED, POSQL_VERIFICATION_FAILED,
+};
+#[cfg(test)]
+mod verify_test;
@@ -0,0 +1,173 @@
+use core::{ffi::c_char, slice};
+use proof_of_sql::{
+    base::{commitment::QueryCommitments, database::OwnedTable},
+    proof_primitive::dory::{
+        DoryScalar, DynamicDoryCommitment, DynamicDoryEvaluationProof, VerifierSetup,
+    },
+    sql::{
+        parse::QueryExpr, postprocessing::apply_postprocessing_steps, proof::VerifiableQueryResult,
+    },
+};
+use std::{
+    ffi::CStr,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+/// The result was verified and the result table was written to the output buffer.
+pub const POSQL_OK: i32 = 0;
+/// A required pointer was null, or the query or schema was not valid UTF-8.
+pub const POSQL_INVALID_ARGUMENT: i32 = 1;
+/// The commitments could not be deserialized.
+pub const POSQL_INVALID_COMMITMENTS: i32 = 2;
+/// The verifiable result could not be deserialized.
+pub const POSQL_INVALID_VERIFIABLE_RESULT: i32 = 3;
+/// The verifier setup could not be deserialized.
+pub const POSQL_INVALID_VERIFIER_SETUP: i32 = 4;
+/// The query could not be parsed or planned against the commitments.
+pub const POSQL_INVALID_QUERY: i32 = 5;
+/// The proof did not verify, or the verified result could not be postprocessed.
+pub const POSQL_VERIFICATION_FAILED: i32 = 6;
+/// The output buffer is too small. The required size was written to `out_len`.
+pub const POSQL_BUFFER_TOO_SMALL: i32 = 7;
+/// The verifier panicked, e.g. on a malformed proof. The panic was caught at the FFI boundary.
+pub const POSQL_PANICKED: i32 = 8;
+
+/// Verifies a Dynamic Dory result and returns the serialized result table, or a status code.
+fn verify(
+    commitments: &[u8],
+    verifiable_result: &[u8],
+    verifier_setup: &[u8],
+    query: &str,
+    default_schema: &str,
+) -> Result<Vec<u8>, i32> {
+    let commitments: QueryCommitments<DynamicDoryCommitment> =
+        postcard::from_bytes(commitments).map_err(|_| POSQL_INVALID_COMMITMENTS)?;
+    let verifiable_result: VerifiableQueryResult<DynamicDoryEvaluationProof> =
+        postcard::from_bytes(verifiable_result).map_err(|_| POSQL_INVALID_VERIFIABLE_RESULT)?;
+    let verifier_setup: VerifierSetup =
+        postcard::from_bytes(verifier_setup).map_err(|_| POSQL_INVALID_VERIFIER_SETUP)?;
+    let query = QueryExpr::try_new(
+        query.parse().map_err(|_| POSQL_INVALID_QUERY)?,
+        default_schema.into(),
+        &commitments,
+    )
+    .map_err(|_| POSQL_INVALID_QUERY)?;
+    let table: OwnedTable<DoryScalar> = verifiable_result
+        .verify(query.proof_expr(), &commitments, &&verifier_setup)
+        .map_err(|_| POSQL_VERIFICATION_FAILED)?
+        .table;
+    let table = apply_postprocessing_steps(table, query.postprocessing())
+        .map_err(|_| POSQL_VERIFICATION_FAILED)?;
+    postcard::to_allocvec(&table).map_err(|_| POSQL_VERIFICATION_FAILED)
+}
+
+/// Runs `f`, reporting a panic as [`POSQL_PANICKED`] since it must not unwind into the caller.
+pub(crate) fn status_or_panicked(f: impl FnOnce() -> i32) -> i32 {
+    catch_unwind(AssertUnwindSafe(f)).unwrap_or(POSQL_PANICKED)
+}
+
+/// Reads `len` bytes at `ptr`, allowing a null `ptr` only when `len` is zero.
+///
+/// # Safety
+///
+/// If `ptr` is not null, it must be valid for reads of `len` bytes.
+unsafe fn bytes<'a>(ptr: *const u8, len: usize) -> Option<&'a [u8]> {
+    match (ptr.is_null(), len) {
+        (true, 0) => Some(&[]),
+        (true, _) => None,
+        (false, _) => Some(slice::from_raw_parts(ptr, len)),
+    }
+}
+
+/// Reads the null terminated UTF-8 string at `ptr`.
+///
+/// # Safety
+///
+/// If `ptr` is not null, it must point to a null terminated string.
+unsafe fn string<'a>(ptr: *const c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        return None;
+    }
+    CStr::from_ptr(ptr).to_str().ok()
+}
+
+/// Verifies a result of `query` that was proven with the Dynamic Dory commitment scheme.
+///
+/// Tables in `query` without a schema are resolved in `default_schema`. On success, the
+/// serialized result table is written to `out`, its length is written to `out_len`, and
+/// [`POSQL_OK`] is returned. If `out_capacity` is too small, nothing is written to `out`, the
+/// required length is written to `out_len`, and [`POSQL_BUFFER_TOO_SMALL`] is returned, so that
+/// the caller can retry with a larger buffer. Any other status means that the result must not be
+/// trusted, and `out_len` is left unchanged. A panic while verifying is caught and reported as
+/// [`POSQL_PANICKED`].
+///
+/// See the crate documentation for the serialization of the buffers.
+///
+/// # Safety
+///
+/// - `commitments`, `verifiable_result` and `verifier_setup` must each be valid for reads of
+///   their length in bytes. They may be null only if their length is zero.
+/// - `query` and `default_schema` must be null terminated strings.
+/// - `out` must be valid for writes of `out_capacity` bytes, and may be null only if
+///   `out_capacity` is zero.
+/// - `out_len` must be valid for a write of a `size_t`.
+/// - None of the buffers may be modified by another thread during the call.
+///
+/// The caller keeps ownership of every buffer and string and must free them. The library does
+/// not keep any of the pointers after returning.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn posql_verify_dynamic_dory(
+    commitments: *const u8,
+    commitments_len: usize,
+    verifiable_result: *const u8,
+    verifiable_result_len: usize,
+    verifier_setup: *const u8,
+    verifier_setup_len: usize,
+    query: *const c_char,
+    default_schema: *const c_char,
+    out: *mut u8,
+    out_capacity: usize,
+    out_len: *mut usize,
+) -> i32 {
+    status_or_panicked(|| {
+        let (
+            Some(commitments),
+            Some(verifiable_result),
+            Some(verifier_setup),
+            Some(query),
+            Some(default_schema),
+        ) = (
+            bytes(commitments, commitments_len),
+            bytes(verifiable_result, verifiable_result_len),
+            bytes(verifier_setup, verifier_setup_len),
+            string(query),
+            string(default_schema),
+        )
+        else {
+            return POSQL_INVALID_ARGUMENT;
+        };
+        if out_len.is_null() || (out.is_null() && out_capacity != 0) {
+            return POSQL_INVALID_ARGUMENT;
+        }
+        match verify(
+            commitments,
+            verifiable_result,
+            verifier_setup,
+            query,
+            default_schema,
+        ) {
+            Ok(table) => {
+                *out_len = table.len();
+                if table.len() > out_capacity {
+                    return POSQL_BUFFER_TOO_SMALL;
+                }
+                if !table.is_empty() {
+                    slice::from_raw_parts_mut(out, table.len()).copy_from_slice(&table);
+                }
+                POSQL_OK
+            }
+            Err(status) => status,
+        }
+    })
+}
@@ -0,0 +1,202 @@
+use super::{
+    posql_verify_dynamic_dory, verify::status_or_panicked, POSQL_BUFFER_TOO_SMALL,
+    POSQL_INVALID_ARGUMENT, POSQL_INVALID_QUERY, POSQL_INVALID_VERIFIABLE_RESULT, POSQL_OK,
+    POSQL_PANICKED, POSQL_VERIFICATION_FAILED,
+};
+use ark_std::test_rng;
+use core::ptr;
+use proof_of_sql::{
+    base::{
+        commitment::{QueryCommitments, QueryCommitmentsExt},
+        database::{owned_table_utility::*, OwnedTable, OwnedTableTestAccessor},
+    },
+    proof_primitive::dory::{
+        DoryScalar, DynamicDoryEvaluationProof, ProverSetup, PublicParameters, VerifierSetup,
+    },
+    sql::{
+        parse::QueryExpr,
+        proof::{ProofPlan, VerifiableQueryResult},
+    },
+};
+use std::ffi::CString;
+
+const QUERY: &str = "SELECT b, a FROM t WHERE a >= 2 ORDER BY a DESC";
+
+/// The serialized inputs of [`posql_verify_dynamic_dory`] for [`QUERY`].
+struct Inputs {
+    commitments: Vec<u8>,
+    verifiable_result: Vec<u8>,
+    verifier_setup: Vec<u8>,
+}
+
+/// Proves [`QUERY`] against a table with rows `a` and serializes everything the verifier needs,
+/// with the commitments taken from a table with rows `committed_a`.
+fn inputs(a: [i64; 3], committed_a: [i64; 3]) -> Inputs {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let table = |a| owned_table([bigint("a", a), varchar("b", ["x", "y", "z"])]);
+    let accessor = OwnedTableTestAccessor::<DynamicDoryEvaluationProof>::new_from_table(
+        "sxt.t".parse().unwrap(),
+        table(a),
+        0,
+        &prover_setup,
+    );
+    let committed_accessor = OwnedTableTestAccessor::<DynamicDoryEvaluationProof>::new_from_table(
+        "sxt.t".parse().unwrap(),
+        table(committed_a),
+        0,
+        &prover_setup,
+    );
+    let query = QueryExpr::try_new(QUERY.parse().unwrap(), "sxt".into(), &accessor).unwrap();
+    let verifiable_result = VerifiableQueryResult::<DynamicDoryEvaluationProof>::new(
+        query.proof_expr(),
+        &accessor,
+        &&prover_setup,
+    );
+    let commitments = QueryCommitments::from_accessor_with_max_bounds(
+        query.proof_expr().get_column_references(),
+        &committed_accessor,
+    );
+    Inputs {
+        commitments: postcard::to_allocvec(&commitments).unwrap(),
+        verifiable_result: postcard::to_allocvec(&verifiable_result).unwrap(),
+        verifier_setup: postcard::to_allocvec(&verifier_setup).unwrap(),
+    }
+}
+
+/// Calls [`posql_verify_dynamic_dory`] with an output buffer of `out_capacity` bytes.
+fn call(inputs: &Inputs, query: &str, out_capacity: usize) -> (i32, Vec<u8>, usize) {
+    let query = CString::new(query).unwrap();
+    let default_schema = CString::new("sxt").unwrap();
+    let mut out = vec![0; out_capacity];
+    let mut out_len = usize::MAX;
+    // SAFETY: every buffer is valid for its length and the strings are null terminated.
+    let status = unsafe {
+        posql_verify_dynamic_dory(
+            inputs.commitments.as_ptr(),
+            inputs.commitments.len(),
+            inputs.verifiable_result.as_ptr(),
+            inputs.verifiable_result.len(),
+            inputs.verifier_setup.as_ptr(),
+            inputs.verifier_setup.len(),
+            query.as_ptr(),
+            default_schema.as_ptr(),
+            out.as_mut_ptr(),
+            out.len(),
+            &mut out_len,
+        )
+    };
+    (status, out, out_len)
+}
+
+#[test]
+fn we_can_verify_a_dynamic_dory_result_through_the_ffi() {
+    let inputs = inputs([1, 2, 3], [1, 2, 3]);
+    let (status, out, out_len) = call(&inputs, QUERY, 1024);
+    assert_eq!(status, POSQL_OK);
+    let table: OwnedTable<DoryScalar> = postcard::from_bytes(&out[..out_len]).unwrap();
+    assert_eq!(
+        table,
+        owned_table([varchar("b", ["z", "y"]), bigint("a", [3, 2])])
+    );
+}
+
+#[test]
+fn we_get_the_required_length_if_the_output_buffer_is_too_small() {
+    let inputs = inputs([1, 2, 3], [1, 2, 3]);
+    let (_, _, required_len) = call(&inputs, QUERY, 1024);
+    let (status, out, out_len) = call(&inputs, QUERY, required_len - 1);
+    assert_eq!(status, POSQL_BUFFER_TOO_SMALL);
+    assert_eq!(out_len, required_len);
+    assert!(out.iter().all(|&byte| byte == 0));
+    let (status, _, out_len) = call(&inputs, QUERY, required_len);
+    assert_eq!(status, POSQL_OK);
+    assert_eq!(out_len, required_len);
+}
+
+#[test]
+fn we_cannot_verify_a_result_against_other_commitments_through_the_ffi() {
+    let inputs = inputs([1, 2, 3], [1, 2, 4]);
+    let (status, _, out_len) = call(&inputs, QUERY, 1024);
+    assert_eq!(status, POSQL_VERIFICATION_FAILED);
+    assert_eq!(out_len, usize::MAX);
+}
+
+#[test]
+fn we_cannot_verify_an_invalid_query_through_the_ffi() {
+    let inputs = inputs([1, 2, 3], [1, 2, 3]);
+    let (status, _, _) = call(&inputs, "SELECT c FROM t", 1024);
+    assert_eq!(status, POSQL_INVALID_QUERY);
+}
+
+#[test]
+fn we_cannot_verify_truncated_result_bytes_through_the_ffi() {
+    let mut inputs = inputs([1, 2, 3], [1, 2, 3]);
+    let len = inputs.verifiable_result.len();
+    for truncated_len in [len - 1, len / 2, 0] {
+        inputs.verifiable_result.truncate(truncated_len);
+        let (status, _, out_len) = call(&inputs, QUERY, 1024);
+        assert_eq!(status, POSQL_INVALID_VERIFIABLE_RESULT);
+        assert_eq!(out_len, usize::MAX);
+    }
+}
+
+/// Truncates the GT messages of the Dory evaluation proof in a serialized verifiable result to
+/// none, without changing the length of the result.
+///
+/// The Dory messages are serialized with `ark_serialize` and end with the GT messages, as a
+/// `u64` count followed by 576 bytes per message. Only the three `usize` fields of the proof,
+/// a byte each for [`QUERY`], follow them. The deserializer ignores the bytes of the messages
+/// once their count is zero.
+fn truncate_gt_messages(verifiable_result: &mut [u8]) {
+    const GT_SIZE: usize = 576;
+    let end = verifiable_result.len() - 3;
+    let count_start = (1..=64_u64)
+        .find_map(|count| {
+            let start = end - 8 - usize::try_from(count).unwrap() * GT_SIZE;
+            (verifiable_result[start..start + 8] == count.to_le_bytes()).then_some(start)
+        })
+        .unwrap();
+    verifiable_result[count_start..count_start + 8].fill(0);
+}
+
+#[test]
+fn we_cannot_verify_a_proof_with_truncated_dory_messages_through_the_ffi() {
+    let mut inputs = inputs([1, 2, 3], [1, 2, 3]);
+    truncate_gt_messages(&mut inputs.verifiable_result);
+    let (status, _, out_len) = call(&inputs, QUERY, 1024);
+    assert_eq!(status, POSQL_VERIFICATION_FAILED);
+    assert_eq!(out_len, usize::MAX);
+}
+
+#[test]
+fn a_panic_is_reported_as_a_status_instead_of_unwinding() {
+    assert_eq!(status_or_panicked(|| POSQL_OK), POSQL_OK);
+    assert_eq!(
+        status_or_panicked(|| panic!("the verifier panicked")),
+        POSQL_PANICKED
+    );
+}
+
+#[test]
+fn we_cannot_pass_null_pointers_to_the_ffi() {
+    let mut out_len = 0;
+    // SAFETY: null pointers are rejected before anything is read.
+    let status = unsafe {
+        posql_verify_dynamic_dory(
+            ptr::null(),
+            1,
+            ptr::null(),
+            0,
+            ptr::null(),
+            0,
+            ptr::null(),
+            ptr::null(),
+            ptr::null_mut(),
+            0,
+            &mut out_len,
+        )
+    };
+    assert_eq!(status, POSQL_INVALID_ARGUMENT);
+}
@@ -4,7 +4,10 @@
 * https://docs.rs/vervolg/latest/vervolg/ast/enum.Statement.html
 ***/
 
-use crate::{posql_time::PoSQLTimestamp, Identifier};
+use crate::{
+    posql_time::{PoSQLDate, PoSQLTimestamp},
+    Identifier,
+};
 use alloc::{boxed::Box, string::String, vec::Vec};
 use bigdecimal::BigDecimal;
 use core::{
@@ -28,6 +31,9 @@ pub enum SetExpression {
         where_expr: Option<Box<Expression>>,
         /// Group by expressions e.g. `a` in `SELECT a, COUNT(*) FROM table GROUP BY a`
         group_by: Vec<Identifier>,
+        /// Whether duplicate rows are removed from the result, i.e. `SELECT DISTINCT a, b FROM table`
+        #[serde(default)]
+        distinct: bool,
     },
 }
 
@@ -38,6 +44,20 @@ pub enum SelectResultExpr {
     ALL,
     /// A single expression e.g. `SELECT a FROM table`
     AliasedResultExpr(AliasedResultExpr),
+    /// All columns in a table with wildcard modifiers
+    /// e.g. `SELECT * EXCLUDE (a) REPLACE (b + 1 AS b) FROM table`
+    ALLWithModifiers(WildcardModifiers),
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+/// The modifiers of a wildcard e.g. `EXCLUDE (a) REPLACE (b + 1 AS b)` in
+/// `SELECT * EXCLUDE (a) REPLACE (b + 1 AS b) FROM table`
+pub struct WildcardModifiers {
+    /// The columns left out of the wildcard e.g. `a` in `EXCLUDE (a)`
+    pub exclude: Vec<Identifier>,
+    /// The columns replaced by an expression, with the replaced column as the alias
+    /// e.g. `b + 1 AS b` in `REPLACE (b + 1 AS b)`
+    pub replace: Vec<AliasedResultExpr>,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
@@ -111,6 +131,9 @@ pub enum BinaryOperator {
 
     /// Comparison >=
     GreaterThanOrEqual,
+
+    /// Integer modulo, with the sign of the dividend
+    Modulo,
 }
 
 /// Possible unary operators for simple expressions
@@ -134,6 +157,8 @@ pub enum AggregationOperator {
     Count,
     /// Return the first value
     First,
+    /// Population variance
+    VarPop,
 }
 
 impl Display for AggregationOperator {
@@ -144,6 +169,28 @@ impl Display for AggregationOperator {
             AggregationOperator::Sum => write!(f, "sum"),
             AggregationOperator::Count => write!(f, "count"),
             AggregationOperator::First => write!(f, "first"),
+            AggregationOperator::VarPop => write!(f, "var_pop"),
+        }
+    }
+}
+
+/// String transformations applied to each value of an expression
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum StringTransformOperator {
+    /// Remove leading and trailing spaces
+    Trim,
+    /// Convert to upper case
+    Upper,
+    /// Convert to lower case
+    Lower,
+}
+
+impl Display for StringTransformOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            StringTransformOperator::Trim => write!(f, "trim"),
+            StringTransformOperator::Upper => write!(f, "upper"),
+            StringTransformOperator::Lower => write!(f, "lower"),
         }
     }
 }
@@ -185,6 +232,24 @@ pub enum Expression {
         /// The expression to aggregate
         expr: Box<Expression>,
     },
+
+    /// String transformation
+    StringTransform {
+        /// The string transformation
+        op: StringTransformOperator,
+        /// The string expression to transform
+        expr: Box<Expression>,
+    },
+
+    /// `SUBSTRING(expr, start, length)`
+    Substring {
+        /// The string expression to take the substring of
+        expr: Box<Expression>,
+        /// The 1-based position of the first character
+        start: i64,
+        /// The number of characters
+        length: i64,
+    },
 }
 
 impl Expression {
@@ -232,6 +297,15 @@ impl Expression {
             expr: Box::new(self),
         })
     }
+
+    /// Create a new `VAR_POP()`
+    #[must_use]
+    pub fn var_pop(self) -> Box<Self> {
+        Box::new(Expression::Aggregation {
+            op: AggregationOperator::VarPop,
+            expr: Box::new(self),
+        })
+    }
     /// Create an `AliasedResultExpr` from an `Expression` using the provided alias.
     /// # Panics
     ///
@@ -324,14 +398,25 @@ pub struct Slice {
     /// number of rows to return
     ///
     /// if `u64::MAX`, specify all rows
-    pub number_rows: u64,
+    pub number_rows: SliceValue<u64>,
 
     /// number of rows to skip
     ///
     /// if 0, specify the first row as starting point
     /// if negative, specify the offset from the end
     /// (e.g. -1 is the last row, -2 is the second to last row, etc.)
-    pub offset_value: i64,
+    pub offset_value: SliceValue<i64>,
+}
+
+/// The value of a `LIMIT` or `OFFSET` clause
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SliceValue<T> {
+    /// A literal value, e.g. `3` in `LIMIT 3`
+    Literal(T),
+    /// A query parameter, e.g. `$1` in `LIMIT $1`, holding its 1-based index
+    ///
+    /// The parameter is resolved when the query is bound to its parameters.
+    Parameter(usize),
 }
 
 /// Literal values
@@ -349,6 +434,8 @@ pub enum Literal {
     Decimal(BigDecimal),
     /// Timestamp Literal
     Timestamp(PoSQLTimestamp),
+    /// Date Literal
+    Date(PoSQLDate),
 }
 
 impl From<bool> for Literal {
@@ -408,6 +495,31 @@ impl From<PoSQLTimestamp> for Literal {
     }
 }
 
+impl From<PoSQLDate> for Literal {
+    fn from(date: PoSQLDate) -> Self {
+        Literal::Date(date)
+    }
+}
+
+/// Helper function to desugar `expr IN (values)` into `expr = value_0 OR expr = value_1 OR ...`
+///
+/// An empty list is `false`.
+pub(crate) fn in_list(expr: &Expression, values: Vec<Literal>) -> Expression {
+    values
+        .into_iter()
+        .map(|value| Expression::Binary {
+            op: BinaryOperator::Equal,
+            left: Box::new(expr.clone()),
+            right: Box::new(Expression::Literal(value)),
+        })
+        .reduce(|left, right| Expression::Binary {
+            op: BinaryOperator::Or,
+            left: Box::new(left),
+            right: Box::new(right),
+        })
+        .unwrap_or(Expression::Literal(Literal::Boolean(false)))
+}
+
 /// Helper function to append an item to a vector
 pub(crate) fn append<T>(list: Vec<T>, item: T) -> Vec<T> {
     let mut result = list;
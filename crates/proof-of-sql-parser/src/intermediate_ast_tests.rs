@@ -1,11 +1,17 @@
 use crate::{
-    intermediate_ast::OrderByDirection::{Asc, Desc},
+    intermediate_ast::{
+        AggregationOperator, Literal,
+        OrderByDirection::{Asc, Desc},
+        SliceValue, StringTransformOperator,
+    },
+    posql_time::{PoSQLDate, PoSQLTimeZone, PoSQLTimestamp},
     sql::*,
     utility::*,
     SelectStatement,
 };
 use alloc::{
     borrow::ToOwned,
+    format,
     string::{String, ToString},
     vec,
 };
@@ -226,6 +232,31 @@ fn we_can_parse_a_query_with_a_column_equals_a_decimal() {
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_can_parse_a_query_with_timestamps_with_fixed_offsets() {
+    for (timestamp, offset) in [
+        ("2024-06-20T11:00:00+05:30", 19_800),
+        ("2024-06-19T21:30:00-08:00", -28_800),
+    ] {
+        let ast = format!("SELECT A FROM SXT_TAB WHERE A = timestamp '{timestamp}'")
+            .parse::<SelectStatement>()
+            .unwrap();
+        let expected_timestamp = PoSQLTimestamp::try_from(timestamp).unwrap();
+        assert_eq!(expected_timestamp.timezone(), PoSQLTimeZone::new(offset));
+        let expected_ast = select(
+            query(
+                cols_res(&["a"]),
+                tab(None, "sxt_tab"),
+                equal(col("a"), lit(Literal::Timestamp(expected_timestamp))),
+                vec![],
+            ),
+            vec![],
+            None,
+        );
+        assert_eq!(ast, expected_ast);
+    }
+}
+
 #[test]
 fn we_can_parse_a_query_with_two_result_columns() {
     let ast = "Select a,  b froM sxt_tab where C = D + 1 and E = F and G"
@@ -288,6 +319,119 @@ fn we_can_parse_a_query_using_select_star_and_a_const() {
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_can_parse_a_query_using_select_star_with_exclude() {
+    let ast = "SELECT * EXCLUDE (internal_id) FROM sxt_tab"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query_all(
+            vec![col_res_all_with_modifiers(&["internal_id"], vec![])],
+            tab(None, "sxt_tab"),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+
+    let ast = "select * exclude internal_id from sxt_tab"
+        .parse::<SelectStatement>()
+        .unwrap();
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_a_query_using_select_star_with_exclude_and_replace() {
+    let ast = "SELECT * EXCLUDE (a, b) REPLACE (c * 2 AS c, 'x' AS d), e FROM sxt_tab"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query_all(
+            vec![
+                col_res_all_with_modifiers(
+                    &["a", "b"],
+                    vec![
+                        aliased_expr(col("c") * lit(2), "c"),
+                        aliased_expr(lit("x"), "d"),
+                    ],
+                ),
+                col_res(col("e"), "e"),
+            ],
+            tab(None, "sxt_tab"),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+
+    let ast = "SELECT * REPLACE (c + 1 AS c) FROM sxt_tab"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query_all(
+            vec![col_res_all_with_modifiers(
+                &[],
+                vec![aliased_expr(col("c") + lit(1), "c")],
+            )],
+            tab(None, "sxt_tab"),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_parse_a_query_using_select_star_with_malformed_modifiers() {
+    for query in [
+        "SELECT * EXCLUDE () FROM sxt_tab",
+        "SELECT * REPLACE (c + 1) FROM sxt_tab",
+        "SELECT * REPLACE c + 1 AS c FROM sxt_tab",
+        "SELECT * REPLACE (c + 1 AS c) EXCLUDE (a) FROM sxt_tab",
+        "SELECT a EXCLUDE (a) FROM sxt_tab",
+    ] {
+        assert!(query.parse::<SelectStatement>().is_err(), "{query}");
+    }
+}
+
+#[test]
+fn we_can_use_exclude_and_replace_as_identifiers() {
+    let ast = "select exclude, replace from exclude where replace = 1"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            cols_res(&["exclude", "replace"]),
+            tab(None, "exclude"),
+            equal(col("replace"), lit(1)),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+
+    let ast = "SELECT * EXCLUDE (exclude) REPLACE (replace + 1 AS replace) FROM sxt_tab"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query_all(
+            vec![col_res_all_with_modifiers(
+                &["exclude"],
+                vec![aliased_expr(col("replace") + lit(1), "replace")],
+            )],
+            tab(None, "sxt_tab"),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
 #[test]
 fn we_can_parse_a_query_using_multiple_select_star_expressions() {
     let ast = "SELECT a, *, b, c, * FROM sxt_Tab WHERE A - B = 3"
@@ -386,6 +530,99 @@ fn we_can_parse_a_query_with_one_logical_not_filter_expression() {
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_can_parse_a_query_with_an_in_list_filter_expression() {
+    let ast = "select a from sxt_tab where b in ('x', 'Y', 'z')"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            cols_res(&["a"]),
+            tab(None, "sxt_tab"),
+            or(
+                or(equal(col("b"), lit("x")), equal(col("b"), lit("Y"))),
+                equal(col("b"), lit("z")),
+            ),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_a_query_with_a_single_element_in_list_as_an_equality() {
+    let ast = "select a from sxt_tab where b + 1 IN (-4)"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = "select a from sxt_tab where b + 1 = -4"
+        .parse::<SelectStatement>()
+        .unwrap();
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_a_query_with_a_not_in_list_filter_expression() {
+    let ast = "select a from sxt_tab where b not in (1, 2) and c"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            cols_res(&["a"]),
+            tab(None, "sxt_tab"),
+            and(
+                not(or(equal(col("b"), lit(1)), equal(col("b"), lit(2)))),
+                col("c"),
+            ),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_a_query_with_an_empty_in_list_as_false() {
+    let ast = "select a from sxt_tab where b in ()"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(cols_res(&["a"]), tab(None, "sxt_tab"), lit(false), vec![]),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+    assert!("select a from sxt_tab where b in (, 1)"
+        .parse::<SelectStatement>()
+        .is_err());
+    assert!("select a from sxt_tab where b in (c)"
+        .parse::<SelectStatement>()
+        .is_err());
+}
+
+#[test]
+fn we_can_parse_a_query_with_a_date_literal() {
+    let ast = "select date from sxt_tab where date >= DATE '2020-01-02'"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            cols_res(&["date"]),
+            tab(None, "sxt_tab"),
+            ge(col("date"), lit(PoSQLDate::from_days_since_epoch(18_263))),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+    assert!("select a from sxt_tab where b = date '2020-13-01'"
+        .parse::<SelectStatement>()
+        .is_err());
+}
+
 #[test]
 fn we_can_parse_a_query_with_one_logical_and_filter_expression() {
     let ast = "select a from sxt_tab where (b = 3) and c"
@@ -931,6 +1168,42 @@ fn we_can_parse_a_query_having_a_simple_limit_and_offset_clause() {
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_can_parse_a_query_having_parameterized_limit_and_offset_clauses() {
+    let ast = "select a from tab limit $1 offset $2"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query_all(cols_res(&["a"]), tab(None, "tab"), vec![]),
+        vec![],
+        slice_values(SliceValue::Parameter(1), SliceValue::Parameter(2)),
+    );
+    assert_eq!(ast, expected_ast);
+
+    let ast = "select a from tab offset $12"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query_all(cols_res(&["a"]), tab(None, "tab"), vec![]),
+        vec![],
+        slice_values(SliceValue::Literal(u64::MAX), SliceValue::Parameter(12)),
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_parse_query_parameters_outside_of_limit_and_offset_clauses_or_with_index_zero() {
+    for query in [
+        "select a from tab limit $0",
+        "select a from tab limit $",
+        "select a from tab limit $-1",
+        "select a from tab where a = $1",
+        "select $1 from tab",
+    ] {
+        assert!(query.parse::<SelectStatement>().is_err(), "{query}");
+    }
+}
+
 #[test]
 fn we_can_parse_a_query_having_a_simple_limit_and_offset_clause_preceded_by_where_expr_and_order_by(
 ) {
@@ -1171,6 +1444,127 @@ fn we_can_parse_a_group_by_clause_containing_multiple_aggregations() {
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_can_parse_a_population_variance_aggregation() {
+    let ast = "select sector, var_pop(price) as v, VAR_POP(price * 2) from tab group by sector"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query_all(
+            vec![
+                col_res(col("sector"), "sector"),
+                var_pop_res(col("price"), "v"),
+                var_pop_res(mul(col("price"), lit(2)), "__var_pop__"),
+            ],
+            tab(None, "tab"),
+            group_by(&["sector"]),
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+    assert_eq!(AggregationOperator::VarPop.to_string(), "var_pop");
+}
+
+#[test]
+fn we_can_parse_string_transformations() {
+    let ast = "select UPPER(name) as n, lower(trim(city)), Trim('  x ') as t from tab where id = 1"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            vec![
+                col_res(upper(col("name")), "n"),
+                col_res(lower(trim(col("city"))), "__expr__"),
+                col_res(trim(lit("  x ")), "t"),
+            ],
+            tab(None, "tab"),
+            equal(col("id"), lit(1)),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+    assert_eq!(StringTransformOperator::Upper.to_string(), "upper");
+}
+
+#[test]
+fn we_can_parse_substrings() {
+    let ast = "select SUBSTRING(card, 1, 4) as c, substring(upper(name), -1, +3) from tab"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query_all(
+            vec![
+                col_res(substring(col("card"), 1, 4), "c"),
+                col_res(substring(upper(col("name")), -1, 3), "__expr__"),
+            ],
+            tab(None, "tab"),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+    assert!("select substring(card, 1) from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+    assert!("select substring(card, 1, 9223372036854775808) from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+}
+
+#[test]
+fn we_can_use_function_names_as_identifiers() {
+    let ast = "select lower, upper(upper) as upper, trim, var_pop(var_pop) as var_pop from substring where lower <= upper group by lower, upper, trim order by lower"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            vec![
+                col_res(col("lower"), "lower"),
+                col_res(upper(col("upper")), "upper"),
+                col_res(col("trim"), "trim"),
+                var_pop_res(col("var_pop"), "var_pop"),
+            ],
+            tab(None, "substring"),
+            le(col("lower"), col("upper")),
+            group_by(&["lower", "upper", "trim"]),
+        ),
+        order("lower", Asc),
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_select_distinct() {
+    let ast = "select DISTINCT a, b as c from tab where d = 3 order by c"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        distinct(query(
+            cols_res(&["a"])
+                .into_iter()
+                .chain([col_res(col("b"), "c")])
+                .collect(),
+            tab(None, "tab"),
+            equal(col("d"), lit(3)),
+            vec![],
+        )),
+        order("c", Asc),
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+    assert!("select distinct from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+    assert!("select a distinct from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+}
+
 #[test]
 fn we_can_parse_a_group_by_clause_containing_multiple_aggregations_where_clause_order_by_and_limit()
 {
@@ -1362,6 +1756,24 @@ fn we_can_parse_multiple_arithmetic_expression_where_multiplication_has_preceden
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_can_parse_a_modulo_expression_with_the_precedence_of_multiplication() {
+    let ast = "select 1 + a % 10 * 2 as m from tab where b % 3 = 0"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            vec![col_res(lit(1) + modulo(col("a"), lit(10)) * lit(2), "m")],
+            tab(None, "tab"),
+            equal(modulo(col("b"), lit(3)), lit(0)),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
 #[test]
 fn we_can_parse_arithmetic_expression_within_aggregations_in_the_result_expr() {
     let ast = "select sum(2 * f + c) as d from tab"
@@ -32,6 +32,9 @@ pub use identifier::Identifier;
 pub mod resource_id;
 pub use resource_id::ResourceId;
 
+pub mod parse_cache;
+pub use parse_cache::ParseCache;
+
 pub mod sqlparser;
 
 // lalrpop-generated code is not clippy-compliant
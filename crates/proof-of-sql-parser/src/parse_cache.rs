@@ -0,0 +1,102 @@
+//! This file defines a cache of parsed identifiers and resource ids.
+use crate::{Identifier, ParseResult, ResourceId};
+use alloc::{collections::BTreeMap, string::String};
+use core::str::FromStr;
+
+/// A cache of parsed [`Identifier`]s and [`ResourceId`]s, keyed by the strings they were parsed
+/// from.
+///
+/// Parsing runs the full grammar, which adds up when the same schema and table names are parsed
+/// over and over, e.g. once per row batch while ingesting data. Parsing through a [`ParseCache`]
+/// only does so the first time a string is seen. Strings that fail to parse are not cached.
+#[derive(Debug, Clone, Default)]
+pub struct ParseCache {
+    identifiers: BTreeMap<String, Identifier>,
+    resource_ids: BTreeMap<String, ResourceId>,
+}
+
+impl ParseCache {
+    /// Creates an empty [`ParseCache`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses `string` as an [`Identifier`], returning the cached result if it was parsed before.
+    ///
+    /// # Errors
+    /// Fails if `string` is not a valid identifier, see [`Identifier::try_new`].
+    pub fn identifier(&mut self, string: &str) -> ParseResult<Identifier> {
+        cached_parse(&mut self.identifiers, string)
+    }
+
+    /// Parses `string` as a [`ResourceId`], returning the cached result if it was parsed before.
+    ///
+    /// # Errors
+    /// Fails if `string` is not a valid resource id, see [`ResourceId::from_str`].
+    pub fn resource_id(&mut self, string: &str) -> ParseResult<ResourceId> {
+        cached_parse(&mut self.resource_ids, string)
+    }
+
+    /// The number of distinct strings successfully parsed through this cache.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.identifiers.len() + self.resource_ids.len()
+    }
+
+    /// Returns `true` if nothing has been parsed through this cache yet.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+fn cached_parse<T: FromStr<Err = crate::ParseError> + Copy>(
+    cache: &mut BTreeMap<String, T>,
+    string: &str,
+) -> ParseResult<T> {
+    if let Some(value) = cache.get(string) {
+        return Ok(*value);
+    }
+    let value = T::from_str(string)?;
+    cache.insert(string.into(), value);
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn we_can_parse_identifiers_through_the_cache() {
+        let mut cache = ParseCache::new();
+        assert!(cache.is_empty());
+        let first = cache.identifier("Sxt_Col").unwrap();
+        let second = cache.identifier("Sxt_Col").unwrap();
+        assert_eq!(first, second);
+        assert_eq!(first, Identifier::try_new("sxt_col").unwrap());
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn we_can_parse_resource_ids_through_the_cache() {
+        let mut cache = ParseCache::new();
+        let first = cache.resource_id("sxt.tab").unwrap();
+        let second = cache.resource_id("sxt.tab").unwrap();
+        assert_eq!(first, second);
+        assert_eq!(first, ResourceId::try_new("sxt", "tab").unwrap());
+        assert_eq!(cache.len(), 1);
+
+        cache.resource_id("SXT.TAB").unwrap();
+        cache.identifier("sxt").unwrap();
+        assert_eq!(cache.len(), 3);
+    }
+
+    #[test]
+    fn invalid_strings_are_not_cached() {
+        let mut cache = ParseCache::new();
+        assert!(cache.identifier("1bad").is_err());
+        assert!(cache.resource_id("no_dot").is_err());
+        assert!(cache.is_empty());
+    }
+}
@@ -0,0 +1,99 @@
+use super::PoSQLTimestampError;
+use alloc::string::ToString;
+use chrono::{NaiveDate, TimeDelta};
+use core::hash::Hash;
+use serde::{Deserialize, Serialize};
+
+/// Represents a calendar date without a time of day or timezone
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct PoSQLDate {
+    /// The number of days since the unix epoch, 1970-01-01.
+    days_since_epoch: i32,
+}
+
+impl PoSQLDate {
+    /// Creates a [`PoSQLDate`] from a number of days since the unix epoch.
+    #[must_use]
+    pub fn from_days_since_epoch(days_since_epoch: i32) -> Self {
+        Self { days_since_epoch }
+    }
+
+    /// Returns the number of days since the unix epoch, 1970-01-01.
+    #[must_use]
+    pub fn days_since_epoch(&self) -> i32 {
+        self.days_since_epoch
+    }
+
+    /// Returns the calendar date.
+    #[must_use]
+    pub fn date(&self) -> NaiveDate {
+        NaiveDate::default() + TimeDelta::days(self.days_since_epoch.into())
+    }
+
+    /// Attempts to parse a date string in the `YYYY-MM-DD` format into a [`PoSQLDate`].
+    ///
+    /// # Errors
+    /// Returns `PoSQLTimestampError::ParsingError` if the input is not a valid `YYYY-MM-DD` date.
+    ///
+    /// # Examples
+    /// ```
+    /// use proof_of_sql_parser::posql_time::PoSQLDate;
+    ///
+    /// let date = PoSQLDate::try_from("1970-01-02").unwrap();
+    /// assert_eq!(date.days_since_epoch(), 1);
+    /// ```
+    pub fn try_from(date_str: &str) -> Result<Self, PoSQLTimestampError> {
+        let date = NaiveDate::parse_from_str(date_str, "%Y-%m-%d").map_err(|e| {
+            PoSQLTimestampError::ParsingError {
+                error: e.to_string(),
+            }
+        })?;
+        let days_since_epoch = date
+            .signed_duration_since(NaiveDate::default())
+            .num_days()
+            .try_into()
+            .map_err(|_| PoSQLTimestampError::ParsingError {
+                error: "date out of range".to_string(),
+            })?;
+        Ok(Self { days_since_epoch })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn we_can_parse_dates() {
+        assert_eq!(
+            PoSQLDate::try_from("1970-01-01")
+                .unwrap()
+                .days_since_epoch(),
+            0
+        );
+        assert_eq!(
+            PoSQLDate::try_from("2000-03-01")
+                .unwrap()
+                .days_since_epoch(),
+            11_017
+        );
+        assert_eq!(
+            PoSQLDate::try_from("1969-12-31")
+                .unwrap()
+                .days_since_epoch(),
+            -1
+        );
+        assert_eq!(
+            PoSQLDate::from_days_since_epoch(11_017).date().to_string(),
+            "2000-03-01"
+        );
+    }
+
+    #[test]
+    fn we_cannot_parse_invalid_dates() {
+        assert!(PoSQLDate::try_from("2023-02-29").is_err());
+        assert!(PoSQLDate::try_from("2023-02-28T00:00:00Z").is_err());
+        assert!(PoSQLDate::try_from("not a date").is_err());
+    }
+}
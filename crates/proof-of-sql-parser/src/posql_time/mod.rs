@@ -1,3 +1,6 @@
+mod date;
+/// Defines a calendar date as a count of days since the unix epoch
+pub use date::PoSQLDate;
 mod error;
 /// Errors related to time operations, including timezone and timestamp conversions.
 pub use error::PoSQLTimestampError;
@@ -9,6 +9,8 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct PoSQLTimestamp {
     /// The datetime representation in UTC.
+    ///
+    /// Timestamps with different offsets that denote the same instant have the same value here.
     timestamp: DateTime<Utc>,
 
     /// The precision of the datetime value, e.g., seconds, milliseconds.
@@ -20,6 +22,8 @@ pub struct PoSQLTimestamp {
 
 impl PoSQLTimestamp {
     /// Returns the combined date and time with time zone.
+    ///
+    /// This is the instant denoted by the timestamp, i.e. any offset has already been applied.
     #[must_use]
     pub fn timestamp(&self) -> DateTime<Utc> {
         self.timestamp
@@ -189,6 +193,17 @@ mod tests {
         assert_eq!(result.timestamp, expected);
     }
 
+    #[test]
+    fn test_rfc3339_timestamps_with_different_offsets_can_denote_the_same_instant() {
+        let ist = PoSQLTimestamp::try_from("2024-06-20T11:00:00+05:30").unwrap();
+        let pst = PoSQLTimestamp::try_from("2024-06-19T21:30:00-08:00").unwrap();
+        assert_eq!(ist.timezone(), PoSQLTimeZone::new(19_800));
+        assert_eq!(pst.timezone(), PoSQLTimeZone::new(-28_800));
+        let expected = Utc.with_ymd_and_hms(2024, 6, 20, 5, 30, 0).unwrap();
+        assert_eq!(ist.timestamp(), expected);
+        assert_eq!(pst.timestamp(), expected);
+    }
+
     #[test]
     fn test_rfc3339_timestamp_with_utc_designator() {
         let input = "2023-06-26T12:34:56Z";
@@ -16,6 +16,19 @@ pub enum PoSQLTimeUnit {
     Nanosecond,
 }
 
+impl PoSQLTimeUnit {
+    /// The number of units in a second, e.g. 1000 for milliseconds
+    #[must_use]
+    pub fn units_per_second(self) -> i64 {
+        match self {
+            PoSQLTimeUnit::Second => 1,
+            PoSQLTimeUnit::Millisecond => 1_000,
+            PoSQLTimeUnit::Microsecond => 1_000_000,
+            PoSQLTimeUnit::Nanosecond => 1_000_000_000,
+        }
+    }
+}
+
 impl From<PoSQLTimeUnit> for u64 {
     fn from(value: PoSQLTimeUnit) -> u64 {
         match value {
@@ -70,6 +83,14 @@ mod time_unit_tests {
         assert_eq!(PoSQLTimeUnit::try_from("9"), Ok(PoSQLTimeUnit::Nanosecond));
     }
 
+    #[test]
+    fn we_can_get_the_units_per_second_of_each_time_unit() {
+        assert_eq!(PoSQLTimeUnit::Second.units_per_second(), 1);
+        assert_eq!(PoSQLTimeUnit::Millisecond.units_per_second(), 1_000);
+        assert_eq!(PoSQLTimeUnit::Microsecond.units_per_second(), 1_000_000);
+        assert_eq!(PoSQLTimeUnit::Nanosecond.units_per_second(), 1_000_000_000);
+    }
+
     #[test]
     fn test_invalid_precision() {
         let invalid_precisions = [
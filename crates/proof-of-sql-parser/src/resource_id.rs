@@ -12,7 +12,7 @@ use core::{
 use sqlparser::ast::Ident;
 
 /// Unique resource identifier, like `schema.object_name`.
-#[derive(Debug, PartialEq, Eq, Clone, Hash, Copy)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash, Copy)]
 pub struct ResourceId {
     schema: Identifier,
     object_name: Identifier,
@@ -80,6 +80,35 @@ impl ResourceId {
 
         format!("{schema}:{object_name}")
     }
+
+    /// Whether this [`ResourceId`] matches `pattern`, like `schema.object_name`.
+    ///
+    /// Each of the two segments of `pattern` is either an identifier, which must match exactly,
+    /// or ends with a `*`, in which case it matches any identifier starting with what comes before
+    /// the `*`. So `ETHEREUM.*` matches every table in the `ETHEREUM` schema and `*.*` matches
+    /// every table. Like identifiers themselves, the segments are compared case-insensitively and
+    /// may be surrounded by white space.
+    ///
+    /// A `pattern` that doesn't have exactly two segments, or has a `*` anywhere but at the end of
+    /// a segment, matches nothing.
+    #[must_use]
+    pub fn matches(&self, pattern: &str) -> bool {
+        let Some((schema_pattern, object_name_pattern)) = pattern.split_once('.') else {
+            return false;
+        };
+        segment_matches(&self.schema, schema_pattern)
+            && segment_matches(&self.object_name, object_name_pattern)
+    }
+}
+
+/// Whether `identifier` matches a single segment of a [`ResourceId::matches`] pattern.
+fn segment_matches(identifier: &Identifier, pattern: &str) -> bool {
+    let pattern = pattern.trim().to_lowercase();
+    match pattern.strip_suffix('*') {
+        Some(prefix) if !prefix.contains(['*', '.']) => identifier.name().starts_with(prefix),
+        Some(_) => false,
+        None => !pattern.contains(['*', '.']) && identifier.name() == pattern,
+    }
 }
 
 impl Display for ResourceId {
@@ -214,6 +243,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn we_can_match_resource_ids_exactly() {
+        let resource_id = ResourceId::try_new("ethereum", "blocks").unwrap();
+        assert!(resource_id.matches("ethereum.blocks"));
+        assert!(resource_id.matches("ETHEREUM.Blocks"));
+        assert!(resource_id.matches(" ethereum . blocks "));
+    }
+
+    #[test]
+    fn we_can_match_resource_ids_with_a_table_wildcard() {
+        let resource_id = ResourceId::try_new("ethereum", "blocks").unwrap();
+        assert!(resource_id.matches("ETHEREUM.*"));
+        assert!(resource_id.matches("ethereum.bl*"));
+        assert!(resource_id.matches("ethereum.blocks*"));
+        assert!(!resource_id.matches("ethereum.tx*"));
+        assert!(!resource_id.matches("polygon.*"));
+    }
+
+    #[test]
+    fn we_can_match_resource_ids_with_a_schema_wildcard() {
+        let resource_id = ResourceId::try_new("ethereum", "blocks").unwrap();
+        assert!(resource_id.matches("*.blocks"));
+        assert!(resource_id.matches("eth*.blocks"));
+        assert!(!resource_id.matches("poly*.blocks"));
+        assert!(!resource_id.matches("*.transactions"));
+    }
+
+    #[test]
+    fn we_can_match_resource_ids_with_a_full_wildcard() {
+        let resource_id = ResourceId::try_new("ethereum", "blocks").unwrap();
+        assert!(resource_id.matches("*.*"));
+        assert!(resource_id.matches("eth*.b*"));
+    }
+
+    #[test]
+    fn we_cannot_match_resource_ids_with_other_patterns() {
+        let resource_id = ResourceId::try_new("ethereum", "blocks").unwrap();
+        assert!(!resource_id.matches("ethereum.block"));
+        assert!(!resource_id.matches("ethereum"));
+        assert!(!resource_id.matches("*"));
+        assert!(!resource_id.matches(""));
+        assert!(!resource_id.matches("ethereum.blocks.*"));
+        assert!(!resource_id.matches("*thereum.blocks"));
+        assert!(!resource_id.matches("eth*um.blocks"));
+        assert!(!resource_id.matches("ethereum.**"));
+    }
+
     #[test]
     fn invalid_resource_id_parsing_fails() {
         assert!(ResourceId::from_str("GOOD_IDENTIFIER").is_err());
@@ -52,6 +52,7 @@ impl SelectStatement {
                 from,
                 where_expr: _,
                 group_by: _,
+                distinct: _,
             } => convert_table_expr_to_resource_id_vector(&from[..], default_schema),
         }
     }
@@ -2,16 +2,18 @@
 use crate::{
     intermediate_ast::{
         AliasedResultExpr, BinaryOperator as PoSqlBinaryOperator, Expression, Literal,
-        OrderBy as PoSqlOrderBy, OrderByDirection, SelectResultExpr, SetExpression,
-        TableExpression, UnaryOperator as PoSqlUnaryOperator,
+        OrderBy as PoSqlOrderBy, OrderByDirection, SelectResultExpr, SetExpression, SliceValue,
+        StringTransformOperator, TableExpression, UnaryOperator as PoSqlUnaryOperator,
+        WildcardModifiers,
     },
     Identifier, ResourceId, SelectStatement,
 };
-use alloc::{boxed::Box, string::ToString, vec};
+use alloc::{boxed::Box, format, string::ToString, vec};
 use core::fmt::Display;
 use sqlparser::ast::{
-    BinaryOperator, DataType, Expr, Function, FunctionArg, FunctionArgExpr, GroupByExpr, Ident,
-    ObjectName, Offset, OffsetRows, OrderByExpr, Query, Select, SelectItem, SetExpr, TableFactor,
+    BinaryOperator, DataType, Distinct, ExcludeSelectItem, Expr, Function, FunctionArg,
+    FunctionArgExpr, GroupByExpr, Ident, ObjectName, Offset, OffsetRows, OrderByExpr, Query,
+    ReplaceSelectElement, ReplaceSelectItem, Select, SelectItem, SetExpr, TableFactor,
     TableWithJoins, TimezoneInfo, UnaryOperator, Value, WildcardAdditionalOptions,
 };
 
@@ -23,6 +25,17 @@ where
     Expr::Value(Value::Number(val.to_string(), false))
 }
 
+/// Convert a `LIMIT` or `OFFSET` value into a [`Expr`].
+fn slice_value<T>(value: SliceValue<T>) -> Expr
+where
+    T: Display,
+{
+    match value {
+        SliceValue::Literal(val) => number(val),
+        SliceValue::Parameter(index) => Expr::Value(Value::Placeholder(format!("${index}"))),
+    }
+}
+
 /// Convert an [`Identifier`] into a [`Expr`].
 fn id(id: Identifier) -> Expr {
     Expr::Identifier(id.into())
@@ -80,6 +93,10 @@ impl From<Literal> for Expr {
                     value: timestamp.timestamp().to_string(),
                 }
             }
+            Literal::Date(date) => Expr::TypedString {
+                data_type: DataType::Date,
+                value: date.date().to_string(),
+            },
         }
     }
 }
@@ -96,6 +113,7 @@ impl From<PoSqlBinaryOperator> for BinaryOperator {
             PoSqlBinaryOperator::Subtract => BinaryOperator::Minus,
             PoSqlBinaryOperator::Multiply => BinaryOperator::Multiply,
             PoSqlBinaryOperator::Division => BinaryOperator::Divide,
+            PoSqlBinaryOperator::Modulo => BinaryOperator::Modulo,
         }
     }
 }
@@ -147,6 +165,36 @@ impl From<Expression> for Expr {
                 special: false,
                 order_by: vec![],
             }),
+            // sqlparser has a dedicated expression for `TRIM`
+            Expression::StringTransform {
+                op: StringTransformOperator::Trim,
+                expr,
+            } => Expr::Trim {
+                expr: Box::new((*expr).into()),
+                trim_where: None,
+                trim_what: None,
+                trim_characters: None,
+            },
+            Expression::StringTransform { op, expr } => Expr::Function(Function {
+                name: ObjectName(vec![Ident::new(op.to_string())]),
+                args: vec![FunctionArg::Unnamed((*expr).into())],
+                filter: None,
+                null_treatment: None,
+                over: None,
+                distinct: false,
+                special: false,
+                order_by: vec![],
+            }),
+            Expression::Substring {
+                expr,
+                start,
+                length,
+            } => Expr::Substring {
+                expr: Box::new((*expr).into()),
+                substring_from: Some(Box::new(Literal::BigInt(start).into())),
+                substring_for: Some(Box::new(Literal::BigInt(length).into())),
+                special: true,
+            },
         }
     }
 }
@@ -170,6 +218,27 @@ impl From<SelectResultExpr> for SelectItem {
                 opt_rename: None,
                 opt_replace: None,
             }),
+            SelectResultExpr::ALLWithModifiers(WildcardModifiers { exclude, replace }) => {
+                SelectItem::Wildcard(WildcardAdditionalOptions {
+                    opt_exclude: (!exclude.is_empty()).then(|| {
+                        ExcludeSelectItem::Multiple(exclude.into_iter().map(Ident::from).collect())
+                    }),
+                    opt_except: None,
+                    opt_rename: None,
+                    opt_replace: (!replace.is_empty()).then(|| ReplaceSelectItem {
+                        items: replace
+                            .into_iter()
+                            .map(|AliasedResultExpr { expr, alias }| {
+                                Box::new(ReplaceSelectElement {
+                                    expr: (*expr).into(),
+                                    column_name: alias.into(),
+                                    as_keyword: true,
+                                })
+                            })
+                            .collect(),
+                    }),
+                })
+            }
             SelectResultExpr::AliasedResultExpr(AliasedResultExpr { expr, alias }) => {
                 SelectItem::ExprWithAlias {
                     expr: (*expr).into(),
@@ -188,8 +257,9 @@ impl From<SetExpression> for Select {
                 from,
                 where_expr,
                 group_by,
+                distinct,
             } => Select {
-                distinct: None,
+                distinct: distinct.then_some(Distinct::Distinct),
                 top: None,
                 projection: result_exprs.into_iter().map(SelectItem::from).collect(),
                 into: None,
@@ -221,10 +291,13 @@ impl From<SelectStatement> for Query {
             with: None,
             body: Box::new(SetExpr::Select(Box::new((*select.expr).into()))),
             order_by: select.order_by.into_iter().map(OrderByExpr::from).collect(),
-            limit: select.slice.clone().map(|slice| number(slice.number_rows)),
+            limit: select
+                .slice
+                .clone()
+                .map(|slice| slice_value(slice.number_rows)),
             limit_by: vec![],
             offset: select.slice.map(|slice| Offset {
-                value: number(slice.offset_value),
+                value: slice_value(slice.offset_value),
                 rows: OffsetRows::None,
             }),
             fetch: None,
@@ -237,7 +310,11 @@ impl From<SelectStatement> for Query {
 #[cfg(test)]
 mod test {
     use super::*;
-    use sqlparser::{ast::Statement, dialect::PostgreSqlDialect, parser::Parser};
+    use sqlparser::{
+        ast::Statement,
+        dialect::{GenericDialect, PostgreSqlDialect},
+        parser::Parser,
+    };
 
     // Check that the intermediate AST can be converted to the SQL parser AST which should functionally match
     // the direct conversion from the SQL string.
@@ -260,6 +337,10 @@ mod test {
             "select a, b from t limit 10;",
             "select a as a, b as b from t limit 10 offset 0;",
         );
+        check_posql_intermediate_ast_to_sqlparser_equivalence(
+            "select a from t limit $1 offset $2;",
+            "select a as a from t limit $1 offset $2;",
+        );
         check_posql_intermediate_ast_to_sqlparser_equivalence(
             "select timestamp '2024-11-07T04:55:12+00:00' as time from t;",
             "select timestamp(0) '2024-11-07 04:55:12 UTC' as time from t;",
@@ -277,6 +358,16 @@ mod test {
         check_posql_intermediate_ast_to_sqlparser_equivalence(sql, sql);
     }
 
+    // Postgres has no wildcard modifiers, so we compare against a dialect that does.
+    #[test]
+    fn we_can_convert_posql_wildcard_modifiers_to_sqlparser() {
+        let sql = "SELECT * EXCLUDE (a, b) REPLACE (c + 1 AS c) FROM t";
+        let posql_ast = sql.parse::<SelectStatement>().unwrap();
+        let converted_sqlparser_ast = &Statement::Query(Box::new(Query::from(posql_ast)));
+        let direct_sqlparser_ast = &Parser::parse_sql(&GenericDialect {}, sql).unwrap()[0];
+        assert_eq!(converted_sqlparser_ast, direct_sqlparser_ast);
+    }
+
     #[test]
     fn we_can_convert_posql_intermediate_ast_to_sqlparser() {
         check_posql_intermediate_ast_to_sqlparser_equality("SELECT * FROM t");
@@ -299,5 +390,14 @@ mod test {
         check_posql_intermediate_ast_to_sqlparser_equality(
             "select cat as cat, sum(a) as s, count(*) as rows from tab where d = 'Space and Time' group by cat;",
         );
+        check_posql_intermediate_ast_to_sqlparser_equality(
+            "select upper(a) as a, lower(trim(b)) as b from tab;",
+        );
+        check_posql_intermediate_ast_to_sqlparser_equality(
+            "select substring(a, 2, 3) as a from tab;",
+        );
+        check_posql_intermediate_ast_to_sqlparser_equality(
+            "select distinct a as a, b as c from tab where d = 1;",
+        );
     }
 }
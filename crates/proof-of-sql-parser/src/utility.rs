@@ -1,7 +1,8 @@
 use crate::{
     intermediate_ast::{
         AggregationOperator, AliasedResultExpr, BinaryOperator, Expression, Literal, OrderBy,
-        OrderByDirection, SelectResultExpr, SetExpression, Slice, TableExpression, UnaryOperator,
+        OrderByDirection, SelectResultExpr, SetExpression, Slice, SliceValue,
+        StringTransformOperator, TableExpression, UnaryOperator, WildcardModifiers,
     },
     Identifier, SelectStatement,
 };
@@ -116,6 +117,16 @@ pub fn div(left: Box<Expression>, right: Box<Expression>) -> Box<Expression> {
     })
 }
 
+/// Construct a new boxed `Expression` A % B
+#[must_use]
+pub fn modulo(left: Box<Expression>, right: Box<Expression>) -> Box<Expression> {
+    Box::new(Expression::Binary {
+        op: BinaryOperator::Modulo,
+        left,
+        right,
+    })
+}
+
 /// Get table from schema and name.
 ///
 /// If the schema is `None`, the table is assumed to be in the default schema.
@@ -172,6 +183,52 @@ pub fn max(expr: Box<Expression>) -> Box<Expression> {
     })
 }
 
+/// Compute the population variance of an expression
+#[must_use]
+pub fn var_pop(expr: Box<Expression>) -> Box<Expression> {
+    Box::new(Expression::Aggregation {
+        op: AggregationOperator::VarPop,
+        expr,
+    })
+}
+
+/// Remove the leading and trailing spaces of a string expression
+#[must_use]
+pub fn trim(expr: Box<Expression>) -> Box<Expression> {
+    Box::new(Expression::StringTransform {
+        op: StringTransformOperator::Trim,
+        expr,
+    })
+}
+
+/// Convert a string expression to upper case
+#[must_use]
+pub fn upper(expr: Box<Expression>) -> Box<Expression> {
+    Box::new(Expression::StringTransform {
+        op: StringTransformOperator::Upper,
+        expr,
+    })
+}
+
+/// Convert a string expression to lower case
+#[must_use]
+pub fn lower(expr: Box<Expression>) -> Box<Expression> {
+    Box::new(Expression::StringTransform {
+        op: StringTransformOperator::Lower,
+        expr,
+    })
+}
+
+/// Take the `length` characters of a string expression starting at the 1-based position `start`
+#[must_use]
+pub fn substring(expr: Box<Expression>, start: i64, length: i64) -> Box<Expression> {
+    Box::new(Expression::Substring {
+        expr,
+        start,
+        length,
+    })
+}
+
 /// Count the amount of non-null entries of expression
 #[must_use]
 pub fn count(expr: Box<Expression>) -> Box<Expression> {
@@ -206,6 +263,22 @@ pub fn col_res_all() -> SelectResultExpr {
     SelectResultExpr::ALL
 }
 
+/// Select all columns from a table except and replacing some i.e. SELECT * EXCLUDE (COL1) REPLACE (EXPR AS COL2)
+///
+/// # Panics
+///
+/// This function will panic if the `exclude` names cannot be parsed as valid [Identifier]s.
+#[must_use]
+pub fn col_res_all_with_modifiers(
+    exclude: &[&str],
+    replace: Vec<AliasedResultExpr>,
+) -> SelectResultExpr {
+    SelectResultExpr::ALLWithModifiers(WildcardModifiers {
+        exclude: exclude.iter().map(|name| name.parse().unwrap()).collect(),
+        replace,
+    })
+}
+
 /// Select one column from a table and give it an alias i.e. SELECT COL AS ALIAS
 ///
 /// # Panics
@@ -251,6 +324,19 @@ pub fn max_res(expr: Box<Expression>, alias: &str) -> SelectResultExpr {
     })
 }
 
+/// Compute the population variance of an expression and give it an alias i.e. `SELECT VAR_POP(EXPR) AS ALIAS`
+///
+/// # Panics
+///
+/// This function will panic if the `alias` cannot be parsed.
+#[must_use]
+pub fn var_pop_res(expr: Box<Expression>, alias: &str) -> SelectResultExpr {
+    SelectResultExpr::AliasedResultExpr(AliasedResultExpr {
+        expr: var_pop(expr),
+        alias: alias.parse().unwrap(),
+    })
+}
+
 /// Compute the sum of an expression and give it an alias i.e. SELECT SUM(EXPR) AS ALIAS
 ///
 /// # Panics
@@ -307,6 +393,7 @@ pub fn query(
         from: vec![tab],
         where_expr: Some(where_expr),
         group_by,
+        distinct: false,
     })
 }
 
@@ -324,9 +411,18 @@ pub fn query_all(
         from: vec![tab],
         where_expr: None,
         group_by,
+        distinct: false,
     })
 }
 
+/// Turn a `SetExpression` of the kind SELECT ... into SELECT DISTINCT ...
+#[must_use]
+pub fn distinct(mut set_expr: Box<SetExpression>) -> Box<SetExpression> {
+    let SetExpression::Query { distinct, .. } = set_expr.as_mut();
+    *distinct = true;
+    set_expr
+}
+
 /// Generate a query of the kind SELECT ... ORDER BY ... [LIMIT ... OFFSET ...]
 ///
 /// Note that `expr` is a boxed `SetExpression`
@@ -376,6 +472,16 @@ pub fn orders(ids: &[&str], directions: &[OrderByDirection]) -> Vec<OrderBy> {
 /// Slice a query result using `LIMIT` and `OFFSET` clauses i.e. LIMIT N OFFSET M
 #[must_use]
 pub fn slice(number_rows: u64, offset_value: i64) -> Option<Slice> {
+    slice_values(
+        SliceValue::Literal(number_rows),
+        SliceValue::Literal(offset_value),
+    )
+}
+
+/// Slice a query result using `LIMIT` and `OFFSET` clauses that may be query parameters
+/// i.e. LIMIT $1 OFFSET $2
+#[must_use]
+pub fn slice_values(number_rows: SliceValue<u64>, offset_value: SliceValue<i64>) -> Option<Slice> {
     Some(Slice {
         number_rows,
         offset_value,
@@ -0,0 +1,96 @@
+//! # Running the Benchmark
+//!
+//! To compare the commitment evaluation proofs of every scheme, use the following command:
+//!
+//! ```bash
+//! cargo bench -p proof-of-sql --bench bench_evaluation_proofs
+//! ```
+//!
+//! `InnerProductProof` is only benchmarked when the `blitzar` feature is enabled.
+#![allow(missing_docs, clippy::missing_docs_in_private_items)]
+use ark_std::{test_rng, UniformRand};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use proof_of_sql::{
+    base::commitment::CommitmentEvaluationProof,
+    proof_primitive::{
+        dory::{
+            DoryEvaluationProof, DoryProverPublicSetup, DoryVerifierPublicSetup,
+            DynamicDoryEvaluationProof, ProverSetup, PublicParameters, VerifierSetup,
+        },
+        evaluation_proof::{commit, prove, verify},
+    },
+};
+
+/// The lengths of the committed vectors
+const LENGTHS: [usize; 3] = [1 << 6, 1 << 10, 1 << 14];
+/// The `max_nu` of the public parameters, which is large enough for every length with Dynamic Dory
+const MAX_NU: usize = 8;
+/// The `sigma` of the Dory setup, which supports lengths up to `2^(2 * DORY_SIGMA)`
+const DORY_SIGMA: usize = 7;
+
+/// Bench proving and verifying the evaluation of a random vector at a random point for every
+/// length in [`LENGTHS`].
+///
+/// # Panics
+///
+/// Will panic if a valid evaluation proof does not verify.
+fn bench_evaluation_proof<CP: CommitmentEvaluationProof>(
+    c: &mut Criterion,
+    prover_setup: &CP::ProverPublicSetup<'_>,
+    verifier_setup: &CP::VerifierPublicSetup<'_>,
+) {
+    let mut group = c.benchmark_group(CP::SCHEME_ID.to_string());
+    let mut rng = test_rng();
+    for length in LENGTHS {
+        let nu = length.trailing_zeros() as usize;
+        let a: Vec<_> = core::iter::repeat_with(|| CP::Scalar::rand(&mut rng))
+            .take(length)
+            .collect();
+        let point: Vec<_> = core::iter::repeat_with(|| CP::Scalar::rand(&mut rng))
+            .take(nu)
+            .collect();
+        let commitment = commit::<CP>(&a, 0, prover_setup);
+        let (proof, evaluation) = prove::<CP>(&a, &point, 0, prover_setup);
+
+        group.bench_function(BenchmarkId::new("prove", length), |b| {
+            b.iter(|| prove::<CP>(black_box(&a), black_box(&point), 0, prover_setup));
+        });
+        group.bench_function(BenchmarkId::new("verify", length), |b| {
+            b.iter(|| {
+                assert!(verify(
+                    black_box(&proof),
+                    &commitment,
+                    &evaluation,
+                    black_box(&point),
+                    0,
+                    length,
+                    verifier_setup,
+                )
+                .is_ok());
+            });
+        });
+    }
+    group.finish();
+}
+
+fn bench_evaluation_proofs(c: &mut Criterion) {
+    let public_parameters = PublicParameters::test_rand(MAX_NU, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+
+    bench_evaluation_proof::<DoryEvaluationProof>(
+        c,
+        &DoryProverPublicSetup::new(&prover_setup, DORY_SIGMA),
+        &DoryVerifierPublicSetup::new(&verifier_setup, DORY_SIGMA),
+    );
+    bench_evaluation_proof::<DynamicDoryEvaluationProof>(c, &&prover_setup, &&verifier_setup);
+    #[cfg(feature = "blitzar")]
+    bench_evaluation_proof::<blitzar::proof::InnerProductProof>(c, &(), &());
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = bench_evaluation_proofs
+}
+criterion_main!(benches);
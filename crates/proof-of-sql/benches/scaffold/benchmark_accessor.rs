@@ -101,4 +101,8 @@ impl<C: Commitment> SchemaAccessor for BenchmarkAccessor<'_, C> {
     fn lookup_schema(&self, table_ref: TableRef) -> Vec<(Ident, ColumnType)> {
         self.table_schemas.get(&table_ref).unwrap().clone()
     }
+
+    fn lookup_tables(&self) -> Vec<TableRef> {
+        self.table_schemas.keys().copied().collect()
+    }
 }
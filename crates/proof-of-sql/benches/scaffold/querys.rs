@@ -139,6 +139,29 @@ const COMPLEX_CONDITION_COLUMNS: &[(&str, ColumnType, OptionalRandBound)] = &[
     ),
     ("d", ColumnType::VarChar, None),
 ];
+const SUM_OF_PRODUCTS_TITLE: &str = "Sum Of Products";
+const SUM_OF_PRODUCTS_SQL: &str =
+    "SELECT SUM(a * b * c) as s, COUNT(*) as n FROM table WHERE d = 'xyz'";
+const UNFUSED_SUM_OF_PRODUCTS_TITLE: &str = "Unfused Sum Of Products";
+const UNFUSED_SUM_OF_PRODUCTS_SQL: &str = "SELECT SUM(a * b * c) as s FROM table WHERE d = 'xyz'";
+const SUM_OF_PRODUCTS_COLUMNS: &[(&str, ColumnType, OptionalRandBound)] = &[
+    (
+        "a",
+        ColumnType::BigInt,
+        Some(|size| (size / 10).max(10) as i64),
+    ),
+    (
+        "b",
+        ColumnType::BigInt,
+        Some(|size| (size / 10).max(10) as i64),
+    ),
+    (
+        "c",
+        ColumnType::Int,
+        Some(|size| (size / 10).max(10) as i64),
+    ),
+    ("d", ColumnType::VarChar, None),
+];
 
 #[allow(clippy::type_complexity)]
 pub const QUERIES: &[(&str, &str, &[(&str, ColumnType, OptionalRandBound)])] = &[
@@ -170,4 +193,14 @@ pub const QUERIES: &[(&str, &str, &[(&str, ColumnType, OptionalRandBound)])] = &
         COMPLEX_CONDITION_SQL,
         COMPLEX_CONDITION_COLUMNS,
     ),
+    (
+        SUM_OF_PRODUCTS_TITLE,
+        SUM_OF_PRODUCTS_SQL,
+        SUM_OF_PRODUCTS_COLUMNS,
+    ),
+    (
+        UNFUSED_SUM_OF_PRODUCTS_TITLE,
+        UNFUSED_SUM_OF_PRODUCTS_SQL,
+        SUM_OF_PRODUCTS_COLUMNS,
+    ),
 ];
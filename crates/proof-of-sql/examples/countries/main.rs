@@ -116,7 +116,7 @@ fn main() {
     );
 
     prove_and_verify_query(
-        "SELECT SUM(gdp) AS total_market_cap FROM countries WHERE country = 'China' OR country = 'India'",
+        "SELECT SUM(gdp) AS total_market_cap FROM countries WHERE country IN ('China', 'India')",
         &accessor,
         &prover_setup,
         &verifier_setup,
@@ -0,0 +1,173 @@
+use super::{
+    commit_accessor::CommitAccessor,
+    csv_accessor::{record_batch_hash, CsvDataAccessor},
+    try_append_batch,
+};
+use arrow::{
+    array::{Int64Array, StringArray},
+    datatypes::{DataType, Field, Schema},
+    record_batch::RecordBatch,
+};
+use proof_of_sql::{
+    base::{commitment::TableCommitment, database::TableRef},
+    proof_primitive::dory::{DynamicDoryCommitment, ProverSetup, PublicParameters},
+};
+use std::{fs, path::Path, sync::Arc};
+
+fn schema() -> Arc<Schema> {
+    Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", DataType::Utf8, false),
+    ]))
+}
+
+pub(super) fn batch(a: &[i64], b: &[&str]) -> RecordBatch {
+    RecordBatch::try_new(
+        schema(),
+        vec![
+            Arc::new(Int64Array::from(a.to_vec())),
+            Arc::new(StringArray::from(b.to_vec())),
+        ],
+    )
+    .unwrap()
+}
+
+pub(super) fn create_table(path: &Path, table_ref: &TableRef, prover_setup: &ProverSetup) {
+    let empty_batch = RecordBatch::new_empty(schema());
+    let table_commitment = TableCommitment::<DynamicDoryCommitment>::try_from_record_batch(
+        &empty_batch,
+        &prover_setup,
+    )
+    .unwrap();
+    CommitAccessor::new(path.to_path_buf())
+        .write_commit(table_ref, &table_commitment)
+        .unwrap();
+    CsvDataAccessor::new(path.to_path_buf())
+        .write_table(table_ref, &empty_batch)
+        .unwrap();
+}
+
+/// Appends `batch` the way `posql_db append` does, with freshly loaded accessors.
+pub(super) fn append(
+    path: &Path,
+    table_ref: &TableRef,
+    batch: &RecordBatch,
+    key: &str,
+    prover_setup: &ProverSetup,
+) -> bool {
+    let mut commit_accessor = CommitAccessor::<DynamicDoryCommitment>::new(path.to_path_buf());
+    commit_accessor.load_commit(*table_ref).unwrap();
+    let csv_accessor = CsvDataAccessor::new(path.to_path_buf());
+    try_append_batch(
+        &mut commit_accessor,
+        &csv_accessor,
+        table_ref,
+        batch,
+        key,
+        prover_setup,
+    )
+    .unwrap()
+}
+
+pub(super) fn load_commit(
+    path: &Path,
+    table_ref: &TableRef,
+) -> TableCommitment<DynamicDoryCommitment> {
+    let mut commit_accessor = CommitAccessor::<DynamicDoryCommitment>::new(path.to_path_buf());
+    commit_accessor.load_commit(*table_ref).unwrap();
+    commit_accessor.get_commit(table_ref).unwrap().clone()
+}
+
+pub(super) fn public_parameters() -> PublicParameters {
+    let mut rng = <ark_std::rand::rngs::StdRng as ark_std::rand::SeedableRng>::from_seed([0u8; 32]);
+    PublicParameters::rand(4, &mut rng)
+}
+
+#[test]
+fn we_can_append_the_same_batch_twice_and_get_the_same_result_as_appending_once() {
+    let public_parameters = public_parameters();
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let table_ref: TableRef = "sxt.table".parse().unwrap();
+    let batch = batch(&[1, 2, 3], &["x", "y", "z"]);
+    let key = record_batch_hash(&batch).unwrap();
+
+    let once = tempfile::tempdir().unwrap();
+    create_table(once.path(), &table_ref, &prover_setup);
+    assert!(append(once.path(), &table_ref, &batch, &key, &prover_setup));
+
+    let twice = tempfile::tempdir().unwrap();
+    create_table(twice.path(), &table_ref, &prover_setup);
+    assert!(append(
+        twice.path(),
+        &table_ref,
+        &batch,
+        &key,
+        &prover_setup
+    ));
+    assert!(!append(
+        twice.path(),
+        &table_ref,
+        &batch,
+        &key,
+        &prover_setup
+    ));
+
+    let commit = load_commit(twice.path(), &table_ref);
+    assert_eq!(commit, load_commit(once.path(), &table_ref));
+    assert_eq!(commit.num_rows(), 3);
+    assert_eq!(
+        fs::read(twice.path().join("sxt.table.csv")).unwrap(),
+        fs::read(once.path().join("sxt.table.csv")).unwrap()
+    );
+}
+
+#[test]
+fn we_can_append_batches_with_different_idempotency_keys() {
+    let public_parameters = public_parameters();
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let table_ref: TableRef = "sxt.table".parse().unwrap();
+    let first_batch = batch(&[1, 2], &["x", "y"]);
+    let second_batch = batch(&[3], &["z"]);
+    let first_key = record_batch_hash(&first_batch).unwrap();
+    let second_key = record_batch_hash(&second_batch).unwrap();
+    assert_ne!(first_key, second_key);
+    assert_eq!(
+        first_key,
+        record_batch_hash(&batch(&[1, 2], &["x", "y"])).unwrap()
+    );
+
+    let dir = tempfile::tempdir().unwrap();
+    create_table(dir.path(), &table_ref, &prover_setup);
+    assert!(append(
+        dir.path(),
+        &table_ref,
+        &first_batch,
+        &first_key,
+        &prover_setup
+    ));
+    assert!(append(
+        dir.path(),
+        &table_ref,
+        &second_batch,
+        &second_key,
+        &prover_setup
+    ));
+    assert!(!append(
+        dir.path(),
+        &table_ref,
+        &first_batch,
+        &first_key,
+        &prover_setup
+    ));
+    assert_eq!(load_commit(dir.path(), &table_ref).num_rows(), 3);
+
+    // The same rows are appended again under an explicit key that has not been used yet
+    assert!(append(
+        dir.path(),
+        &table_ref,
+        &second_batch,
+        "retry-of-second-batch",
+        &prover_setup
+    ));
+    assert_eq!(load_commit(dir.path(), &table_ref).num_rows(), 4);
+}
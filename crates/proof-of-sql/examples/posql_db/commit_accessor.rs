@@ -1,40 +1,101 @@
 use core::error::Error;
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use proof_of_sql::base::{
     commitment::{Commitment, QueryCommitments, TableCommitment},
     database::{CommitmentAccessor, MetadataAccessor, SchemaAccessor, TableRef},
 };
 use serde::{Deserialize, Serialize};
-use std::{fs, path::PathBuf};
+use std::{
+    fs::{self, OpenOptions},
+    io::{ErrorKind, Write},
+    path::PathBuf,
+};
 pub struct CommitAccessor<C: Commitment> {
     base_path: PathBuf,
     inner: QueryCommitments<C>,
+    /// The idempotency keys of the batches that have been appended to each table.
+    applied_keys: IndexMap<TableRef, IndexSet<String>>,
 }
 impl<C: Commitment + Serialize + for<'a> Deserialize<'a>> CommitAccessor<C> {
     pub fn new(base_path: PathBuf) -> Self {
         Self {
             base_path,
             inner: IndexMap::default(),
+            applied_keys: IndexMap::default(),
         }
     }
+    /// Writes the commitment of a table.
+    ///
+    /// The commitment is written to a temporary file first, so that the commitment file is
+    /// replaced atomically and never left partially written.
     pub fn write_commit(
         &self,
         table_ref: &TableRef,
         commit: &TableCommitment<C>,
     ) -> Result<(), Box<dyn Error>> {
         let path = self.base_path.join(format!("{table_ref}.commit"));
-        fs::write(path, postcard::to_allocvec(commit)?)?;
+        let tmp_path = self.base_path.join(format!("{table_ref}.commit.tmp"));
+        fs::write(&tmp_path, postcard::to_allocvec(commit)?)?;
+        fs::rename(tmp_path, path)?;
         Ok(())
     }
+    /// Loads the commitment of a table, along with the idempotency keys of the batches that have
+    /// been appended to it.
+    ///
+    /// A table without a keys file has no batches recorded yet.
     pub fn load_commit(&mut self, table_ref: TableRef) -> Result<(), Box<dyn Error>> {
         let path = self.base_path.join(format!("{table_ref}.commit"));
         let commit = postcard::from_bytes(&fs::read(path)?)?;
         self.inner.insert(table_ref, commit);
+        let keys = match fs::read_to_string(self.get_keys_path(&table_ref)) {
+            Ok(keys) => keys.lines().map(String::from).collect(),
+            Err(error) if error.kind() == ErrorKind::NotFound => IndexSet::default(),
+            Err(error) => return Err(error.into()),
+        };
+        self.applied_keys.insert(table_ref, keys);
         Ok(())
     }
     pub fn get_commit(&self, table_ref: &TableRef) -> Option<&TableCommitment<C>> {
         self.inner.get(table_ref)
     }
+    fn get_keys_path(&self, table_ref: &TableRef) -> PathBuf {
+        self.base_path.join(format!("{table_ref}.keys"))
+    }
+    /// Returns whether a batch with the idempotency key `key` has been appended to the table.
+    pub fn is_applied(&self, table_ref: &TableRef, key: &str) -> bool {
+        self.applied_keys
+            .get(table_ref)
+            .is_some_and(|keys| keys.contains(key))
+    }
+    /// Records that a batch with the idempotency key `key` has been appended to the table.
+    ///
+    /// This should be called once the data and the commitment of the batch have been written.
+    pub fn record_applied(
+        &mut self,
+        table_ref: &TableRef,
+        key: &str,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.get_keys_path(table_ref))?;
+        writeln!(file, "{key}")?;
+        self.applied_keys
+            .entry(*table_ref)
+            .or_default()
+            .insert(key.to_string());
+        Ok(())
+    }
+    /// Forgets the idempotency keys of all batches that have been appended to the table.
+    pub fn clear_applied(&mut self, table_ref: &TableRef) -> Result<(), Box<dyn Error>> {
+        match fs::remove_file(self.get_keys_path(table_ref)) {
+            Ok(()) => {}
+            Err(error) if error.kind() == ErrorKind::NotFound => {}
+            Err(error) => return Err(error.into()),
+        }
+        self.applied_keys.shift_remove(table_ref);
+        Ok(())
+    }
 }
 
 impl<C: Commitment> CommitmentAccessor<C> for CommitAccessor<C> {
@@ -69,4 +130,8 @@ impl<C: Commitment> SchemaAccessor for CommitAccessor<C> {
     )> {
         self.inner.lookup_schema(table_ref)
     }
+
+    fn lookup_tables(&self) -> Vec<proof_of_sql::base::database::TableRef> {
+        self.inner.lookup_tables()
+    }
 }
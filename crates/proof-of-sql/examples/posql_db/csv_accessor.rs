@@ -7,14 +7,18 @@ use proof_of_sql::base::{
     scalar::Scalar,
 };
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     path::{Path, PathBuf},
     sync::Arc,
 };
 
+/// Writes `batch` to a temporary file first, so that the csv file is replaced atomically.
 fn write_record_batch_to_csv(batch: &RecordBatch, path: &Path) -> Result<(), Box<dyn Error>> {
-    let mut writer = WriterBuilder::new().build(File::create(path)?);
+    let tmp_path = path.with_extension("csv.tmp");
+    let mut writer = WriterBuilder::new().build(File::create(&tmp_path)?);
     writer.write(batch)?;
+    drop(writer);
+    fs::rename(tmp_path, path)?;
     Ok(())
 }
 pub fn read_record_batch_from_csv(
@@ -27,6 +31,17 @@ pub fn read_record_batch_from_csv(
     let batch = csv.next().ok_or("Empty table.")??;
     Ok(batch)
 }
+/// Computes the default idempotency key of a batch, which is the blake3 hash of its csv rows.
+///
+/// Appending two batches with the same rows is therefore only done once.
+pub fn record_batch_hash(batch: &RecordBatch) -> Result<String, Box<dyn Error>> {
+    let mut rows = Vec::new();
+    WriterBuilder::new()
+        .with_header(false)
+        .build(&mut rows)
+        .write(batch)?;
+    Ok(blake3::hash(&rows).to_hex().to_string())
+}
 fn append_record_batch_to_csv(batch: &RecordBatch, path: &Path) -> Result<(), Box<dyn Error>> {
     let mut writer = WriterBuilder::new()
         .with_header(false)
@@ -109,4 +124,7 @@ impl SchemaAccessor for CsvDataAccessor {
     )> {
         self.inner.lookup_schema(table_ref)
     }
+    fn lookup_tables(&self) -> Vec<TableRef> {
+        self.inner.lookup_tables()
+    }
 }
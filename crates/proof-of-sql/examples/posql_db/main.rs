@@ -1,10 +1,14 @@
 #![doc = include_str!("README.md")]
+#[cfg(test)]
+mod append_test;
 /// TODO: add docs
 mod commit_accessor;
 /// TODO: add docs
 mod csv_accessor;
 /// TODO: add docs
 mod record_batch_accessor;
+#[cfg(test)]
+mod truncate_test;
 
 use arrow::{
     datatypes::{DataType, Field, Schema},
@@ -12,7 +16,8 @@ use arrow::{
 };
 use clap::{arg, Parser, Subcommand, ValueEnum};
 use commit_accessor::CommitAccessor;
-use csv_accessor::{read_record_batch_from_csv, CsvDataAccessor};
+use core::error::Error;
+use csv_accessor::{read_record_batch_from_csv, record_batch_hash, CsvDataAccessor};
 use itertools::Itertools;
 use proof_of_sql::{
     base::{
@@ -94,6 +99,20 @@ enum Commands {
         /// The file name of the csv file to append.
         #[arg(short, long)]
         file: PathBuf,
+        /// The idempotency key of the batch. A batch whose key has already been appended is
+        /// skipped, so that retrying an append never appends the batch twice. Defaults to a hash
+        /// of the rows of the batch.
+        #[arg(short, long)]
+        key: Option<String>,
+    },
+    /// Removes all rows of an existing table, keeping its schema, and resets the commitment of that
+    /// table to the commitment of an empty table.
+    ///
+    /// Example: `posql_db truncate -t sxt.table`
+    Truncate {
+        /// The table to truncate. The table name should be in the format `schema.table`.
+        #[arg(short, long)]
+        table: TableRef,
     },
     /// Proves a query and writes the proof to a file.
     ///
@@ -119,6 +138,61 @@ enum Commands {
     },
 }
 
+/// Appends `batch` to a table whose commitment is loaded in `commit_accessor`, unless a batch with
+/// the idempotency key `key` has already been appended to it.
+///
+/// Returns whether the batch was appended. The key is only recorded once both the csv file and the
+/// commitment have been written.
+fn try_append_batch(
+    commit_accessor: &mut CommitAccessor<DynamicDoryCommitment>,
+    csv_accessor: &CsvDataAccessor,
+    table_ref: &TableRef,
+    batch: &RecordBatch,
+    key: &str,
+    prover_setup: &ProverSetup,
+) -> Result<bool, Box<dyn Error>> {
+    if commit_accessor.is_applied(table_ref, key) {
+        return Ok(false);
+    }
+    let mut table_commitment = commit_accessor
+        .get_commit(table_ref)
+        .ok_or("Table commitment not loaded.")?
+        .clone();
+    csv_accessor.append_batch(table_ref, batch)?;
+    let timer = start_timer("Updating Commitment");
+    table_commitment.try_append_record_batch(batch, &prover_setup)?;
+    end_timer(timer);
+    commit_accessor.write_commit(table_ref, &table_commitment)?;
+    commit_accessor.record_applied(table_ref, key)?;
+    Ok(true)
+}
+
+/// Resets a table whose commitment is loaded in `commit_accessor` to zero rows, keeping its schema.
+///
+/// The csv file and the commitment become those of a freshly created table, and the idempotency
+/// keys of the appended batches are forgotten. Each file is replaced atomically, and a truncate
+/// that fails part way can simply be retried.
+fn truncate_table(
+    commit_accessor: &mut CommitAccessor<DynamicDoryCommitment>,
+    csv_accessor: &CsvDataAccessor,
+    table_ref: &TableRef,
+    prover_setup: &ProverSetup,
+) -> Result<(), Box<dyn Error>> {
+    let schema = Schema::new(
+        commit_accessor
+            .lookup_schema(*table_ref)
+            .iter()
+            .map(|(i, t)| Field::new(i.value.as_str(), t.into(), false))
+            .collect::<Vec<_>>(),
+    );
+    let batch = RecordBatch::new_empty(Arc::new(schema));
+    let table_commitment = TableCommitment::try_from_record_batch(&batch, &prover_setup)?;
+    csv_accessor.write_table(table_ref, &batch)?;
+    commit_accessor.clear_applied(table_ref)?;
+    commit_accessor.write_commit(table_ref, &table_commitment)?;
+    Ok(())
+}
+
 /// # Panics
 ///
 /// Will panic if the call to `stdout().flush()` fails, indicating that the
@@ -194,6 +268,7 @@ fn main() {
         Commands::Append {
             table: table_name,
             file: file_path,
+            key,
         } => {
             let mut commit_accessor =
                 CommitAccessor::<DynamicDoryCommitment>::new(PathBuf::from(args.path.clone()));
@@ -201,7 +276,6 @@ fn main() {
             commit_accessor
                 .load_commit(table_name)
                 .expect("Failed to load commit");
-            let mut table_commitment = commit_accessor.get_commit(&table_name).unwrap().clone();
             let schema = Schema::new(
                 commit_accessor
                     .lookup_schema(table_name)
@@ -211,17 +285,31 @@ fn main() {
             );
             let append_batch =
                 read_record_batch_from_csv(schema, &file_path).expect("Failed to read csv file.");
-            csv_accessor
-                .append_batch(&table_name, &append_batch)
-                .expect("Failed to write batch");
-            let timer = start_timer("Updating Commitment");
-            table_commitment
-                .try_append_record_batch(&append_batch, &&prover_setup)
-                .expect("Failed to append batch");
-            end_timer(timer);
+            let key = key.unwrap_or_else(|| {
+                record_batch_hash(&append_batch).expect("Failed to hash csv file.")
+            });
+            let appended = try_append_batch(
+                &mut commit_accessor,
+                &csv_accessor,
+                &table_name,
+                &append_batch,
+                &key,
+                &prover_setup,
+            )
+            .expect("Failed to append batch");
+            if !appended {
+                println!("Skipped batch {key}, which has already been appended to {table_name}.");
+            }
+        }
+        Commands::Truncate { table } => {
+            let mut commit_accessor =
+                CommitAccessor::<DynamicDoryCommitment>::new(PathBuf::from(args.path.clone()));
+            let csv_accessor = CsvDataAccessor::new(PathBuf::from(args.path));
             commit_accessor
-                .write_commit(&table_name, &table_commitment)
-                .expect("Failed to write commit");
+                .load_commit(table)
+                .expect("Failed to load commit");
+            truncate_table(&mut commit_accessor, &csv_accessor, &table, &prover_setup)
+                .expect("Failed to truncate table");
         }
         Commands::Prove { query, file } => {
             let mut commit_accessor =
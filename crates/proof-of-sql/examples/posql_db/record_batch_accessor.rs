@@ -90,4 +90,8 @@ impl SchemaAccessor for RecordBatchAccessor {
             })
             .collect()
     }
+
+    fn lookup_tables(&self) -> Vec<TableRef> {
+        self.tables.keys().copied().collect()
+    }
 }
@@ -0,0 +1,127 @@
+use super::{
+    append_test::{append, batch, create_table, load_commit, public_parameters},
+    commit_accessor::CommitAccessor,
+    csv_accessor::{record_batch_hash, CsvDataAccessor},
+    truncate_table,
+};
+use proof_of_sql::{
+    base::database::TableRef,
+    proof_primitive::dory::{DynamicDoryCommitment, ProverSetup},
+};
+use std::{fs, path::Path};
+
+/// Truncates the table the way `posql_db truncate` does, with freshly loaded accessors.
+fn truncate(path: &Path, table_ref: &TableRef, prover_setup: &ProverSetup) {
+    let mut commit_accessor = CommitAccessor::<DynamicDoryCommitment>::new(path.to_path_buf());
+    commit_accessor.load_commit(*table_ref).unwrap();
+    let csv_accessor = CsvDataAccessor::new(path.to_path_buf());
+    truncate_table(&mut commit_accessor, &csv_accessor, table_ref, prover_setup).unwrap();
+}
+
+#[test]
+fn we_can_truncate_a_table_and_get_the_same_result_as_creating_it() {
+    let public_parameters = public_parameters();
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let table_ref: TableRef = "sxt.table".parse().unwrap();
+
+    let created = tempfile::tempdir().unwrap();
+    create_table(created.path(), &table_ref, &prover_setup);
+
+    let truncated = tempfile::tempdir().unwrap();
+    create_table(truncated.path(), &table_ref, &prover_setup);
+    let batch = batch(&[1, 2, 3], &["x", "y", "z"]);
+    let key = record_batch_hash(&batch).unwrap();
+    assert!(append(
+        truncated.path(),
+        &table_ref,
+        &batch,
+        &key,
+        &prover_setup
+    ));
+    truncate(truncated.path(), &table_ref, &prover_setup);
+
+    let commit = load_commit(truncated.path(), &table_ref);
+    assert_eq!(commit, load_commit(created.path(), &table_ref));
+    assert_eq!(commit.num_rows(), 0);
+    assert_eq!(
+        fs::read(truncated.path().join("sxt.table.csv")).unwrap(),
+        fs::read(created.path().join("sxt.table.csv")).unwrap()
+    );
+    assert!(!truncated.path().join("sxt.table.keys").exists());
+
+    // Truncating an empty table changes nothing
+    truncate(truncated.path(), &table_ref, &prover_setup);
+    assert_eq!(
+        load_commit(truncated.path(), &table_ref),
+        load_commit(created.path(), &table_ref)
+    );
+}
+
+#[test]
+fn we_can_append_to_a_truncated_table() {
+    let public_parameters = public_parameters();
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let table_ref: TableRef = "sxt.table".parse().unwrap();
+    let first_batch = batch(&[1, 2], &["x", "y"]);
+    let second_batch = batch(&[3], &["z"]);
+    let first_key = record_batch_hash(&first_batch).unwrap();
+    let second_key = record_batch_hash(&second_batch).unwrap();
+
+    let expected = tempfile::tempdir().unwrap();
+    create_table(expected.path(), &table_ref, &prover_setup);
+    assert!(append(
+        expected.path(),
+        &table_ref,
+        &first_batch,
+        &first_key,
+        &prover_setup
+    ));
+    assert!(append(
+        expected.path(),
+        &table_ref,
+        &second_batch,
+        &second_key,
+        &prover_setup
+    ));
+
+    let dir = tempfile::tempdir().unwrap();
+    create_table(dir.path(), &table_ref, &prover_setup);
+    assert!(append(
+        dir.path(),
+        &table_ref,
+        &second_batch,
+        &second_key,
+        &prover_setup
+    ));
+    truncate(dir.path(), &table_ref, &prover_setup);
+    // The keys of the batches appended before the truncate are forgotten
+    assert!(append(
+        dir.path(),
+        &table_ref,
+        &first_batch,
+        &first_key,
+        &prover_setup
+    ));
+    assert!(append(
+        dir.path(),
+        &table_ref,
+        &second_batch,
+        &second_key,
+        &prover_setup
+    ));
+    assert!(!append(
+        dir.path(),
+        &table_ref,
+        &second_batch,
+        &second_key,
+        &prover_setup
+    ));
+
+    let commit = load_commit(dir.path(), &table_ref);
+    assert_eq!(commit, load_commit(expected.path(), &table_ref));
+    assert_eq!(commit.num_rows(), 3);
+    assert_eq!(
+        fs::read(dir.path().join("sxt.table.csv")).unwrap(),
+        fs::read(expected.path().join("sxt.table.csv")).unwrap()
+    );
+}
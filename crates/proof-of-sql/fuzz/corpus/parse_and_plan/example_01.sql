@@ -0,0 +1 @@
+SELECT g, COUNT(*) AS album_count FROM tab GROUP BY g ORDER BY g
\ No newline at end of file
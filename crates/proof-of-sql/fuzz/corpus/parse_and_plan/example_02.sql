@@ -0,0 +1 @@
+SELECT g, a, b FROM tab WHERE b >= 1970 AND b < 1980 ORDER BY b
\ No newline at end of file
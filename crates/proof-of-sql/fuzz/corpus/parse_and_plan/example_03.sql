@@ -0,0 +1 @@
+SELECT COUNT(*) AS total FROM sxt.tab
\ No newline at end of file
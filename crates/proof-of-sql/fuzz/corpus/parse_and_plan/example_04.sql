@@ -0,0 +1 @@
+SELECT g, a, b FROM tab WHERE (g = 'Rock' OR g = 'Hard Rock' OR g = 'Progressive Rock') AND b > 1975 ORDER BY b DESC
\ No newline at end of file
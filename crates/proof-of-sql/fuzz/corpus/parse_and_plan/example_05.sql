@@ -0,0 +1 @@
+SELECT a, COUNT(*) AS total FROM tab WHERE a > 100 GROUP BY a ORDER BY total DESC LIMIT 5
\ No newline at end of file
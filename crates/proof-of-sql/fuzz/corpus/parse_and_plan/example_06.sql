@@ -0,0 +1 @@
+SELECT g, f FROM tab ORDER BY f DESC LIMIT 5
\ No newline at end of file
@@ -0,0 +1 @@
+SELECT g, a, f FROM tab WHERE a > 2000 AND f > 4.5
\ No newline at end of file
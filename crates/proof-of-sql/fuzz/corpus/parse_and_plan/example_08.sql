@@ -0,0 +1 @@
+SELECT g, SUM(e) AS total_revenue FROM tab GROUP BY g ORDER BY total_revenue DESC LIMIT 5
\ No newline at end of file
@@ -0,0 +1 @@
+SELECT SUM(a) AS total_market_cap FROM tab WHERE g IN ('China', 'India')
\ No newline at end of file
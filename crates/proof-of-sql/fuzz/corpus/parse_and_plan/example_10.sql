@@ -0,0 +1 @@
+SELECT g FROM tab WHERE a > 500 AND a < 1500
\ No newline at end of file
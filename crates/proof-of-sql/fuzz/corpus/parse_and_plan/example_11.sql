@@ -0,0 +1 @@
+SELECT g FROM tab WHERE h = TRUE ORDER BY g
\ No newline at end of file
@@ -0,0 +1 @@
+SELECT g, SUM(f)/COUNT(*) as avg_density FROM tab GROUP BY g ORDER BY g
\ No newline at end of file
@@ -0,0 +1 @@
+SELECT g, MAX(a) as max_a, COUNT(*) as c FROM tab GROUP BY g ORDER BY max_a DESC
\ No newline at end of file
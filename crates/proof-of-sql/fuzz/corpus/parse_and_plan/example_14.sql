@@ -0,0 +1 @@
+SELECT g, COUNT(*) AS num FROM tab WHERE i > timestamp '2000-01-01T00:00:00Z' GROUP BY g ORDER BY num DESC LIMIT 5
\ No newline at end of file
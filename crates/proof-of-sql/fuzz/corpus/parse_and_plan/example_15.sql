@@ -0,0 +1 @@
+SELECT g FROM tab WHERE NOT h
\ No newline at end of file
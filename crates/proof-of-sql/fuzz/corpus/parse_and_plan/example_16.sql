@@ -0,0 +1 @@
+SELECT * FROM tab WHERE c > 25 AND d < 75
\ No newline at end of file
@@ -0,0 +1 @@
+SELECT COUNT(*) FROM tab WHERE g = 'Tuna'
\ No newline at end of file
@@ -0,0 +1 @@
+SELECT g, c FROM tab WHERE g = 'Tuna' ORDER BY c ASC
\ No newline at end of file
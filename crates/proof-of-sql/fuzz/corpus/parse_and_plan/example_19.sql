@@ -0,0 +1 @@
+SELECT MAX(b) FROM tab
\ No newline at end of file
@@ -0,0 +1 @@
+SELECT a, j FROM tab WHERE j > DATE '2020-01-01' AND g NOT IN ('x', 'y')
\ No newline at end of file
@@ -0,0 +1 @@
+SELECT a * 2 + b - c AS expr, to_timestamp(0) AS t FROM tab WHERE e = -1 OFFSET 2
\ No newline at end of file
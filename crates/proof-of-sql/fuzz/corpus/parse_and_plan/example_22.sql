@@ -0,0 +1 @@
+SELECT * EXCLUDE (g) FROM tab
\ No newline at end of file
@@ -0,0 +1,6 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use proof_of_sql_fuzz::parse_and_plan;
+
+fuzz_target!(|data: &[u8]| parse_and_plan(data));
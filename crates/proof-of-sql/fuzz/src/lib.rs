@@ -0,0 +1,82 @@
+//! Fuzzing harness for the parser and planner.
+//!
+//! [`parse_and_plan`] parses arbitrary input as a [`SelectStatement`] and, when it parses, plans
+//! it as a [`QueryExpr`] against a fixed schema. Both steps must report invalid input as typed
+//! errors, so any panic is a bug.
+use proof_of_sql::{
+    base::{
+        database::{ColumnType, SchemaAccessor, TableRef},
+        math::decimal::Precision,
+    },
+    sql::parse::QueryExpr,
+};
+use proof_of_sql_parser::{
+    posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
+    SelectStatement,
+};
+use sqlparser::ast::Ident;
+
+/// The schema queries are planned against: one table, `sxt.tab`, with a column of every type
+pub struct FuzzSchemaAccessor;
+
+impl FuzzSchemaAccessor {
+    /// The table of the schema
+    #[must_use]
+    pub fn table_ref() -> TableRef {
+        TableRef::new("sxt.tab".parse().expect("valid resource id"))
+    }
+
+    fn columns() -> Vec<(Ident, ColumnType)> {
+        vec![
+            ("a".into(), ColumnType::BigInt),
+            ("b".into(), ColumnType::Int),
+            ("c".into(), ColumnType::SmallInt),
+            ("d".into(), ColumnType::TinyInt),
+            ("e".into(), ColumnType::Int128),
+            (
+                "f".into(),
+                ColumnType::Decimal75(Precision::new(10).expect("valid precision"), 2),
+            ),
+            ("g".into(), ColumnType::VarChar),
+            ("h".into(), ColumnType::Boolean),
+            (
+                "i".into(),
+                ColumnType::TimestampTZ(PoSQLTimeUnit::Second, PoSQLTimeZone::utc()),
+            ),
+            ("j".into(), ColumnType::Date),
+        ]
+    }
+}
+
+impl SchemaAccessor for FuzzSchemaAccessor {
+    fn lookup_column(&self, table_ref: TableRef, column_id: Ident) -> Option<ColumnType> {
+        self.lookup_schema(table_ref)
+            .into_iter()
+            .find_map(|(id, column_type)| (id == column_id).then_some(column_type))
+    }
+
+    fn lookup_schema(&self, table_ref: TableRef) -> Vec<(Ident, ColumnType)> {
+        if table_ref == Self::table_ref() {
+            Self::columns()
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn lookup_tables(&self) -> Vec<TableRef> {
+        vec![Self::table_ref()]
+    }
+}
+
+/// Parses `data` as a query and, if it parses, plans it against [`FuzzSchemaAccessor`].
+///
+/// Invalid UTF-8 is ignored. Parse and planning errors are expected; only panics are failures.
+pub fn parse_and_plan(data: &[u8]) {
+    let Ok(query) = core::str::from_utf8(data) else {
+        return;
+    };
+    let Ok(statement) = query.parse::<SelectStatement>() else {
+        return;
+    };
+    let _ = QueryExpr::try_new(statement, "sxt".into(), &FuzzSchemaAccessor);
+}
@@ -0,0 +1,38 @@
+use proof_of_sql::sql::parse::QueryExpr;
+use proof_of_sql_fuzz::{parse_and_plan, FuzzSchemaAccessor};
+use proof_of_sql_parser::SelectStatement;
+use std::{fs, path::Path};
+
+fn corpus() -> Vec<(String, Vec<u8>)> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("corpus/parse_and_plan");
+    let mut entries = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| {
+            let path = entry.unwrap().path();
+            (path.display().to_string(), fs::read(&path).unwrap())
+        })
+        .collect::<Vec<_>>();
+    entries.sort();
+    entries
+}
+
+#[test]
+fn the_harness_runs_the_seed_corpus_without_panicking() {
+    let corpus = corpus();
+    assert!(!corpus.is_empty());
+    for (_, data) in &corpus {
+        parse_and_plan(data);
+    }
+}
+
+#[test]
+fn every_seed_can_be_planned_against_the_fuzz_schema() {
+    for (path, data) in corpus() {
+        let statement = std::str::from_utf8(&data)
+            .unwrap()
+            .parse::<SelectStatement>()
+            .unwrap_or_else(|e| panic!("{path} does not parse: {e}"));
+        QueryExpr::try_new(statement, "sxt".into(), &FuzzSchemaAccessor)
+            .unwrap_or_else(|e| panic!("{path} can not be planned: {e}"));
+    }
+}
@@ -1,10 +1,14 @@
 use super::scalar_and_i256_conversions::convert_i256_to_scalar;
-use crate::base::{database::Column, math::decimal::Precision, scalar::Scalar};
+use crate::base::{
+    database::{Column, ColumnType},
+    math::decimal::Precision,
+    scalar::Scalar,
+};
 use arrow::{
     array::{
-        Array, ArrayRef, BooleanArray, Decimal128Array, Decimal256Array, Int16Array, Int32Array,
-        Int64Array, Int8Array, StringArray, TimestampMicrosecondArray, TimestampMillisecondArray,
-        TimestampNanosecondArray, TimestampSecondArray,
+        Array, ArrayRef, BooleanArray, Date32Array, Decimal128Array, Decimal256Array, Int16Array,
+        Int32Array, Int64Array, Int8Array, StringArray, TimestampMicrosecondArray,
+        TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray, UInt64Array,
     },
     datatypes::{i256, DataType, TimeUnit as ArrowTimeUnit},
 };
@@ -27,6 +31,14 @@ pub enum ArrowArrayToColumnConversionError {
         /// The unsupported datatype
         datatype: DataType,
     },
+    /// This error occurs when the arrow type does not match the expected column type.
+    #[snafu(display("type mismatch: expected a column of type {expected} but got {actual}"))]
+    TypeMismatch {
+        /// The expected column type
+        expected: ColumnType,
+        /// The actual datatype of the array
+        actual: DataType,
+    },
     /// Variant for decimal errors
     #[snafu(transparent)]
     DecimalError {
@@ -276,6 +288,24 @@ impl ArrayRefExt for ArrayRef {
                     })
                 }
             }
+            DataType::Date32 => {
+                if let Some(array) = self.as_any().downcast_ref::<Date32Array>() {
+                    Ok(Column::Date(&array.values()[range.start..range.end]))
+                } else {
+                    Err(ArrowArrayToColumnConversionError::UnsupportedType {
+                        datatype: self.data_type().clone(),
+                    })
+                }
+            }
+            DataType::UInt64 => {
+                if let Some(array) = self.as_any().downcast_ref::<UInt64Array>() {
+                    Ok(Column::UInt64(&array.values()[range.start..range.end]))
+                } else {
+                    Err(ArrowArrayToColumnConversionError::UnsupportedType {
+                        datatype: self.data_type().clone(),
+                    })
+                }
+            }
             data_type => Err(ArrowArrayToColumnConversionError::UnsupportedType {
                 datatype: data_type.clone(),
             }),
@@ -646,6 +676,19 @@ mod tests {
         assert_eq!(result.unwrap(), Column::TinyInt(&[-3, 42]));
     }
 
+    #[test]
+    fn we_can_convert_uint64_array_normal_range() {
+        let alloc = Bump::new();
+        let array: ArrayRef = Arc::new(UInt64Array::from(vec![1, u64::MAX, 42]));
+        let result = array.to_column::<DoryScalar>(&alloc, &(1..3), None);
+        assert_eq!(result.unwrap(), Column::UInt64(&[u64::MAX, 42]));
+        let result = array.to_column::<DoryScalar>(&alloc, &(2..4), None);
+        assert_eq!(
+            result,
+            Err(ArrowArrayToColumnConversionError::IndexOutOfBounds { len: 3, index: 4 })
+        );
+    }
+
     #[test]
     fn we_can_convert_int16_array_normal_range() {
         let alloc = Bump::new();
@@ -31,6 +31,8 @@ impl From<&ColumnType> for DataType {
                 };
                 DataType::Timestamp(arrow_timeunit, arrow_timezone)
             }
+            ColumnType::Date => DataType::Date32,
+            ColumnType::UInt64 => DataType::UInt64,
         }
     }
 }
@@ -63,6 +65,8 @@ impl TryFrom<DataType> for ColumnType {
                 ))
             }
             DataType::Utf8 => Ok(ColumnType::VarChar),
+            DataType::Date32 => Ok(ColumnType::Date),
+            DataType::UInt64 => Ok(ColumnType::UInt64),
             _ => Err(format!("Unsupported arrow data type {data_type:?}")),
         }
     }
@@ -24,3 +24,10 @@ pub mod scalar_and_i256_conversions;
 
 /// Module for handling conversions between columns and Arrow arrays.
 pub mod column_arrow_conversions;
+
+/// Module for an accessor over tables fed as a stream of record batches.
+pub mod streaming_record_batch_accessor;
+
+#[cfg(test)]
+/// Tests for the streaming record batch accessor.
+mod streaming_record_batch_accessor_test;
@@ -7,6 +7,8 @@
 //! `VarChar` <-> `Utf8/String`
 //! `Int128` <-> `Decimal128(38,0)`
 //! `Decimal75` <-> `S`
+//! `Date` <-> `Date32`
+//! `UInt64` <-> `UInt64`
 //!
 //! Note: this converts `Int128` values to `Decimal128(38,0)`, which are backed by `i128`.
 //! This is because there is no `Int128` type in Arrow.
@@ -14,7 +16,7 @@
 //! However, the actual arrow backing `i128` is the correct value.
 use super::scalar_and_i256_conversions::{convert_i256_to_scalar, convert_scalar_to_i256};
 use crate::base::{
-    database::{OwnedColumn, OwnedTable, OwnedTableError},
+    database::{ColumnType, OwnedColumn, OwnedTable, OwnedTableError},
     map::IndexMap,
     math::decimal::Precision,
     scalar::Scalar,
@@ -22,9 +24,9 @@ use crate::base::{
 use alloc::sync::Arc;
 use arrow::{
     array::{
-        ArrayRef, BooleanArray, Decimal128Array, Decimal256Array, Int16Array, Int32Array,
-        Int64Array, Int8Array, StringArray, TimestampMicrosecondArray, TimestampMillisecondArray,
-        TimestampNanosecondArray, TimestampSecondArray,
+        ArrayRef, BooleanArray, Date32Array, Decimal128Array, Decimal256Array, Int16Array,
+        Int32Array, Int64Array, Int8Array, StringArray, TimestampMicrosecondArray,
+        TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray, UInt64Array,
     },
     datatypes::{i256, DataType, Schema, SchemaRef, TimeUnit as ArrowTimeUnit},
     error::ArrowError,
@@ -73,6 +75,14 @@ pub enum OwnedArrowConversionError {
         /// The underlying source error
         source: PoSQLTimestampError,
     },
+    /// This error occurs when trying to convert a column whose type has no arrow type.
+    #[snafu(display(
+        "unsupported type: column of type {column_type} can not be converted to arrow"
+    ))]
+    UnsupportedColumnType {
+        /// The unsupported column type
+        column_type: ColumnType,
+    },
 }
 
 /// # Panics
@@ -110,6 +120,8 @@ impl<S: Scalar> From<OwnedColumn<S>> for ArrayRef {
                 PoSQLTimeUnit::Microsecond => Arc::new(TimestampMicrosecondArray::from(col)),
                 PoSQLTimeUnit::Nanosecond => Arc::new(TimestampNanosecondArray::from(col)),
             },
+            OwnedColumn::Date(col) => Arc::new(Date32Array::from(col)),
+            OwnedColumn::UInt64(col) => Arc::new(UInt64Array::from(col)),
         }
     }
 }
@@ -152,6 +164,8 @@ impl<S: Scalar> TryFrom<&ArrayRef> for OwnedColumn<S> {
     /// - `Decimal128Array` when converting from `DataType::Decimal128(38, 0)`.
     /// - `Decimal256Array` when converting from `DataType::Decimal256` if precision is less than or equal to 75.
     /// - `StringArray` when converting from `DataType::Utf8`.
+    /// - `Date32Array` when converting from `DataType::Date32`.
+    /// - `UInt64Array` when converting from `DataType::UInt64`.
     fn try_from(value: &ArrayRef) -> Result<Self, Self::Error> {
         match &value.data_type() {
             // Arrow uses a bit-packed representation for booleans.
@@ -227,6 +241,22 @@ impl<S: Scalar> TryFrom<&ArrayRef> for OwnedColumn<S> {
                     .map(|s| s.unwrap().to_string())
                     .collect(),
             )),
+            DataType::Date32 => Ok(Self::Date(
+                value
+                    .as_any()
+                    .downcast_ref::<Date32Array>()
+                    .unwrap()
+                    .values()
+                    .to_vec(),
+            )),
+            DataType::UInt64 => Ok(Self::UInt64(
+                value
+                    .as_any()
+                    .downcast_ref::<UInt64Array>()
+                    .unwrap()
+                    .values()
+                    .to_vec(),
+            )),
             DataType::Timestamp(time_unit, timezone) => match time_unit {
                 ArrowTimeUnit::Second => {
                     let array = value
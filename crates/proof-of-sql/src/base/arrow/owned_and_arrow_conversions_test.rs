@@ -9,7 +9,10 @@ use crate::{
 };
 use alloc::sync::Arc;
 use arrow::{
-    array::{ArrayRef, BooleanArray, Decimal128Array, Float32Array, Int64Array, StringArray},
+    array::{
+        ArrayRef, BooleanArray, Date32Array, Decimal128Array, Float32Array, Int64Array,
+        StringArray, UInt64Array,
+    },
     datatypes::Schema,
     record_batch::RecordBatch,
 };
@@ -46,6 +49,12 @@ fn we_can_convert_between_int128_owned_column_and_array_ref_impl(data: Vec<i128>
         ),
     );
 }
+fn we_can_convert_between_uint64_owned_column_and_array_ref_impl(data: Vec<u64>) {
+    we_can_convert_between_owned_column_and_array_ref_impl(
+        &OwnedColumn::<TestScalar>::UInt64(data.clone()),
+        Arc::new(UInt64Array::from(data)),
+    );
+}
 fn we_can_convert_between_varchar_owned_column_and_array_ref_impl(data: Vec<String>) {
     we_can_convert_between_owned_column_and_array_ref_impl(
         &OwnedColumn::<TestScalar>::VarChar(data.clone()),
@@ -57,6 +66,7 @@ fn we_can_convert_between_owned_column_and_array_ref() {
     we_can_convert_between_boolean_owned_column_and_array_ref_impl(vec![]);
     we_can_convert_between_bigint_owned_column_and_array_ref_impl(vec![]);
     we_can_convert_between_int128_owned_column_and_array_ref_impl(vec![]);
+    we_can_convert_between_uint64_owned_column_and_array_ref_impl(vec![]);
     we_can_convert_between_varchar_owned_column_and_array_ref_impl(vec![]);
     let data = vec![true, false, true, false, true, false, true, false, true];
     we_can_convert_between_boolean_owned_column_and_array_ref_impl(data);
@@ -64,6 +74,8 @@ fn we_can_convert_between_owned_column_and_array_ref() {
     we_can_convert_between_bigint_owned_column_and_array_ref_impl(data);
     let data = vec![0, 1, 2, 3, 4, 5, 6, i128::MIN, i128::MAX];
     we_can_convert_between_int128_owned_column_and_array_ref_impl(data);
+    let data = vec![0, 1, 2, 3, 4, 5, 6, u64::MAX];
+    we_can_convert_between_uint64_owned_column_and_array_ref_impl(data);
     let data = vec!["0", "1", "2", "3", "4", "5", "6"];
     we_can_convert_between_varchar_owned_column_and_array_ref_impl(
         data.into_iter().map(String::from).collect(),
@@ -129,6 +141,25 @@ fn we_can_convert_between_owned_table_and_record_batch() {
     );
 }
 
+#[test]
+fn we_can_convert_between_owned_table_and_record_batch_with_a_date_column() {
+    let dates: ArrayRef = Arc::new(Date32Array::from(vec![0, 18_262, -1, i32::MIN, i32::MAX]));
+    we_can_convert_between_owned_table_and_record_batch_impl(
+        &owned_table([
+            date("d", [0, 18_262, -1, i32::MIN, i32::MAX]),
+            bigint("int64", [0, 1, 2, 3, 4]),
+        ]),
+        &RecordBatch::try_from_iter([
+            ("d", dates),
+            (
+                "int64",
+                Arc::new(Int64Array::from(vec![0, 1, 2, 3, 4])) as ArrayRef,
+            ),
+        ])
+        .unwrap(),
+    );
+}
+
 #[test]
 #[should_panic(expected = "not implemented: Cannot convert Scalar type to arrow type")]
 fn we_panic_when_converting_an_owned_table_with_a_scalar_column() {
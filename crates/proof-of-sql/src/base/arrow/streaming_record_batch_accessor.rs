@@ -0,0 +1,195 @@
+use super::{
+    arrow_array_to_column_conversion::ArrayRefExt,
+    record_batch_errors::{AppendRecordBatchTableCommitmentError, RecordBatchToColumnsError},
+};
+use crate::base::{
+    commitment::{Commitment, TableCommitment},
+    database::{
+        union_util::column_union, Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor,
+        MetadataAccessor, SchemaAccessor, TableRef,
+    },
+    map::IndexMap,
+};
+use alloc::vec::Vec;
+use arrow::{datatypes::SchemaRef, record_batch::RecordBatch};
+use bumpalo::Bump;
+use snafu::Snafu;
+use sqlparser::ast::Ident;
+
+/// Errors that can occur when inserting a stream of record batches into a
+/// [`StreamingRecordBatchAccessor`].
+#[derive(Debug, Snafu)]
+pub enum StreamingRecordBatchAccessorError {
+    /// The schema of the table could not be committed to.
+    #[snafu(transparent)]
+    Schema {
+        /// The underlying source error
+        source: RecordBatchToColumnsError,
+    },
+    /// A record batch could not be appended to the table commitment, e.g. because its schema
+    /// differs from the table's.
+    #[snafu(transparent)]
+    AppendRecordBatch {
+        /// The underlying source error
+        source: AppendRecordBatchTableCommitmentError,
+    },
+}
+
+/// An accessor over tables that are fed as a stream of arrow [`RecordBatch`]es.
+///
+/// The commitment to a table is computed incrementally while its batches are inserted, with
+/// [`TableCommitment::try_append_record_batch`], so committing only ever converts one batch at a
+/// time, rather than the whole table. The batches themselves are kept as they are.
+///
+/// The prover needs every column that a query references as one contiguous [`Column`], so
+/// [`DataAccessor::get_column`] converts the requested column of every batch and concatenates the
+/// chunks. Only the requested column is converted, never the whole table. The concatenation, and
+/// any chunk whose arrow layout differs from the [`Column`] layout, e.g. of strings, are allocated
+/// in an arena owned by the accessor, and every call allocates them anew. Nothing in the arena is
+/// freed until [`Self::reset_columns`] is called or the accessor is dropped. A proof calls
+/// `get_column` twice per referenced column, so each proof adds at least two copies of every
+/// referenced column. Call [`Self::reset_columns`] between proofs to keep memory bounded by the
+/// batches plus the columns of a single proof.
+pub struct StreamingRecordBatchAccessor<C: Commitment> {
+    tables: IndexMap<TableRef, (Vec<RecordBatch>, TableCommitment<C>)>,
+    alloc: Bump,
+}
+
+impl<C: Commitment> Default for StreamingRecordBatchAccessor<C> {
+    fn default() -> Self {
+        Self {
+            tables: IndexMap::default(),
+            alloc: Bump::new(),
+        }
+    }
+}
+
+impl<C: Commitment> StreamingRecordBatchAccessor<C> {
+    /// Inserts a table with the given `schema`, committing to its `batches` one at a time.
+    ///
+    /// # Errors
+    /// Returns an error if the schema has an unsupported type, or if a batch does not match it.
+    pub fn try_insert_table(
+        &mut self,
+        table_ref: TableRef,
+        schema: SchemaRef,
+        batches: impl IntoIterator<Item = RecordBatch>,
+        setup: &C::PublicSetup<'_>,
+    ) -> Result<(), StreamingRecordBatchAccessorError> {
+        let mut commitment =
+            TableCommitment::try_from_record_batch(&RecordBatch::new_empty(schema), setup)?;
+        let batches = batches
+            .into_iter()
+            .map(|batch| {
+                commitment.try_append_record_batch(&batch, setup)?;
+                Ok(batch)
+            })
+            .collect::<Result<Vec<_>, StreamingRecordBatchAccessorError>>()?;
+        self.tables.insert(table_ref, (batches, commitment));
+        Ok(())
+    }
+
+    /// Frees every column returned by [`DataAccessor::get_column`] so far.
+    ///
+    /// This takes `&mut self`, so it can only be called once no returned column is in use.
+    pub fn reset_columns(&mut self) {
+        self.alloc.reset();
+    }
+
+    /// The number of bytes held by the columns returned by [`DataAccessor::get_column`].
+    #[cfg(test)]
+    pub(super) fn column_bytes(&self) -> usize {
+        self.alloc.allocated_bytes()
+    }
+
+    /// Returns the commitment to a table.
+    #[must_use]
+    pub fn get_table_commitment(&self, table_ref: &TableRef) -> Option<&TableCommitment<C>> {
+        self.tables.get(table_ref).map(|(_, commitment)| commitment)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the table cannot be found.
+    fn get_table_entry(&self, table_ref: &TableRef) -> &(Vec<RecordBatch>, TableCommitment<C>) {
+        self.tables.get(table_ref).expect("Table not found")
+    }
+}
+
+/// # Panics
+///
+/// Panics if the table or column cannot be found, or if the column cannot be converted.
+impl<C: Commitment> DataAccessor<C::Scalar> for StreamingRecordBatchAccessor<C> {
+    fn get_column(&self, column: ColumnRef) -> Column<C::Scalar> {
+        let (batches, _) = self.get_table_entry(&column.table_ref());
+        let chunks = batches
+            .iter()
+            .map(|batch| {
+                batch
+                    .column_by_name(column.column_id().value.as_str())
+                    .expect("Column not found")
+                    .to_column(&self.alloc, &(0..batch.num_rows()), None)
+                    .expect("Failed to convert arrow column.")
+            })
+            .collect::<Vec<_>>();
+        column_union(
+            &chunks.iter().collect::<Vec<_>>(),
+            &self.alloc,
+            *column.column_type(),
+        )
+        .expect("Every batch matches the committed schema.")
+    }
+}
+
+/// # Panics
+///
+/// Panics if the table or column cannot be found.
+impl<C: Commitment> CommitmentAccessor<C> for StreamingRecordBatchAccessor<C> {
+    fn get_commitment(&self, column: ColumnRef) -> C {
+        self.get_table_entry(&column.table_ref())
+            .1
+            .column_commitments()
+            .get_commitment(&column.column_id())
+            .expect("Column not found")
+    }
+}
+
+/// # Panics
+///
+/// Panics if the table cannot be found.
+impl<C: Commitment> MetadataAccessor for StreamingRecordBatchAccessor<C> {
+    fn get_length(&self, table_ref: TableRef) -> usize {
+        self.get_table_entry(&table_ref).1.num_rows()
+    }
+
+    fn get_offset(&self, table_ref: TableRef) -> usize {
+        self.get_table_entry(&table_ref).1.range().start
+    }
+}
+
+/// # Panics
+///
+/// Panics if the table cannot be found.
+impl<C: Commitment> SchemaAccessor for StreamingRecordBatchAccessor<C> {
+    fn lookup_column(&self, table_ref: TableRef, column_id: Ident) -> Option<ColumnType> {
+        self.get_table_entry(&table_ref)
+            .1
+            .column_commitments()
+            .get_metadata(&column_id)
+            .map(|metadata| *metadata.column_type())
+    }
+
+    fn lookup_schema(&self, table_ref: TableRef) -> Vec<(Ident, ColumnType)> {
+        self.get_table_entry(&table_ref)
+            .1
+            .column_commitments()
+            .column_metadata()
+            .iter()
+            .map(|(identifier, metadata)| (identifier.clone(), *metadata.column_type()))
+            .collect()
+    }
+
+    fn lookup_tables(&self) -> Vec<TableRef> {
+        self.tables.keys().copied().collect()
+    }
+}
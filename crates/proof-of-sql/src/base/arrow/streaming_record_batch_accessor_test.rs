@@ -0,0 +1,142 @@
+use super::streaming_record_batch_accessor::{
+    StreamingRecordBatchAccessor, StreamingRecordBatchAccessorError,
+};
+use crate::{
+    base::{
+        commitment::TableCommitment,
+        database::{
+            owned_table_utility::*, Column, ColumnRef, ColumnType, DataAccessor, MetadataAccessor,
+            SchemaAccessor, TableRef,
+        },
+    },
+    proof_primitive::dory::{
+        DoryScalar, DynamicDoryCommitment, DynamicDoryEvaluationProof, ProverSetup,
+        PublicParameters, VerifierSetup,
+    },
+    record_batch,
+    sql::{parse::QueryExpr, proof::VerifiableQueryResult},
+};
+use ark_std::test_rng;
+
+#[test]
+fn we_can_prove_a_filter_over_a_table_fed_as_three_batches() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let batches = [
+        record_batch!("a" => [1_i64, 5, 2], "b" => ["x", "y", "z"]),
+        record_batch!("a" => [7_i64, 3], "b" => ["u", "v"]),
+        record_batch!("a" => [4_i64, 0, 9, 6], "b" => ["w", "s", "t", "r"]),
+    ];
+    let t: TableRef = "sxt.table".parse().unwrap();
+    let mut accessor = StreamingRecordBatchAccessor::<DynamicDoryCommitment>::default();
+    accessor
+        .try_insert_table(t, batches[0].schema(), batches, &&prover_setup)
+        .unwrap();
+
+    // The incremental commitment matches a commitment to the whole table at once
+    let table = owned_table::<DoryScalar>([
+        bigint("a", [1_i64, 5, 2, 7, 3, 4, 0, 9, 6]),
+        varchar("b", ["x", "y", "z", "u", "v", "w", "s", "t", "r"]),
+    ]);
+    assert_eq!(
+        accessor.get_table_commitment(&t).unwrap(),
+        &TableCommitment::from_owned_table_with_offset(&table, 0, &&prover_setup)
+    );
+    assert_eq!(accessor.get_length(t), 9);
+    assert_eq!(accessor.lookup_schema(t).len(), 2);
+
+    let query = QueryExpr::try_new(
+        "SELECT * FROM table WHERE a >= 5".parse().unwrap(),
+        "sxt".into(),
+        &accessor,
+    )
+    .unwrap();
+    let verifiable_result = VerifiableQueryResult::<DynamicDoryEvaluationProof>::new(
+        query.proof_expr(),
+        &accessor,
+        &&prover_setup,
+    );
+    let result = verifiable_result
+        .verify(query.proof_expr(), &accessor, &&verifier_setup)
+        .unwrap()
+        .table;
+    let expected_result = owned_table([
+        bigint("a", [5_i64, 7, 9, 6]),
+        varchar("b", ["y", "u", "t", "r"]),
+    ]);
+    assert_eq!(result, expected_result);
+}
+
+#[test]
+fn we_can_insert_a_table_with_no_batches() {
+    let public_parameters = PublicParameters::test_rand(2, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let schema = record_batch!("a" => [1_i64]).schema();
+    let t: TableRef = "sxt.table".parse().unwrap();
+    let mut accessor = StreamingRecordBatchAccessor::<DynamicDoryCommitment>::default();
+    accessor
+        .try_insert_table(t, schema, [], &&prover_setup)
+        .unwrap();
+    assert_eq!(accessor.get_length(t), 0);
+    assert_eq!(accessor.lookup_schema(t).len(), 1);
+}
+
+#[test]
+fn we_cannot_insert_a_batch_that_does_not_match_the_schema() {
+    let public_parameters = PublicParameters::test_rand(2, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let batches = [
+        record_batch!("a" => [1_i64, 2]),
+        record_batch!("a" => ["x", "y"]),
+    ];
+    let mut accessor = StreamingRecordBatchAccessor::<DynamicDoryCommitment>::default();
+    assert!(matches!(
+        accessor.try_insert_table(
+            "sxt.table".parse().unwrap(),
+            batches[0].schema(),
+            batches,
+            &&prover_setup
+        ),
+        Err(StreamingRecordBatchAccessorError::AppendRecordBatch { .. })
+    ));
+    assert!(accessor.lookup_tables().is_empty());
+}
+
+#[test]
+fn we_can_free_the_columns_of_a_streaming_accessor_between_queries() {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let batches = [
+        record_batch!("a" => (0..256_i64).collect::<Vec<_>>()),
+        record_batch!("a" => (256..512_i64).collect::<Vec<_>>()),
+    ];
+    let t: TableRef = "sxt.table".parse().unwrap();
+    let mut accessor = StreamingRecordBatchAccessor::<DynamicDoryCommitment>::default();
+    accessor
+        .try_insert_table(t, batches[0].schema(), batches, &&prover_setup)
+        .unwrap();
+    let column_ref = ColumnRef::new(t, "a".into(), ColumnType::BigInt);
+    let expected: Vec<i64> = (0..512).collect();
+
+    // Resetting between calls reuses the same memory
+    for _ in 0..16 {
+        assert_eq!(
+            accessor.get_column(column_ref.clone()),
+            Column::BigInt(expected.as_slice())
+        );
+        accessor.reset_columns();
+    }
+    let bytes_with_reset = accessor.column_bytes();
+
+    // Otherwise every call allocates the concatenated column again
+    for _ in 0..16 {
+        assert_eq!(
+            accessor.get_column(column_ref.clone()),
+            Column::BigInt(expected.as_slice())
+        );
+    }
+    let bytes_without_reset = accessor.column_bytes();
+    assert!(bytes_without_reset >= 16 * 512 * 8);
+    assert!(bytes_with_reset < bytes_without_reset / 4);
+}
@@ -215,6 +215,10 @@ pub enum ColumnBounds {
     Int128(Bounds<i128>),
     /// The bounds of a Timestamp column.
     TimestampTZ(Bounds<i64>),
+    /// The bounds of a Date column.
+    Date(Bounds<i32>),
+    /// The bounds of a `UInt64` column.
+    UInt64(Bounds<u64>),
 }
 
 impl ColumnBounds {
@@ -232,6 +236,8 @@ impl ColumnBounds {
             CommittableColumn::TimestampTZ(_, _, times) => {
                 ColumnBounds::TimestampTZ(Bounds::from_iter(*times))
             }
+            CommittableColumn::Date(days) => ColumnBounds::Date(Bounds::from_iter(*days)),
+            CommittableColumn::UInt64(ints) => ColumnBounds::UInt64(Bounds::from_iter(*ints)),
             CommittableColumn::Boolean(_)
             | CommittableColumn::Decimal75(_, _, _)
             | CommittableColumn::Scalar(_)
@@ -264,6 +270,12 @@ impl ColumnBounds {
             (ColumnBounds::Int128(bounds_a), ColumnBounds::Int128(bounds_b)) => {
                 Ok(ColumnBounds::Int128(bounds_a.union(bounds_b)))
             }
+            (ColumnBounds::Date(bounds_a), ColumnBounds::Date(bounds_b)) => {
+                Ok(ColumnBounds::Date(bounds_a.union(bounds_b)))
+            }
+            (ColumnBounds::UInt64(bounds_a), ColumnBounds::UInt64(bounds_b)) => {
+                Ok(ColumnBounds::UInt64(bounds_a.union(bounds_b)))
+            }
             (bounds_a, bounds_b) => Err(ColumnBoundsMismatch {
                 bounds_a: Box::new(bounds_a),
                 bounds_b: Box::new(bounds_b),
@@ -296,6 +308,12 @@ impl ColumnBounds {
             (ColumnBounds::TimestampTZ(bounds_a), ColumnBounds::TimestampTZ(bounds_b)) => {
                 Ok(ColumnBounds::TimestampTZ(bounds_a.difference(bounds_b)))
             }
+            (ColumnBounds::Date(bounds_a), ColumnBounds::Date(bounds_b)) => {
+                Ok(ColumnBounds::Date(bounds_a.difference(bounds_b)))
+            }
+            (ColumnBounds::UInt64(bounds_a), ColumnBounds::UInt64(bounds_b)) => {
+                Ok(ColumnBounds::UInt64(bounds_a.difference(bounds_b)))
+            }
             (_, _) => Err(ColumnBoundsMismatch {
                 bounds_a: Box::new(self),
                 bounds_b: Box::new(other),
@@ -50,6 +50,8 @@ impl ColumnCommitmentMetadata {
             | (ColumnType::BigInt, ColumnBounds::BigInt(_))
             | (ColumnType::Int128, ColumnBounds::Int128(_))
             | (ColumnType::TimestampTZ(_, _), ColumnBounds::TimestampTZ(_))
+            | (ColumnType::Date, ColumnBounds::Date(_))
+            | (ColumnType::UInt64, ColumnBounds::UInt64(_))
             | (
                 ColumnType::Boolean
                 | ColumnType::VarChar
@@ -88,10 +90,18 @@ impl ColumnCommitmentMetadata {
                 BoundsInner::try_new(i64::MIN, i64::MAX)
                     .expect("i64::MIN and i64::MAX are valid bounds for TimeStamp"),
             )),
+            ColumnType::Date => ColumnBounds::Date(super::Bounds::Bounded(
+                BoundsInner::try_new(i32::MIN, i32::MAX)
+                    .expect("i32::MIN and i32::MAX are valid bounds for Date"),
+            )),
             ColumnType::Int128 => ColumnBounds::Int128(super::Bounds::Bounded(
                 BoundsInner::try_new(i128::MIN, i128::MAX)
                     .expect("i128::MIN and i128::MAX are valid bounds for Int128"),
             )),
+            ColumnType::UInt64 => ColumnBounds::UInt64(super::Bounds::Bounded(
+                BoundsInner::try_new(u64::MIN, u64::MAX)
+                    .expect("u64::MIN and u64::MAX are valid bounds for UInt64"),
+            )),
             _ => ColumnBounds::NoOrder,
         };
         Self::try_new(column_type, bounds).expect("default bounds for column type are valid")
@@ -1,11 +1,15 @@
-use super::Commitment;
-use crate::base::{proof::Transcript, scalar::Scalar};
+use super::{Commitment, CommitmentSchemeId};
+use crate::base::{
+    proof::{Keccak256Transcript, Transcript},
+    scalar::Scalar,
+};
 #[cfg(feature = "blitzar")]
 use crate::base::{scalar::MontScalar, slice_ops};
+use alloc::vec;
 #[cfg(feature = "blitzar")]
 use blitzar::proof::{InnerProductProof, ProofError};
 #[cfg(feature = "blitzar")]
-use curve25519_dalek::RistrettoPoint;
+use curve25519_dalek::{ristretto::CompressedRistretto, RistrettoPoint};
 use serde::{Deserialize, Serialize};
 
 /// A trait for using commitment schemes generically. Specifically, this trait is for the evaluation proof of a commitment scheme.
@@ -24,6 +28,9 @@ pub trait CommitmentEvaluationProof {
     /// The public setup parameters required by the verifier.
     /// This is simply precomputed data that is required by the verifier to verify a proof.
     type VerifierPublicSetup<'a>: Copy;
+    /// The tag of the commitment scheme, which is embedded in serialized proofs and also names
+    /// the scheme, e.g. in summaries of proofs.
+    const SCHEME_ID: CommitmentSchemeId;
     /// Create a new proof.
     ///
     /// Note: `b_point` must have length `nu`, where `2^nu` is at least the length of `a`.
@@ -36,6 +43,46 @@ pub trait CommitmentEvaluationProof {
         generators_offset: u64,
         setup: &Self::ProverPublicSetup<'_>,
     ) -> Self;
+    /// Create a new proof like [`Self::new`], unless `should_continue` returns `false`, in which
+    /// case `None` is returned.
+    ///
+    /// By default, `should_continue` is only checked before creating the proof. Schemes with
+    /// several rounds should check it between rounds.
+    fn new_with_cancellation(
+        transcript: &mut impl Transcript,
+        a: &[Self::Scalar],
+        b_point: &[Self::Scalar],
+        generators_offset: u64,
+        setup: &Self::ProverPublicSetup<'_>,
+        should_continue: &dyn Fn() -> bool,
+    ) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        should_continue().then(|| Self::new(transcript, a, b_point, generators_offset, setup))
+    }
+    /// The serialized size in bytes of a proof created by [`Self::new`] for an `a` of length
+    /// `a_length` and a `b_point` of length `b_point_length`.
+    ///
+    /// By default, a proof of zeros is created and serialized. Schemes whose proof size only
+    /// depends on the lengths should compute it without creating the proof.
+    fn serialized_proof_size(
+        a_length: usize,
+        b_point_length: usize,
+        setup: &Self::ProverPublicSetup<'_>,
+    ) -> usize
+    where
+        Self: Serialize + Sized,
+    {
+        let proof = Self::new(
+            &mut Keccak256Transcript::new(),
+            &vec![Self::Scalar::ZERO; a_length],
+            &vec![Self::Scalar::ZERO; b_point_length],
+            0,
+            setup,
+        );
+        postcard::to_allocvec(&proof).map_or(0, |bytes| bytes.len())
+    }
     /// Verify a proof.
     ///
     /// Note: `b_point` must have length `nu`, where `2^nu` is at least the length of `a`.
@@ -85,6 +132,7 @@ impl CommitmentEvaluationProof for InnerProductProof {
     type Error = ProofError;
     type ProverPublicSetup<'a> = ();
     type VerifierPublicSetup<'a> = ();
+    const SCHEME_ID: CommitmentSchemeId = CommitmentSchemeId::InnerProduct;
     fn new(
         transcript: &mut impl Transcript,
         a: &[Self::Scalar],
@@ -112,6 +160,18 @@ impl CommitmentEvaluationProof for InnerProductProof {
         })
     }
 
+    fn serialized_proof_size(
+        a_length: usize,
+        _b_point_length: usize,
+        _setup: &Self::ProverPublicSetup<'_>,
+    ) -> usize {
+        // Each halving of `a` sends an `L` and an `R` point, followed by the final scalar.
+        let num_rounds = a_length.next_power_of_two().trailing_zeros() as usize;
+        let points = vec![CompressedRistretto::default(); num_rounds];
+        postcard::to_allocvec(&(&points, &points, curve25519_dalek::scalar::Scalar::ZERO))
+            .map_or(0, |bytes| bytes.len())
+    }
+
     fn verify_batched_proof(
         &self,
         transcript: &mut impl Transcript,
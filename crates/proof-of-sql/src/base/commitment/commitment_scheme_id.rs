@@ -0,0 +1,32 @@
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+/// The canonical tag of a commitment scheme
+///
+/// Every serialized proof starts with the tag of the scheme it was created with, so that a proof
+/// of one scheme can be told apart from a proof of another before verifying it.
+///
+/// The tag is serialized as the index of the variant, so new variants must only ever be added
+/// at the end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CommitmentSchemeId {
+    /// The inner product argument over curve25519, see `InnerProductProof`
+    InnerProduct,
+    /// The Dory scheme, see `DoryEvaluationProof`
+    Dory,
+    /// The Dynamic Dory scheme, see `DynamicDoryEvaluationProof`
+    DynamicDory,
+    /// The insecure scheme used in tests, see `NaiveEvaluationProof`
+    Naive,
+}
+
+impl fmt::Display for CommitmentSchemeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CommitmentSchemeId::InnerProduct => "InnerProduct",
+            CommitmentSchemeId::Dory => "Dory",
+            CommitmentSchemeId::DynamicDory => "DynamicDory",
+            CommitmentSchemeId::Naive => "Naive",
+        })
+    }
+}
@@ -43,9 +43,13 @@ pub enum CommittableColumn<'a> {
     VarChar(Vec<[u64; 4]>),
     /// Borrowed Timestamp column with Timezone, mapped to `i64`.
     TimestampTZ(PoSQLTimeUnit, PoSQLTimeZone, &'a [i64]),
+    /// Borrowed Date column, mapped to `i32`.
+    Date(&'a [i32]),
     /// Borrowed byte column, mapped to `u8`. This is not a `PoSQL`
     /// type, we need this to commit to words in the range check.
     RangeCheckWord(&'a [u8]),
+    /// Borrowed `UInt64` column, mapped to `u64`.
+    UInt64(&'a [u64]),
 }
 
 impl<'a> CommittableColumn<'a> {
@@ -55,7 +59,7 @@ impl<'a> CommittableColumn<'a> {
         match self {
             CommittableColumn::TinyInt(col) => col.len(),
             CommittableColumn::SmallInt(col) => col.len(),
-            CommittableColumn::Int(col) => col.len(),
+            CommittableColumn::Int(col) | CommittableColumn::Date(col) => col.len(),
             CommittableColumn::BigInt(col) | CommittableColumn::TimestampTZ(_, _, col) => col.len(),
             CommittableColumn::Int128(col) => col.len(),
             CommittableColumn::Decimal75(_, _, col)
@@ -63,6 +67,7 @@ impl<'a> CommittableColumn<'a> {
             | CommittableColumn::VarChar(col) => col.len(),
             CommittableColumn::Boolean(col) => col.len(),
             CommittableColumn::RangeCheckWord(col) => col.len(),
+            CommittableColumn::UInt64(col) => col.len(),
         }
     }
 
@@ -94,9 +99,11 @@ impl<'a> From<&CommittableColumn<'a>> for ColumnType {
             CommittableColumn::VarChar(_) => ColumnType::VarChar,
             CommittableColumn::Boolean(_) => ColumnType::Boolean,
             CommittableColumn::TimestampTZ(tu, tz, _) => ColumnType::TimestampTZ(*tu, *tz),
+            CommittableColumn::Date(_) => ColumnType::Date,
             CommittableColumn::RangeCheckWord(_) => {
                 unimplemented!("Range check words are not a column type.")
             }
+            CommittableColumn::UInt64(_) => ColumnType::UInt64,
         }
     }
 }
@@ -120,6 +127,8 @@ impl<'a, S: Scalar> From<&Column<'a, S>> for CommittableColumn<'a> {
                 CommittableColumn::VarChar(as_limbs)
             }
             Column::TimestampTZ(tu, tz, times) => CommittableColumn::TimestampTZ(*tu, *tz, times),
+            Column::Date(days) => CommittableColumn::Date(days),
+            Column::UInt64(ints) => CommittableColumn::UInt64(ints),
         }
     }
 }
@@ -159,6 +168,8 @@ impl<'a, S: Scalar> From<&'a OwnedColumn<S>> for CommittableColumn<'a> {
             OwnedColumn::TimestampTZ(tu, tz, times) => {
                 CommittableColumn::TimestampTZ(*tu, *tz, times as &[_])
             }
+            OwnedColumn::Date(days) => CommittableColumn::Date(days),
+            OwnedColumn::UInt64(ints) => (ints as &[_]).into(),
         }
     }
 }
@@ -195,6 +206,12 @@ impl<'a> From<&'a [i128]> for CommittableColumn<'a> {
         CommittableColumn::Int128(value)
     }
 }
+
+impl<'a> From<&'a [u64]> for CommittableColumn<'a> {
+    fn from(value: &'a [u64]) -> Self {
+        CommittableColumn::UInt64(value)
+    }
+}
 impl<'a, S: Scalar> From<&'a [S]> for CommittableColumn<'a> {
     fn from(value: &'a [S]) -> Self {
         CommittableColumn::Scalar(value.iter().map(RefInto::<[u64; 4]>::ref_into).collect())
@@ -212,7 +229,7 @@ impl<'a, 'b> From<&'a CommittableColumn<'b>> for Sequence<'a> {
         match value {
             CommittableColumn::TinyInt(ints) => Sequence::from(*ints),
             CommittableColumn::SmallInt(ints) => Sequence::from(*ints),
-            CommittableColumn::Int(ints) => Sequence::from(*ints),
+            CommittableColumn::Int(ints) | CommittableColumn::Date(ints) => Sequence::from(*ints),
             CommittableColumn::BigInt(ints) => Sequence::from(*ints),
             CommittableColumn::Int128(ints) => Sequence::from(*ints),
             CommittableColumn::Decimal75(_, _, limbs)
@@ -221,6 +238,7 @@ impl<'a, 'b> From<&'a CommittableColumn<'b>> for Sequence<'a> {
             CommittableColumn::Boolean(bools) => Sequence::from(*bools),
             CommittableColumn::TimestampTZ(_, _, times) => Sequence::from(*times),
             CommittableColumn::RangeCheckWord(words) => Sequence::from(*words),
+            CommittableColumn::UInt64(ints) => Sequence::from(*ints),
         }
     }
 }
@@ -347,6 +365,19 @@ mod tests {
         assert_eq!(bigint_committable_column.column_type(), ColumnType::BigInt);
     }
 
+    #[test]
+    fn we_can_get_type_and_length_of_uint64_column() {
+        let uint64_committable_column = CommittableColumn::UInt64(&[]);
+        assert_eq!(uint64_committable_column.len(), 0);
+        assert!(uint64_committable_column.is_empty());
+        assert_eq!(uint64_committable_column.column_type(), ColumnType::UInt64);
+
+        let uint64_committable_column = CommittableColumn::UInt64(&[12, 34, u64::MAX]);
+        assert_eq!(uint64_committable_column.len(), 3);
+        assert!(!uint64_committable_column.is_empty());
+        assert_eq!(uint64_committable_column.column_type(), ColumnType::UInt64);
+    }
+
     #[test]
     fn we_can_get_type_and_length_of_decimal_column() {
         // empty case
@@ -628,6 +659,23 @@ mod tests {
         assert_eq!(from_owned_column, CommittableColumn::BigInt(&[12, 34, 56]));
     }
 
+    #[test]
+    fn we_can_convert_from_owned_uint64_column() {
+        let owned_column = OwnedColumn::<TestScalar>::UInt64(Vec::new());
+        let from_owned_column = CommittableColumn::from(&owned_column);
+        assert_eq!(from_owned_column, CommittableColumn::UInt64(&[]));
+
+        let owned_column = OwnedColumn::<TestScalar>::UInt64(vec![12, 34, u64::MAX]);
+        let from_owned_column = CommittableColumn::from(&owned_column);
+        assert_eq!(
+            from_owned_column,
+            CommittableColumn::UInt64(&[12, 34, u64::MAX])
+        );
+        let from_borrowed_column =
+            CommittableColumn::from(&Column::<TestScalar>::UInt64(&[12, 34, u64::MAX]));
+        assert_eq!(from_borrowed_column, from_owned_column);
+    }
+
     #[test]
     fn we_can_convert_from_owned_tinyint_column() {
         // empty case
@@ -780,6 +828,36 @@ mod tests {
         assert_eq!(commitment_buffer[0], commitment_buffer[1]);
     }
 
+    #[test]
+    fn we_can_commit_to_uint64_column_through_committable_column() {
+        // empty case
+        let committable_column = CommittableColumn::UInt64(&[]);
+        let sequence = Sequence::from(&committable_column);
+        let mut commitment_buffer = [CompressedRistretto::default()];
+        compute_curve25519_commitments(&mut commitment_buffer, &[sequence], 0);
+        assert_eq!(commitment_buffer[0], CompressedRistretto::default());
+
+        // The values are committed as the unsigned scalars, so u64::MAX is not committed as -1
+        let values = [12, 0, u64::MAX];
+        let committable_column = CommittableColumn::UInt64(&values);
+        let scalars = values.map(TestScalar::from);
+        let scalar_column = CommittableColumn::from(scalars.as_slice());
+        let negative_one_column = CommittableColumn::BigInt(&[12, 0, -1]);
+
+        let mut commitment_buffer = [CompressedRistretto::default(); 3];
+        compute_curve25519_commitments(
+            &mut commitment_buffer,
+            &[
+                Sequence::from(&committable_column),
+                Sequence::from(&scalar_column),
+                Sequence::from(&negative_one_column),
+            ],
+            0,
+        );
+        assert_eq!(commitment_buffer[0], commitment_buffer[1]);
+        assert_ne!(commitment_buffer[0], commitment_buffer[2]);
+    }
+
     #[test]
     fn we_can_commit_to_rangecheckword_column_through_committable_column() {
         // empty case
@@ -32,8 +32,8 @@ pub use column_commitments::{AppendColumnCommitmentsError, ColumnCommitments, Du
 
 mod table_commitment;
 pub use table_commitment::{
-    AppendTableCommitmentError, MixedLengthColumns, NegativeRange, TableCommitment,
-    TableCommitmentArithmeticError, TableCommitmentFromColumnsError,
+    AppendTableCommitmentError, MixedLengthColumns, NegativeRange, RemoveTableCommitmentError,
+    TableCommitment, TableCommitmentArithmeticError, TableCommitmentFromColumnsError,
 };
 
 mod query_commitments;
@@ -140,6 +140,9 @@ impl Commitment for RistrettoPoint {
 
 mod commitment_evaluation_proof;
 pub use commitment_evaluation_proof::CommitmentEvaluationProof;
+
+mod commitment_scheme_id;
+pub use commitment_scheme_id::CommitmentSchemeId;
 #[cfg(test)]
 pub(crate) mod commitment_evaluation_proof_test;
 
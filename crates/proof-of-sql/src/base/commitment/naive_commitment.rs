@@ -1,9 +1,10 @@
 use super::Commitment;
 use crate::base::{
     commitment::CommittableColumn,
-    scalar::{test_scalar::TestScalar, Scalar},
+    scalar::{test_scalar::TestScalar, MontScalar, Scalar},
 };
 use alloc::{vec, vec::Vec};
+use ark_ff::MontConfig;
 use core::{
     cmp,
     fmt::Debug,
@@ -12,11 +13,14 @@ use core::{
 use serde::{Deserialize, Serialize};
 
 /// A naive [Commitment] implementation that should only be used for the purpose of unit testing.
+///
+/// The commitment is generic over the scalar field, so it can be used to test the proof logic over
+/// any field implementing [`Scalar`]. It defaults to [`TestScalar`].
 #[derive(Clone, Debug, Eq, Default, Serialize, Deserialize)]
-pub struct NaiveCommitment(pub Vec<TestScalar>);
+pub struct NaiveCommitment<S: Scalar = TestScalar>(pub Vec<S>);
 
-impl Add for NaiveCommitment {
-    type Output = NaiveCommitment;
+impl<S: Scalar> Add for NaiveCommitment<S> {
+    type Output = NaiveCommitment<S>;
 
     fn add(self, rhs: Self) -> Self::Output {
         let mut new_self = self.clone();
@@ -25,8 +29,8 @@ impl Add for NaiveCommitment {
     }
 }
 
-impl Sub for NaiveCommitment {
-    type Output = NaiveCommitment;
+impl<S: Scalar> Sub for NaiveCommitment<S> {
+    type Output = NaiveCommitment<S>;
 
     fn sub(self, rhs: Self) -> Self::Output {
         let mut new_self = self.clone();
@@ -34,25 +38,24 @@ impl Sub for NaiveCommitment {
         new_self
     }
 }
-impl Neg for NaiveCommitment {
-    type Output = NaiveCommitment;
+impl<S: Scalar> Neg for NaiveCommitment<S> {
+    type Output = NaiveCommitment<S>;
 
     fn neg(self) -> Self::Output {
-        Self(self.0.iter().map(core::ops::Neg::neg).collect())
+        Self(self.0.iter().map(|s| -*s).collect())
     }
 }
 
-impl SubAssign for NaiveCommitment {
+impl<S: Scalar> SubAssign for NaiveCommitment<S> {
     fn sub_assign(&mut self, rhs: Self) {
         self.add_assign(rhs.neg());
     }
 }
 
-impl AddAssign for NaiveCommitment {
+impl<S: Scalar> AddAssign for NaiveCommitment<S> {
     fn add_assign(&mut self, rhs: Self) {
         if self.0.len() < rhs.0.len() {
-            self.0
-                .extend((self.0.len()..rhs.0.len()).map(|_i| TestScalar::ZERO));
+            self.0.extend((self.0.len()..rhs.0.len()).map(|_i| S::ZERO));
         }
         self.0
             .iter_mut()
@@ -61,51 +64,62 @@ impl AddAssign for NaiveCommitment {
     }
 }
 
-impl PartialEq for NaiveCommitment {
+impl<S: Scalar> PartialEq for NaiveCommitment<S> {
     fn eq(&self, other: &Self) -> bool {
         match self.0.len().cmp(&other.0.len()) {
             cmp::Ordering::Less => {
                 let mut extended_self = self.0.clone();
-                extended_self.extend((self.0.len()..other.0.len()).map(|_i| TestScalar::ZERO));
+                extended_self.extend((self.0.len()..other.0.len()).map(|_i| S::ZERO));
                 extended_self == other.0
             }
             cmp::Ordering::Equal => self.0 == other.0,
             cmp::Ordering::Greater => {
                 let mut extended_other = other.0.clone();
-                extended_other.extend((other.0.len()..self.0.len()).map(|_i| TestScalar::ZERO));
+                extended_other.extend((other.0.len()..self.0.len()).map(|_i| S::ZERO));
                 extended_other == self.0
             }
         }
     }
 }
 
-impl core::ops::Mul<NaiveCommitment> for TestScalar {
-    type Output = NaiveCommitment;
-    fn mul(self, rhs: NaiveCommitment) -> Self::Output {
-        self * &rhs
+// The scalar side of these products can't be a bare type parameter because of the orphan rule,
+// but every `Scalar` in this crate is a `MontScalar`.
+impl<T: MontConfig<4>> core::ops::Mul<NaiveCommitment<MontScalar<T>>> for MontScalar<T>
+where
+    MontScalar<T>: Scalar,
+{
+    type Output = NaiveCommitment<MontScalar<T>>;
+    fn mul(self, rhs: NaiveCommitment<MontScalar<T>>) -> Self::Output {
+        rhs * self
     }
 }
-impl core::ops::Mul<TestScalar> for NaiveCommitment {
-    type Output = NaiveCommitment;
-    fn mul(self, rhs: TestScalar) -> Self::Output {
+impl<S: Scalar> core::ops::Mul<S> for NaiveCommitment<S> {
+    type Output = NaiveCommitment<S>;
+    fn mul(self, rhs: S) -> Self::Output {
         &self * rhs
     }
 }
-impl core::ops::Mul<&NaiveCommitment> for TestScalar {
-    type Output = NaiveCommitment;
-    fn mul(self, rhs: &NaiveCommitment) -> Self::Output {
+impl<T: MontConfig<4>> core::ops::Mul<&NaiveCommitment<MontScalar<T>>> for MontScalar<T>
+where
+    MontScalar<T>: Scalar,
+{
+    type Output = NaiveCommitment<MontScalar<T>>;
+    fn mul(self, rhs: &NaiveCommitment<MontScalar<T>>) -> Self::Output {
         rhs * self
     }
 }
-impl core::ops::Mul<TestScalar> for &NaiveCommitment {
-    type Output = NaiveCommitment;
-    fn mul(self, rhs: TestScalar) -> Self::Output {
+impl<S: Scalar> core::ops::Mul<S> for &NaiveCommitment<S> {
+    type Output = NaiveCommitment<S>;
+    fn mul(self, rhs: S) -> Self::Output {
         NaiveCommitment(self.0.iter().map(|s| rhs * *s).collect())
     }
 }
 
-impl Commitment for NaiveCommitment {
-    type Scalar = TestScalar;
+impl<T: MontConfig<4>> Commitment for NaiveCommitment<MontScalar<T>>
+where
+    MontScalar<T>: Scalar,
+{
+    type Scalar = MontScalar<T>;
     type PublicSetup<'a> = ();
 
     fn compute_commitments(
@@ -116,8 +130,8 @@ impl Commitment for NaiveCommitment {
         committable_columns
             .iter()
             .map(|cc| {
-                let mut vectors: Vec<TestScalar> = vec![TestScalar::ZERO; offset];
-                let mut existing_scalars: Vec<TestScalar> = match cc {
+                let mut vectors: Vec<MontScalar<T>> = vec![MontScalar::ZERO; offset];
+                let mut existing_scalars: Vec<MontScalar<T>> = match cc {
                     CommittableColumn::Boolean(bool_vec) => {
                         bool_vec.iter().map(core::convert::Into::into).collect()
                     }
@@ -149,10 +163,16 @@ impl Commitment for NaiveCommitment {
                     CommittableColumn::TimestampTZ(_, _, i64_vec) => {
                         i64_vec.iter().map(core::convert::Into::into).collect()
                     }
+                    CommittableColumn::Date(i32_vec) => {
+                        i32_vec.iter().map(core::convert::Into::into).collect()
+                    }
                     CommittableColumn::RangeCheckWord(u8_scalar_vec) => u8_scalar_vec
                         .iter()
                         .map(core::convert::Into::into)
                         .collect(),
+                    CommittableColumn::UInt64(u64_vec) => {
+                        u64_vec.iter().map(core::convert::Into::into).collect()
+                    }
                 };
                 vectors.append(&mut existing_scalars);
                 NaiveCommitment(vectors)
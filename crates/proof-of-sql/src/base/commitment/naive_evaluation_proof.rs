@@ -1,15 +1,19 @@
-use super::{naive_commitment::NaiveCommitment, CommitmentEvaluationProof};
+use super::{naive_commitment::NaiveCommitment, CommitmentEvaluationProof, CommitmentSchemeId};
 use crate::base::{
     polynomial::compute_evaluation_vector,
     proof::Transcript,
-    scalar::{test_scalar::TestScalar, Scalar},
+    scalar::{test_scalar::TestScalar, MontScalar, Scalar},
 };
+use ark_ff::MontConfig;
 use core::ops::Add;
+use serde::{Deserialize, Serialize};
 
 /// This should only be used for the purpose of unit testing.
-pub struct NaiveEvaluationProof {
-    a: NaiveCommitment,
-    b_point: Vec<TestScalar>,
+///
+/// Like [`NaiveCommitment`], this is generic over the scalar field and defaults to [`TestScalar`].
+pub struct NaiveEvaluationProof<S: Scalar = TestScalar> {
+    a: NaiveCommitment<S>,
+    b_point: Vec<S>,
     challenge: [u8; 32],
 }
 
@@ -18,10 +22,13 @@ pub struct NaiveEvaluationProof {
 /// [`CommitmentEvaluationProof`] for [`NaiveEvaluationProof`].
 pub struct NaiveEvaluationProofError;
 
-impl CommitmentEvaluationProof for NaiveEvaluationProof {
-    type Scalar = TestScalar;
+impl<T: MontConfig<4>> CommitmentEvaluationProof for NaiveEvaluationProof<MontScalar<T>>
+where
+    MontScalar<T>: Scalar + Serialize + for<'a> Deserialize<'a>,
+{
+    type Scalar = MontScalar<T>;
 
-    type Commitment = NaiveCommitment;
+    type Commitment = NaiveCommitment<MontScalar<T>>;
 
     type Error = NaiveEvaluationProofError;
 
@@ -29,6 +36,8 @@ impl CommitmentEvaluationProof for NaiveEvaluationProof {
 
     type VerifierPublicSetup<'a> = ();
 
+    const SCHEME_ID: CommitmentSchemeId = CommitmentSchemeId::Naive;
+
     fn new(
         transcript: &mut impl Transcript,
         a: &[Self::Scalar],
@@ -39,7 +48,7 @@ impl CommitmentEvaluationProof for NaiveEvaluationProof {
         let challenge = transcript.challenge_as_le();
         let result = Self {
             a: NaiveCommitment(
-                itertools::repeat_n(TestScalar::ZERO, generators_offset.try_into().unwrap())
+                itertools::repeat_n(MontScalar::ZERO, generators_offset.try_into().unwrap())
                     .chain(a.iter().copied())
                     .collect(),
             ),
@@ -51,6 +60,20 @@ impl CommitmentEvaluationProof for NaiveEvaluationProof {
         result
     }
 
+    fn serialized_proof_size(
+        a_length: usize,
+        b_point_length: usize,
+        _setup: &Self::ProverPublicSetup<'_>,
+    ) -> usize {
+        // The proof is never serialized, so this is the size of its fields as if it were.
+        postcard::to_allocvec(&(
+            vec![MontScalar::<T>::ZERO; a_length],
+            vec![MontScalar::<T>::ZERO; b_point_length],
+            [0u8; 32],
+        ))
+        .map_or(0, |bytes| bytes.len())
+    }
+
     fn verify_batched_proof(
         &self,
         transcript: &mut impl Transcript,
@@ -72,7 +95,7 @@ impl CommitmentEvaluationProof for NaiveEvaluationProof {
         let folded_commits = commit_batch
             .iter()
             .zip(batching_factors)
-            .map(|(c, m)| *m * c)
+            .map(|(c, &m)| c * m)
             .fold(NaiveCommitment(vec![]), Add::add);
         let product = evaluations
             .iter()
@@ -82,7 +105,7 @@ impl CommitmentEvaluationProof for NaiveEvaluationProof {
         if folded_commits != self.a {
             return Err(NaiveEvaluationProofError);
         }
-        let mut b_vec = vec![TestScalar::ZERO; 1 << b_point.len()];
+        let mut b_vec = vec![MontScalar::ZERO; 1 << b_point.len()];
         compute_evaluation_vector(&mut b_vec, b_point);
         let expected_product = self
             .a
@@ -91,7 +114,7 @@ impl CommitmentEvaluationProof for NaiveEvaluationProof {
             .skip(generators_offset.try_into().unwrap())
             .zip(b_vec)
             .map(|(&a, b)| a * b)
-            .sum::<TestScalar>();
+            .sum::<MontScalar<T>>();
         if expected_product != product {
             return Err(NaiveEvaluationProofError);
         }
@@ -103,9 +126,20 @@ impl CommitmentEvaluationProof for NaiveEvaluationProof {
 
 mod tests {
     use super::NaiveEvaluationProof;
-    use crate::base::commitment::commitment_evaluation_proof_test::{
-        test_commitment_evaluation_proof_with_length_1, test_random_commitment_evaluation_proof,
-        test_simple_commitment_evaluation_proof,
+    use crate::{
+        base::{
+            commitment::commitment_evaluation_proof_test::{
+                test_commitment_evaluation_proof_with_length_1,
+                test_random_commitment_evaluation_proof, test_simple_commitment_evaluation_proof,
+            },
+            database::{owned_table_utility::*, OwnedTable, OwnedTableTestAccessor},
+            scalar::Curve25519Scalar,
+        },
+        proof_primitive::dory::DoryScalar,
+        sql::{
+            proof::VerifiableQueryResult, proof_exprs::test_utility::*,
+            proof_plans::test_utility::*,
+        },
     };
 
     #[test]
@@ -234,4 +268,50 @@ mod tests {
         test_random_commitment_evaluation_proof::<NaiveEvaluationProof>(2, 2, &(), &());
         test_random_commitment_evaluation_proof::<NaiveEvaluationProof>(2, 200, &(), &());
     }
+
+    #[test]
+    fn we_can_instantiate_the_naive_evaluation_proof_over_other_scalar_fields() {
+        test_simple_commitment_evaluation_proof::<NaiveEvaluationProof<DoryScalar>>(&(), &());
+        test_commitment_evaluation_proof_with_length_1::<NaiveEvaluationProof<DoryScalar>>(
+            &(),
+            &(),
+        );
+        test_random_commitment_evaluation_proof::<NaiveEvaluationProof<DoryScalar>>(
+            20,
+            10,
+            &(),
+            &(),
+        );
+        test_random_commitment_evaluation_proof::<NaiveEvaluationProof<Curve25519Scalar>>(
+            20,
+            10,
+            &(),
+            &(),
+        );
+    }
+
+    #[test]
+    fn we_can_prove_and_verify_a_query_over_the_bls12_381_scalar_field() {
+        let t = "sxt.t".parse().unwrap();
+        let accessor = OwnedTableTestAccessor::<NaiveEvaluationProof<DoryScalar>>::new_from_table(
+            t,
+            owned_table([
+                bigint("a", [1, -2, 3, 4, -5]),
+                varchar("b", ["x", "y", "x", "z", "x"]),
+            ]),
+            0,
+            (),
+        );
+        let ast = filter(
+            cols_expr_plan(t, &["a", "b"], &accessor),
+            tab(t),
+            equal(column(t, "b", &accessor), const_varchar("x")),
+        );
+        let verifiable_res =
+            VerifiableQueryResult::<NaiveEvaluationProof<DoryScalar>>::new(&ast, &accessor, &());
+        let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+        let expected_res: OwnedTable<DoryScalar> =
+            owned_table([bigint("a", [1, 3, -5]), varchar("b", ["x", "x", "x"])]);
+        assert_eq!(res, expected_res);
+    }
 }
@@ -128,6 +128,10 @@ impl<C: Commitment> SchemaAccessor for QueryCommitments<C> {
             })
             .collect()
     }
+
+    fn lookup_tables(&self) -> Vec<crate::base::database::TableRef> {
+        self.keys().copied().collect()
+    }
 }
 
 #[cfg(all(test, feature = "blitzar"))]
@@ -1,9 +1,9 @@
 use super::{
-    committable_column::CommittableColumn, AppendColumnCommitmentsError, ColumnCommitments,
-    ColumnCommitmentsMismatch, Commitment, DuplicateIdents,
+    committable_column::CommittableColumn, AppendColumnCommitmentsError, ColumnBounds,
+    ColumnCommitments, ColumnCommitmentsMismatch, Commitment, DuplicateIdents,
 };
 use crate::base::{
-    database::{ColumnField, CommitmentAccessor, OwnedTable, TableRef},
+    database::{ColumnField, ColumnType, CommitmentAccessor, OwnedTable, TableRef},
     scalar::Scalar,
 };
 use alloc::vec::Vec;
@@ -78,6 +78,24 @@ pub enum TableCommitmentArithmeticError {
     NonContiguous,
 }
 
+/// Errors that can occur when attempting to remove rows from a [`TableCommitment`].
+#[derive(Debug, Snafu)]
+pub enum RemoveTableCommitmentError {
+    /// Cannot commit to the removed rows.
+    #[snafu(transparent)]
+    FromColumns {
+        /// The underlying source error
+        source: TableCommitmentFromColumnsError,
+    },
+    /// The removed rows do not match the [`TableCommitment`], or there are more of them than
+    /// rows in the [`TableCommitment`].
+    #[snafu(transparent)]
+    Arithmetic {
+        /// The underlying source error
+        source: TableCommitmentArithmeticError,
+    },
+}
+
 /// Commitment for an entire table, with column and table metadata.
 ///
 /// Unlike [`ColumnCommitments`], all columns in this commitment must have the same length.
@@ -156,6 +174,17 @@ impl<C: Commitment> TableCommitment<C> {
         self.range.len()
     }
 
+    /// Returns the name, type and committed bounds of each column, in column order.
+    ///
+    /// The bounds are the ones range checks are verified against, so they are useful to inspect
+    /// when debugging failed verifications.
+    pub fn column_bounds(&self) -> impl Iterator<Item = (&Ident, ColumnType, ColumnBounds)> {
+        self.column_commitments
+            .column_metadata()
+            .iter()
+            .map(|(ident, metadata)| (ident, *metadata.column_type(), *metadata.bounds()))
+    }
+
     /// Returns a [`TableCommitment`] to the provided columns with the given row offset.
     ///
     /// Provided columns must have the same length and no duplicate idents.
@@ -233,7 +262,10 @@ impl<C: Commitment> TableCommitment<C> {
         Ok(())
     }
 
-    /// Append data of the provided table to the exiting [`TableCommitment`].
+    /// Append data of the provided table to the existing [`TableCommitment`].
+    ///
+    /// The row offset is assumed to be the end of the [`TableCommitment`]'s current range.
+    /// Unlike `try_append_record_batch`, this does not require the `arrow` feature.
     ///
     /// Will error on a variety of mismatches.
     /// See [`ColumnCommitmentsMismatch`] for an enumeration of these errors.
@@ -262,6 +294,55 @@ impl<C: Commitment> TableCommitment<C> {
             })
     }
 
+    /// Remove the first rows of the [`TableCommitment`], whose data are the provided columns.
+    ///
+    /// Only the commitment to the removed rows is computed and subtracted, and the start of the
+    /// range is moved past them. The removed rows must be provided since their contribution
+    /// cannot be recovered from the commitment itself.
+    ///
+    /// Will error on a variety of mismatches, if the provided columns have mixed length, or with
+    /// [`NegativeRange`] if there are more provided rows than rows in the [`TableCommitment`].
+    /// The [`TableCommitment`] is unchanged on error.
+    pub fn try_remove_prefix_rows<'a, COL>(
+        &mut self,
+        columns: impl IntoIterator<Item = (&'a Ident, COL)>,
+        setup: &C::PublicSetup<'_>,
+    ) -> Result<(), RemoveTableCommitmentError>
+    where
+        COL: Into<CommittableColumn<'a>>,
+    {
+        let removed = Self::try_from_columns_with_offset(columns, self.range.start, setup)?;
+        self.try_remove_rows(removed)?;
+        Ok(())
+    }
+
+    /// Remove the last rows of the [`TableCommitment`], whose data are the provided columns.
+    ///
+    /// This is the counterpart of [`TableCommitment::try_remove_prefix_rows`] that moves the end
+    /// of the range instead, and errors in the same cases.
+    pub fn try_remove_suffix_rows<'a, COL>(
+        &mut self,
+        columns: impl IntoIterator<Item = (&'a Ident, COL)>,
+        setup: &C::PublicSetup<'_>,
+    ) -> Result<(), RemoveTableCommitmentError>
+    where
+        COL: Into<CommittableColumn<'a>>,
+    {
+        let columns: Vec<(&Ident, CommittableColumn)> = columns
+            .into_iter()
+            .map(|(identifier, column)| (identifier, column.into()))
+            .collect();
+        let num_rows = num_rows_of_columns(columns.iter().map(|(_, column)| column))
+            .map_err(TableCommitmentFromColumnsError::from)?;
+        if num_rows > self.range.len() {
+            Err(TableCommitmentArithmeticError::from(NegativeRange))?;
+        }
+        let removed =
+            Self::try_from_columns_with_offset(columns, self.range.end - num_rows, setup)?;
+        self.try_remove_rows(removed)?;
+        Ok(())
+    }
+
     /// Add new columns to this [`TableCommitment`].
     ///
     /// Columns must have the same length as the current commitment and no duplicate idents.
@@ -354,6 +435,14 @@ impl<C: Commitment> TableCommitment<C> {
             range,
         })
     }
+
+    /// Subtract `removed`, which must start or end where `self` does, from `self`.
+    ///
+    /// `self` is only replaced if the subtraction succeeds.
+    fn try_remove_rows(&mut self, removed: Self) -> Result<(), TableCommitmentArithmeticError> {
+        *self = self.clone().try_sub(removed)?;
+        Ok(())
+    }
 }
 
 /// Return the number of rows for the provided columns, erroring if they have mixed length.
@@ -379,13 +468,14 @@ mod tests {
     use super::*;
     use crate::{
         base::{
-            commitment::naive_commitment::NaiveCommitment,
+            commitment::{naive_commitment::NaiveCommitment, Bounds},
             database::{owned_table_utility::*, Column, OwnedColumn},
             map::IndexMap,
             scalar::test_scalar::TestScalar,
         },
         record_batch,
     };
+    use proof_of_sql_parser::posql_time::{PoSQLTimeUnit, PoSQLTimeZone};
 
     #[test]
     #[allow(clippy::reversed_empty_ranges)]
@@ -461,6 +551,58 @@ mod tests {
         assert_eq!(table_commitment_from_owned_table, table_commitment);
     }
 
+    #[test]
+    fn we_can_get_the_committed_bounds_of_each_column() {
+        let table = owned_table::<TestScalar>([
+            bigint("bigint_id", [1, 5, -5, 0]),
+            varchar("varchar_id", ["Lorem", "ipsum", "dolor", "sit"]),
+            timestamptz(
+                "timestamp_id",
+                PoSQLTimeUnit::Second,
+                PoSQLTimeZone::utc(),
+                [30, 10, 20, 40],
+            ),
+        ]);
+        let table_commitment =
+            TableCommitment::<NaiveCommitment>::from_owned_table_with_offset(&table, 2, &());
+        let column_bounds: Vec<_> = table_commitment.column_bounds().collect();
+        assert_eq!(
+            column_bounds,
+            [
+                (
+                    &Ident::new("bigint_id"),
+                    ColumnType::BigInt,
+                    ColumnBounds::BigInt(Bounds::sharp(-5, 5).unwrap())
+                ),
+                (
+                    &Ident::new("varchar_id"),
+                    ColumnType::VarChar,
+                    ColumnBounds::NoOrder
+                ),
+                (
+                    &Ident::new("timestamp_id"),
+                    ColumnType::TimestampTZ(PoSQLTimeUnit::Second, PoSQLTimeZone::utc()),
+                    ColumnBounds::TimestampTZ(Bounds::sharp(10, 40).unwrap())
+                ),
+            ]
+        );
+
+        let empty_table_commitment =
+            TableCommitment::<NaiveCommitment>::from_owned_table_with_offset(
+                &owned_table::<TestScalar>([bigint("bigint_id", [0; 0])]),
+                0,
+                &(),
+            );
+        assert_eq!(
+            empty_table_commitment.column_bounds().collect::<Vec<_>>(),
+            [(
+                &Ident::new("bigint_id"),
+                ColumnType::BigInt,
+                ColumnBounds::BigInt(Bounds::Empty)
+            )]
+        );
+    }
+
     #[test]
     fn we_cannot_construct_table_commitment_from_duplicate_identifiers() {
         let duplicate_identifier_a = "duplicate_identifier_a".into();
@@ -629,6 +771,80 @@ mod tests {
         assert_eq!(table_commitment, table_commitment_clone);
     }
 
+    #[test]
+    fn we_can_append_owned_tables_to_table_commitment_with_offset() {
+        let first_table: OwnedTable<TestScalar> = owned_table([
+            bigint("a", [1, 2]),
+            varchar("b", ["Lorem", "ipsum"]),
+            boolean("c", [true, false]),
+        ]);
+        let second_table: OwnedTable<TestScalar> = owned_table([
+            bigint("a", [3, 4, 5]),
+            varchar("b", ["dolor", "sit", "amet"]),
+            boolean("c", [false, false, true]),
+        ]);
+        let concatenated_table: OwnedTable<TestScalar> = owned_table([
+            bigint("a", [1, 2, 3, 4, 5]),
+            varchar("b", ["Lorem", "ipsum", "dolor", "sit", "amet"]),
+            boolean("c", [true, false, false, false, true]),
+        ]);
+
+        let mut table_commitment = TableCommitment::<NaiveCommitment>::from_owned_table_with_offset(
+            &owned_table::<TestScalar>([
+                bigint("a", [0; 0]),
+                varchar("b", [""; 0]),
+                boolean("c", [true; 0]),
+            ]),
+            3,
+            &(),
+        );
+        table_commitment
+            .append_owned_table(&first_table, &())
+            .unwrap();
+        assert_eq!(table_commitment.range(), &(3..5));
+        table_commitment
+            .append_owned_table(&second_table, &())
+            .unwrap();
+        assert_eq!(table_commitment.range(), &(3..8));
+
+        assert_eq!(
+            table_commitment,
+            TableCommitment::from_owned_table_with_offset(&concatenated_table, 3, &())
+        );
+    }
+
+    #[test]
+    fn we_cannot_append_owned_tables_with_a_different_schema_to_table_commitment() {
+        let base_table: OwnedTable<TestScalar> =
+            owned_table([bigint("a", [1, 2]), varchar("b", ["Lorem", "ipsum"])]);
+        let mut table_commitment =
+            TableCommitment::<NaiveCommitment>::from_owned_table_with_offset(&base_table, 0, &());
+        let original_table_commitment = table_commitment.clone();
+
+        let table_diff_type: OwnedTable<TestScalar> =
+            owned_table([bigint("a", [3]), bigint("b", [4])]);
+        assert!(matches!(
+            table_commitment.append_owned_table(&table_diff_type, &()),
+            Err(ColumnCommitmentsMismatch::ColumnCommitmentMetadata { .. })
+        ));
+
+        let table_diff_name: OwnedTable<TestScalar> =
+            owned_table([bigint("a", [3]), varchar("c", ["dolor"])]);
+        assert!(matches!(
+            table_commitment.append_owned_table(&table_diff_name, &()),
+            Err(ColumnCommitmentsMismatch::Ident { .. })
+        ));
+
+        let table_diff_len: OwnedTable<TestScalar> = owned_table([bigint("a", [3])]);
+        assert!(matches!(
+            table_commitment.append_owned_table(&table_diff_len, &()),
+            Err(ColumnCommitmentsMismatch::NumColumns)
+        ));
+
+        // make sure the commitment wasn't mutated
+        assert_eq!(table_commitment, original_table_commitment);
+    }
+
     #[test]
     fn we_cannot_append_mismatched_columns_to_table_commitment() {
         let base_table: OwnedTable<TestScalar> = owned_table([
@@ -991,6 +1207,118 @@ mod tests {
         // subtraction for column metadata is tested more thoroughly at a lower level
     }
 
+    #[test]
+    fn we_can_remove_appended_rows_from_table_commitment() {
+        let first_rows: OwnedTable<TestScalar> = owned_table([
+            bigint("a", [1, 5, -5]),
+            varchar("b", ["Lorem", "ipsum", "dolor"]),
+        ]);
+        let appended_rows: OwnedTable<TestScalar> =
+            owned_table([bigint("a", [0, 10]), varchar("b", ["sit", "amet"])]);
+        let original_table_commitment =
+            TableCommitment::<NaiveCommitment>::from_owned_table_with_offset(&first_rows, 2, &());
+        let mut table_commitment = original_table_commitment.clone();
+        table_commitment
+            .append_owned_table(&appended_rows, &())
+            .unwrap();
+
+        // removing the appended rows again returns to the original commitment
+        let mut suffix_removed = table_commitment.clone();
+        suffix_removed
+            .try_remove_suffix_rows(appended_rows.inner_table(), &())
+            .unwrap();
+        assert_eq!(
+            suffix_removed.column_commitments().commitments(),
+            original_table_commitment.column_commitments().commitments()
+        );
+        assert_eq!(suffix_removed.range(), &(2..5));
+
+        // pruning the first rows leaves a commitment to the appended rows at their offset
+        let mut prefix_removed = table_commitment.clone();
+        prefix_removed
+            .try_remove_prefix_rows(first_rows.inner_table(), &())
+            .unwrap();
+        let appended_table_commitment =
+            TableCommitment::from_owned_table_with_offset(&appended_rows, 5, &());
+        assert_eq!(
+            prefix_removed.column_commitments().commitments(),
+            appended_table_commitment.column_commitments().commitments()
+        );
+        assert_eq!(prefix_removed.range(), &(5..7));
+
+        // removing every row leaves an empty commitment
+        prefix_removed
+            .try_remove_prefix_rows(appended_rows.inner_table(), &())
+            .unwrap();
+        assert_eq!(prefix_removed.num_rows(), 0);
+        assert_eq!(prefix_removed.range(), &(7..7));
+        assert_eq!(
+            prefix_removed.column_commitments().commitments(),
+            &vec![NaiveCommitment::default(); 2]
+        );
+    }
+
+    #[test]
+    fn we_cannot_remove_more_or_mismatched_rows_from_table_commitment() {
+        let table: OwnedTable<TestScalar> = owned_table([
+            bigint("a", [1, 5, -5]),
+            varchar("b", ["Lorem", "ipsum", "dolor"]),
+        ]);
+        let mut table_commitment =
+            TableCommitment::<NaiveCommitment>::from_owned_table_with_offset(&table, 2, &());
+        let original_table_commitment = table_commitment.clone();
+
+        let too_many_rows: OwnedTable<TestScalar> = owned_table([
+            bigint("a", [1, 5, -5, 0]),
+            varchar("b", ["Lorem", "ipsum", "dolor", "sit"]),
+        ]);
+        assert!(matches!(
+            table_commitment.try_remove_prefix_rows(too_many_rows.inner_table(), &()),
+            Err(RemoveTableCommitmentError::Arithmetic {
+                source: TableCommitmentArithmeticError::NegativeRange { .. }
+            })
+        ));
+        assert!(matches!(
+            table_commitment.try_remove_suffix_rows(too_many_rows.inner_table(), &()),
+            Err(RemoveTableCommitmentError::Arithmetic {
+                source: TableCommitmentArithmeticError::NegativeRange { .. }
+            })
+        ));
+
+        let mismatched_rows: OwnedTable<TestScalar> =
+            owned_table([bigint("a", [1]), bigint("b", [2])]);
+        assert!(matches!(
+            table_commitment.try_remove_prefix_rows(mismatched_rows.inner_table(), &()),
+            Err(RemoveTableCommitmentError::Arithmetic {
+                source: TableCommitmentArithmeticError::ColumnMismatch { .. }
+            })
+        ));
+
+        let a: Ident = "a".into();
+        let b: Ident = "b".into();
+        let mixed_length_rows = [
+            (&a, OwnedColumn::<TestScalar>::BigInt(vec![-5])),
+            (
+                &b,
+                OwnedColumn::VarChar(vec!["ipsum".to_string(), "dolor".to_string()]),
+            ),
+        ];
+        assert!(matches!(
+            table_commitment.try_remove_suffix_rows(
+                mixed_length_rows
+                    .iter()
+                    .map(|(ident, column)| (*ident, column)),
+                &()
+            ),
+            Err(RemoveTableCommitmentError::FromColumns {
+                source: TableCommitmentFromColumnsError::MixedLengthColumns { .. }
+            })
+        ));
+
+        // make sure the commitment wasn't mutated
+        assert_eq!(table_commitment, original_table_commitment);
+    }
+
     #[test]
     fn we_cannot_sub_mismatched_table_commitments() {
         let base_table: OwnedTable<TestScalar> = owned_table([
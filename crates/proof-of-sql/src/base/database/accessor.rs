@@ -134,4 +134,16 @@ pub trait SchemaAccessor {
     /// Precondition 1: the table must exist and be tamperproof.
     /// Precondition 2: `table_name` must be lowercase.
     fn lookup_schema(&self, table_ref: TableRef) -> Vec<(Ident, ColumnType)>;
+
+    /// Lookup the references of all the tables that can be queried
+    ///
+    /// Return:
+    ///   - The list of table references
+    ///
+    /// This is used to reject queries over unknown tables before any schema is looked up.
+    /// Accessors that cannot list their tables may keep the default, which returns an empty list
+    /// and so skips that check.
+    fn lookup_tables(&self) -> Vec<TableRef> {
+        Vec::new()
+    }
 }
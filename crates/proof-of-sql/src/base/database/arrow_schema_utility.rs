@@ -2,12 +2,35 @@
 //! It includes functionality to convert Arrow schemas to PoSQL-compatible formats.
 
 use alloc::sync::Arc;
-use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow::{
+    array::{Array, ArrayRef, Decimal256Array},
+    compute::{cast_with_options, CastOptions},
+    datatypes::{DataType, Field, Schema, SchemaRef},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+
+/// The precision of the decimal type that floating-point columns are converted to.
+pub const FLOAT_DECIMAL_PRECISION: u8 = 20;
+/// The scale of the decimal type that floating-point columns are converted to.
+pub const FLOAT_DECIMAL_SCALE: i8 = 10;
+
+/// Returns the PoSQL-compatible replacement for an Arrow data type, if it needs one.
+fn posql_compatible_data_type(data_type: &DataType) -> Option<DataType> {
+    match data_type {
+        DataType::Float16 | DataType::Float32 | DataType::Float64 => Some(DataType::Decimal256(
+            FLOAT_DECIMAL_PRECISION,
+            FLOAT_DECIMAL_SCALE,
+        )),
+        _ => None,
+    }
+}
 
 /// Converts an Arrow schema to a PoSQL-compatible schema.
 ///
 /// This function takes an Arrow `SchemaRef` and returns a new `SchemaRef` where
-/// floating-point data types (Float16, Float32, Float64) are converted to Decimal256(75, 30).
+/// floating-point data types (Float16, Float32, Float64) are converted to
+/// Decimal256([`FLOAT_DECIMAL_PRECISION`], [`FLOAT_DECIMAL_SCALE`]).
 /// Other data types remain unchanged.
 ///
 /// # Arguments
@@ -23,15 +46,58 @@ pub fn get_posql_compatible_schema(schema: &SchemaRef) -> SchemaRef {
         .fields()
         .iter()
         .map(|field| {
-            let new_data_type = match field.data_type() {
-                DataType::Float16 | DataType::Float32 | DataType::Float64 => {
-                    DataType::Decimal256(20, 10)
-                }
-                _ => field.data_type().clone(),
-            };
+            let new_data_type = posql_compatible_data_type(field.data_type())
+                .unwrap_or_else(|| field.data_type().clone());
             Field::new(field.name(), new_data_type, field.is_nullable())
         })
         .collect();
 
     Arc::new(Schema::new(new_fields))
 }
+
+/// Converts the floating-point columns of a record batch to decimals.
+///
+/// Every Float16, Float32 and Float64 column is converted to
+/// Decimal256([`FLOAT_DECIMAL_PRECISION`], [`FLOAT_DECIMAL_SCALE`]), so the result matches
+/// [`get_posql_compatible_schema`] and can be committed to and queried like any decimal column.
+/// Other columns are left unchanged.
+///
+/// **This conversion is lossy.** Each value is multiplied by `10^FLOAT_DECIMAL_SCALE` and rounded
+/// to the nearest integer, so digits beyond the scale are discarded and distinct floats may
+/// become equal. It is deterministic, however: the same float always becomes the same decimal,
+/// and therefore the same committed scalar, which keeps equality and ordering well-defined.
+///
+/// # Errors
+///
+/// Returns an error if a floating-point value is `NaN`, infinite, or too large for the target
+/// precision.
+///
+/// # Panics
+///
+/// Will panic if casting to `Decimal256` does not produce a [`Decimal256Array`], which should
+/// never happen.
+pub fn canonicalize_float_columns(batch: &RecordBatch) -> Result<RecordBatch, ArrowError> {
+    let cast_options = CastOptions {
+        safe: false,
+        ..CastOptions::default()
+    };
+    let columns = batch
+        .columns()
+        .iter()
+        .map(|column| -> Result<ArrayRef, ArrowError> {
+            match posql_compatible_data_type(column.data_type()) {
+                Some(data_type) => {
+                    let array = cast_with_options(column, &data_type, &cast_options)?;
+                    array
+                        .as_any()
+                        .downcast_ref::<Decimal256Array>()
+                        .expect("casting to Decimal256 produces a Decimal256Array")
+                        .validate_decimal_precision(FLOAT_DECIMAL_PRECISION)?;
+                    Ok(array)
+                }
+                None => Ok(Arc::clone(column)),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    RecordBatch::try_new(get_posql_compatible_schema(&batch.schema()), columns)
+}
@@ -0,0 +1,168 @@
+use super::arrow_schema_utility::{
+    canonicalize_float_columns, get_posql_compatible_schema, FLOAT_DECIMAL_PRECISION,
+    FLOAT_DECIMAL_SCALE,
+};
+use crate::{
+    base::{
+        commitment::{
+            naive_commitment::NaiveCommitment, naive_evaluation_proof::NaiveEvaluationProof,
+            TableCommitment,
+        },
+        database::{owned_table_utility::*, OwnedTable, OwnedTableTestAccessor},
+        scalar::test_scalar::TestScalar,
+    },
+    sql::{parse::QueryExpr, proof::VerifiableQueryResult},
+};
+use alloc::sync::Arc;
+use arrow::{
+    array::{ArrayRef, Decimal256Array, Float32Array, Float64Array, Int64Array},
+    datatypes::{i256, DataType, Field, Schema},
+    record_batch::RecordBatch,
+};
+
+fn float_batch(prices: Vec<f64>) -> RecordBatch {
+    let ids = (0..i64::try_from(prices.len()).unwrap()).collect::<Vec<_>>();
+    RecordBatch::try_new(
+        Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("price", DataType::Float64, false),
+        ])),
+        vec![
+            Arc::new(Int64Array::from(ids)),
+            Arc::new(Float64Array::from(prices)),
+        ],
+    )
+    .unwrap()
+}
+
+#[test]
+fn we_can_convert_float_fields_of_a_schema_to_decimals() {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Float16, false),
+        Field::new("b", DataType::Float32, true),
+        Field::new("c", DataType::Float64, false),
+        Field::new("d", DataType::Int64, false),
+    ]));
+    let decimal = DataType::Decimal256(FLOAT_DECIMAL_PRECISION, FLOAT_DECIMAL_SCALE);
+    let expected = Arc::new(Schema::new(vec![
+        Field::new("a", decimal.clone(), false),
+        Field::new("b", decimal.clone(), true),
+        Field::new("c", decimal, false),
+        Field::new("d", DataType::Int64, false),
+    ]));
+    assert_eq!(get_posql_compatible_schema(&schema), expected);
+}
+
+#[test]
+fn we_can_canonicalize_float_columns_to_decimals() {
+    let batch = RecordBatch::try_new(
+        Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Float32, false),
+            Field::new("b", DataType::Float64, false),
+            Field::new("c", DataType::Int64, false),
+        ])),
+        vec![
+            Arc::new(Float32Array::from(vec![0.5_f32, -2.25, 3.0])),
+            Arc::new(Float64Array::from(vec![
+                0.1,
+                -1.000_000_000_06,
+                12_345.678_9,
+            ])),
+            Arc::new(Int64Array::from(vec![1, 2, 3])),
+        ],
+    )
+    .unwrap();
+    let canonical_batch = canonicalize_float_columns(&batch).unwrap();
+    assert_eq!(
+        canonical_batch.schema(),
+        get_posql_compatible_schema(&batch.schema())
+    );
+    let expected_a: ArrayRef = Arc::new(
+        Decimal256Array::from(vec![
+            i256::from_i128(5_000_000_000),
+            i256::from_i128(-22_500_000_000),
+            i256::from_i128(30_000_000_000),
+        ])
+        .with_precision_and_scale(FLOAT_DECIMAL_PRECISION, FLOAT_DECIMAL_SCALE)
+        .unwrap(),
+    );
+    // Digits beyond the scale are rounded away
+    let expected_b: ArrayRef = Arc::new(
+        Decimal256Array::from(vec![
+            i256::from_i128(1_000_000_000),
+            i256::from_i128(-10_000_000_001),
+            i256::from_i128(123_456_789_000_000),
+        ])
+        .with_precision_and_scale(FLOAT_DECIMAL_PRECISION, FLOAT_DECIMAL_SCALE)
+        .unwrap(),
+    );
+    assert_eq!(canonical_batch.column(0), &expected_a);
+    assert_eq!(canonical_batch.column(1), &expected_b);
+    assert_eq!(canonical_batch.column(2), batch.column(2));
+}
+
+#[test]
+fn we_cannot_canonicalize_floats_that_are_not_finite_or_too_large() {
+    for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1e10, -1e12] {
+        assert!(canonicalize_float_columns(&float_batch(vec![1.0, value])).is_err());
+    }
+}
+
+#[test]
+fn we_always_commit_to_the_same_scalar_for_the_same_float() {
+    let prices = vec![19.99, 0.1, 19.99, 1.0 / 3.0, 0.1 + 0.2, 0.3];
+    let first_commitment = TableCommitment::<NaiveCommitment>::try_from_record_batch(
+        &canonicalize_float_columns(&float_batch(prices.clone())).unwrap(),
+        &(),
+    )
+    .unwrap();
+    let second_commitment = TableCommitment::<NaiveCommitment>::try_from_record_batch(
+        &canonicalize_float_columns(&float_batch(prices)).unwrap(),
+        &(),
+    )
+    .unwrap();
+    assert_eq!(first_commitment, second_commitment);
+
+    // Equal floats commit to equal scalars, and floats that only differ beyond the scale collide
+    let NaiveCommitment(price_scalars) = &first_commitment.column_commitments().commitments()[1];
+    assert_eq!(price_scalars[0], price_scalars[2]);
+    assert_eq!(price_scalars[4], price_scalars[5]);
+    assert_eq!(price_scalars[3], TestScalar::from(3_333_333_333_i64));
+}
+
+#[test]
+fn we_can_prove_a_filter_on_canonicalized_float_columns() {
+    let batch =
+        canonicalize_float_columns(&float_batch(vec![19.99, 5.0, 100.25, 20.0, 0.1])).unwrap();
+    let t = "sxt.prices".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<NaiveEvaluationProof>::new_from_table(
+        t,
+        OwnedTable::try_from(batch).unwrap(),
+        0,
+        (),
+    );
+    let query = QueryExpr::try_new(
+        "SELECT id, price FROM prices WHERE price >= 19.99"
+            .parse()
+            .unwrap(),
+        "sxt".into(),
+        &accessor,
+    )
+    .unwrap();
+    let verifiable_result =
+        VerifiableQueryResult::<NaiveEvaluationProof>::new(query.proof_expr(), &accessor, &());
+    let result = verifiable_result
+        .verify(query.proof_expr(), &accessor, &())
+        .unwrap()
+        .table;
+    let expected: OwnedTable<TestScalar> = owned_table([
+        bigint("id", [0, 2, 3]),
+        decimal75(
+            "price",
+            FLOAT_DECIMAL_PRECISION,
+            FLOAT_DECIMAL_SCALE,
+            [199_900_000_000_i64, 1_002_500_000_000, 200_000_000_000],
+        ),
+    ]);
+    assert_eq!(result, expected);
+}
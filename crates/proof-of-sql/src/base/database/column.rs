@@ -50,6 +50,11 @@ pub enum Column<'a, S: Scalar> {
     /// - the second element maps to a timezone
     /// - the third element maps to columns of timeunits since unix epoch
     TimestampTZ(PoSQLTimeUnit, PoSQLTimeZone, &'a [i64]),
+    /// Date columns
+    /// - the element maps to columns of days since unix epoch
+    Date(&'a [i32]),
+    /// u64 columns
+    UInt64(&'a [u64]),
 }
 
 impl<'a, S: Scalar> Column<'a, S> {
@@ -69,6 +74,8 @@ impl<'a, S: Scalar> Column<'a, S> {
             Self::TimestampTZ(time_unit, timezone, _) => {
                 ColumnType::TimestampTZ(*time_unit, *timezone)
             }
+            Self::Date(_) => ColumnType::Date,
+            Self::UInt64(_) => ColumnType::UInt64,
         }
     }
     /// Returns the length of the column.
@@ -80,7 +87,7 @@ impl<'a, S: Scalar> Column<'a, S> {
             Self::Boolean(col) => col.len(),
             Self::TinyInt(col) => col.len(),
             Self::SmallInt(col) => col.len(),
-            Self::Int(col) => col.len(),
+            Self::Int(col) | Self::Date(col) => col.len(),
             Self::BigInt(col) | Self::TimestampTZ(_, _, col) => col.len(),
             Self::VarChar((col, scals)) => {
                 assert_eq!(col.len(), scals.len());
@@ -88,6 +95,7 @@ impl<'a, S: Scalar> Column<'a, S> {
             }
             Self::Int128(col) => col.len(),
             Self::Scalar(col) | Self::Decimal75(_, _, col) => col.len(),
+            Self::UInt64(col) => col.len(),
         }
     }
     /// Returns `true` if the column has no elements.
@@ -130,6 +138,10 @@ impl<'a, S: Scalar> Column<'a, S> {
             LiteralValue::TimeStampTZ(tu, tz, value) => {
                 Column::TimestampTZ(*tu, *tz, alloc.alloc_slice_fill_copy(length, *value))
             }
+            LiteralValue::Date(value) => Column::Date(alloc.alloc_slice_fill_copy(length, *value)),
+            LiteralValue::UInt64(value) => {
+                Column::UInt64(alloc.alloc_slice_fill_copy(length, *value))
+            }
             LiteralValue::VarChar(string) => Column::VarChar((
                 alloc.alloc_slice_fill_with(length, |_| alloc.alloc_str(string) as &str),
                 alloc.alloc_slice_fill_copy(length, S::from(string)),
@@ -162,6 +174,8 @@ impl<'a, S: Scalar> Column<'a, S> {
                 ))
             }
             OwnedColumn::TimestampTZ(tu, tz, col) => Column::TimestampTZ(*tu, *tz, col.as_slice()),
+            OwnedColumn::Date(col) => Column::Date(col.as_slice()),
+            OwnedColumn::UInt64(col) => Column::UInt64(col.as_slice()),
         }
     }
 
@@ -245,6 +259,22 @@ impl<'a, S: Scalar> Column<'a, S> {
         }
     }
 
+    /// Returns the column as a slice of i32 if it is a date column. Otherwise, returns None.
+    pub(crate) fn as_date(&self) -> Option<&'a [i32]> {
+        match self {
+            Self::Date(col) => Some(col),
+            _ => None,
+        }
+    }
+
+    /// Returns the column as a slice of u64 if it is a uint64 column. Otherwise, returns None.
+    pub(crate) fn as_uint64(&self) -> Option<&'a [u64]> {
+        match self {
+            Self::UInt64(col) => Some(col),
+            _ => None,
+        }
+    }
+
     /// Returns element at index as scalar
     ///
     /// Note that if index is out of bounds, this function will return None
@@ -253,11 +283,12 @@ impl<'a, S: Scalar> Column<'a, S> {
             Self::Boolean(col) => S::from(col[index]),
             Self::TinyInt(col) => S::from(col[index]),
             Self::SmallInt(col) => S::from(col[index]),
-            Self::Int(col) => S::from(col[index]),
+            Self::Int(col) | Self::Date(col) => S::from(col[index]),
             Self::BigInt(col) | Self::TimestampTZ(_, _, col) => S::from(col[index]),
             Self::Int128(col) => S::from(col[index]),
             Self::Scalar(col) | Self::Decimal75(_, _, col) => col[index],
             Self::VarChar((_, scals)) => scals[index],
+            Self::UInt64(col) => S::from(col[index]),
         })
     }
 
@@ -271,11 +302,12 @@ impl<'a, S: Scalar> Column<'a, S> {
             Self::VarChar((_, values)) => slice_cast_with(values, |s| *s * scale_factor),
             Self::TinyInt(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
             Self::SmallInt(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
-            Self::Int(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
+            Self::Int(col) | Self::Date(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
             Self::BigInt(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
             Self::Int128(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
             Self::Scalar(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
             Self::TimestampTZ(_, _, col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
+            Self::UInt64(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
         }
     }
 }
@@ -317,6 +349,15 @@ pub enum ColumnType {
     /// Mapped to `S`
     #[serde(alias = "SCALAR", alias = "scalar")]
     Scalar,
+    /// Mapped to i32, the number of days since the unix epoch
+    #[serde(alias = "DATE", alias = "date")]
+    Date,
+    /// Mapped to u64
+    ///
+    /// Unsigned values are kept apart from the signed integer types: they can only be compared
+    /// with each other and with integer literals in `[0, 2^64)`, so a sign is never reinterpreted.
+    #[serde(alias = "UINT64", alias = "uint64")]
+    UInt64,
 }
 
 impl ColumnType {
@@ -396,9 +437,10 @@ impl ColumnType {
         match self {
             Self::TinyInt => Some(3_u8),
             Self::SmallInt => Some(5_u8),
-            Self::Int => Some(10_u8),
+            Self::Int | Self::Date => Some(10_u8),
             Self::BigInt | Self::TimestampTZ(_, _) => Some(19_u8),
             Self::Int128 => Some(39_u8),
+            Self::UInt64 => Some(20_u8),
             Self::Decimal75(precision, _) => Some(precision.value()),
             // Scalars are not in database & are only used for typeless comparisons for testing so we return 0
             // so that they do not cause errors when used in comparisons.
@@ -416,7 +458,9 @@ impl ColumnType {
             | Self::Int
             | Self::BigInt
             | Self::Int128
-            | Self::Scalar => Some(0),
+            | Self::Date
+            | Self::Scalar
+            | Self::UInt64 => Some(0),
             Self::Boolean | Self::VarChar => None,
             Self::TimestampTZ(tu, _) => match tu {
                 PoSQLTimeUnit::Second => Some(0),
@@ -434,9 +478,10 @@ impl ColumnType {
             Self::Boolean => size_of::<bool>(),
             Self::TinyInt => size_of::<i8>(),
             Self::SmallInt => size_of::<i16>(),
-            Self::Int => size_of::<i32>(),
+            Self::Int | Self::Date => size_of::<i32>(),
             Self::BigInt | Self::TimestampTZ(_, _) => size_of::<i64>(),
             Self::Int128 => size_of::<i128>(),
+            Self::UInt64 => size_of::<u64>(),
             Self::Scalar | Self::Decimal75(_, _) | Self::VarChar => size_of::<[u64; 4]>(),
         }
     }
@@ -457,8 +502,11 @@ impl ColumnType {
             | Self::Int
             | Self::BigInt
             | Self::Int128
-            | Self::TimestampTZ(_, _) => true,
-            Self::Decimal75(_, _) | Self::Scalar | Self::VarChar | Self::Boolean => false,
+            | Self::TimestampTZ(_, _)
+            | Self::Date => true,
+            Self::Decimal75(_, _) | Self::Scalar | Self::VarChar | Self::Boolean | Self::UInt64 => {
+                false
+            }
         }
     }
 }
@@ -485,6 +533,8 @@ impl Display for ColumnType {
             ColumnType::TimestampTZ(timeunit, timezone) => {
                 write!(f, "TIMESTAMP(TIMEUNIT: {timeunit}, TIMEZONE: {timezone})")
             }
+            ColumnType::Date => write!(f, "DATE"),
+            ColumnType::UInt64 => write!(f, "UINT64"),
         }
     }
 }
@@ -707,6 +757,14 @@ mod tests {
             serde_json::from_str::<ColumnType>(r#""smallint""#).unwrap(),
             ColumnType::SmallInt
         );
+        assert_eq!(
+            serde_json::from_str::<ColumnType>(r#""UINT64""#).unwrap(),
+            ColumnType::UInt64
+        );
+        assert_eq!(
+            serde_json::from_str::<ColumnType>(r#""uint64""#).unwrap(),
+            ColumnType::UInt64
+        );
         assert_eq!(
             serde_json::from_str::<ColumnType>(r#""int""#).unwrap(),
             ColumnType::Int
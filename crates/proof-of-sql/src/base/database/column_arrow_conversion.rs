@@ -0,0 +1,82 @@
+//! Conversion between a single [`Column`] and an arrow [`ArrayRef`].
+use super::{
+    ArrayRefExt, ArrowArrayToColumnConversionError, Column, ColumnType, OwnedArrowConversionError,
+    OwnedColumn,
+};
+use crate::base::scalar::Scalar;
+use alloc::{string::ToString, sync::Arc};
+use arrow::array::{
+    ArrayRef, TimestampMicrosecondArray, TimestampMillisecondArray, TimestampNanosecondArray,
+    TimestampSecondArray,
+};
+use bumpalo::Bump;
+use proof_of_sql_parser::posql_time::PoSQLTimeUnit;
+
+impl<'a, S: Scalar> Column<'a, S> {
+    /// Convert the column to an arrow array.
+    ///
+    /// The arrow type of the array is the one given by `DataType::from(&self.column_type())`.
+    /// In particular, timestamps keep the unit they are stored in, so no value is rescaled, and
+    /// the timezone is kept on the arrow type.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OwnedArrowConversionError::UnsupportedColumnType`] if the column is a
+    /// [`Column::Scalar`], which has no arrow type.
+    pub fn to_arrow_array(&self) -> Result<ArrayRef, OwnedArrowConversionError> {
+        Ok(match self {
+            Column::Scalar(_) => {
+                return Err(OwnedArrowConversionError::UnsupportedColumnType {
+                    column_type: self.column_type(),
+                })
+            }
+            Column::TimestampTZ(time_unit, timezone, col) => {
+                let timezone = timezone.to_string();
+                match time_unit {
+                    PoSQLTimeUnit::Second => {
+                        Arc::new(TimestampSecondArray::from(col.to_vec()).with_timezone(timezone))
+                    }
+                    PoSQLTimeUnit::Millisecond => Arc::new(
+                        TimestampMillisecondArray::from(col.to_vec()).with_timezone(timezone),
+                    ),
+                    PoSQLTimeUnit::Microsecond => Arc::new(
+                        TimestampMicrosecondArray::from(col.to_vec()).with_timezone(timezone),
+                    ),
+                    PoSQLTimeUnit::Nanosecond => Arc::new(
+                        TimestampNanosecondArray::from(col.to_vec()).with_timezone(timezone),
+                    ),
+                }
+            }
+            _ => ArrayRef::from(OwnedColumn::from(self)),
+        })
+    }
+
+    /// Convert an arrow array to a column of type `column_type`.
+    ///
+    /// The arrow type of `array` must map to exactly `column_type`. Values are never cast, so
+    /// e.g. a timestamp array must have the same unit and timezone as `column_type`.
+    /// `alloc` is used for the values that can not be borrowed from `array`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `array` contains nulls, if its type is not supported, or if its type
+    /// does not match `column_type`.
+    pub fn try_from_arrow_array(
+        array: &'a ArrayRef,
+        column_type: ColumnType,
+        alloc: &'a Bump,
+    ) -> Result<Self, ArrowArrayToColumnConversionError> {
+        let actual = ColumnType::try_from(array.data_type().clone()).map_err(|_| {
+            ArrowArrayToColumnConversionError::UnsupportedType {
+                datatype: array.data_type().clone(),
+            }
+        })?;
+        if actual != column_type {
+            return Err(ArrowArrayToColumnConversionError::TypeMismatch {
+                expected: column_type,
+                actual: array.data_type().clone(),
+            });
+        }
+        array.to_column(alloc, &(0..array.len()), None)
+    }
+}
@@ -0,0 +1,206 @@
+use super::{ArrowArrayToColumnConversionError, Column, ColumnType, OwnedArrowConversionError};
+use crate::base::{
+    math::decimal::Precision,
+    scalar::{test_scalar::TestScalar, Scalar},
+};
+use alloc::sync::Arc;
+use arrow::{
+    array::{
+        Array, ArrayRef, BooleanArray, Decimal256Array, Int64Array, StringArray,
+        TimestampMicrosecondArray, TimestampMillisecondArray, TimestampNanosecondArray,
+        TimestampSecondArray,
+    },
+    datatypes::{i256, DataType, TimeUnit as ArrowTimeUnit},
+};
+use bumpalo::Bump;
+use proof_of_sql_parser::posql_time::{PoSQLTimeUnit, PoSQLTimeZone};
+
+/// Converts `column` to arrow and back, checking the arrow type on the way.
+fn round_trip(column: Column<'_, TestScalar>) {
+    let alloc = Bump::new();
+    let array = column.to_arrow_array().unwrap();
+    assert_eq!(array.data_type(), &DataType::from(&column.column_type()));
+    assert_eq!(array.len(), column.len());
+    let round_tripped = Column::try_from_arrow_array(&array, column.column_type(), &alloc).unwrap();
+    assert_eq!(round_tripped, column);
+}
+
+/// Checks that an arrow array with nulls is rejected.
+fn assert_nulls_are_rejected(array: impl Array + 'static, column_type: ColumnType) {
+    let array: ArrayRef = Arc::new(array);
+    assert_eq!(array.null_count(), 1);
+    let alloc = Bump::new();
+    assert_eq!(
+        Column::<TestScalar>::try_from_arrow_array(&array, column_type, &alloc),
+        Err(ArrowArrayToColumnConversionError::ArrayContainsNulls)
+    );
+}
+
+#[test]
+fn we_can_round_trip_boolean_columns_through_arrow() {
+    round_trip(Column::Boolean(&[true, false, true]));
+    round_trip(Column::Boolean(&[]));
+}
+
+#[test]
+fn we_cannot_convert_boolean_arrays_with_nulls_to_columns() {
+    assert_nulls_are_rejected(
+        BooleanArray::from(vec![Some(true), None]),
+        ColumnType::Boolean,
+    );
+}
+
+#[test]
+fn we_can_round_trip_varchar_columns_through_arrow() {
+    let strings = ["a", "", "hello world"];
+    let scalars = strings.map(TestScalar::from);
+    round_trip(Column::VarChar((&strings, &scalars)));
+    round_trip(Column::VarChar((&[], &[])));
+}
+
+#[test]
+fn we_cannot_convert_utf8_arrays_with_nulls_to_columns() {
+    assert_nulls_are_rejected(
+        StringArray::from(vec![Some("a"), None]),
+        ColumnType::VarChar,
+    );
+}
+
+#[test]
+fn we_can_round_trip_decimal75_columns_through_arrow() {
+    let values = [
+        TestScalar::from(-123),
+        TestScalar::ZERO,
+        TestScalar::from(i128::MAX),
+    ];
+    round_trip(Column::Decimal75(Precision::new(75).unwrap(), 2, &values));
+    round_trip(Column::Decimal75(Precision::new(5).unwrap(), -3, &[]));
+}
+
+#[test]
+fn we_cannot_convert_decimal256_arrays_with_nulls_to_columns() {
+    assert_nulls_are_rejected(
+        Decimal256Array::from(vec![Some(i256::from(1)), None])
+            .with_precision_and_scale(10, 2)
+            .unwrap(),
+        ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+    );
+}
+
+#[test]
+fn we_can_round_trip_timestamp_columns_of_every_unit_through_arrow() {
+    let values = [i64::MIN, -1, 0, 1_700_000_000, i64::MAX];
+    for time_unit in [
+        PoSQLTimeUnit::Second,
+        PoSQLTimeUnit::Millisecond,
+        PoSQLTimeUnit::Microsecond,
+        PoSQLTimeUnit::Nanosecond,
+    ] {
+        for timezone in [PoSQLTimeZone::utc(), PoSQLTimeZone::new(-5 * 3600)] {
+            round_trip(Column::TimestampTZ(time_unit, timezone, &values));
+            round_trip(Column::TimestampTZ(time_unit, timezone, &[]));
+        }
+    }
+}
+
+#[test]
+fn we_keep_the_stored_timestamp_unit_when_converting_to_arrow() {
+    let column = Column::<TestScalar>::TimestampTZ(
+        PoSQLTimeUnit::Nanosecond,
+        PoSQLTimeZone::utc(),
+        &[1_700_000_000_123_456_789],
+    );
+    let array = column.to_arrow_array().unwrap();
+    let array = array
+        .as_any()
+        .downcast_ref::<TimestampNanosecondArray>()
+        .unwrap();
+    assert_eq!(array.values().as_ref(), &[1_700_000_000_123_456_789]);
+}
+
+#[test]
+fn we_cannot_convert_timestamp_arrays_with_nulls_to_columns() {
+    let timezone = PoSQLTimeZone::utc();
+    let values = vec![Some(1), None];
+    assert_nulls_are_rejected(
+        TimestampSecondArray::from(values.clone()).with_timezone("+00:00"),
+        ColumnType::TimestampTZ(PoSQLTimeUnit::Second, timezone),
+    );
+    assert_nulls_are_rejected(
+        TimestampMillisecondArray::from(values.clone()).with_timezone("+00:00"),
+        ColumnType::TimestampTZ(PoSQLTimeUnit::Millisecond, timezone),
+    );
+    assert_nulls_are_rejected(
+        TimestampMicrosecondArray::from(values.clone()).with_timezone("+00:00"),
+        ColumnType::TimestampTZ(PoSQLTimeUnit::Microsecond, timezone),
+    );
+    assert_nulls_are_rejected(
+        TimestampNanosecondArray::from(values).with_timezone("+00:00"),
+        ColumnType::TimestampTZ(PoSQLTimeUnit::Nanosecond, timezone),
+    );
+}
+
+#[test]
+fn we_cannot_convert_timestamp_arrays_of_another_unit_to_columns() {
+    let alloc = Bump::new();
+    let array: ArrayRef =
+        Arc::new(TimestampMillisecondArray::from(vec![1_000]).with_timezone("+00:00"));
+    assert_eq!(
+        Column::<TestScalar>::try_from_arrow_array(
+            &array,
+            ColumnType::TimestampTZ(PoSQLTimeUnit::Nanosecond, PoSQLTimeZone::utc()),
+            &alloc
+        ),
+        Err(ArrowArrayToColumnConversionError::TypeMismatch {
+            expected: ColumnType::TimestampTZ(PoSQLTimeUnit::Nanosecond, PoSQLTimeZone::utc()),
+            actual: DataType::Timestamp(ArrowTimeUnit::Millisecond, Some("+00:00".into())),
+        })
+    );
+}
+
+#[test]
+fn we_can_round_trip_other_columns_through_arrow() {
+    round_trip(Column::TinyInt(&[i8::MIN, 0, i8::MAX]));
+    round_trip(Column::SmallInt(&[i16::MIN, 0, i16::MAX]));
+    round_trip(Column::Int(&[i32::MIN, 0, i32::MAX]));
+    round_trip(Column::BigInt(&[i64::MIN, 0, i64::MAX]));
+    round_trip(Column::Int128(&[i128::MIN + 1, 0, i128::MAX]));
+    round_trip(Column::Date(&[-1, 0, 19_000]));
+    round_trip(Column::UInt64(&[0, u64::MAX]));
+}
+
+#[test]
+fn we_cannot_convert_arrays_to_columns_of_another_type() {
+    let alloc = Bump::new();
+    let array: ArrayRef = Arc::new(Int64Array::from(vec![1, 2]));
+    assert_eq!(
+        Column::<TestScalar>::try_from_arrow_array(&array, ColumnType::Int, &alloc),
+        Err(ArrowArrayToColumnConversionError::TypeMismatch {
+            expected: ColumnType::Int,
+            actual: DataType::Int64,
+        })
+    );
+}
+
+#[test]
+fn we_cannot_convert_unsupported_arrays_to_columns() {
+    let alloc = Bump::new();
+    let array: ArrayRef = Arc::new(arrow::array::Float32Array::from(vec![1.0]));
+    assert_eq!(
+        Column::<TestScalar>::try_from_arrow_array(&array, ColumnType::BigInt, &alloc),
+        Err(ArrowArrayToColumnConversionError::UnsupportedType {
+            datatype: DataType::Float32,
+        })
+    );
+}
+
+#[test]
+fn we_cannot_convert_scalar_columns_to_arrow() {
+    let column = Column::<TestScalar>::Scalar(&[TestScalar::ONE]);
+    assert!(matches!(
+        column.to_arrow_array(),
+        Err(OwnedArrowConversionError::UnsupportedColumnType {
+            column_type: ColumnType::Scalar
+        })
+    ));
+}
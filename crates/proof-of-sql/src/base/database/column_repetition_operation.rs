@@ -71,6 +71,18 @@ pub trait RepetitionOp {
                     }) as &[_],
                 )
             }
+            ColumnType::Date => {
+                let mut iter = Self::op(column.as_date().expect("Column types should match"), n);
+                Column::Date(alloc.alloc_slice_fill_with(len, |_| {
+                    iter.next().expect("Iterator should have enough elements")
+                }) as &[_])
+            }
+            ColumnType::UInt64 => {
+                let mut iter = Self::op(column.as_uint64().expect("Column types should match"), n);
+                Column::UInt64(alloc.alloc_slice_fill_with(len, |_| {
+                    iter.next().expect("Iterator should have enough elements")
+                }) as &[_])
+            }
             ColumnType::VarChar => {
                 let (raw_result, raw_scalars) =
                     column.as_varchar().expect("Column types should match");
@@ -21,6 +21,16 @@ impl<S: Scalar> OwnedTable<S> {
                 self.evaluate_binary_expr(&(*op).into(), left, right)
             }
             Expression::Unary { op, expr } => self.evaluate_unary_expr((*op).into(), expr),
+            Expression::StringTransform { op, expr } => {
+                Ok(self.evaluate(expr)?.element_wise_string_transform(*op)?)
+            }
+            Expression::Substring {
+                expr,
+                start,
+                length,
+            } => Ok(self
+                .evaluate(expr)?
+                .element_wise_substring(*start, *length)?),
             _ => Err(ExpressionEvaluationError::Unsupported {
                 expression: format!("Expression {expr:?} is not supported yet"),
             }),
@@ -60,6 +70,7 @@ impl<S: Scalar> OwnedTable<S> {
                 its.timezone(),
                 vec![its.timestamp().timestamp(); len],
             )),
+            Literal::Date(date) => Ok(OwnedColumn::Date(vec![date.days_since_epoch(); len])),
         }
     }
 
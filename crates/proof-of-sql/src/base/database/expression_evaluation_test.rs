@@ -189,6 +189,85 @@ fn we_can_evaluate_an_arithmetic_expression() {
     assert_eq!(actual_column, expected_column);
 }
 
+#[test]
+fn we_can_evaluate_string_transformations() {
+    let table: OwnedTable<TestScalar> = owned_table([varchar(
+        "names",
+        ["  Alice ", "bob", "\tÉmile ", "straße", ""],
+    )]);
+    let actual_column = table.evaluate(&trim(col("names"))).unwrap();
+    let expected_column = OwnedColumn::VarChar(
+        ["Alice", "bob", "\tÉmile", "straße", ""]
+            .map(String::from)
+            .to_vec(),
+    );
+    assert_eq!(actual_column, expected_column);
+
+    let actual_column = table.evaluate(&upper(trim(col("names")))).unwrap();
+    let expected_column = OwnedColumn::VarChar(
+        ["ALICE", "BOB", "\tÉMILE", "STRASSE", ""]
+            .map(String::from)
+            .to_vec(),
+    );
+    assert_eq!(actual_column, expected_column);
+
+    let actual_column = table.evaluate(&lower(col("names"))).unwrap();
+    let expected_column = OwnedColumn::VarChar(
+        ["  alice ", "bob", "\témile ", "straße", ""]
+            .map(String::from)
+            .to_vec(),
+    );
+    assert_eq!(actual_column, expected_column);
+
+    let actual_column = table.evaluate(&lower(lit("ÀB"))).unwrap();
+    let expected_column = OwnedColumn::VarChar(vec!["àb".to_string(); 5]);
+    assert_eq!(actual_column, expected_column);
+}
+
+#[test]
+fn we_can_evaluate_substrings_of_ascii_strings() {
+    let table: OwnedTable<TestScalar> =
+        owned_table([varchar("cards", ["4111111111111111", "5500", "34", ""])]);
+    let substrings = |start, length| match table
+        .evaluate(&substring(col("cards"), start, length))
+        .unwrap()
+    {
+        OwnedColumn::VarChar(values) => values,
+        _ => panic!("SUBSTRING must return a VARCHAR column"),
+    };
+    assert_eq!(substrings(1, 4), ["4111", "5500", "34", ""]);
+    assert_eq!(substrings(3, 2), ["11", "00", "", ""]);
+    // Positions before the first character count towards the length
+    assert_eq!(substrings(0, 2), ["4", "5", "3", ""]);
+    assert_eq!(substrings(-2, 5), ["41", "55", "34", ""]);
+    // A start beyond the end of the string yields an empty string
+    assert_eq!(substrings(5, 3), ["111", "", "", ""]);
+    assert_eq!(substrings(17, 1), ["", "", "", ""]);
+    // So does a non-positive length
+    assert_eq!(substrings(1, 0), ["", "", "", ""]);
+    assert_eq!(substrings(2, -1), ["", "", "", ""]);
+    // Extreme bounds don't overflow
+    assert_eq!(substrings(i64::MIN, i64::MAX), ["", "", "", ""]);
+    assert_eq!(substrings(2, i64::MAX), ["111111111111111", "500", "4", ""]);
+    assert_eq!(substrings(i64::MAX, i64::MAX), ["", "", "", ""]);
+}
+
+#[test]
+fn we_can_evaluate_substrings_of_multi_byte_strings() {
+    let table: OwnedTable<TestScalar> =
+        owned_table([varchar("words", ["héllo", "日本語テキスト", "🦀rust", "ß"])]);
+    let actual_column = table.evaluate(&substring(col("words"), 2, 3)).unwrap();
+    let expected_column =
+        OwnedColumn::VarChar(["éll", "本語テ", "rus", ""].map(String::from).to_vec());
+    assert_eq!(actual_column, expected_column);
+
+    let actual_column = table
+        .evaluate(&upper(substring(col("words"), 1, 1)))
+        .unwrap();
+    let expected_column = OwnedColumn::VarChar(["H", "日", "🦀", "SS"].map(String::from).to_vec());
+    assert_eq!(actual_column, expected_column);
+}
+
 #[test]
 fn we_cannot_evaluate_expressions_if_column_operation_errors_out() {
     let table: OwnedTable<TestScalar> = owned_table([
@@ -215,6 +294,24 @@ fn we_cannot_evaluate_expressions_if_column_operation_errors_out() {
         })
     ));
 
+    // UPPER doesn't work on bigint
+    let expr = upper(col("bigints"));
+    assert!(matches!(
+        table.evaluate(&expr),
+        Err(ExpressionEvaluationError::ColumnOperationError {
+            source: ColumnOperationError::UnaryOperationInvalidColumnType { .. }
+        })
+    ));
+
+    // SUBSTRING doesn't work on bigint
+    let expr = substring(col("bigints"), 1, 2);
+    assert!(matches!(
+        table.evaluate(&expr),
+        Err(ExpressionEvaluationError::ColumnOperationError {
+            source: ColumnOperationError::UnaryOperationInvalidColumnType { .. }
+        })
+    ));
+
     // + doesn't work on varchar
     let expr = add(col("sarah"), col("bigints"));
     assert!(matches!(
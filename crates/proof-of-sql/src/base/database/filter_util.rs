@@ -75,5 +75,11 @@ pub fn filter_column_by_index<'a, S: Scalar>(
             *tz,
             alloc.alloc_slice_fill_iter(indexes.iter().map(|&i| col[i])),
         ),
+        Column::Date(col) => {
+            Column::Date(alloc.alloc_slice_fill_iter(indexes.iter().map(|&i| col[i])))
+        }
+        Column::UInt64(col) => {
+            Column::UInt64(alloc.alloc_slice_fill_iter(indexes.iter().map(|&i| col[i])))
+        }
     }
 }
@@ -3,14 +3,18 @@
 use crate::base::{
     database::{
         filter_util::filter_column_by_index, order_by_util::compare_indexes_by_columns, Column,
+        ColumnType,
     },
     if_rayon,
+    math::decimal::{Precision, MAX_SUPPORTED_PRECISION},
     scalar::Scalar,
 };
 use alloc::vec::Vec;
 use bumpalo::Bump;
 use core::cmp::Ordering;
 use itertools::Itertools;
+use num_bigint::BigInt;
+use num_traits::{Signed, Zero};
 #[cfg(feature = "rayon")]
 use rayon::prelude::ParallelSliceMut;
 use snafu::Snafu;
@@ -142,6 +146,95 @@ pub fn aggregate_columns<'a, S: Scalar>(
     })
 }
 
+/// The number of digits by which the scale of a `VAR_POP` result exceeds twice the scale of its input.
+pub(crate) const VAR_POP_SCALE_INCREASE: i8 = 6;
+
+/// Returns the type of `VAR_POP` applied to values of type `column_type`, or `None` if the type
+/// is not numeric.
+///
+/// The variance of values with precision `p` and scale `s` is exact at scale `2s` and, being at
+/// most the largest square, fits in precision `2p`. Since the final division by the number of rows
+/// introduces a fractional part, [`VAR_POP_SCALE_INCREASE`] more digits are kept. The precision
+/// is capped at the maximum supported precision.
+///
+/// # Panics
+/// Will not panic since the precision and scale are clamped into their valid ranges.
+pub(crate) fn var_pop_column_type(column_type: ColumnType) -> Option<ColumnType> {
+    if !column_type.is_numeric() {
+        return None;
+    }
+    let increase = i16::from(VAR_POP_SCALE_INCREASE);
+    let precision = (2 * i16::from(column_type.precision_value()?) + increase)
+        .min(i16::from(MAX_SUPPORTED_PRECISION));
+    let scale = (2 * i16::from(column_type.scale()?) + increase).clamp(-precision, precision);
+    Some(ColumnType::Decimal75(
+        Precision::new(u8::try_from(precision).expect("the precision is between 6 and 75"))
+            .expect("the precision is between 6 and 75"),
+        i8::try_from(scale).expect("the scale is at most the precision in absolute value"),
+    ))
+}
+
+/// Returns the squares of `values`, or `None` if summing the values or their squares could leave
+/// the range of signed scalars.
+///
+/// The grouped sums are computed in the scalar field, so they are only exact while every partial
+/// sum is at most [`Scalar::MAX_SIGNED`] in absolute value. This holds if the number of values
+/// times the largest square does. Large `INT128` or wide `DECIMAL` values fail this check.
+pub(crate) fn var_pop_squares<'a, S: Scalar>(alloc: &'a Bump, values: &[S]) -> Option<&'a [S]> {
+    let largest_square = values
+        .iter()
+        .map(|&value| Into::<BigInt>::into(value).pow(2))
+        .max()
+        .unwrap_or_default();
+    if BigInt::from(values.len()) * largest_square > S::MAX_SIGNED.into() {
+        return None;
+    }
+    Some(alloc.alloc_slice_fill_iter(values.iter().map(|&value| value * value)))
+}
+
+/// Combines the grouped sums of some values, the grouped sums of their squares and the group sizes
+/// into the population variance of each group, i.e. `(n * sum(x^2) - sum(x)^2) / n^2`.
+///
+/// The results are the raw values of decimals whose scale is `scale_increase` more than twice
+/// the scale of the input values, rounded half away from zero. See [`var_pop_column_type`].
+///
+/// Returns `None` if a group is empty or a result does not fit in a scalar.
+pub(crate) fn var_pop_from_sums<S: Scalar>(
+    sums: &[S],
+    sums_of_squares: &[S],
+    counts: &[i64],
+    scale_increase: i8,
+) -> Option<Vec<S>> {
+    let power_of_ten = BigInt::from(10).pow(scale_increase.unsigned_abs().into());
+    let (multiplier, divisor) = if scale_increase >= 0 {
+        (power_of_ten, BigInt::from(1))
+    } else {
+        (BigInt::from(1), power_of_ten)
+    };
+    sums.iter()
+        .zip(sums_of_squares)
+        .zip(counts)
+        .map(|((&sum, &sum_of_squares), &count)| {
+            let n = BigInt::from(count);
+            let numerator = (&n * Into::<BigInt>::into(sum_of_squares)
+                - Into::<BigInt>::into(sum).pow(2))
+                * &multiplier;
+            let denominator = &n * &n * &divisor;
+            if denominator.is_zero() {
+                return None;
+            }
+            // Round half away from zero
+            let quotient: BigInt = (2 * numerator.abs() + &denominator) / (2 * &denominator);
+            let quotient = if numerator.is_negative() {
+                -quotient
+            } else {
+                quotient
+            };
+            S::try_from(quotient).ok()
+        })
+        .collect()
+}
+
 /// Returns a slice with the lifetime of `alloc` that contains the grouped sums of `column`.
 /// The `counts` slice contains the number of elements in each group and the `indexes` slice
 /// contains the indexes of the elements in `column`.
@@ -164,7 +257,11 @@ pub(crate) fn sum_aggregate_column_by_index_counts<'a, S: Scalar>(
         }
         Column::Scalar(col) => sum_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         // The following should never be reached because the `SUM` function can only be applied to numeric types.
-        Column::VarChar(_) | Column::TimestampTZ(_, _, _) | Column::Boolean(_) => {
+        Column::VarChar(_)
+        | Column::TimestampTZ(_, _, _)
+        | Column::Date(_)
+        | Column::Boolean(_)
+        | Column::UInt64(_) => {
             unreachable!("SUM can not be applied to non-numeric types")
         }
     }
@@ -185,9 +282,12 @@ pub(crate) fn max_aggregate_column_by_index_counts<'a, S: Scalar>(
         Column::Boolean(col) => max_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::TinyInt(col) => max_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::SmallInt(col) => max_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
-        Column::Int(col) => max_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
+        Column::Int(col) | Column::Date(col) => {
+            max_aggregate_slice_by_index_counts(alloc, col, counts, indexes)
+        }
         Column::BigInt(col) => max_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::Int128(col) => max_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
+        Column::UInt64(col) => max_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::Decimal75(_, _, col) => {
             max_aggregate_slice_by_index_counts(alloc, col, counts, indexes)
         }
@@ -217,9 +317,12 @@ pub(crate) fn min_aggregate_column_by_index_counts<'a, S: Scalar>(
         Column::Boolean(col) => min_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::TinyInt(col) => min_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::SmallInt(col) => min_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
-        Column::Int(col) => min_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
+        Column::Int(col) | Column::Date(col) => {
+            min_aggregate_slice_by_index_counts(alloc, col, counts, indexes)
+        }
         Column::BigInt(col) => min_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::Int128(col) => min_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
+        Column::UInt64(col) => min_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::Decimal75(_, _, col) => {
             min_aggregate_slice_by_index_counts(alloc, col, counts, indexes)
         }
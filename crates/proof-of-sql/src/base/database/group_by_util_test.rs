@@ -1,6 +1,7 @@
 use crate::{
     base::{
-        database::{group_by_util::*, Column},
+        database::{group_by_util::*, Column, ColumnType},
+        math::decimal::Precision,
         scalar::test_scalar::TestScalar,
     },
     proof_primitive::dory::DoryScalar,
@@ -574,3 +575,79 @@ fn we_can_min_aggregate_columns_by_counts() {
     let result = min_aggregate_column_by_index_counts(&alloc, &columns_c, counts, indexes);
     assert_eq!(result, expected);
 }
+
+#[test]
+fn we_can_get_the_type_of_population_variances() {
+    let decimal =
+        |precision, scale| ColumnType::Decimal75(Precision::new(precision).unwrap(), scale);
+    assert_eq!(
+        var_pop_column_type(ColumnType::BigInt),
+        Some(decimal(44, 6))
+    );
+    assert_eq!(
+        var_pop_column_type(ColumnType::TinyInt),
+        Some(decimal(12, 6))
+    );
+    assert_eq!(
+        var_pop_column_type(ColumnType::Int128),
+        Some(decimal(75, 6))
+    );
+    assert_eq!(var_pop_column_type(decimal(10, 2)), Some(decimal(26, 10)));
+    assert_eq!(var_pop_column_type(decimal(10, -5)), Some(decimal(26, -4)));
+    assert_eq!(var_pop_column_type(decimal(60, 40)), Some(decimal(75, 75)));
+    assert_eq!(var_pop_column_type(ColumnType::VarChar), None);
+    assert_eq!(var_pop_column_type(ColumnType::Boolean), None);
+}
+
+#[test]
+fn we_can_compute_population_variances_from_sums() {
+    // Groups [1, 2, 4], [10, 10] and [-3, 3]
+    let sums = [7, 20, 0].map(TestScalar::from);
+    let sums_of_squares = [21, 200, 18].map(TestScalar::from);
+    let counts = [3, 2, 2];
+    assert_eq!(
+        var_pop_from_sums(&sums, &sums_of_squares, &counts, 0),
+        Some([2, 0, 9].map(TestScalar::from).to_vec())
+    );
+    assert_eq!(
+        var_pop_from_sums(&sums, &sums_of_squares, &counts, 3),
+        Some([1556, 0, 9000].map(TestScalar::from).to_vec())
+    );
+    assert_eq!(
+        var_pop_from_sums(&sums, &sums_of_squares, &counts, -1),
+        Some([0, 0, 1].map(TestScalar::from).to_vec())
+    );
+    assert_eq!(
+        var_pop_from_sums(&sums, &sums_of_squares, &[3, 2, 0], 0),
+        None
+    );
+    assert_eq!(
+        var_pop_from_sums::<TestScalar>(&[], &[], &[], VAR_POP_SCALE_INCREASE),
+        Some(vec![])
+    );
+}
+
+#[test]
+fn we_can_only_square_values_whose_sums_stay_in_range() {
+    let alloc = Bump::new();
+    // 2 * (2^125)^2 = 2^251 is just below the largest signed scalar, 3 * (2^125)^2 is not
+    let edge = TestScalar::from(1_i128 << 125);
+    assert_eq!(
+        var_pop_squares(&alloc, &[edge, -edge]),
+        Some(&[edge * edge, edge * edge][..])
+    );
+    assert_eq!(var_pop_squares(&alloc, &[edge, -edge, edge]), None);
+    assert_eq!(
+        var_pop_squares(&alloc, &[TestScalar::from(i128::MAX)]),
+        None
+    );
+    assert_eq!(
+        var_pop_squares(&alloc, &[TestScalar::from(i128::MIN), TestScalar::from(1)]),
+        None
+    );
+    assert_eq!(
+        var_pop_squares(&alloc, &[TestScalar::from(-3), TestScalar::from(4)]),
+        Some(&[TestScalar::from(9), TestScalar::from(16)][..])
+    );
+    assert_eq!(var_pop_squares::<TestScalar>(&alloc, &[]), Some(&[][..]));
+}
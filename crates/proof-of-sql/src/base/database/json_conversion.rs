@@ -0,0 +1,431 @@
+//! Conversion between JSON and [`OwnedTable`].
+use super::{ColumnField, ColumnType, OwnedColumn, OwnedTable, OwnedTableError};
+use crate::base::{
+    map::IndexMap,
+    math::{i256::I256, BigDecimalExt},
+    scalar::Scalar,
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, TimeDelta, Timelike};
+use core::str::FromStr;
+use num_bigint::BigInt;
+use proof_of_sql_parser::posql_time::{PoSQLDate, PoSQLTimeUnit, PoSQLTimeZone, PoSQLTimestamp};
+use serde_json::{json, Value};
+use snafu::Snafu;
+use sqlparser::ast::Ident;
+
+/// Errors that can occur when converting JSON records to an [`OwnedTable`].
+#[derive(Snafu, Debug, PartialEq, Eq)]
+pub enum JsonConversionError {
+    /// A record is not a JSON object.
+    #[snafu(display("record {row} is not a JSON object"))]
+    NotAnObject {
+        /// The index of the record
+        row: usize,
+    },
+    /// A field of the schema is missing or `null` in a record.
+    #[snafu(display(
+        "field {column} of record {row} is missing or null, and null values are not supported in OwnedColumn yet"
+    ))]
+    MissingValue {
+        /// The index of the record
+        row: usize,
+        /// The missing field
+        column: Ident,
+    },
+    /// A value can not be coerced to the declared type of its field.
+    #[snafu(display(
+        "field {column} of record {row} has value {value}, which can not be coerced to {column_type}"
+    ))]
+    InvalidValue {
+        /// The index of the record
+        row: usize,
+        /// The field with the invalid value
+        column: Ident,
+        /// The declared type of the field
+        column_type: ColumnType,
+        /// The invalid value
+        value: String,
+    },
+    /// The declared type of a field is not supported for JSON ingestion.
+    #[snafu(display("JSON ingestion is not supported for columns of type {column_type}"))]
+    UnsupportedType {
+        /// The unsupported type
+        column_type: ColumnType,
+    },
+    /// A JSON table is not of the form produced by [`OwnedTable::to_json`].
+    #[snafu(display("malformed JSON table: {reason}"))]
+    MalformedTable {
+        /// What is wrong with the table
+        reason: String,
+    },
+    /// The columns of a JSON table do not form a valid [`OwnedTable`].
+    #[snafu(transparent)]
+    OwnedTable {
+        /// The underlying source error
+        source: OwnedTableError,
+    },
+}
+
+/// Builds an [`OwnedTable`] from JSON records, coercing every field to the type declared by `schema`.
+///
+/// Every record must be a JSON object with a non-null value for each field of `schema`. Fields
+/// that are not part of `schema` are ignored. Besides values of the matching JSON type, the
+/// following coercions are performed:
+/// - numeric columns accept numbers and numeric strings, e.g. `12` or `"12"`,
+/// - decimal columns accept numbers with at most the declared scale, e.g. `1.5` or `"1.5"`,
+/// - boolean columns accept `"true"` and `"false"`,
+/// - varchar columns accept numbers and booleans, which are stored as their JSON text,
+/// - timestamp columns accept integers in the declared time unit as well as RFC 3339 strings.
+///
+/// # Errors
+/// Returns a [`JsonConversionError`] naming the offending record and field if a value is missing,
+/// null or can not be coerced to the declared type.
+#[allow(
+    clippy::missing_panics_doc,
+    reason = "every column is built from one value per record, so the column lengths always match"
+)]
+pub fn owned_table_from_json_records<S: Scalar>(
+    records: &[Value],
+    schema: &[ColumnField],
+) -> Result<OwnedTable<S>, JsonConversionError> {
+    let objects = records
+        .iter()
+        .enumerate()
+        .map(|(row, record)| {
+            record
+                .as_object()
+                .ok_or(JsonConversionError::NotAnObject { row })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let columns = schema
+        .iter()
+        .map(|field| {
+            let name = field.name();
+            let values = objects
+                .iter()
+                .enumerate()
+                .map(|(row, object)| match object.get(name.value.as_str()) {
+                    None | Some(Value::Null) => Err(JsonConversionError::MissingValue {
+                        row,
+                        column: name.clone(),
+                    }),
+                    Some(value) => Ok((row, value)),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let column = json_values_to_column(&name, field.data_type(), &values)?;
+            Ok((name, column))
+        })
+        .collect::<Result<IndexMap<_, _>, JsonConversionError>>()?;
+    Ok(OwnedTable::try_new(columns).expect("all columns have one value per record"))
+}
+
+fn json_values_to_column<S: Scalar>(
+    name: &Ident,
+    column_type: ColumnType,
+    values: &[(usize, &Value)],
+) -> Result<OwnedColumn<S>, JsonConversionError> {
+    Ok(match column_type {
+        ColumnType::Boolean => OwnedColumn::Boolean(coerce_values(
+            name,
+            column_type,
+            values,
+            |value| match value {
+                Value::Bool(b) => Some(*b),
+                Value::String(s) => s.parse().ok(),
+                _ => None,
+            },
+        )?),
+        ColumnType::TinyInt => {
+            OwnedColumn::TinyInt(coerce_values(name, column_type, values, json_to_integer)?)
+        }
+        ColumnType::SmallInt => {
+            OwnedColumn::SmallInt(coerce_values(name, column_type, values, json_to_integer)?)
+        }
+        ColumnType::Int => {
+            OwnedColumn::Int(coerce_values(name, column_type, values, json_to_integer)?)
+        }
+        ColumnType::BigInt => {
+            OwnedColumn::BigInt(coerce_values(name, column_type, values, json_to_integer)?)
+        }
+        ColumnType::Int128 => {
+            OwnedColumn::Int128(coerce_values(name, column_type, values, json_to_integer)?)
+        }
+        ColumnType::UInt64 => {
+            OwnedColumn::UInt64(coerce_values(name, column_type, values, json_to_integer)?)
+        }
+        ColumnType::VarChar => OwnedColumn::VarChar(coerce_values(
+            name,
+            column_type,
+            values,
+            |value| match value {
+                Value::String(s) => Some(s.clone()),
+                Value::Number(_) | Value::Bool(_) => Some(value.to_string()),
+                _ => None,
+            },
+        )?),
+        ColumnType::Decimal75(precision, scale) => OwnedColumn::Decimal75(
+            precision,
+            scale,
+            coerce_values(name, column_type, values, |value| {
+                let decimal = match value {
+                    Value::Number(n) => BigDecimal::from_str(&n.to_string()).ok(),
+                    Value::String(s) => BigDecimal::from_str(s).ok(),
+                    _ => None,
+                }?;
+                let bigint = decimal
+                    .try_into_bigint_with_precision_and_scale(precision.value(), scale)
+                    .ok()?;
+                Some(I256::from_num_bigint(&bigint).into_scalar())
+            })?,
+        ),
+        ColumnType::TimestampTZ(time_unit, time_zone) => OwnedColumn::TimestampTZ(
+            time_unit,
+            time_zone,
+            coerce_values(name, column_type, values, |value| match value {
+                Value::Number(n) => n.as_i64(),
+                Value::String(s) => s.parse().ok().or_else(|| {
+                    let timestamp = PoSQLTimestamp::try_from(s.as_str()).ok()?.timestamp();
+                    match time_unit {
+                        PoSQLTimeUnit::Second => Some(timestamp.timestamp()),
+                        PoSQLTimeUnit::Millisecond => Some(timestamp.timestamp_millis()),
+                        PoSQLTimeUnit::Microsecond => Some(timestamp.timestamp_micros()),
+                        PoSQLTimeUnit::Nanosecond => timestamp.timestamp_nanos_opt(),
+                    }
+                }),
+                _ => None,
+            })?,
+        ),
+        ColumnType::Date => {
+            OwnedColumn::Date(coerce_values(
+                name,
+                column_type,
+                values,
+                |value| match value {
+                    Value::Number(n) => n.as_i64()?.try_into().ok(),
+                    Value::String(s) => s
+                        .parse()
+                        .ok()
+                        .or_else(|| Some(PoSQLDate::try_from(s.as_str()).ok()?.days_since_epoch())),
+                    _ => None,
+                },
+            )?)
+        }
+        ColumnType::Scalar => Err(JsonConversionError::UnsupportedType { column_type })?,
+    })
+}
+
+/// Coerces every value with `coercion`, reporting the first value that can not be coerced
+fn coerce_values<T>(
+    name: &Ident,
+    column_type: ColumnType,
+    values: &[(usize, &Value)],
+    coercion: impl Fn(&Value) -> Option<T>,
+) -> Result<Vec<T>, JsonConversionError> {
+    values
+        .iter()
+        .map(|&(row, value)| {
+            coercion(value).ok_or_else(|| JsonConversionError::InvalidValue {
+                row,
+                column: name.clone(),
+                column_type,
+                value: value.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Converts a JSON integer or integer string to an integer of type `T`, if it fits
+fn json_to_integer<T: TryFrom<i128>>(value: &Value) -> Option<T> {
+    let integer: i128 = match value {
+        Value::Number(n) => n
+            .as_i64()
+            .map(i128::from)
+            .or_else(|| n.as_u64().map(i128::from)),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }?;
+    integer.try_into().ok()
+}
+
+impl<S: Scalar> OwnedTable<S> {
+    /// Serializes the table to a column-oriented JSON object.
+    ///
+    /// The object has a single `columns` array, holding one object per column in order, with the
+    /// column's `name`, its `type` tag (the serde encoding of its [`ColumnType`]) and its `values`.
+    /// Values are encoded so that they can be read back exactly, including by `JavaScript` clients:
+    /// - booleans and integers up to `INT` as JSON booleans and numbers,
+    /// - `BIGINT`, `INT128`, `UINT64` and scalars as integer strings, since they may exceed `2^53`,
+    /// - decimals as decimal strings with the column's scale, e.g. `"-1.50"`,
+    /// - timestamps as RFC 3339 strings in the column's time zone, e.g.
+    ///   `"2009-01-03T18:15:05.123+03:00"`, and dates as `YYYY-MM-DD` strings.
+    ///
+    /// A timestamp or date whose year does not have four digits, or a timestamp whose time zone is
+    /// not a whole number of minutes, is written as its raw integer instead. There is no encoding
+    /// of `NULL`, since [`OwnedColumn`] has no null values yet.
+    #[must_use]
+    pub fn to_json(&self) -> Value {
+        let columns = self
+            .inner_table()
+            .iter()
+            .map(|(name, column)| {
+                json!({
+                    "name": name.value,
+                    "type": column.column_type(),
+                    "values": column_to_json_values(column),
+                })
+            })
+            .collect::<Vec<_>>();
+        json!({ "columns": columns })
+    }
+
+    /// Deserializes a table produced by [`OwnedTable::to_json`].
+    ///
+    /// Values are coerced to the tagged type of their column as in
+    /// [`owned_table_from_json_records`], so e.g. a `BIGINT` value may also be a JSON number.
+    ///
+    /// # Errors
+    /// Returns a [`JsonConversionError`] if the JSON does not have the form of a serialized table,
+    /// if a value is `null` or can not be coerced to the type of its column, or if the columns have
+    /// different lengths or repeated names.
+    pub fn from_json(json: &Value) -> Result<Self, JsonConversionError> {
+        let malformed = |reason: &str| JsonConversionError::MalformedTable {
+            reason: reason.to_string(),
+        };
+        let columns = json
+            .get("columns")
+            .and_then(Value::as_array)
+            .ok_or_else(|| malformed("expected an object with a `columns` array"))?;
+        let mut table = IndexMap::default();
+        for column in columns {
+            let column = column
+                .as_object()
+                .ok_or_else(|| malformed("expected every column to be an object"))?;
+            let name: Ident = column
+                .get("name")
+                .and_then(Value::as_str)
+                .ok_or_else(|| malformed("expected every column to have a string `name`"))?
+                .into();
+            let column_type: ColumnType = serde_json::from_value(
+                column.get("type").cloned().unwrap_or_default(),
+            )
+            .map_err(|error| JsonConversionError::MalformedTable {
+                reason: format!("invalid type of column {name}: {error}"),
+            })?;
+            let values = column
+                .get("values")
+                .and_then(Value::as_array)
+                .ok_or_else(|| malformed("expected every column to have a `values` array"))?
+                .iter()
+                .enumerate()
+                .map(|(row, value)| match value {
+                    Value::Null => Err(JsonConversionError::MissingValue {
+                        row,
+                        column: name.clone(),
+                    }),
+                    _ => Ok((row, value)),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let column = match column_type {
+                ColumnType::Scalar => {
+                    OwnedColumn::Scalar(coerce_values(&name, column_type, &values, |value| {
+                        S::try_from(BigInt::from_str(value.as_str()?).ok()?).ok()
+                    })?)
+                }
+                _ => json_values_to_column(&name, column_type, &values)?,
+            };
+            if table.contains_key(&name) {
+                return Err(OwnedTableError::DuplicateColumn { column: name }.into());
+            }
+            table.insert(name, column);
+        }
+        Ok(OwnedTable::try_new(table)?)
+    }
+}
+
+/// Encodes the values of a column as described in [`OwnedTable::to_json`]
+fn column_to_json_values<S: Scalar>(column: &OwnedColumn<S>) -> Vec<Value> {
+    match column {
+        OwnedColumn::Boolean(col) => col.iter().map(|&b| Value::from(b)).collect(),
+        OwnedColumn::TinyInt(col) => col.iter().map(|&i| Value::from(i)).collect(),
+        OwnedColumn::SmallInt(col) => col.iter().map(|&i| Value::from(i)).collect(),
+        OwnedColumn::Int(col) => col.iter().map(|&i| Value::from(i)).collect(),
+        OwnedColumn::BigInt(col) => col.iter().map(|i| Value::from(i.to_string())).collect(),
+        OwnedColumn::Int128(col) => col.iter().map(|i| Value::from(i.to_string())).collect(),
+        OwnedColumn::UInt64(col) => col.iter().map(|i| Value::from(i.to_string())).collect(),
+        OwnedColumn::VarChar(col) => col.iter().map(|s| Value::from(s.as_str())).collect(),
+        OwnedColumn::Decimal75(_, scale, col) => col
+            .iter()
+            .map(|&s| Value::from(BigDecimal::new(s.into(), (*scale).into()).to_string()))
+            .collect(),
+        OwnedColumn::Scalar(col) => col
+            .iter()
+            .map(|&s| Value::from(Into::<BigInt>::into(s).to_string()))
+            .collect(),
+        OwnedColumn::TimestampTZ(time_unit, time_zone, col) => col
+            .iter()
+            .map(|&t| {
+                timestamp_to_rfc3339(t, *time_unit, *time_zone).map_or(Value::from(t), Value::from)
+            })
+            .collect(),
+        OwnedColumn::Date(col) => col
+            .iter()
+            .map(|&d| date_to_string(d).map_or(Value::from(d), Value::from))
+            .collect(),
+    }
+}
+
+/// Whether a year has four digits, as RFC 3339 requires
+fn is_rfc3339_year(year: i32) -> bool {
+    (0..=9999).contains(&year)
+}
+
+/// Formats days since the unix epoch as a `YYYY-MM-DD` string
+pub(super) fn date_to_string(days_since_epoch: i32) -> Option<String> {
+    NaiveDate::default()
+        .checked_add_signed(TimeDelta::try_days(days_since_epoch.into())?)
+        .filter(|date| is_rfc3339_year(date.year()))
+        .map(|date| date.to_string())
+}
+
+/// Formats a timestamp in `time_unit` as an RFC 3339 string with the offset of `time_zone`
+pub(super) fn timestamp_to_rfc3339(
+    timestamp: i64,
+    time_unit: PoSQLTimeUnit,
+    time_zone: PoSQLTimeZone,
+) -> Option<String> {
+    let units_per_second = time_unit.units_per_second();
+    let seconds = timestamp.div_euclid(units_per_second);
+    let nanoseconds = timestamp.rem_euclid(units_per_second) * (1_000_000_000 / units_per_second);
+    let offset = time_zone.offset();
+    let date_time = DateTime::from_timestamp(seconds, nanoseconds.try_into().ok()?)?
+        .with_timezone(&FixedOffset::east_opt(offset)?);
+    if !is_rfc3339_year(date_time.year()) || offset % 60 != 0 {
+        return None;
+    }
+    let nanosecond = date_time.nanosecond();
+    let fraction = match nanosecond {
+        0 => String::new(),
+        n if n % 1_000_000 == 0 => format!(".{:03}", n / 1_000_000),
+        n if n % 1_000 == 0 => format!(".{:06}", n / 1_000),
+        n => format!(".{n:09}"),
+    };
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset_minutes = offset.unsigned_abs() / 60;
+    Some(format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{fraction}{sign}{:02}:{:02}",
+        date_time.year(),
+        date_time.month(),
+        date_time.day(),
+        date_time.hour(),
+        date_time.minute(),
+        date_time.second(),
+        offset_minutes / 60,
+        offset_minutes % 60,
+    ))
+}
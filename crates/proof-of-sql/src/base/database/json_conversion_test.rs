@@ -0,0 +1,324 @@
+use super::{
+    owned_table_from_json_records, owned_table_utility::*, ColumnField, ColumnType,
+    JsonConversionError, OwnedTable,
+};
+use crate::base::{
+    database::OwnedTableError,
+    math::decimal::Precision,
+    scalar::{test_scalar::TestScalar, Scalar},
+};
+use alloc::{string::ToString, vec, vec::Vec};
+use core::str::FromStr;
+use num_bigint::BigInt;
+use proof_of_sql_parser::posql_time::{PoSQLTimeUnit, PoSQLTimeZone};
+use serde_json::{json, Value};
+
+fn schema() -> Vec<ColumnField> {
+    vec![
+        ColumnField::new("a".into(), ColumnType::BigInt),
+        ColumnField::new("b".into(), ColumnType::TinyInt),
+        ColumnField::new("c".into(), ColumnType::Boolean),
+        ColumnField::new("d".into(), ColumnType::VarChar),
+        ColumnField::new(
+            "e".into(),
+            ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+        ),
+        ColumnField::new(
+            "f".into(),
+            ColumnType::TimestampTZ(PoSQLTimeUnit::Millisecond, PoSQLTimeZone::utc()),
+        ),
+    ]
+}
+
+#[test]
+fn we_can_convert_json_records_to_an_owned_table_with_coercions() {
+    let records = [
+        json!({"a": 1, "b": 2, "c": true, "d": "x", "e": 1.5, "f": 1_000, "extra": null}),
+        json!({"a": "-2", "b": "-3", "c": "false", "d": 42, "e": "-0.25", "f": "1970-01-01T00:00:02Z"}),
+        json!({"a": 9_007_199_254_740_993_i64, "b": 127, "c": false, "d": true, "e": 3, "f": "2000"}),
+    ];
+    let table: OwnedTable<TestScalar> = owned_table_from_json_records(&records, &schema()).unwrap();
+    let expected_table = owned_table([
+        bigint("a", [1_i64, -2, 9_007_199_254_740_993]),
+        tinyint("b", [2_i8, -3, 127]),
+        boolean("c", [true, false, false]),
+        varchar("d", ["x", "42", "true"]),
+        decimal75("e", 10, 2, [150, -25, 300]),
+        timestamptz(
+            "f",
+            PoSQLTimeUnit::Millisecond,
+            PoSQLTimeZone::utc(),
+            [1_000, 2_000, 2_000],
+        ),
+    ]);
+    assert_eq!(table, expected_table);
+}
+
+#[test]
+fn we_can_convert_no_json_records_to_an_empty_owned_table() {
+    let table: OwnedTable<TestScalar> = owned_table_from_json_records(&[], &schema()).unwrap();
+    assert_eq!(table.num_rows(), 0);
+    assert_eq!(table.num_columns(), 6);
+}
+
+#[test]
+fn we_cannot_convert_json_records_with_missing_or_null_fields() {
+    let records = [
+        json!({"a": 1, "b": 2, "c": true, "d": "x", "e": 1.5, "f": 1_000}),
+        json!({"a": 1, "b": 2, "c": true, "d": null, "e": 1.5, "f": 1_000}),
+    ];
+    assert_eq!(
+        owned_table_from_json_records::<TestScalar>(&records, &schema()),
+        Err(JsonConversionError::MissingValue {
+            row: 1,
+            column: "d".into()
+        })
+    );
+    let records = [json!({"a": 1, "b": 2, "c": true, "d": "x", "e": 1.5})];
+    assert_eq!(
+        owned_table_from_json_records::<TestScalar>(&records, &schema()),
+        Err(JsonConversionError::MissingValue {
+            row: 0,
+            column: "f".into()
+        })
+    );
+}
+
+#[test]
+fn we_cannot_convert_json_records_with_values_that_can_not_be_coerced() {
+    let valid = json!({"a": 1, "b": 2, "c": true, "d": "x", "e": 1.5, "f": 1_000});
+    for (column, value, column_type) in [
+        ("a", json!(1.5), ColumnType::BigInt),
+        ("a", json!("one"), ColumnType::BigInt),
+        ("b", json!(128), ColumnType::TinyInt),
+        ("c", json!(1), ColumnType::Boolean),
+        ("d", json!(["x"]), ColumnType::VarChar),
+        (
+            "e",
+            json!(1.234),
+            ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+        ),
+        (
+            "e",
+            json!(123_456_789),
+            ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+        ),
+        (
+            "f",
+            json!("yesterday"),
+            ColumnType::TimestampTZ(PoSQLTimeUnit::Millisecond, PoSQLTimeZone::utc()),
+        ),
+    ] {
+        let mut invalid = valid.clone();
+        invalid[column] = value.clone();
+        assert_eq!(
+            owned_table_from_json_records::<TestScalar>(&[valid.clone(), invalid], &schema()),
+            Err(JsonConversionError::InvalidValue {
+                row: 1,
+                column: column.into(),
+                column_type,
+                value: value.to_string(),
+            })
+        );
+    }
+}
+
+#[test]
+fn we_cannot_convert_json_records_that_are_not_objects() {
+    let records: [Value; 2] = [json!({"a": 1}), json!([1])];
+    assert_eq!(
+        owned_table_from_json_records::<TestScalar>(
+            &records,
+            &[ColumnField::new("a".into(), ColumnType::BigInt)]
+        ),
+        Err(JsonConversionError::NotAnObject { row: 1 })
+    );
+}
+
+#[test]
+fn we_cannot_convert_json_records_to_scalar_columns() {
+    let records = [json!({"a": 1})];
+    assert_eq!(
+        owned_table_from_json_records::<TestScalar>(
+            &records,
+            &[ColumnField::new("a".into(), ColumnType::Scalar)]
+        ),
+        Err(JsonConversionError::UnsupportedType {
+            column_type: ColumnType::Scalar
+        })
+    );
+}
+
+#[test]
+fn we_can_serialize_an_owned_table_to_column_oriented_json() {
+    let table = owned_table::<TestScalar>([
+        bigint("a", [1_i64, -9_007_199_254_740_993]),
+        boolean("b", [true, false]),
+        decimal75("c", 10, 2, [150, -5]),
+        timestamptz(
+            "d",
+            PoSQLTimeUnit::Millisecond,
+            PoSQLTimeZone::new(10800),
+            [1_230_999_305_123, 0],
+        ),
+        date("e", [0, -1]),
+    ]);
+    assert_eq!(
+        table.to_json(),
+        json!({"columns": [
+            {"name": "a", "type": "BigInt", "values": ["1", "-9007199254740993"]},
+            {"name": "b", "type": "Boolean", "values": [true, false]},
+            {"name": "c", "type": {"Decimal75": [10, 2]}, "values": ["1.50", "-0.05"]},
+            {
+                "name": "d",
+                "type": {"TimestampTZ": ["Millisecond", {"offset": 10800}]},
+                "values": ["2009-01-03T19:15:05.123+03:00", "1970-01-01T03:00:00+03:00"]
+            },
+            {"name": "e", "type": "Date", "values": ["1970-01-01", "1969-12-31"]},
+        ]})
+    );
+}
+
+#[test]
+fn we_can_round_trip_every_column_type_through_json() {
+    let large_decimal = TestScalar::try_from(BigInt::from_str(&"9".repeat(75)).unwrap()).unwrap();
+    let table = owned_table::<TestScalar>([
+        boolean("boolean", [true, false, true]),
+        tinyint("tinyint", [i8::MIN, 0, i8::MAX]),
+        smallint("smallint", [i16::MIN, 0, i16::MAX]),
+        int("int", [i32::MIN, 0, i32::MAX]),
+        bigint("bigint", [i64::MIN, 0, i64::MAX]),
+        int128("int128", [i128::MIN, 0, i128::MAX]),
+        varchar("varchar", ["", "\"quoted\"", "ünïcödé"]),
+        decimal75(
+            "decimal",
+            75,
+            0,
+            [-large_decimal, TestScalar::ZERO, large_decimal],
+        ),
+        decimal75(
+            "scaled_decimal",
+            75,
+            30,
+            [-large_decimal, TestScalar::ONE, large_decimal],
+        ),
+        decimal75("negative_scale", 5, -3, [-99_999, 1, 12_345]),
+        scalar(
+            "scalar",
+            [
+                -TestScalar::MAX_SIGNED,
+                TestScalar::ZERO,
+                TestScalar::MAX_SIGNED,
+            ],
+        ),
+        timestamptz(
+            "seconds",
+            PoSQLTimeUnit::Second,
+            PoSQLTimeZone::new(-18000),
+            [-1, 1_231_006_505, i64::MAX],
+        ),
+        timestamptz(
+            "milliseconds",
+            PoSQLTimeUnit::Millisecond,
+            PoSQLTimeZone::utc(),
+            [-1, 1_231_006_505_001, 0],
+        ),
+        timestamptz(
+            "microseconds",
+            PoSQLTimeUnit::Microsecond,
+            PoSQLTimeZone::new(3600),
+            [-1, 1_231_006_505_000_001, 0],
+        ),
+        timestamptz(
+            "nanoseconds",
+            PoSQLTimeUnit::Nanosecond,
+            PoSQLTimeZone::utc(),
+            [i64::MIN, 1_231_006_505_000_000_001, i64::MAX],
+        ),
+        date("date", [i32::MIN, -1, 2_932_896]),
+    ]);
+    let json = table.to_json();
+    let round_tripped = OwnedTable::from_json(&serde_json::from_str(&json.to_string()).unwrap());
+    assert_eq!(round_tripped, Ok(table));
+    // Out of range timestamps and dates are written as integers
+    assert_eq!(json["columns"][11]["values"][2], json!(i64::MAX));
+    assert_eq!(json["columns"][15]["values"][0], json!(i32::MIN));
+    assert_eq!(json["columns"][15]["values"][2], json!("9999-12-31"));
+}
+
+#[test]
+fn we_can_round_trip_an_empty_owned_table_through_json() {
+    let table = owned_table::<TestScalar>([]);
+    assert_eq!(table.to_json(), json!({"columns": []}));
+    assert_eq!(OwnedTable::from_json(&table.to_json()), Ok(table));
+    let table = owned_table::<TestScalar>([bigint("a", [0_i64; 0])]);
+    assert_eq!(OwnedTable::from_json(&table.to_json()), Ok(table));
+}
+
+#[test]
+fn we_cannot_deserialize_json_tables_with_null_or_invalid_values() {
+    let json = json!({"columns": [
+        {"name": "a", "type": "BigInt", "values": ["1", null]},
+    ]});
+    assert_eq!(
+        OwnedTable::<TestScalar>::from_json(&json),
+        Err(JsonConversionError::MissingValue {
+            row: 1,
+            column: "a".into()
+        })
+    );
+    let json = json!({"columns": [
+        {"name": "a", "type": "Scalar", "values": ["1", "1.5"]},
+    ]});
+    assert_eq!(
+        OwnedTable::<TestScalar>::from_json(&json),
+        Err(JsonConversionError::InvalidValue {
+            row: 1,
+            column: "a".into(),
+            column_type: ColumnType::Scalar,
+            value: "\"1.5\"".to_string(),
+        })
+    );
+}
+
+#[test]
+fn we_cannot_deserialize_malformed_json_tables() {
+    for json in [
+        json!([]),
+        json!({"rows": []}),
+        json!({"columns": [1]}),
+        json!({"columns": [{"type": "BigInt", "values": []}]}),
+        json!({"columns": [{"name": "a", "values": []}]}),
+        json!({"columns": [{"name": "a", "type": "Float", "values": []}]}),
+        json!({"columns": [{"name": "a", "type": "BigInt", "values": 1}]}),
+    ] {
+        assert!(matches!(
+            OwnedTable::<TestScalar>::from_json(&json),
+            Err(JsonConversionError::MalformedTable { .. })
+        ));
+    }
+}
+
+#[test]
+fn we_cannot_deserialize_json_tables_with_inconsistent_columns() {
+    let json = json!({"columns": [
+        {"name": "a", "type": "BigInt", "values": ["1"]},
+        {"name": "a", "type": "Int", "values": [1]},
+    ]});
+    assert_eq!(
+        OwnedTable::<TestScalar>::from_json(&json),
+        Err(JsonConversionError::OwnedTable {
+            source: OwnedTableError::DuplicateColumn { column: "a".into() }
+        })
+    );
+    let json = json!({"columns": [
+        {"name": "a", "type": "BigInt", "values": ["1"]},
+        {"name": "b", "type": "Int", "values": [1, 2]},
+    ]});
+    assert_eq!(
+        OwnedTable::<TestScalar>::from_json(&json),
+        Err(JsonConversionError::OwnedTable {
+            source: OwnedTableError::ColumnLengthMismatch
+        })
+    );
+}
@@ -4,6 +4,9 @@ use crate::base::{
     scalar::Scalar,
 };
 use alloc::string::String;
+use bigdecimal::BigDecimal;
+use core::fmt;
+use num_bigint::BigUint;
 use proof_of_sql_parser::posql_time::{PoSQLTimeUnit, PoSQLTimeZone};
 use serde::{Deserialize, Serialize};
 
@@ -40,6 +43,10 @@ pub enum LiteralValue {
     /// `TimeStamp` defined over a unit (s, ms, ns, etc) and timezone with backing store
     /// mapped to i64, which is time units since unix epoch
     TimeStampTZ(PoSQLTimeUnit, PoSQLTimeZone, i64),
+    /// Date literals with backing store mapped to i32, which is days since unix epoch
+    Date(i32),
+    /// u64 literals
+    UInt64(u64),
 }
 
 impl LiteralValue {
@@ -57,6 +64,8 @@ impl LiteralValue {
             Self::Scalar(_) => ColumnType::Scalar,
             Self::Decimal75(precision, scale, _) => ColumnType::Decimal75(*precision, *scale),
             Self::TimeStampTZ(tu, tz, _) => ColumnType::TimestampTZ(*tu, *tz),
+            Self::Date(_) => ColumnType::Date,
+            Self::UInt64(_) => ColumnType::UInt64,
         }
     }
 
@@ -66,13 +75,42 @@ impl LiteralValue {
             Self::Boolean(b) => b.into(),
             Self::TinyInt(i) => i.into(),
             Self::SmallInt(i) => i.into(),
-            Self::Int(i) => i.into(),
+            Self::Int(i) | Self::Date(i) => i.into(),
             Self::BigInt(i) => i.into(),
             Self::VarChar(str) => str.into(),
             Self::Decimal75(_, _, i) => i.into_scalar(),
             Self::Int128(i) => i.into(),
             Self::Scalar(limbs) => (*limbs).into(),
             Self::TimeStampTZ(_, _, time) => time.into(),
+            Self::UInt64(i) => i.into(),
+        }
+    }
+}
+
+impl fmt::Display for LiteralValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Boolean(b) => write!(f, "{b}"),
+            Self::TinyInt(i) => write!(f, "{i}"),
+            Self::SmallInt(i) => write!(f, "{i}"),
+            Self::Int(i) => write!(f, "{i}"),
+            Self::BigInt(i) => write!(f, "{i}"),
+            Self::VarChar(str) => write!(f, "'{}'", str.replace('\'', "''")),
+            Self::Int128(i) => write!(f, "{i}"),
+            Self::Decimal75(_, scale, i) => {
+                write!(f, "{}", BigDecimal::new(i.to_num_bigint(), (*scale).into()))
+            }
+            Self::Scalar(limbs) => write!(
+                f,
+                "{}",
+                limbs
+                    .iter()
+                    .rev()
+                    .fold(BigUint::from(0u8), |acc, &limb| (acc << 64) + limb)
+            ),
+            Self::TimeStampTZ(tu, tz, time) => write!(f, "timestamp {time} ({tu}, {tz})"),
+            Self::Date(days) => write!(f, "date {days}"),
+            Self::UInt64(i) => write!(f, "{i}"),
         }
     }
 }
@@ -50,11 +50,21 @@ pub use crate::base::arrow::{
     owned_and_arrow_conversions::OwnedArrowConversionError,
     record_batch_utility::ToArrow,
     scalar_and_i256_conversions,
+    streaming_record_batch_accessor::{
+        StreamingRecordBatchAccessor, StreamingRecordBatchAccessorError,
+    },
 };
 pub use table_ref::TableRef;
 
+#[cfg(feature = "arrow")]
+mod column_arrow_conversion;
+#[cfg(all(test, feature = "arrow"))]
+mod column_arrow_conversion_test;
+
 #[cfg(feature = "arrow")]
 pub mod arrow_schema_utility;
+#[cfg(all(test, feature = "arrow"))]
+mod arrow_schema_utility_test;
 
 mod owned_column;
 pub use owned_column::OwnedColumn;
@@ -73,6 +83,16 @@ pub(crate) use owned_table::{OwnedTableError, TableCoercionError};
 mod owned_table_test;
 pub mod owned_table_utility;
 
+mod owned_table_display;
+pub use owned_table_display::{OwnedColumnPreview, OwnedTablePreview};
+#[cfg(test)]
+mod owned_table_display_test;
+
+mod json_conversion;
+pub use json_conversion::{owned_table_from_json_records, JsonConversionError};
+#[cfg(test)]
+mod json_conversion_test;
+
 mod table;
 #[cfg(test)]
 pub(crate) use table::TableError;
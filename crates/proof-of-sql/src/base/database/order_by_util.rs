@@ -20,9 +20,10 @@ pub(crate) fn compare_indexes_by_columns<S: Scalar>(
             Column::Boolean(col) => col[i].cmp(&col[j]),
             Column::TinyInt(col) => col[i].cmp(&col[j]),
             Column::SmallInt(col) => col[i].cmp(&col[j]),
-            Column::Int(col) => col[i].cmp(&col[j]),
+            Column::Int(col) | Column::Date(col) => col[i].cmp(&col[j]),
             Column::BigInt(col) | Column::TimestampTZ(_, _, col) => col[i].cmp(&col[j]),
             Column::Int128(col) => col[i].cmp(&col[j]),
+            Column::UInt64(col) => col[i].cmp(&col[j]),
             Column::Decimal75(_, _, col) => col[i].signed_cmp(&col[j]),
             Column::Scalar(col) => col[i].cmp(&col[j]),
             Column::VarChar((col, _)) => col[i].cmp(col[j]),
@@ -73,7 +74,8 @@ pub(crate) fn compare_single_row_of_tables<S: Scalar>(
             (Column::SmallInt(left_col), Column::SmallInt(right_col)) => {
                 left_col[left_row_index].cmp(&right_col[right_row_index])
             }
-            (Column::Int(left_col), Column::Int(right_col)) => {
+            (Column::Int(left_col), Column::Int(right_col))
+            | (Column::Date(left_col), Column::Date(right_col)) => {
                 left_col[left_row_index].cmp(&right_col[right_row_index])
             }
             (Column::BigInt(left_col), Column::BigInt(right_col))
@@ -130,11 +132,12 @@ pub(crate) fn compare_indexes_by_owned_columns_with_direction<S: Scalar>(
                 OwnedColumn::Boolean(col) => col[i].cmp(&col[j]),
                 OwnedColumn::TinyInt(col) => col[i].cmp(&col[j]),
                 OwnedColumn::SmallInt(col) => col[i].cmp(&col[j]),
-                OwnedColumn::Int(col) => col[i].cmp(&col[j]),
+                OwnedColumn::Int(col) | OwnedColumn::Date(col) => col[i].cmp(&col[j]),
                 OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => {
                     col[i].cmp(&col[j])
                 }
                 OwnedColumn::Int128(col) => col[i].cmp(&col[j]),
+                OwnedColumn::UInt64(col) => col[i].cmp(&col[j]),
                 OwnedColumn::Decimal75(_, _, col) => col[i].signed_cmp(&col[j]),
                 OwnedColumn::Scalar(col) => col[i].cmp(&col[j]),
                 OwnedColumn::VarChar(col) => col[i].cmp(&col[j]),
@@ -7,7 +7,10 @@ use crate::{
     proof_primitive::dory::DoryScalar,
 };
 use core::cmp::Ordering;
-use proof_of_sql_parser::intermediate_ast::OrderByDirection;
+use proof_of_sql_parser::{
+    intermediate_ast::OrderByDirection,
+    posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
+};
 
 #[test]
 fn we_can_compare_indexes_by_columns_with_no_columns() {
@@ -292,3 +295,56 @@ fn we_can_compare_columns_with_direction() {
         Ordering::Less
     );
 }
+
+#[test]
+fn we_can_compare_date_and_timestamp_columns_chronologically() {
+    // 1969-12-31, 1970-01-01, 2024-02-29 and 1900-01-01 as days since the unix epoch
+    let dates = [-1, 0, 19_782, -25_567];
+    // Timestamps before and after the unix epoch, in milliseconds
+    let timestamps = [-1_500, 1_000, 1_000, -86_400_000];
+    let tz = PoSQLTimeZone::utc();
+
+    let date_column = Column::<TestScalar>::Date(&dates);
+    let timestamp_column =
+        Column::<TestScalar>::TimestampTZ(PoSQLTimeUnit::Millisecond, tz, &timestamps);
+    let columns = &[date_column];
+    assert_eq!(compare_indexes_by_columns(columns, 0, 1), Ordering::Less);
+    assert_eq!(compare_indexes_by_columns(columns, 2, 1), Ordering::Greater);
+    assert_eq!(compare_indexes_by_columns(columns, 3, 0), Ordering::Less);
+    let columns = &[timestamp_column];
+    assert_eq!(compare_indexes_by_columns(columns, 0, 1), Ordering::Less);
+    assert_eq!(compare_indexes_by_columns(columns, 1, 2), Ordering::Equal);
+    assert_eq!(compare_indexes_by_columns(columns, 3, 0), Ordering::Less);
+
+    let rows = &[date_column, timestamp_column];
+    assert_eq!(
+        compare_single_row_of_tables(rows, rows, 3, 0).unwrap(),
+        Ordering::Less
+    );
+    assert_eq!(
+        compare_single_row_of_tables(rows, rows, 2, 2).unwrap(),
+        Ordering::Equal
+    );
+
+    let order_by_pairs = vec![
+        (
+            OwnedColumn::<TestScalar>::TimestampTZ(
+                PoSQLTimeUnit::Millisecond,
+                tz,
+                timestamps.to_vec(),
+            ),
+            OrderByDirection::Asc,
+        ),
+        (OwnedColumn::Date(dates.to_vec()), OrderByDirection::Desc),
+    ];
+    // Earlier timestamp
+    assert_eq!(
+        compare_indexes_by_owned_columns_with_direction(&order_by_pairs, 3, 0),
+        Ordering::Less
+    );
+    // Equal timestamps, later date first
+    assert_eq!(
+        compare_indexes_by_owned_columns_with_direction(&order_by_pairs, 2, 1),
+        Ordering::Less
+    );
+}
@@ -43,6 +43,10 @@ pub enum OwnedColumn<S: Scalar> {
     Scalar(Vec<S>),
     /// Timestamp columns
     TimestampTZ(PoSQLTimeUnit, PoSQLTimeZone, Vec<i64>),
+    /// Date columns, as days since unix epoch
+    Date(Vec<i32>),
+    /// u64 columns
+    UInt64(Vec<u64>),
 }
 
 impl<S: Scalar> OwnedColumn<S> {
@@ -52,7 +56,7 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::Boolean(col) => inner_product_ref_cast(col, vec),
             OwnedColumn::TinyInt(col) => inner_product_ref_cast(col, vec),
             OwnedColumn::SmallInt(col) => inner_product_ref_cast(col, vec),
-            OwnedColumn::Int(col) => inner_product_ref_cast(col, vec),
+            OwnedColumn::Int(col) | OwnedColumn::Date(col) => inner_product_ref_cast(col, vec),
             OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => {
                 inner_product_ref_cast(col, vec)
             }
@@ -61,6 +65,7 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::Decimal75(_, _, col) | OwnedColumn::Scalar(col) => {
                 inner_product_ref_cast(col, vec)
             }
+            OwnedColumn::UInt64(col) => inner_product_ref_cast(col, vec),
         }
     }
 
@@ -71,11 +76,12 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::Boolean(col) => col.len(),
             OwnedColumn::TinyInt(col) => col.len(),
             OwnedColumn::SmallInt(col) => col.len(),
-            OwnedColumn::Int(col) => col.len(),
+            OwnedColumn::Int(col) | OwnedColumn::Date(col) => col.len(),
             OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => col.len(),
             OwnedColumn::VarChar(col) => col.len(),
             OwnedColumn::Int128(col) => col.len(),
             OwnedColumn::Decimal75(_, _, col) | OwnedColumn::Scalar(col) => col.len(),
+            OwnedColumn::UInt64(col) => col.len(),
         }
     }
 
@@ -86,6 +92,7 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::TinyInt(col) => OwnedColumn::TinyInt(permutation.try_apply(col)?),
             OwnedColumn::SmallInt(col) => OwnedColumn::SmallInt(permutation.try_apply(col)?),
             OwnedColumn::Int(col) => OwnedColumn::Int(permutation.try_apply(col)?),
+            OwnedColumn::Date(col) => OwnedColumn::Date(permutation.try_apply(col)?),
             OwnedColumn::BigInt(col) => OwnedColumn::BigInt(permutation.try_apply(col)?),
             OwnedColumn::VarChar(col) => OwnedColumn::VarChar(permutation.try_apply(col)?),
             OwnedColumn::Int128(col) => OwnedColumn::Int128(permutation.try_apply(col)?),
@@ -96,6 +103,7 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::TimestampTZ(tu, tz, col) => {
                 OwnedColumn::TimestampTZ(*tu, *tz, permutation.try_apply(col)?)
             }
+            OwnedColumn::UInt64(col) => OwnedColumn::UInt64(permutation.try_apply(col)?),
         })
     }
 
@@ -107,6 +115,7 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::TinyInt(col) => OwnedColumn::TinyInt(col[start..end].to_vec()),
             OwnedColumn::SmallInt(col) => OwnedColumn::SmallInt(col[start..end].to_vec()),
             OwnedColumn::Int(col) => OwnedColumn::Int(col[start..end].to_vec()),
+            OwnedColumn::Date(col) => OwnedColumn::Date(col[start..end].to_vec()),
             OwnedColumn::BigInt(col) => OwnedColumn::BigInt(col[start..end].to_vec()),
             OwnedColumn::VarChar(col) => OwnedColumn::VarChar(col[start..end].to_vec()),
             OwnedColumn::Int128(col) => OwnedColumn::Int128(col[start..end].to_vec()),
@@ -117,6 +126,39 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::TimestampTZ(tu, tz, col) => {
                 OwnedColumn::TimestampTZ(*tu, *tz, col[start..end].to_vec())
             }
+            OwnedColumn::UInt64(col) => OwnedColumn::UInt64(col[start..end].to_vec()),
+        }
+    }
+
+    /// Returns the column with only the entries where `mask` is `true`.
+    ///
+    /// Entries beyond the end of `mask` are dropped.
+    #[must_use]
+    pub fn filter(&self, mask: &[bool]) -> Self {
+        fn filter_slice<T: Clone>(col: &[T], mask: &[bool]) -> Vec<T> {
+            col.iter()
+                .zip(mask)
+                .filter(|(_, &keep)| keep)
+                .map(|(value, _)| value.clone())
+                .collect()
+        }
+        match self {
+            OwnedColumn::Boolean(col) => OwnedColumn::Boolean(filter_slice(col, mask)),
+            OwnedColumn::TinyInt(col) => OwnedColumn::TinyInt(filter_slice(col, mask)),
+            OwnedColumn::SmallInt(col) => OwnedColumn::SmallInt(filter_slice(col, mask)),
+            OwnedColumn::Int(col) => OwnedColumn::Int(filter_slice(col, mask)),
+            OwnedColumn::Date(col) => OwnedColumn::Date(filter_slice(col, mask)),
+            OwnedColumn::BigInt(col) => OwnedColumn::BigInt(filter_slice(col, mask)),
+            OwnedColumn::VarChar(col) => OwnedColumn::VarChar(filter_slice(col, mask)),
+            OwnedColumn::Int128(col) => OwnedColumn::Int128(filter_slice(col, mask)),
+            OwnedColumn::Decimal75(precision, scale, col) => {
+                OwnedColumn::Decimal75(*precision, *scale, filter_slice(col, mask))
+            }
+            OwnedColumn::Scalar(col) => OwnedColumn::Scalar(filter_slice(col, mask)),
+            OwnedColumn::TimestampTZ(tu, tz, col) => {
+                OwnedColumn::TimestampTZ(*tu, *tz, filter_slice(col, mask))
+            }
+            OwnedColumn::UInt64(col) => OwnedColumn::UInt64(filter_slice(col, mask)),
         }
     }
 
@@ -127,11 +169,12 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::Boolean(col) => col.is_empty(),
             OwnedColumn::TinyInt(col) => col.is_empty(),
             OwnedColumn::SmallInt(col) => col.is_empty(),
-            OwnedColumn::Int(col) => col.is_empty(),
+            OwnedColumn::Int(col) | OwnedColumn::Date(col) => col.is_empty(),
             OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => col.is_empty(),
             OwnedColumn::VarChar(col) => col.is_empty(),
             OwnedColumn::Int128(col) => col.is_empty(),
             OwnedColumn::Scalar(col) | OwnedColumn::Decimal75(_, _, col) => col.is_empty(),
+            OwnedColumn::UInt64(col) => col.is_empty(),
         }
     }
     /// Returns the type of the column.
@@ -150,6 +193,8 @@ impl<S: Scalar> OwnedColumn<S> {
                 ColumnType::Decimal75(*precision, *scale)
             }
             OwnedColumn::TimestampTZ(tu, tz, _) => ColumnType::TimestampTZ(*tu, *tz),
+            OwnedColumn::Date(_) => ColumnType::Date,
+            OwnedColumn::UInt64(_) => ColumnType::UInt64,
         }
     }
 
@@ -224,6 +269,24 @@ impl<S: Scalar> OwnedColumn<S> {
                     })?;
                 Ok(OwnedColumn::TimestampTZ(tu, tz, raw_values))
             }
+            ColumnType::Date => Ok(OwnedColumn::Date(
+                scalars
+                    .iter()
+                    .map(|s| -> Result<i32, _> { TryInto::<i32>::try_into(*s) })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| OwnedColumnError::ScalarConversionError {
+                        error: "Overflow in scalar conversions".to_string(),
+                    })?,
+            )),
+            ColumnType::UInt64 => Ok(OwnedColumn::UInt64(
+                scalars
+                    .iter()
+                    .map(|s| -> Result<u64, _> { TryInto::<u64>::try_into(*s) })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| OwnedColumnError::ScalarConversionError {
+                        error: "Overflow in scalar conversions".to_string(),
+                    })?,
+            )),
             // Can not convert scalars to VarChar
             ColumnType::VarChar => Err(OwnedColumnError::TypeCastError {
                 from_type: ColumnType::Scalar,
@@ -247,6 +310,122 @@ impl<S: Scalar> OwnedColumn<S> {
         Self::try_from_scalars(&scalars, column_type)
     }
 
+    /// Returns an iterator over the values of a `BOOLEAN` column, or `None` for any other type.
+    ///
+    /// The typed iterators let a verified result be consumed without matching on the variant.
+    /// `NULL` is not supported yet, so every row yields a value.
+    #[must_use]
+    pub fn as_boolean_iter(&self) -> Option<impl Iterator<Item = bool> + '_> {
+        match self {
+            OwnedColumn::Boolean(col) => Some(col.iter().copied()),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over the values of a `TINYINT` column, or `None` for any other type.
+    #[must_use]
+    pub fn as_tinyint_iter(&self) -> Option<impl Iterator<Item = i8> + '_> {
+        match self {
+            OwnedColumn::TinyInt(col) => Some(col.iter().copied()),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over the values of a `SMALLINT` column, or `None` for any other type.
+    #[must_use]
+    pub fn as_smallint_iter(&self) -> Option<impl Iterator<Item = i16> + '_> {
+        match self {
+            OwnedColumn::SmallInt(col) => Some(col.iter().copied()),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over the values of an `INT` column, or `None` for any other type.
+    #[must_use]
+    pub fn as_int_iter(&self) -> Option<impl Iterator<Item = i32> + '_> {
+        match self {
+            OwnedColumn::Int(col) => Some(col.iter().copied()),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over the values of a `BIGINT` column, or `None` for any other type.
+    #[must_use]
+    pub fn as_bigint_iter(&self) -> Option<impl Iterator<Item = i64> + '_> {
+        match self {
+            OwnedColumn::BigInt(col) => Some(col.iter().copied()),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over the values of an `INT128` column, or `None` for any other type.
+    #[must_use]
+    pub fn as_int128_iter(&self) -> Option<impl Iterator<Item = i128> + '_> {
+        match self {
+            OwnedColumn::Int128(col) => Some(col.iter().copied()),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over the values of a `VARCHAR` column, or `None` for any other type.
+    #[must_use]
+    pub fn as_varchar_iter(&self) -> Option<impl Iterator<Item = &str> + '_> {
+        match self {
+            OwnedColumn::VarChar(col) => Some(col.iter().map(String::as_str)),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over the unscaled values of a `DECIMAL75` column, or `None` for any
+    /// other type.
+    ///
+    /// A value `v` stands for `v * 10^-scale`, where the scale is that of [`Self::column_type`].
+    #[must_use]
+    pub fn as_decimal_iter(&self) -> Option<impl Iterator<Item = S> + '_> {
+        match self {
+            OwnedColumn::Decimal75(_, _, col) => Some(col.iter().copied()),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over the values of a `SCALAR` column, or `None` for any other type.
+    #[must_use]
+    pub fn as_scalar_iter(&self) -> Option<impl Iterator<Item = S> + '_> {
+        match self {
+            OwnedColumn::Scalar(col) => Some(col.iter().copied()),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over the values of a `TIMESTAMP` column, in the time unit of
+    /// [`Self::column_type`], or `None` for any other type.
+    #[must_use]
+    pub fn as_timestamp_iter(&self) -> Option<impl Iterator<Item = i64> + '_> {
+        match self {
+            OwnedColumn::TimestampTZ(_, _, col) => Some(col.iter().copied()),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over the values of a `DATE` column, as days since the unix epoch, or
+    /// `None` for any other type.
+    #[must_use]
+    pub fn as_date_iter(&self) -> Option<impl Iterator<Item = i32> + '_> {
+        match self {
+            OwnedColumn::Date(col) => Some(col.iter().copied()),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over the values of a `UINT64` column, or `None` for any other type.
+    #[must_use]
+    pub fn as_uint64_iter(&self) -> Option<impl Iterator<Item = u64> + '_> {
+        match self {
+            OwnedColumn::UInt64(col) => Some(col.iter().copied()),
+            _ => None,
+        }
+    }
+
     #[cfg(test)]
     /// Returns an iterator over the raw data of the column
     /// assuming the underlying type is [i8], panicking if it is not.
@@ -270,8 +449,8 @@ impl<S: Scalar> OwnedColumn<S> {
     /// assuming the underlying type is [i32], panicking if it is not.
     pub fn i32_iter(&self) -> impl Iterator<Item = &i32> {
         match self {
-            OwnedColumn::Int(col) => col.iter(),
-            _ => panic!("Expected Int column"),
+            OwnedColumn::Int(col) | OwnedColumn::Date(col) => col.iter(),
+            _ => panic!("Expected Int or Date column"),
         }
     }
     #[cfg(test)]
@@ -338,6 +517,8 @@ impl<'a, S: Scalar> From<&Column<'a, S>> for OwnedColumn<S> {
             }
             Column::Scalar(col) => OwnedColumn::Scalar(col.to_vec()),
             Column::TimestampTZ(tu, tz, col) => OwnedColumn::TimestampTZ(*tu, *tz, col.to_vec()),
+            Column::Date(col) => OwnedColumn::Date(col.to_vec()),
+            Column::UInt64(col) => OwnedColumn::UInt64(col.to_vec()),
         }
     }
 }
@@ -361,7 +542,7 @@ impl<S: Scalar> OwnedColumn<S> {
     ///
     /// Otherwise, this function will return an error if:
     /// * The column type is not `Scalar`.
-    /// * The target type is not a numeric type.
+    /// * The target type is not a numeric or timestamp type.
     /// * There is an overflow during the coercion.
     pub(crate) fn try_coerce_scalar_to_numeric(
         self,
@@ -404,6 +585,12 @@ impl<S: Scalar> OwnedColumn<S> {
                 ColumnType::Decimal75(precision, scale) => {
                     Ok(OwnedColumn::Decimal75(precision, scale, vec))
                 }
+                ColumnType::TimestampTZ(time_unit, time_zone) => vec
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .try_collect()
+                    .map_err(|_| ColumnCoercionError::Overflow)
+                    .map(|values| OwnedColumn::TimestampTZ(time_unit, time_zone, values)),
                 _ => Err(ColumnCoercionError::InvalidTypeCoercion),
             }
         } else {
@@ -435,6 +622,82 @@ mod test {
         );
     }
 
+    #[test]
+    fn we_can_iterate_over_the_typed_values_of_every_column_type() {
+        let col: OwnedColumn<TestScalar> = OwnedColumn::Boolean(vec![true, false]);
+        assert_eq!(
+            col.as_boolean_iter().unwrap().collect::<Vec<_>>(),
+            [true, false]
+        );
+        let col: OwnedColumn<TestScalar> = OwnedColumn::TinyInt(vec![-1, 2]);
+        assert_eq!(col.as_tinyint_iter().unwrap().collect::<Vec<_>>(), [-1, 2]);
+        let col: OwnedColumn<TestScalar> = OwnedColumn::SmallInt(vec![-1, 2]);
+        assert_eq!(col.as_smallint_iter().unwrap().collect::<Vec<_>>(), [-1, 2]);
+        let col: OwnedColumn<TestScalar> = OwnedColumn::Int(vec![-1, 2]);
+        assert_eq!(col.as_int_iter().unwrap().collect::<Vec<_>>(), [-1, 2]);
+        let col: OwnedColumn<TestScalar> = OwnedColumn::BigInt(vec![i64::MIN, 2]);
+        assert_eq!(
+            col.as_bigint_iter().unwrap().collect::<Vec<_>>(),
+            [i64::MIN, 2]
+        );
+        let col: OwnedColumn<TestScalar> = OwnedColumn::Int128(vec![i128::MAX, 2]);
+        assert_eq!(
+            col.as_int128_iter().unwrap().collect::<Vec<_>>(),
+            [i128::MAX, 2]
+        );
+        let col: OwnedColumn<TestScalar> =
+            OwnedColumn::VarChar(vec!["a".to_string(), String::new()]);
+        assert_eq!(
+            col.as_varchar_iter().unwrap().collect::<Vec<_>>(),
+            ["a", ""]
+        );
+        let col: OwnedColumn<TestScalar> = OwnedColumn::Decimal75(
+            Precision::new(10).unwrap(),
+            2,
+            vec![TestScalar::from(-150), TestScalar::from(1)],
+        );
+        assert_eq!(
+            col.as_decimal_iter().unwrap().collect::<Vec<_>>(),
+            [TestScalar::from(-150), TestScalar::from(1)]
+        );
+        let col: OwnedColumn<TestScalar> = OwnedColumn::Scalar(vec![TestScalar::from(3)]);
+        assert_eq!(
+            col.as_scalar_iter().unwrap().collect::<Vec<_>>(),
+            [TestScalar::from(3)]
+        );
+        let col: OwnedColumn<TestScalar> =
+            OwnedColumn::TimestampTZ(PoSQLTimeUnit::Second, PoSQLTimeZone::utc(), vec![-1, 2]);
+        assert_eq!(
+            col.as_timestamp_iter().unwrap().collect::<Vec<_>>(),
+            [-1, 2]
+        );
+        let col: OwnedColumn<TestScalar> = OwnedColumn::Date(vec![19_723, -1]);
+        assert_eq!(
+            col.as_date_iter().unwrap().collect::<Vec<_>>(),
+            [19_723, -1]
+        );
+        let col: OwnedColumn<TestScalar> = OwnedColumn::BigInt(vec![]);
+        assert_eq!(col.as_bigint_iter().unwrap().count(), 0);
+    }
+
+    #[test]
+    fn we_cannot_iterate_over_a_column_as_another_type() {
+        let col: OwnedColumn<TestScalar> = OwnedColumn::BigInt(vec![1, 2]);
+        assert!(col.as_boolean_iter().is_none());
+        assert!(col.as_tinyint_iter().is_none());
+        assert!(col.as_smallint_iter().is_none());
+        assert!(col.as_int_iter().is_none());
+        assert!(col.as_int128_iter().is_none());
+        assert!(col.as_varchar_iter().is_none());
+        assert!(col.as_decimal_iter().is_none());
+        assert!(col.as_scalar_iter().is_none());
+        // Timestamps and dates share their representation with BIGINT and INT, but not their type
+        assert!(col.as_timestamp_iter().is_none());
+        let col: OwnedColumn<TestScalar> = OwnedColumn::Int(vec![1, 2]);
+        assert!(col.as_date_iter().is_none());
+        assert!(col.as_bigint_iter().is_none());
+    }
+
     #[test]
     fn we_can_convert_columns_to_owned_columns_round_trip() {
         let alloc = Bump::new();
@@ -729,6 +992,19 @@ mod test {
             coerced_col,
             OwnedColumn::Decimal75(Precision::new(75).unwrap(), 0, scalars)
         );
+
+        // Coerce to TimestampTZ
+        let coerced_col = col
+            .clone()
+            .try_coerce_scalar_to_numeric(ColumnType::TimestampTZ(
+                PoSQLTimeUnit::Second,
+                PoSQLTimeZone::utc(),
+            ))
+            .unwrap();
+        assert_eq!(
+            coerced_col,
+            OwnedColumn::TimestampTZ(PoSQLTimeUnit::Second, PoSQLTimeZone::utc(), vec![1, 2, 3])
+        );
     }
 
     #[test]
@@ -778,7 +1054,14 @@ mod test {
         assert!(matches!(res, Err(ColumnCoercionError::Overflow)));
 
         // Attempt to coerce to Int128
-        let res = col.try_coerce_scalar_to_numeric(ColumnType::Int128);
+        let res = col.clone().try_coerce_scalar_to_numeric(ColumnType::Int128);
+        assert!(matches!(res, Err(ColumnCoercionError::Overflow)));
+
+        // Attempt to coerce to TimestampTZ
+        let res = col.try_coerce_scalar_to_numeric(ColumnType::TimestampTZ(
+            PoSQLTimeUnit::Second,
+            PoSQLTimeZone::utc(),
+        ));
         assert!(matches!(res, Err(ColumnCoercionError::Overflow)));
     }
 }
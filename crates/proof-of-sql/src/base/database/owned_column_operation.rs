@@ -9,7 +9,8 @@ use crate::base::{
     },
     scalar::Scalar,
 };
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
+use proof_of_sql_parser::intermediate_ast::StringTransformOperator;
 
 impl<S: Scalar> OwnedColumn<S> {
     /// Element-wise NOT operation for a column
@@ -23,6 +24,57 @@ impl<S: Scalar> OwnedColumn<S> {
         }
     }
 
+    /// Element-wise TRIM, UPPER or LOWER for a VARCHAR column
+    ///
+    /// TRIM only removes spaces, as in SQL, and the case conversions follow Unicode.
+    pub fn element_wise_string_transform(
+        &self,
+        op: StringTransformOperator,
+    ) -> ColumnOperationResult<Self> {
+        let transform = match op {
+            StringTransformOperator::Trim => |s: &String| s.trim_matches(' ').to_string(),
+            StringTransformOperator::Upper => |s: &String| s.to_uppercase(),
+            StringTransformOperator::Lower => |s: &String| s.to_lowercase(),
+        };
+        match self {
+            Self::VarChar(values) => Ok(Self::VarChar(values.iter().map(transform).collect())),
+            _ => Err(ColumnOperationError::UnaryOperationInvalidColumnType {
+                operator: op.to_string().to_uppercase(),
+                operand_type: self.column_type(),
+            }),
+        }
+    }
+
+    /// Element-wise `SUBSTRING(column, start, length)` for a VARCHAR column
+    ///
+    /// As in SQL, `start` is the 1-based position of the first character and the result consists
+    /// of the characters at positions `start` up to, but excluding, `start + length`. Positions are
+    /// counted in characters rather than bytes, and positions before the first character are
+    /// allowed but contribute nothing. A non-positive `length` or a `start` past the end of a
+    /// string results in an empty string.
+    pub fn element_wise_substring(&self, start: i64, length: i64) -> ColumnOperationResult<Self> {
+        let first = start.max(1);
+        let end = if length > 0 {
+            start.saturating_add(length)
+        } else {
+            first
+        };
+        let skip = usize::try_from(first - 1).unwrap_or(usize::MAX);
+        let take = usize::try_from(end.saturating_sub(first).max(0)).unwrap_or(usize::MAX);
+        match self {
+            Self::VarChar(values) => Ok(Self::VarChar(
+                values
+                    .iter()
+                    .map(|s| s.chars().skip(skip).take(take).collect())
+                    .collect(),
+            )),
+            _ => Err(ColumnOperationError::UnaryOperationInvalidColumnType {
+                operator: "SUBSTRING".to_string(),
+                operand_type: self.column_type(),
+            }),
+        }
+    }
+
     /// Element-wise AND for two columns
     pub fn element_wise_and(&self, rhs: &Self) -> ColumnOperationResult<Self> {
         if self.len() != rhs.len() {
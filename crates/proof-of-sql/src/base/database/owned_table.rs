@@ -1,10 +1,16 @@
 use super::{ColumnField, OwnedColumn, Table};
 use crate::base::{
-    database::ColumnCoercionError, map::IndexMap, polynomial::compute_evaluation_vector,
+    database::{
+        order_by_util::compare_indexes_by_owned_columns_with_direction, ColumnCoercionError,
+    },
+    map::IndexMap,
+    math::permutation::Permutation,
+    polynomial::compute_evaluation_vector,
     scalar::Scalar,
 };
 use alloc::{vec, vec::Vec};
 use itertools::{EitherOrBoth, Itertools};
+use proof_of_sql_parser::intermediate_ast::OrderByDirection;
 use serde::{Deserialize, Serialize};
 use snafu::Snafu;
 use sqlparser::ast::Ident;
@@ -15,6 +21,26 @@ pub enum OwnedTableError {
     /// The columns have different lengths.
     #[snafu(display("Columns have different lengths"))]
     ColumnLengthMismatch,
+    /// The column does not exist in the table.
+    #[snafu(display("Column {column} does not exist in the table"))]
+    ColumnNotFound {
+        /// The missing column
+        column: Ident,
+    },
+    /// The column was requested more than once.
+    #[snafu(display("Column {column} appears more than once"))]
+    DuplicateColumn {
+        /// The duplicated column
+        column: Ident,
+    },
+    /// The row mask does not have one entry per row.
+    #[snafu(display("Mask has length {mask_length} but the table has {num_rows} rows"))]
+    MaskLengthMismatch {
+        /// The length of the mask
+        mask_length: usize,
+        /// The number of rows of the table
+        num_rows: usize,
+    },
 }
 
 /// Errors that can occur when coercing a table.
@@ -126,10 +152,126 @@ impl<S: Scalar> OwnedTable<S> {
     pub fn inner_table(&self) -> &IndexMap<Ident, OwnedColumn<S>> {
         &self.table
     }
+    /// Return the schema of this table as a `Vec` of `ColumnField`s
+    #[must_use]
+    pub fn schema(&self) -> Vec<ColumnField> {
+        self.table
+            .iter()
+            .map(|(name, column)| ColumnField::new(name.clone(), column.column_type()))
+            .collect()
+    }
     /// Returns the columns of this table as an Iterator
     pub fn column_names(&self) -> impl Iterator<Item = &Ident> {
         self.table.keys()
     }
+    /// Returns a new table with only the given columns, in the given order.
+    ///
+    /// This is a host-side reshape of the table, e.g. of an already verified result, and is not
+    /// proven in any way.
+    ///
+    /// # Errors
+    /// Returns an error if a column does not exist in the table or appears more than once.
+    pub fn project(&self, column_names: &[Ident]) -> Result<Self, OwnedTableError> {
+        let mut table = IndexMap::with_capacity_and_hasher(column_names.len(), <_>::default());
+        for name in column_names {
+            let column = self
+                .table
+                .get(name)
+                .ok_or_else(|| OwnedTableError::ColumnNotFound {
+                    column: name.clone(),
+                })?;
+            if table.insert(name.clone(), column.clone()).is_some() {
+                return Err(OwnedTableError::DuplicateColumn {
+                    column: name.clone(),
+                });
+            }
+        }
+        Ok(Self { table })
+    }
+    /// Returns a new table with only the rows where `mask` is `true`, keeping their order.
+    ///
+    /// Like [`Self::project`], this is a host-side operation that is not proven in any way.
+    ///
+    /// # Errors
+    /// Returns an error if the length of `mask` differs from the number of rows.
+    pub fn filter_rows(&self, mask: &[bool]) -> Result<Self, OwnedTableError> {
+        let num_rows = self.num_rows();
+        if mask.len() != num_rows {
+            return Err(OwnedTableError::MaskLengthMismatch {
+                mask_length: mask.len(),
+                num_rows,
+            });
+        }
+        Ok(Self {
+            table: self
+                .table
+                .iter()
+                .map(|(name, column)| (name.clone(), column.filter(mask)))
+                .collect(),
+        })
+    }
+    /// Returns the table with its rows sorted by the given columns, the first key being the most
+    /// significant. Each key is a column name and whether to sort that column in ascending order.
+    ///
+    /// The sort is stable and compares values exactly as `ORDER BY` postprocessing does, so rows
+    /// that are equal in every key keep their original relative order.
+    ///
+    /// # Errors
+    /// Returns an error if a key is not a column of the table.
+    pub fn sort_by(&self, keys: &[(Ident, bool)]) -> Result<Self, OwnedTableError> {
+        let order_by_pairs =
+            keys.iter()
+                .map(|(identifier, ascending)| {
+                    let column = self.table.get(identifier).ok_or_else(|| {
+                        OwnedTableError::ColumnNotFound {
+                            column: identifier.clone(),
+                        }
+                    })?;
+                    let direction = if *ascending {
+                        OrderByDirection::Asc
+                    } else {
+                        OrderByDirection::Desc
+                    };
+                    Ok((column.clone(), direction))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+        Ok(self.clone().into_row_order(&order_by_pairs))
+    }
+    /// Returns the table with its rows sorted in ascending order by all of its columns, the first
+    /// column being the most significant.
+    ///
+    /// Tables with the same columns and the same rows, in any order, have the same canonical
+    /// form.
+    ///
+    /// # Panics
+    /// Panics if the columns have differing lengths, which a valid `OwnedTable` never has.
+    #[must_use]
+    pub fn into_canonical_row_order(self) -> Self {
+        let order_by_pairs = self
+            .table
+            .values()
+            .map(|column| (column.clone(), OrderByDirection::Asc))
+            .collect::<Vec<_>>();
+        self.into_row_order(&order_by_pairs)
+    }
+    /// Stably sorts the rows of the table by the given columns and directions.
+    ///
+    /// # Panics
+    /// Panics if the columns have differing lengths, which a valid `OwnedTable` never has.
+    fn into_row_order(self, order_by_pairs: &[(OwnedColumn<S>, OrderByDirection)]) -> Self {
+        let permutation = Permutation::unchecked_new_from_cmp(self.num_rows(), |&a, &b| {
+            compare_indexes_by_owned_columns_with_direction(order_by_pairs, a, b)
+        });
+        Self::try_from_iter(self.table.into_iter().map(|(identifier, column)| {
+            (
+                identifier,
+                column
+                    .try_permute(&permutation)
+                    .expect("There should be no column length mismatch here"),
+            )
+        }))
+        .expect("There should be no column length mismatch here")
+    }
 
     pub(crate) fn mle_evaluations(&self, evaluation_point: &[S]) -> Vec<S> {
         let mut evaluation_vector = vec![S::ZERO; self.num_rows()];
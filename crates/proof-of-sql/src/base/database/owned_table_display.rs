@@ -0,0 +1,159 @@
+use super::{
+    json_conversion::{date_to_string, timestamp_to_rfc3339},
+    OwnedColumn, OwnedTable,
+};
+use crate::base::scalar::Scalar;
+use bigdecimal::BigDecimal;
+use core::fmt::{self, Display, Formatter, Write};
+use num_bigint::BigInt;
+
+/// The number of rows rendered by the [`Display`] implementations of [`OwnedTable`] and
+/// [`OwnedColumn`]
+const DEFAULT_PREVIEW_ROWS: usize = 10;
+
+/// A compact rendering of the first rows of an [`OwnedTable`], created by [`OwnedTable::preview`].
+///
+/// The first line lists the columns with their types, followed by one line per row with the
+/// values separated by `|`. If the table has more rows, a final line gives their number.
+pub struct OwnedTablePreview<'a, S: Scalar> {
+    table: &'a OwnedTable<S>,
+    max_rows: usize,
+}
+
+/// A compact rendering of the first values of an [`OwnedColumn`], created by
+/// [`OwnedColumn::preview`].
+pub struct OwnedColumnPreview<'a, S: Scalar> {
+    column: &'a OwnedColumn<S>,
+    max_rows: usize,
+}
+
+impl<S: Scalar> OwnedTable<S> {
+    /// Renders the schema and at most `max_rows` rows of the table.
+    ///
+    /// Only `core::fmt` is used, so this is also available without the `std` feature.
+    #[must_use]
+    pub fn preview(&self, max_rows: usize) -> OwnedTablePreview<'_, S> {
+        OwnedTablePreview {
+            table: self,
+            max_rows,
+        }
+    }
+}
+
+impl<S: Scalar> OwnedColumn<S> {
+    /// Renders at most `max_rows` values of the column.
+    ///
+    /// Only `core::fmt` is used, so this is also available without the `std` feature.
+    #[must_use]
+    pub fn preview(&self, max_rows: usize) -> OwnedColumnPreview<'_, S> {
+        OwnedColumnPreview {
+            column: self,
+            max_rows,
+        }
+    }
+
+    /// Writes the value at `index`, which must be less than the length of the column.
+    fn fmt_value(&self, index: usize, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Boolean(col) => write!(f, "{}", col[index]),
+            Self::TinyInt(col) => write!(f, "{}", col[index]),
+            Self::SmallInt(col) => write!(f, "{}", col[index]),
+            Self::Int(col) => write!(f, "{}", col[index]),
+            Self::BigInt(col) => write!(f, "{}", col[index]),
+            Self::Int128(col) => write!(f, "{}", col[index]),
+            Self::UInt64(col) => write!(f, "{}", col[index]),
+            Self::VarChar(col) => {
+                f.write_char('\'')?;
+                for c in col[index].chars() {
+                    if c == '\'' {
+                        f.write_char('\'')?;
+                    }
+                    f.write_char(c)?;
+                }
+                f.write_char('\'')
+            }
+            Self::Decimal75(_, scale, col) => {
+                write!(f, "{}", BigDecimal::new(col[index].into(), (*scale).into()))
+            }
+            Self::Scalar(col) => write!(f, "{}", Into::<BigInt>::into(col[index])),
+            Self::TimestampTZ(time_unit, time_zone, col) => {
+                match timestamp_to_rfc3339(col[index], *time_unit, *time_zone) {
+                    Some(timestamp) => f.write_str(&timestamp),
+                    None => write!(f, "{}", col[index]),
+                }
+            }
+            Self::Date(col) => match date_to_string(col[index]) {
+                Some(date) => f.write_str(&date),
+                None => write!(f, "{}", col[index]),
+            },
+        }
+    }
+}
+
+/// Writes the line reporting the rows that were not rendered, if there are any.
+fn fmt_remaining_rows(num_rows: usize, max_rows: usize, f: &mut Formatter<'_>) -> fmt::Result {
+    match num_rows.saturating_sub(max_rows) {
+        0 => Ok(()),
+        1 => f.write_str("... 1 more row"),
+        remaining => write!(f, "... {remaining} more rows"),
+    }
+}
+
+impl<S: Scalar> Display for OwnedTablePreview<'_, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, (name, column)) in self.table.inner_table().iter().enumerate() {
+            if i > 0 {
+                f.write_str(" | ")?;
+            }
+            write!(f, "{}: {}", name.value, column.column_type())?;
+        }
+        let num_rows = self.table.num_rows();
+        for row in 0..num_rows.min(self.max_rows) {
+            f.write_char('\n')?;
+            for (i, column) in self.table.inner_table().values().enumerate() {
+                if i > 0 {
+                    f.write_str(" | ")?;
+                }
+                column.fmt_value(row, f)?;
+            }
+        }
+        if num_rows > self.max_rows {
+            f.write_char('\n')?;
+        }
+        fmt_remaining_rows(num_rows, self.max_rows, f)
+    }
+}
+
+impl<S: Scalar> Display for OwnedColumnPreview<'_, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_char('[')?;
+        let len = self.column.len();
+        for index in 0..len.min(self.max_rows) {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            self.column.fmt_value(index, f)?;
+        }
+        if len > self.max_rows {
+            if self.max_rows > 0 {
+                f.write_str(", ")?;
+            }
+            fmt_remaining_rows(len, self.max_rows, f)?;
+        }
+        f.write_char(']')
+    }
+}
+
+/// Renders the schema and the first rows of the table, see [`OwnedTable::preview`]
+impl<S: Scalar> Display for OwnedTable<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.preview(DEFAULT_PREVIEW_ROWS).fmt(f)
+    }
+}
+
+/// Renders the first values of the column, see [`OwnedColumn::preview`]
+impl<S: Scalar> Display for OwnedColumn<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.preview(DEFAULT_PREVIEW_ROWS).fmt(f)
+    }
+}
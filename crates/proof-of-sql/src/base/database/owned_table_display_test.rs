@@ -0,0 +1,94 @@
+use crate::base::{
+    database::{owned_table_utility::*, OwnedColumn, OwnedTable},
+    map::IndexMap,
+    scalar::test_scalar::TestScalar,
+};
+use alloc::{format, string::ToString, vec::Vec};
+use proof_of_sql_parser::posql_time::{PoSQLTimeUnit, PoSQLTimeZone};
+
+#[test]
+fn we_can_display_an_owned_table_with_every_column_type() {
+    let table: OwnedTable<TestScalar> = owned_table([
+        boolean("b", [true, false]),
+        tinyint("ti", [-1_i8, 2]),
+        smallint("si", [3_i16, -4]),
+        int("i", [5, 6]),
+        bigint("bi", [-7_i64, 8]),
+        int128("i128", [i128::MAX, -9]),
+        varchar("v", ["it's", "日本"]),
+        decimal75("d", 10, 2, [12345, -5]),
+        scalar("s", [1, 2]),
+        timestamptz(
+            "t",
+            PoSQLTimeUnit::Millisecond,
+            PoSQLTimeZone::utc(),
+            [1_500, 0],
+        ),
+        date("dt", [0, 19_000]),
+    ]);
+    assert_eq!(
+        table.to_string(),
+        "b: BOOLEAN | ti: TINYINT | si: SMALLINT | i: INT | bi: BIGINT | i128: DECIMAL \
+         | v: VARCHAR | d: DECIMAL75(PRECISION: 10, SCALE: 2) | s: SCALAR \
+         | t: TIMESTAMP(TIMEUNIT: milliseconds (precision: 3), TIMEZONE: +00:00) | dt: DATE\n\
+         true | -1 | 3 | 5 | -7 | 170141183460469231731687303715884105727 | 'it''s' | 123.45 | 1 \
+         | 1970-01-01T00:00:01.500+00:00 | 1970-01-01\n\
+         false | 2 | -4 | 6 | 8 | -9 | '日本' | -0.05 | 2 | 1970-01-01T00:00:00+00:00 | 2022-01-08"
+    );
+}
+
+#[test]
+fn we_can_display_a_preview_of_the_first_rows_of_an_owned_table() {
+    let table: OwnedTable<TestScalar> = owned_table([
+        bigint("a", [1, 2, 3, 4]),
+        varchar("b", ["w", "x", "y", "z"]),
+    ]);
+    assert_eq!(
+        format!("{}", table.preview(2)),
+        "a: BIGINT | b: VARCHAR\n1 | 'w'\n2 | 'x'\n... 2 more rows"
+    );
+    assert_eq!(
+        format!("{}", table.preview(3)),
+        "a: BIGINT | b: VARCHAR\n1 | 'w'\n2 | 'x'\n3 | 'y'\n... 1 more row"
+    );
+    assert_eq!(
+        format!("{}", table.preview(4)),
+        "a: BIGINT | b: VARCHAR\n1 | 'w'\n2 | 'x'\n3 | 'y'\n4 | 'z'"
+    );
+    assert_eq!(
+        format!("{}", table.preview(0)),
+        "a: BIGINT | b: VARCHAR\n... 4 more rows"
+    );
+
+    // The default display renders at most 10 rows
+    let table: OwnedTable<TestScalar> = owned_table([int("a", 0..12)]);
+    let display = table.to_string();
+    assert_eq!(display.lines().count(), 12);
+    assert!(display.ends_with("\n9\n... 2 more rows"));
+}
+
+#[test]
+fn we_can_display_owned_tables_without_rows_or_columns() {
+    let table: OwnedTable<TestScalar> = owned_table([bigint("a", [0; 0])]);
+    assert_eq!(table.to_string(), "a: BIGINT");
+    let table = OwnedTable::<TestScalar>::try_new(IndexMap::default()).unwrap();
+    assert_eq!(table.to_string(), "");
+}
+
+#[test]
+fn we_can_display_a_preview_of_an_owned_column() {
+    let column = OwnedColumn::<TestScalar>::VarChar(["a", "b", "c"].map(Into::into).to_vec());
+    assert_eq!(column.to_string(), "['a', 'b', 'c']");
+    assert_eq!(
+        format!("{}", column.preview(2)),
+        "['a', 'b', ... 1 more row]"
+    );
+    assert_eq!(format!("{}", column.preview(0)), "[... 3 more rows]");
+    assert_eq!(
+        OwnedColumn::<TestScalar>::BigInt(Vec::new()).to_string(),
+        "[]"
+    );
+    let column = OwnedColumn::<TestScalar>::Date(Vec::from([i32::MAX, -1]));
+    // Dates that can't be represented as a calendar date fall back to their raw value
+    assert_eq!(column.to_string(), "[2147483647, 1969-12-31]");
+}
@@ -167,3 +167,206 @@ fn we_cannot_create_an_owned_table_with_differing_column_lengths() {
         Err(OwnedTableError::ColumnLengthMismatch)
     ));
 }
+#[test]
+fn we_can_put_an_owned_table_into_canonical_row_order() {
+    let table = owned_table::<TestScalar>([
+        bigint("a", [3, -1, 3, 0, -1]),
+        varchar("b", ["x", "z", "a", "y", "z"]),
+        boolean("c", [true, false, false, true, true]),
+    ]);
+    let permuted = owned_table::<TestScalar>([
+        bigint("a", [-1, 0, 3, -1, 3]),
+        varchar("b", ["z", "y", "x", "z", "a"]),
+        boolean("c", [true, true, true, false, false]),
+    ]);
+    let expected = owned_table::<TestScalar>([
+        bigint("a", [-1, -1, 0, 3, 3]),
+        varchar("b", ["z", "z", "y", "a", "x"]),
+        boolean("c", [false, true, true, false, true]),
+    ]);
+    assert_eq!(table.into_canonical_row_order(), expected);
+    assert_eq!(permuted.into_canonical_row_order(), expected);
+}
+#[test]
+fn we_can_put_tables_without_rows_or_columns_into_canonical_row_order() {
+    let empty = owned_table::<TestScalar>([bigint("a", [0; 0]), varchar("b", ["0"; 0])]);
+    assert_eq!(empty.clone().into_canonical_row_order(), empty);
+    let no_columns = OwnedTable::<TestScalar>::try_new(IndexMap::default()).unwrap();
+    assert_eq!(no_columns.clone().into_canonical_row_order(), no_columns);
+}
+#[test]
+fn we_can_project_an_owned_table_onto_a_subset_of_reordered_columns() {
+    let table = owned_table::<TestScalar>([
+        bigint("a", [1, 2, 3]),
+        varchar("b", ["x", "y", "z"]),
+        boolean("c", [true, false, true]),
+    ]);
+    assert_eq!(
+        table.project(&["c".into(), "a".into()]).unwrap(),
+        owned_table([boolean("c", [true, false, true]), bigint("a", [1, 2, 3])])
+    );
+    assert_eq!(
+        table.project(&["b".into()]).unwrap(),
+        owned_table([varchar("b", ["x", "y", "z"])])
+    );
+    assert_eq!(
+        table.project(&[]).unwrap(),
+        OwnedTable::try_new(IndexMap::default()).unwrap()
+    );
+}
+#[test]
+fn we_cannot_project_an_owned_table_onto_unknown_or_duplicate_columns() {
+    let table = owned_table::<TestScalar>([bigint("a", [1, 2, 3]), varchar("b", ["x", "y", "z"])]);
+    assert_eq!(
+        table.project(&["a".into(), "d".into()]),
+        Err(OwnedTableError::ColumnNotFound { column: "d".into() })
+    );
+    assert_eq!(
+        table.project(&["b".into(), "a".into(), "b".into()]),
+        Err(OwnedTableError::DuplicateColumn { column: "b".into() })
+    );
+}
+#[test]
+fn we_can_filter_the_rows_of_an_owned_table_with_every_column_type() {
+    let table = owned_table::<TestScalar>([
+        boolean("boolean", [true, false, true]),
+        tinyint("tinyint", [1_i8, 2, 3]),
+        smallint("smallint", [1_i16, 2, 3]),
+        int("int", [1, 2, 3]),
+        bigint("bigint", [1, 2, 3]),
+        int128("int128", [1, 2, 3]),
+        uint64("uint64", [1_u64, 2, 3]),
+        varchar("varchar", ["a", "b", "c"]),
+        scalar("scalar", [1, 2, 3]),
+        decimal75("decimal", 12, 2, [100, 250, -375]),
+        timestamptz(
+            "timestamp",
+            PoSQLTimeUnit::Millisecond,
+            PoSQLTimeZone::new(3600),
+            [0, 1_000, -1_000],
+        ),
+        date("date", [0, 18_262, -1]),
+    ]);
+    let expected = owned_table::<TestScalar>([
+        boolean("boolean", [true, true]),
+        tinyint("tinyint", [1_i8, 3]),
+        smallint("smallint", [1_i16, 3]),
+        int("int", [1, 3]),
+        bigint("bigint", [1, 3]),
+        int128("int128", [1, 3]),
+        uint64("uint64", [1_u64, 3]),
+        varchar("varchar", ["a", "c"]),
+        scalar("scalar", [1, 3]),
+        decimal75("decimal", 12, 2, [100, -375]),
+        timestamptz(
+            "timestamp",
+            PoSQLTimeUnit::Millisecond,
+            PoSQLTimeZone::new(3600),
+            [0, -1_000],
+        ),
+        date("date", [0, -1]),
+    ]);
+    assert_eq!(table.filter_rows(&[true, false, true]).unwrap(), expected);
+    assert_eq!(
+        table.filter_rows(&[true, true, true]).unwrap(),
+        table.clone()
+    );
+}
+#[test]
+fn we_can_filter_out_every_row_of_an_owned_table() {
+    let table = owned_table::<TestScalar>([
+        bigint("a", [1, 2, 3]),
+        varchar("b", ["x", "y", "z"]),
+        decimal75("c", 5, 1, [10, 20, 30]),
+    ]);
+    let filtered = table.filter_rows(&[false, false, false]).unwrap();
+    assert_eq!(
+        filtered,
+        owned_table([
+            bigint("a", [0; 0]),
+            varchar("b", [""; 0]),
+            decimal75("c", 5, 1, [0; 0]),
+        ])
+    );
+    assert_eq!(filtered.num_rows(), 0);
+    assert_eq!(filtered.schema(), table.schema());
+}
+#[test]
+fn we_cannot_filter_the_rows_of_an_owned_table_with_a_mask_of_the_wrong_length() {
+    let table = owned_table::<TestScalar>([bigint("a", [1, 2, 3]), varchar("b", ["x", "y", "z"])]);
+    assert_eq!(
+        table.filter_rows(&[true, false]),
+        Err(OwnedTableError::MaskLengthMismatch {
+            mask_length: 2,
+            num_rows: 3
+        })
+    );
+    assert_eq!(
+        table.filter_rows(&[true; 4]),
+        Err(OwnedTableError::MaskLengthMismatch {
+            mask_length: 4,
+            num_rows: 3
+        })
+    );
+    let no_columns = OwnedTable::<TestScalar>::try_new(IndexMap::default()).unwrap();
+    assert_eq!(no_columns.filter_rows(&[]).unwrap(), no_columns);
+}
+#[test]
+fn we_can_sort_an_owned_table_by_a_decimal_column() {
+    let table = owned_table::<TestScalar>([
+        decimal75("price", 10, 2, [250, -100, 0, -375, 1_000]),
+        varchar("item", ["b", "c", "d", "a", "e"]),
+        bigint("id", [1, 2, 3, 4, 5]),
+    ]);
+    assert_eq!(
+        table.sort_by(&[("price".into(), true)]).unwrap(),
+        owned_table([
+            decimal75("price", 10, 2, [-375, -100, 0, 250, 1_000]),
+            varchar("item", ["a", "c", "d", "b", "e"]),
+            bigint("id", [4, 2, 3, 1, 5]),
+        ])
+    );
+    assert_eq!(
+        table.sort_by(&[("price".into(), false)]).unwrap(),
+        owned_table([
+            decimal75("price", 10, 2, [1_000, 250, 0, -100, -375]),
+            varchar("item", ["e", "b", "d", "c", "a"]),
+            bigint("id", [5, 1, 3, 2, 4]),
+        ])
+    );
+}
+#[test]
+fn we_can_sort_an_owned_table_by_a_varchar_column_and_keep_ties_in_order() {
+    let table = owned_table::<TestScalar>([
+        varchar("name", ["bob", "alice", "carol", "alice", "bob"]),
+        bigint("score", [7, 3, 5, 9, 2]),
+        boolean("flag", [true, false, true, true, false]),
+    ]);
+    assert_eq!(
+        table.sort_by(&[("name".into(), true)]).unwrap(),
+        owned_table([
+            varchar("name", ["alice", "alice", "bob", "bob", "carol"]),
+            bigint("score", [3, 9, 7, 2, 5]),
+            boolean("flag", [false, true, true, false, true]),
+        ])
+    );
+    assert_eq!(
+        table
+            .sort_by(&[("name".into(), false), ("score".into(), true)])
+            .unwrap(),
+        owned_table([
+            varchar("name", ["carol", "bob", "bob", "alice", "alice"]),
+            bigint("score", [5, 2, 7, 3, 9]),
+            boolean("flag", [true, false, true, false, true]),
+        ])
+    );
+    assert_eq!(table.sort_by(&[]).unwrap(), table);
+}
+#[test]
+fn we_cannot_sort_an_owned_table_by_an_unknown_column() {
+    let table = owned_table::<TestScalar>([bigint("a", [1, 2, 3])]);
+    assert_eq!(
+        table.sort_by(&[("a".into(), true), ("d".into(), true)]),
+        Err(OwnedTableError::ColumnNotFound { column: "d".into() })
+    );
+}
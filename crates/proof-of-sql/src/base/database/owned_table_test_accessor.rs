@@ -110,6 +110,8 @@ impl<CP: CommitmentEvaluationProof> DataAccessor<CP::Scalar> for OwnedTableTestA
                 Column::VarChar((col, scals))
             }
             OwnedColumn::TimestampTZ(tu, tz, col) => Column::TimestampTZ(*tu, *tz, col),
+            OwnedColumn::Date(col) => Column::Date(col),
+            OwnedColumn::UInt64(col) => Column::UInt64(col),
         }
     }
 }
@@ -173,6 +175,10 @@ impl<CP: CommitmentEvaluationProof> SchemaAccessor for OwnedTableTestAccessor<'_
             .map(|(id, col)| (id.clone(), col.column_type()))
             .collect()
     }
+
+    fn lookup_tables(&self) -> Vec<TableRef> {
+        self.tables.keys().copied().collect()
+    }
 }
 
 impl<'a, CP: CommitmentEvaluationProof> OwnedTableTestAccessor<'a, CP> {
@@ -160,6 +160,25 @@ pub fn int128<S: Scalar>(
     )
 }
 
+/// Creates a `(Ident, OwnedColumn)` pair for a uint64 column.
+/// This is primarily intended for use in conjunction with [`owned_table`].
+/// # Example
+/// ```
+/// use proof_of_sql::base::{database::owned_table_utility::*, scalar::Curve25519Scalar};
+/// let result = owned_table::<Curve25519Scalar>([
+///     uint64("a", [1, 2, u64::MAX]),
+/// ]);
+/// ```
+pub fn uint64<S: Scalar>(
+    name: impl Into<Ident>,
+    data: impl IntoIterator<Item = impl Into<u64>>,
+) -> (Ident, OwnedColumn<S>) {
+    (
+        name.into(),
+        OwnedColumn::UInt64(data.into_iter().map(Into::into).collect()),
+    )
+}
+
 /// Creates a `(Ident, OwnedColumn)` pair for a scalar column.
 /// This is primarily intended for use in conjunction with [`owned_table`].
 /// # Example
@@ -261,3 +280,21 @@ pub fn timestamptz<S: Scalar>(
         OwnedColumn::TimestampTZ(time_unit, timezone, data.into_iter().collect()),
     )
 }
+
+/// Creates a `(Ident, OwnedColumn)` pair for a date column.
+/// This is primarily intended for use in conjunction with [`owned_table`].
+///
+/// The data is provided as the number of days since the unix epoch.
+/// # Example
+/// ```rust
+/// use proof_of_sql::base::{database::owned_table_utility::*, scalar::Curve25519Scalar};
+/// let result = owned_table::<Curve25519Scalar>([
+///     date("a", [0, 18_262, -1]),
+/// ]);
+/// ```
+pub fn date<S: Scalar>(
+    name: impl Into<Ident>,
+    data: impl IntoIterator<Item = i32>,
+) -> (Ident, OwnedColumn<S>) {
+    (name.into(), OwnedColumn::Date(data.into_iter().collect()))
+}
@@ -8,7 +8,9 @@ use proof_of_sql_parser::{impl_serde_from_str, ResourceId};
 use sqlparser::ast::Ident;
 
 /// Expression for an SQL table
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+///
+/// Table references are ordered by schema and then by table name.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Copy)]
 pub struct TableRef {
     resource_id: ResourceId,
 }
@@ -37,6 +39,12 @@ impl TableRef {
     pub fn resource_id(&self) -> ResourceId {
         self.resource_id
     }
+
+    /// Whether the table matches `pattern`, like `schema.*`. See [`ResourceId::matches`].
+    #[must_use]
+    pub fn matches(&self, pattern: &str) -> bool {
+        self.resource_id.matches(pattern)
+    }
 }
 
 impl FromStr for TableRef {
@@ -146,6 +146,10 @@ impl<CP: CommitmentEvaluationProof> SchemaAccessor for TableTestAccessor<'_, CP>
             .map(|(id, col)| (id.clone(), col.column_type()))
             .collect()
     }
+
+    fn lookup_tables(&self) -> Vec<TableRef> {
+        self.tables.keys().copied().collect()
+    }
 }
 
 impl<'a, CP: CommitmentEvaluationProof> TableTestAccessor<'a, CP> {
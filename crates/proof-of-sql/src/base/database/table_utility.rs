@@ -200,6 +200,28 @@ pub fn borrowed_int128<S: Scalar>(
     (name.into(), Column::Int128(alloc_data))
 }
 
+/// Creates a `(Ident, Column)` pair for a uint64 column.
+/// This is primarily intended for use in conjunction with [`table`].
+///
+/// # Example
+/// ```
+/// use bumpalo::Bump;
+/// use proof_of_sql::base::{database::table_utility::*, scalar::Curve25519Scalar};
+/// let alloc = Bump::new();
+/// let result = table::<Curve25519Scalar>([
+///     borrowed_uint64("a", [1, 2, u64::MAX], &alloc),
+/// ]);
+/// ```
+pub fn borrowed_uint64<S: Scalar>(
+    name: impl Into<Ident>,
+    data: impl IntoIterator<Item = impl Into<u64>>,
+    alloc: &Bump,
+) -> (Ident, Column<'_, S>) {
+    let transformed_data: Vec<u64> = data.into_iter().map(Into::into).collect();
+    let alloc_data = alloc.alloc_slice_copy(&transformed_data);
+    (name.into(), Column::UInt64(alloc_data))
+}
+
 /// Creates a `(Ident, Column)` pair for a scalar column.
 /// This is primarily intended for use in conjunction with [`table`].
 ///
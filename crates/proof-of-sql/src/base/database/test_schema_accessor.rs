@@ -26,6 +26,10 @@ impl SchemaAccessor for TestSchemaAccessor {
             .map(|(id, col)| (id.clone(), *col))
             .collect()
     }
+
+    fn lookup_tables(&self) -> Vec<TableRef> {
+        self.schemas.keys().copied().collect()
+    }
 }
 
 #[cfg(test)]
@@ -94,4 +98,12 @@ mod tests {
         );
         assert_eq!(accessor.lookup_schema(not_a_table), vec![]);
     }
+
+    #[test]
+    fn test_lookup_tables() {
+        let accessor = sample_test_schema_accessor();
+        let table1: TableRef = TableRef::new("schema.table1".parse().unwrap());
+        let table2: TableRef = TableRef::new("schema.table2".parse().unwrap());
+        assert_eq!(accessor.lookup_tables(), vec![table1, table2]);
+    }
 }
@@ -8,7 +8,7 @@ use bumpalo::Bump;
 
 /// Check if two schemas are compatible
 /// Note that we can tolerate differences in column names but not in column types
-fn are_schemas_compatible(left: &[ColumnField], right: &[ColumnField]) -> bool {
+pub(crate) fn are_schemas_compatible(left: &[ColumnField], right: &[ColumnField]) -> bool {
     left.len() == right.len()
         && left
             .iter()
@@ -16,6 +16,14 @@ fn are_schemas_compatible(left: &[ColumnField], right: &[ColumnField]) -> bool {
             .all(|(field1, field2)| field1.data_type() == field2.data_type())
 }
 
+/// Check if a column of type `actual` can be unioned into a column of type `expected`
+///
+/// Arithmetic expressions evaluate to scalar columns, so a scalar column can stand in for any
+/// numeric type.
+fn is_column_type_compatible(expected: ColumnType, actual: ColumnType) -> bool {
+    expected == actual || (actual == ColumnType::Scalar && expected.is_numeric())
+}
+
 /// Union multiple columns of the same type into a single column
 ///
 /// # Panics
@@ -164,6 +172,26 @@ pub fn column_union<'a, S: Scalar>(
                 }) as &[_],
             )
         }
+        ColumnType::Date => {
+            let mut iter = columns
+                .iter()
+                .flat_map(|col| col.as_date().expect("Column types should match"))
+                .copied();
+
+            Column::Date(alloc.alloc_slice_fill_with(len, |_| {
+                iter.next().expect("Iterator should have enough elements")
+            }) as &[_])
+        }
+        ColumnType::UInt64 => {
+            let mut iter = columns
+                .iter()
+                .flat_map(|col| col.as_uint64().expect("Column types should match"))
+                .copied();
+
+            Column::UInt64(alloc.alloc_slice_fill_with(len, |_| {
+                iter.next().expect("Iterator should have enough elements")
+            }) as &[_])
+        }
     })
 }
 
@@ -179,7 +207,13 @@ pub fn table_union<'a, S: Scalar>(
     // Check schema equality
     let possible_bad_schema = tables
         .iter()
-        .filter(|&table| (!are_schemas_compatible(&schema, &table.schema())))
+        .filter(|&table| {
+            let table_schema = table.schema();
+            table_schema.len() != schema.len()
+                || schema.iter().zip(&table_schema).any(|(expected, actual)| {
+                    !is_column_type_compatible(expected.data_type(), actual.data_type())
+                })
+        })
         .map(|table| table.schema().clone())
         .next();
     if let Some(bad_schema) = possible_bad_schema {
@@ -195,11 +229,30 @@ pub fn table_union<'a, S: Scalar>(
         schema.iter().enumerate().map(|(i, field)| {
             let columns: Vec<_> = tables
                 .iter()
-                .map(|table| table.column(i).expect("Schemas should be compatible"))
+                .map(|table| *table.column(i).expect("Schemas should be compatible"))
+                .collect();
+            // If any of the columns is a scalar column, the union is a scalar column as well
+            let column_type = if columns
+                .iter()
+                .all(|column| column.column_type() == field.data_type())
+            {
+                field.data_type()
+            } else {
+                ColumnType::Scalar
+            };
+            let columns: Vec<_> = columns
+                .into_iter()
+                .map(|column| match column_type {
+                    ColumnType::Scalar => Column::Scalar(
+                        alloc.alloc_slice_copy(&column.to_scalar_with_scaling(0)) as &[_],
+                    ),
+                    _ => column,
+                })
                 .collect();
             (
                 field.name(),
-                column_union(&columns, alloc, field.data_type()).expect("Failed to union columns"),
+                column_union(&columns.iter().collect::<Vec<_>>(), alloc, column_type)
+                    .expect("Failed to union columns"),
             )
         }),
         TableOptions::new(Some(num_rows)),
@@ -343,6 +396,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn we_can_union_tables_with_scalar_columns_of_numeric_fields() {
+        let alloc = Bump::new();
+        let table0 = Table::<'_, TestScalar>::try_new_with_options(
+            IndexMap::from_iter(vec![
+                ("a".into(), Column::BigInt(&[1, 2])),
+                ("b".into(), Column::BigInt(&[3, 4])),
+            ]),
+            TableOptions::new(Some(2)),
+        )
+        .unwrap();
+        let scalars = [5, 6].map(TestScalar::from);
+        let table1 = Table::<'_, TestScalar>::try_new_with_options(
+            IndexMap::from_iter(vec![
+                ("c".into(), Column::Scalar(&scalars)),
+                ("d".into(), Column::BigInt(&[7, 8])),
+            ]),
+            TableOptions::new(Some(2)),
+        )
+        .unwrap();
+        let result = table_union(
+            &[table0, table1],
+            &alloc,
+            vec![
+                ColumnField::new("e".into(), ColumnType::BigInt),
+                ColumnField::new("f".into(), ColumnType::BigInt),
+            ],
+        )
+        .unwrap();
+        let expected_scalars = [1, 2, 5, 6].map(TestScalar::from);
+        assert_eq!(
+            result,
+            Table::<'_, TestScalar>::try_new_with_options(
+                IndexMap::from_iter(vec![
+                    ("e".into(), Column::Scalar(&expected_scalars)),
+                    ("f".into(), Column::BigInt(&[3, 4, 7, 8])),
+                ]),
+                TableOptions::new(Some(4)),
+            )
+            .unwrap()
+        );
+
+        // A scalar column can not stand in for a non-numeric field
+        assert!(matches!(
+            table_union(
+                &[result],
+                &alloc,
+                vec![
+                    ColumnField::new("e".into(), ColumnType::VarChar),
+                    ColumnField::new("f".into(), ColumnType::BigInt),
+                ],
+            ),
+            Err(TableOperationError::UnionIncompatibleSchemas { .. })
+        ));
+    }
+
     #[test]
     fn we_cannot_union_tables_with_incompatible_schema() {
         let alloc = Bump::new();
@@ -44,6 +44,25 @@ impl I256 {
             num_bigint::Sign::Plus | num_bigint::Sign::NoSign => Self(limbs),
         }
     }
+
+    #[must_use]
+    /// Conversion into a [`num_bigint::BigInt`]. The conversion handles negative values.
+    ///
+    /// NOTE: this is not a particularly efficient method. Please either refactor or avoid when performance matters.
+    pub fn to_num_bigint(self) -> num_bigint::BigInt {
+        let is_negative = self.0[3] & 0x8000_0000_0000_0000 != 0;
+        let abs = if is_negative { self.neg() } else { self };
+        let magnitude = abs
+            .0
+            .iter()
+            .rev()
+            .fold(num_bigint::BigInt::from(0), |acc, &limb| (acc << 64) + limb);
+        if is_negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
 }
 impl From<i32> for I256 {
     fn from(value: i32) -> Self {
@@ -237,6 +256,20 @@ mod tests {
         }
     }
     #[test]
+    fn we_can_convert_i256_to_num_bigint() {
+        assert_eq!(ZERO.to_num_bigint(), BigInt::from(0));
+        assert_eq!(ONE.to_num_bigint(), BigInt::from(1));
+        assert_eq!(NEG_ONE.to_num_bigint(), BigInt::from(-1));
+        assert_eq!(TWO.to_num_bigint(), BigInt::from(2));
+        assert_eq!(NEG_TWO.to_num_bigint(), BigInt::from(-2));
+        assert_eq!(A.to_num_bigint(), A_STR.parse().unwrap());
+        assert_eq!(NEG_A.to_num_bigint(), -A_STR.parse::<BigInt>().unwrap());
+        assert_eq!(B.to_num_bigint(), B_STR.parse().unwrap());
+        assert_eq!(NEG_B.to_num_bigint(), -B_STR.parse::<BigInt>().unwrap());
+        assert_eq!(C.to_num_bigint(), C_STR.parse().unwrap());
+        assert_eq!(NEG_C.to_num_bigint(), -C_STR.parse::<BigInt>().unwrap());
+    }
+    #[test]
     fn we_can_convert_i256_from_i32() {
         assert_eq!(I256::from(0), ZERO);
         assert_eq!(I256::from(1), ONE);
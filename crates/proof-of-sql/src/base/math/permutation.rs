@@ -29,15 +29,14 @@ pub struct Permutation {
 
 impl Permutation {
     /// Create a new permutation from a comparison function with the given length
+    ///
+    /// The sort is stable, so indexes that compare equal keep their original relative order.
     pub(crate) fn unchecked_new_from_cmp<F>(length: usize, cmp: F) -> Self
     where
         F: Fn(&usize, &usize) -> Ordering + Sync,
     {
         let mut indexes = (0..length).collect_vec();
-        if_rayon!(
-            indexes.par_sort_unstable_by(cmp),
-            indexes.sort_unstable_by(cmp)
-        );
+        if_rayon!(indexes.par_sort_by(cmp), indexes.sort_by(cmp));
         Self {
             permutation: indexes,
         }
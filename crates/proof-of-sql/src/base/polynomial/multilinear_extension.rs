@@ -105,9 +105,10 @@ impl<S: Scalar> MultilinearExtension<S> for &Column<'_, S> {
             }
             Column::TinyInt(c) => c.inner_product(evaluation_vec),
             Column::SmallInt(c) => c.inner_product(evaluation_vec),
-            Column::Int(c) => c.inner_product(evaluation_vec),
+            Column::Int(c) | Column::Date(c) => c.inner_product(evaluation_vec),
             Column::BigInt(c) | Column::TimestampTZ(_, _, c) => c.inner_product(evaluation_vec),
             Column::Int128(c) => c.inner_product(evaluation_vec),
+            Column::UInt64(c) => c.inner_product(evaluation_vec),
         }
     }
 
@@ -119,9 +120,10 @@ impl<S: Scalar> MultilinearExtension<S> for &Column<'_, S> {
             }
             Column::TinyInt(c) => c.mul_add(res, multiplier),
             Column::SmallInt(c) => c.mul_add(res, multiplier),
-            Column::Int(c) => c.mul_add(res, multiplier),
+            Column::Int(c) | Column::Date(c) => c.mul_add(res, multiplier),
             Column::BigInt(c) | Column::TimestampTZ(_, _, c) => c.mul_add(res, multiplier),
             Column::Int128(c) => c.mul_add(res, multiplier),
+            Column::UInt64(c) => c.mul_add(res, multiplier),
         }
     }
 
@@ -133,9 +135,10 @@ impl<S: Scalar> MultilinearExtension<S> for &Column<'_, S> {
             }
             Column::TinyInt(c) => c.to_sumcheck_term(num_vars),
             Column::SmallInt(c) => c.to_sumcheck_term(num_vars),
-            Column::Int(c) => c.to_sumcheck_term(num_vars),
+            Column::Int(c) | Column::Date(c) => c.to_sumcheck_term(num_vars),
             Column::BigInt(c) | Column::TimestampTZ(_, _, c) => c.to_sumcheck_term(num_vars),
             Column::Int128(c) => c.to_sumcheck_term(num_vars),
+            Column::UInt64(c) => c.to_sumcheck_term(num_vars),
         }
     }
 
@@ -147,9 +150,10 @@ impl<S: Scalar> MultilinearExtension<S> for &Column<'_, S> {
             }
             Column::TinyInt(c) => MultilinearExtension::<S>::id(c),
             Column::SmallInt(c) => MultilinearExtension::<S>::id(c),
-            Column::Int(c) => MultilinearExtension::<S>::id(c),
+            Column::Int(c) | Column::Date(c) => MultilinearExtension::<S>::id(c),
             Column::BigInt(c) | Column::TimestampTZ(_, _, c) => MultilinearExtension::<S>::id(c),
             Column::Int128(c) => MultilinearExtension::<S>::id(c),
+            Column::UInt64(c) => MultilinearExtension::<S>::id(c),
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::base::commitment::CommitmentSchemeId;
 use snafu::Snafu;
 
 #[derive(Snafu, Debug)]
@@ -20,6 +21,20 @@ pub enum ProofError {
     FieldCountMismatch,
     #[snafu(transparent)]
     ProofSizeMismatch { source: ProofSizeMismatch },
+    /// This error occurs when proving is cancelled before the proof is complete.
+    #[snafu(display("Proving was cancelled"))]
+    Cancelled,
+    /// This error occurs when a proof of one commitment scheme is verified with another.
+    #[snafu(display("Commitment scheme mismatch: expected {expected}, found {found}"))]
+    SchemeMismatch {
+        /// The scheme of the verifier
+        expected: CommitmentSchemeId,
+        /// The scheme the proof was created with
+        found: CommitmentSchemeId,
+    },
+    /// This error occurs when the prover divides by zero, e.g. in `/` or `%`.
+    #[snafu(display("Division by zero"))]
+    DivisionByZero,
 }
 
 #[derive(Snafu, Debug)]
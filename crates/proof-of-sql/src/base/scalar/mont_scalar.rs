@@ -557,6 +557,28 @@ where
     }
 }
 
+impl<T> TryFrom<MontScalar<T>> for u64
+where
+    T: MontConfig<4>,
+    MontScalar<T>: Scalar,
+{
+    type Error = ScalarConversionError;
+    fn try_from(value: MontScalar<T>) -> Result<Self, Self::Error> {
+        if value > <MontScalar<T>>::MAX_SIGNED {
+            return Err(ScalarConversionError::Overflow {
+                error: format!("{value} is negative and does not fit in a u64"),
+            });
+        }
+        let abs: [u64; 4] = value.into();
+        if abs[1] != 0 || abs[2] != 0 || abs[3] != 0 {
+            return Err(ScalarConversionError::Overflow {
+                error: format!("{value} is too large to fit in a u64"),
+            });
+        }
+        Ok(abs[0])
+    }
+}
+
 impl<T> TryFrom<MontScalar<T>> for i128
 where
     T: MontConfig<4>,
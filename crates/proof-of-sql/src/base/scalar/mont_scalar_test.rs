@@ -244,6 +244,28 @@ fn test_curve25519_scalar_to_i64_overflow() {
     );
 }
 
+#[test]
+fn test_curve25519_scalar_to_u64() {
+    assert_eq!(u64::try_from(Curve25519Scalar::from(0)).unwrap(), 0);
+    assert_eq!(u64::try_from(Curve25519Scalar::ONE).unwrap(), 1);
+    assert_eq!(
+        u64::try_from(Curve25519Scalar::from(u64::MAX)).unwrap(),
+        u64::MAX
+    );
+}
+
+#[test]
+fn test_curve25519_scalar_to_u64_overflow() {
+    assert!(matches!(
+        u64::try_from(Curve25519Scalar::from(u128::from(u64::MAX) + 1)),
+        Err(ScalarConversionError::Overflow { .. })
+    ));
+    assert!(matches!(
+        u64::try_from(Curve25519Scalar::from(-1)),
+        Err(ScalarConversionError::Overflow { .. })
+    ));
+}
+
 #[test]
 fn test_curve25519_scalar_to_i128() {
     assert_eq!(i128::try_from(Curve25519Scalar::from(0)).unwrap(), 0);
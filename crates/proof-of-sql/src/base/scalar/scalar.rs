@@ -31,12 +31,14 @@ pub trait Scalar:
     + for<'a> core::convert::From<&'a i64> // Required for `Column` to implement `MultilinearExtension`
     + for<'a> core::convert::From<&'a i128> // Required for `Column` to implement `MultilinearExtension`
     + for<'a> core::convert::From<&'a u8> // Required for `Column` to implement `MultilinearExtension`
+    + for<'a> core::convert::From<&'a u64> // Required for `Column` to implement `MultilinearExtension`
     + core::convert::TryInto <bool>
     + core::convert::TryInto <i8>
     + core::convert::TryInto <i16>
     + core::convert::TryInto <i32>
     + core::convert::TryInto <i64>
     + core::convert::TryInto <i128>
+    + core::convert::TryInto <u64>
     + core::convert::Into<[u64; 4]>
     + core::convert::From<[u64; 4]>
     + core::cmp::Ord
@@ -52,6 +54,7 @@ pub trait Scalar:
     + VarInt
     + core::convert::From<String>
     + core::convert::From<i128>
+    + core::convert::From<u64>
     + core::convert::From<i64>
     + core::convert::From<i32>
     + core::convert::From<i16>
@@ -11,6 +11,10 @@ use alloc::vec::Vec;
 /// The serialization is done in a manner that is compatible with efficient proof verification
 /// on the EVM.
 ///
+/// Columns are serialized as their index in [`ProofPlan::get_column_references`], i.e. in the
+/// order of table and then column name, and tables as their index in
+/// [`ProofPlan::get_table_references`]. A verifier must number the columns the same way.
+///
 /// # Arguments
 ///
 /// * `query_expr` - A reference to the `QueryExpr` to be serialized.
@@ -146,9 +150,9 @@ mod tests {
         let bytes = serialize_query_expr::<TestScalar>(&query_expr).unwrap();
         let expected_bytes = iter::empty::<u8>()
             .chain([FILTER_EXEC_NUM, 0, 1]) // filter expr, table number, result count
-            .chain([COLUMN_EXPR_NUM, 0]) // column expr, column b (#0)
+            .chain([COLUMN_EXPR_NUM, 1]) // column expr, column b (#1)
             .chain([EQUALS_EXPR_NUM]) // equals expr
-            .chain([COLUMN_EXPR_NUM, 1]) // column expr, column a (#1)
+            .chain([COLUMN_EXPR_NUM, 0]) // column expr, column a (#0)
             .chain([LITERAL_EXPR_NUM, BIGINT_TYPE_NUM]) // literal expr, literal type
             .chain([0; 31]) // leading 0s of literal value
             .chain([5]) // literal value
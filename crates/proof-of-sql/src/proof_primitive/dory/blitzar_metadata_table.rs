@@ -33,13 +33,14 @@ pub const fn min_as_f(column_type: ColumnType) -> F {
     match column_type {
         ColumnType::TinyInt => MontFp!("-128"),
         ColumnType::SmallInt => MontFp!("-32768"),
-        ColumnType::Int => MontFp!("-2147483648"),
+        ColumnType::Int | ColumnType::Date => MontFp!("-2147483648"),
         ColumnType::BigInt | ColumnType::TimestampTZ(_, _) => MontFp!("-9223372036854775808"),
         ColumnType::Int128 => MontFp!("-170141183460469231731687303715884105728"),
         ColumnType::Decimal75(_, _)
         | ColumnType::Scalar
         | ColumnType::VarChar
-        | ColumnType::Boolean => MontFp!("0"),
+        | ColumnType::Boolean
+        | ColumnType::UInt64 => MontFp!("0"),
     }
 }
 
@@ -116,7 +117,7 @@ fn copy_column_data_to_slice(
         CommittableColumn::SmallInt(column) => {
             scalar_row_slice[start..end].copy_from_slice(&column[index].offset_to_bytes());
         }
-        CommittableColumn::Int(column) => {
+        CommittableColumn::Int(column) | CommittableColumn::Date(column) => {
             scalar_row_slice[start..end].copy_from_slice(&column[index].offset_to_bytes());
         }
         CommittableColumn::BigInt(column) | CommittableColumn::TimestampTZ(_, _, column) => {
@@ -130,6 +131,9 @@ fn copy_column_data_to_slice(
         | CommittableColumn::VarChar(column) => {
             scalar_row_slice[start..end].copy_from_slice(&column[index].offset_to_bytes());
         }
+        CommittableColumn::UInt64(column) => {
+            scalar_row_slice[start..end].copy_from_slice(&column[index].offset_to_bytes());
+        }
         CommittableColumn::RangeCheckWord(_) => todo!(),
     }
 }
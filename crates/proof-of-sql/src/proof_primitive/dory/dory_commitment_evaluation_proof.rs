@@ -1,12 +1,15 @@
 use super::{
     build_vmv_prover_state, build_vmv_verifier_state, compute_T_vec_prime, compute_nu,
-    eval_vmv_re_prove, eval_vmv_re_verify, extended_dory_inner_product_prove,
+    eval_vmv_re_prove, eval_vmv_re_verify, extended_dory_inner_product_prove_with_cancellation,
     extended_dory_inner_product_verify,
     extended_dory_reduce_helper::extended_dory_reduce_verify_fold_s_vecs, DeferredGT,
     DoryCommitment, DoryMessages, DoryProverPublicSetup, DoryScalar, DoryVerifierPublicSetup, F,
 };
 use crate::{
-    base::{commitment::CommitmentEvaluationProof, proof::Transcript},
+    base::{
+        commitment::{CommitmentEvaluationProof, CommitmentSchemeId},
+        proof::Transcript,
+    },
     utils::log,
 };
 use snafu::Snafu;
@@ -34,6 +37,7 @@ impl CommitmentEvaluationProof for DoryEvaluationProof {
     type Error = DoryError;
     type ProverPublicSetup<'a> = DoryProverPublicSetup<'a>;
     type VerifierPublicSetup<'a> = DoryVerifierPublicSetup<'a>;
+    const SCHEME_ID: CommitmentSchemeId = CommitmentSchemeId::Dory;
 
     #[tracing::instrument(name = "DoryEvaluationProof::new", level = "debug", skip_all)]
     fn new(
@@ -43,31 +47,64 @@ impl CommitmentEvaluationProof for DoryEvaluationProof {
         generators_offset: u64,
         setup: &Self::ProverPublicSetup<'_>,
     ) -> Self {
+        Self::new_with_cancellation(transcript, a, b_point, generators_offset, setup, &|| true)
+            .unwrap_or_default()
+    }
+
+    #[tracing::instrument(
+        name = "DoryEvaluationProof::new_with_cancellation",
+        level = "debug",
+        skip_all
+    )]
+    fn new_with_cancellation(
+        transcript: &mut impl Transcript,
+        a: &[Self::Scalar],
+        b_point: &[Self::Scalar],
+        generators_offset: u64,
+        setup: &Self::ProverPublicSetup<'_>,
+        should_continue: &dyn Fn() -> bool,
+    ) -> Option<Self> {
         log::log_memory_usage("Start");
 
         // Dory PCS Logic
         if generators_offset != 0 {
             // TODO: support offsets other than 0.
             // Note: this will always result in a verification error.
-            return DoryMessages::default();
+            return Some(DoryMessages::default());
         }
         let a: &[F] = bytemuck::TransparentWrapper::peel_slice(a);
         let b_point: &[F] = bytemuck::TransparentWrapper::peel_slice(b_point);
         let prover_setup = setup.prover_setup();
         let nu = compute_nu(b_point.len(), setup.sigma());
         if nu > prover_setup.max_nu {
-            return DoryMessages::default(); // Note: this will always result in a verification error.
+            return Some(DoryMessages::default()); // Note: this will always result in a verification error.
         }
         let T_vec_prime = compute_T_vec_prime(a, setup.sigma(), nu, prover_setup);
         let state = build_vmv_prover_state(a, b_point, T_vec_prime, setup.sigma(), nu);
 
         let mut messages = DoryMessages::default();
         let extended_state = eval_vmv_re_prove(&mut messages, transcript, state, prover_setup);
-        extended_dory_inner_product_prove(&mut messages, transcript, extended_state, prover_setup);
+        if !extended_dory_inner_product_prove_with_cancellation(
+            &mut messages,
+            transcript,
+            extended_state,
+            prover_setup,
+            should_continue,
+        ) {
+            return None;
+        }
 
         log::log_memory_usage("End");
 
-        messages
+        Some(messages)
+    }
+
+    fn serialized_proof_size(
+        _a_length: usize,
+        b_point_length: usize,
+        setup: &Self::ProverPublicSetup<'_>,
+    ) -> usize {
+        DoryMessages::serialized_size(compute_nu(b_point_length, setup.sigma()))
     }
 
     #[tracing::instrument(
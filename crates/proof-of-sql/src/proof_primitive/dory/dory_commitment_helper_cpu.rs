@@ -1,7 +1,6 @@
-use super::{pairings, DoryCommitment, DoryProverPublicSetup, DoryScalar, G1Projective};
+use super::{pairings, row_commitment::RowCommitment, DoryCommitment, DoryProverPublicSetup};
 use crate::{base::commitment::CommittableColumn, utils::log};
 use alloc::vec::Vec;
-use ark_ec::VariableBaseMSM;
 use core::iter::once;
 
 #[tracing::instrument(name = "compute_dory_commitment_impl (cpu)", level = "debug", skip_all)]
@@ -12,15 +11,11 @@ use core::iter::once;
 /// - `Gamma_1.last()` returns `None` when computing remaining row commitments.
 /// - `Gamma_2.last()` returns `None` when computing the commitment for the entire matrix.
 /// - The slices accessed in `Gamma_1.last().unwrap()` or `Gamma_2.last().unwrap()` are out of bounds.
-fn compute_dory_commitment_impl<'a, T>(
-    column: &'a [T],
+fn compute_dory_commitment_impl<T: RowCommitment>(
+    column: &[T],
     offset: usize,
     setup: &DoryProverPublicSetup,
-) -> DoryCommitment
-where
-    &'a T: Into<DoryScalar>,
-    T: Sync,
-{
+) -> DoryCommitment {
     log::log_memory_usage("Start");
 
     // Compute offsets for the matrix.
@@ -36,14 +31,14 @@ where
     let remaining_rows = remaining_elements.chunks(num_columns);
 
     // Compute commitments for the rows.
-    let first_row_commit = G1Projective::msm_unchecked(
+    let first_row_commit = T::row_commitment(
         &setup.prover_setup().Gamma_1.last().unwrap()[first_row_offset..num_columns],
-        &Vec::from_iter(first_row.iter().map(|s| s.into().0)),
+        first_row,
     );
     let remaining_row_commits = remaining_rows.map(|row| {
-        G1Projective::msm_unchecked(
+        T::row_commitment(
             &setup.prover_setup().Gamma_1.last().unwrap()[..num_columns],
-            &Vec::from_iter(row.iter().map(|s| s.into().0)),
+            row,
         )
     });
 
@@ -68,7 +63,9 @@ fn compute_dory_commitment(
         CommittableColumn::Scalar(column) => compute_dory_commitment_impl(column, offset, setup),
         CommittableColumn::TinyInt(column) => compute_dory_commitment_impl(column, offset, setup),
         CommittableColumn::SmallInt(column) => compute_dory_commitment_impl(column, offset, setup),
-        CommittableColumn::Int(column) => compute_dory_commitment_impl(column, offset, setup),
+        CommittableColumn::Int(column) | CommittableColumn::Date(column) => {
+            compute_dory_commitment_impl(column, offset, setup)
+        }
         CommittableColumn::BigInt(column) => compute_dory_commitment_impl(column, offset, setup),
         CommittableColumn::Int128(column) => compute_dory_commitment_impl(column, offset, setup),
         CommittableColumn::Decimal75(_, _, column) => {
@@ -82,6 +79,7 @@ fn compute_dory_commitment(
         CommittableColumn::RangeCheckWord(column) => {
             compute_dory_commitment_impl(column, offset, setup)
         }
+        CommittableColumn::UInt64(column) => compute_dory_commitment_impl(column, offset, setup),
     }
 }
 
@@ -721,3 +721,15 @@ fn we_can_compute_a_dory_commitment_with_mixed_committable_columns_with_an_offse
         + Pairing::pairing(Gamma_1[3], Gamma_2[1]) * F::from(18);
     assert_eq!(res[9].0, expected);
 }
+
+#[test]
+fn we_can_compute_a_dory_commitment_with_a_large_boolean_column() {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 5);
+    let values: Vec<bool> = (0..1000).map(|i| i % 3 == 0 || i % 7 == 0).collect();
+    let as_integers: Vec<i64> = values.iter().map(|&b| i64::from(b)).collect();
+    let res = compute_dory_commitments(&[CommittableColumn::Boolean(&values)], 5, &setup);
+    let expected = compute_dory_commitments(&[CommittableColumn::BigInt(&as_integers)], 5, &setup);
+    assert_eq!(res, expected);
+}
@@ -1,6 +1,6 @@
 use super::{DoryScalar, G1Affine, G2Affine, F, GT};
 use crate::base::{impl_serde_for_ark_serde_checked, proof::Transcript};
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 use ark_ff::Field;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use num_traits::Zero;
@@ -27,6 +27,20 @@ impl_serde_for_ark_serde_checked!(DoryMessages);
 
 #[cfg_attr(not(test), allow(dead_code))]
 impl DoryMessages {
+    /// The serialized size in bytes of the messages of a proof with `nu` rounds.
+    ///
+    /// `eval_vmv_re` sends 2 GT and 1 G1 messages, each of the `nu` rounds of `extended_dory_reduce`
+    /// sends 6 GT, 3 G1 and 3 G2 messages, and `scalar_product` sends 1 G1 and 1 G2 message.
+    /// Every message of a group has the same serialized size.
+    pub(super) fn serialized_size(nu: usize) -> usize {
+        let messages = Self {
+            F_messages: Vec::new(),
+            G1_messages: vec![G1Affine::default(); 3 * nu + 2],
+            G2_messages: vec![G2Affine::default(); 3 * nu + 1],
+            GT_messages: vec![GT::default(); 6 * nu + 2],
+        };
+        postcard::to_allocvec(&messages).map_or(0, |bytes| bytes.len())
+    }
     /// Pushes a field element from the prover onto the queue, and appends it to the transcript.
     pub(super) fn prover_send_F_message(&mut self, transcript: &mut impl Transcript, message: F) {
         transcript.extend_canonical_serialize_as_le(&message);
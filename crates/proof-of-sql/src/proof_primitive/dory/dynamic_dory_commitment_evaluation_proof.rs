@@ -1,12 +1,15 @@
 use super::{
     dynamic_build_vmv_state::{build_dynamic_vmv_prover_state, build_dynamic_vmv_verifier_state},
     dynamic_dory_helper::{compute_dynamic_T_vec_prime, compute_dynamic_nu, fold_dynamic_tensors},
-    eval_vmv_re_prove, eval_vmv_re_verify, extended_dory_inner_product_prove,
+    eval_vmv_re_prove, eval_vmv_re_verify, extended_dory_inner_product_prove_with_cancellation,
     extended_dory_inner_product_verify, DeferredGT, DoryMessages, DoryScalar,
     DynamicDoryCommitment, ProverSetup, VerifierSetup, F,
 };
 use crate::{
-    base::{commitment::CommitmentEvaluationProof, proof::Transcript},
+    base::{
+        commitment::{CommitmentEvaluationProof, CommitmentSchemeId},
+        proof::Transcript,
+    },
     utils::log,
 };
 use serde::{Deserialize, Serialize};
@@ -36,6 +39,7 @@ impl CommitmentEvaluationProof for DynamicDoryEvaluationProof {
     type Error = DoryError;
     type ProverPublicSetup<'a> = &'a ProverSetup<'a>;
     type VerifierPublicSetup<'a> = &'a VerifierSetup;
+    const SCHEME_ID: CommitmentSchemeId = CommitmentSchemeId::DynamicDory;
 
     #[tracing::instrument(name = "DoryEvaluationProof::new", level = "debug", skip_all)]
     fn new(
@@ -45,30 +49,64 @@ impl CommitmentEvaluationProof for DynamicDoryEvaluationProof {
         generators_offset: u64,
         setup: &Self::ProverPublicSetup<'_>,
     ) -> Self {
+        Self::new_with_cancellation(transcript, a, b_point, generators_offset, setup, &|| true)
+            .unwrap_or_default()
+    }
+
+    #[tracing::instrument(
+        name = "DynamicDoryEvaluationProof::new_with_cancellation",
+        level = "debug",
+        skip_all
+    )]
+    fn new_with_cancellation(
+        transcript: &mut impl Transcript,
+        a: &[Self::Scalar],
+        b_point: &[Self::Scalar],
+        generators_offset: u64,
+        setup: &Self::ProverPublicSetup<'_>,
+        should_continue: &dyn Fn() -> bool,
+    ) -> Option<Self> {
         log::log_memory_usage("Start");
 
         // Dory PCS Logic
         if generators_offset != 0 {
             // TODO: support offsets other than 0.
             // Note: this will always result in a verification error.
-            return DynamicDoryEvaluationProof::default();
+            return Some(DynamicDoryEvaluationProof::default());
         }
         let a: &[F] = bytemuck::TransparentWrapper::peel_slice(a);
         let b_point: &[F] = bytemuck::TransparentWrapper::peel_slice(b_point);
         let nu = compute_dynamic_nu(b_point.len());
         if nu > setup.max_nu {
-            return DynamicDoryEvaluationProof::default(); // Note: this will always result in a verification error.
+            return Some(DynamicDoryEvaluationProof::default()); // Note: this will always result in a verification error.
         }
         let T_vec_prime = compute_dynamic_T_vec_prime(a, nu, setup);
         let state = build_dynamic_vmv_prover_state(a, b_point, T_vec_prime, nu);
 
         let mut messages = DoryMessages::default();
         let extended_state = eval_vmv_re_prove(&mut messages, transcript, state, setup);
-        extended_dory_inner_product_prove(&mut messages, transcript, extended_state, setup);
+        if !extended_dory_inner_product_prove_with_cancellation(
+            &mut messages,
+            transcript,
+            extended_state,
+            setup,
+            should_continue,
+        ) {
+            return None;
+        }
 
         log::log_memory_usage("End");
 
-        Self(messages)
+        Some(Self(messages))
+    }
+
+    fn serialized_proof_size(
+        _a_length: usize,
+        b_point_length: usize,
+        _setup: &Self::ProverPublicSetup<'_>,
+    ) -> usize {
+        // The newtype wrapper serializes as the messages themselves.
+        DoryMessages::serialized_size(compute_dynamic_nu(b_point_length))
     }
 
     #[tracing::instrument(
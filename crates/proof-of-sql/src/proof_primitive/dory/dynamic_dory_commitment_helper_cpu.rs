@@ -1,13 +1,11 @@
-use super::{pairings, DoryScalar, DynamicDoryCommitment, G1Projective, ProverSetup, GT};
+use super::{pairings, row_commitment::RowCommitment, DynamicDoryCommitment, ProverSetup, GT};
 use crate::{
-    base::{commitment::CommittableColumn, if_rayon, slice_ops::slice_cast},
+    base::{commitment::CommittableColumn, if_rayon},
     proof_primitive::dynamic_matrix_utils::matrix_structure::{
         full_width_of_row, row_and_column_from_index, row_start_index,
     },
 };
 use alloc::vec::Vec;
-use ark_ec::VariableBaseMSM;
-use bytemuck::TransparentWrapper;
 use num_traits::Zero;
 #[cfg(feature = "rayon")]
 use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
@@ -20,15 +18,11 @@ use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterato
 /// - `setup.Gamma_2.last()` returns `None`, indicating that `Gamma_2` is empty.
 /// - The indexing for `Gamma_2` with `first_row..=last_row` goes out of bounds.
 #[allow(clippy::range_plus_one)]
-fn compute_dory_commitment_impl<'a, T>(
-    column: &'a [T],
+fn compute_dory_commitment_impl<T: RowCommitment>(
+    column: &[T],
     offset: usize,
     setup: &ProverSetup,
-) -> DynamicDoryCommitment
-where
-    &'a T: Into<DoryScalar>,
-    T: Sync,
-{
+) -> DynamicDoryCommitment {
     if column.is_empty() {
         return DynamicDoryCommitment::default();
     }
@@ -55,10 +49,7 @@ where
         } else {
             (0..width, row_start - offset..width + row_start - offset)
         };
-        G1Projective::msm_unchecked(
-            &Gamma_1[gamma_range],
-            TransparentWrapper::peel_slice(&slice_cast::<_, DoryScalar>(&column[column_range])),
-        )
+        T::row_commitment(&Gamma_1[gamma_range], &column[column_range])
     })
     .collect();
 
@@ -77,7 +68,9 @@ fn compute_dory_commitment(
         CommittableColumn::Scalar(column) => compute_dory_commitment_impl(column, offset, setup),
         CommittableColumn::TinyInt(column) => compute_dory_commitment_impl(column, offset, setup),
         CommittableColumn::SmallInt(column) => compute_dory_commitment_impl(column, offset, setup),
-        CommittableColumn::Int(column) => compute_dory_commitment_impl(column, offset, setup),
+        CommittableColumn::Int(column) | CommittableColumn::Date(column) => {
+            compute_dory_commitment_impl(column, offset, setup)
+        }
         CommittableColumn::BigInt(column) => compute_dory_commitment_impl(column, offset, setup),
         CommittableColumn::Int128(column) => compute_dory_commitment_impl(column, offset, setup),
         CommittableColumn::VarChar(column) | CommittableColumn::Decimal75(_, _, column) => {
@@ -90,6 +83,7 @@ fn compute_dory_commitment(
         CommittableColumn::RangeCheckWord(column) => {
             compute_dory_commitment_impl(column, offset, setup)
         }
+        CommittableColumn::UInt64(column) => compute_dory_commitment_impl(column, offset, setup),
     }
 }
 
@@ -550,3 +550,15 @@ fn we_can_compute_a_dynamic_dory_commitment_with_mixed_committable_columns_with_
         + Pairing::pairing(Gamma_1[3], Gamma_2[3]) * F::from(18);
     assert_eq!(res[9].0, expected);
 }
+
+#[test]
+fn we_can_compute_a_dynamic_dory_commitment_with_a_large_boolean_column() {
+    let public_parameters = PublicParameters::test_rand(6, &mut test_rng());
+    let setup = ProverSetup::from(&public_parameters);
+    let values: Vec<bool> = (0..1000).map(|i| i % 3 == 0 || i % 7 == 0).collect();
+    let as_integers: Vec<i64> = values.iter().map(|&b| i64::from(b)).collect();
+    let res = compute_dynamic_dory_commitments(&[CommittableColumn::Boolean(&values)], 5, &setup);
+    let expected =
+        compute_dynamic_dory_commitments(&[CommittableColumn::BigInt(&as_integers)], 5, &setup);
+    assert_eq!(res, expected);
+}
@@ -13,24 +13,31 @@ use crate::{
 
 /// This is the prover side of the extended Dory-Innerproduct algorithm in section 4.3 of https://eprint.iacr.org/2020/1274.pdf.
 /// This function builds/enqueues `messages`, appends to `transcript`, and consumes `state`.
+/// `should_continue` is checked before each reduction. Returns `false`, leaving `messages` incomplete, if it returns `false`.
 #[tracing::instrument(level = "debug", skip_all)]
-pub fn extended_dory_inner_product_prove(
+pub fn extended_dory_inner_product_prove_with_cancellation(
     messages: &mut DoryMessages,
     transcript: &mut impl Transcript,
     mut state: ExtendedProverState,
     setup: &ProverSetup,
-) {
+    should_continue: &dyn Fn() -> bool,
+) -> bool {
     log::log_memory_usage("Start");
 
     let nu = state.base_state.nu;
     assert!(setup.max_nu >= nu);
     for _ in 0..nu {
+        if !should_continue() {
+            return false;
+        }
         extended_dory_reduce_prove(messages, transcript, &mut state, setup);
     }
     let base_state = fold_scalars_0_prove(messages, transcript, state, setup);
     scalar_product_prove(messages, transcript, &base_state);
 
     log::log_memory_usage("End");
+
+    true
 }
 
 /// This is the verifier side of the extended Dory-Innerproduct algorithm in section 4.3 of https://eprint.iacr.org/2020/1274.pdf.
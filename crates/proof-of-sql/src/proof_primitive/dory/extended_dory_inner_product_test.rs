@@ -1,5 +1,5 @@
 use super::{
-    extended_dory_inner_product_prove, extended_dory_inner_product_verify,
+    extended_dory_inner_product_prove_with_cancellation, extended_dory_inner_product_verify,
     extended_dory_reduce_helper::extended_dory_reduce_verify_fold_s_vecs, rand_F_tensors,
     rand_G_vecs, test_rng, DoryMessages, ExtendedProverState, G1Affine, PublicParameters, GT,
 };
@@ -20,7 +20,13 @@ fn we_can_prove_and_verify_an_extended_dory_inner_product() {
 
     let mut transcript = Transcript::new(b"extended_dory_inner_product_test");
     let mut messages = DoryMessages::default();
-    extended_dory_inner_product_prove(&mut messages, &mut transcript, prover_state, &prover_setup);
+    assert!(extended_dory_inner_product_prove_with_cancellation(
+        &mut messages,
+        &mut transcript,
+        prover_state,
+        &prover_setup,
+        &|| true
+    ));
 
     let mut transcript = Transcript::new(b"extended_dory_inner_product_test");
     assert!(extended_dory_inner_product_verify(
@@ -48,12 +54,13 @@ fn we_can_prove_and_verify_an_extended_dory_inner_product_for_multiple_nu_values
 
         let mut transcript = Transcript::new(b"extended_dory_inner_product_test");
         let mut messages = DoryMessages::default();
-        extended_dory_inner_product_prove(
+        assert!(extended_dory_inner_product_prove_with_cancellation(
             &mut messages,
             &mut transcript,
             prover_state,
             &prover_setup,
-        );
+            &|| true
+        ));
 
         let mut transcript = Transcript::new(b"extended_dory_inner_product_test");
         assert!(extended_dory_inner_product_verify(
@@ -80,7 +87,13 @@ fn we_fail_to_verify_an_extended_dory_inner_product_when_a_message_is_modified()
 
     let mut transcript = Transcript::new(b"extended_dory_inner_product_test");
     let mut messages = DoryMessages::default();
-    extended_dory_inner_product_prove(&mut messages, &mut transcript, prover_state, &prover_setup);
+    assert!(extended_dory_inner_product_prove_with_cancellation(
+        &mut messages,
+        &mut transcript,
+        prover_state,
+        &prover_setup,
+        &|| true
+    ));
 
     messages.GT_messages[0] = GT::rand(&mut rng);
 
@@ -108,7 +121,13 @@ fn we_fail_to_verify_an_extended_dory_inner_product_when_there_are_too_few_GT_me
 
     let mut transcript = Transcript::new(b"extended_dory_inner_product_test");
     let mut messages = DoryMessages::default();
-    extended_dory_inner_product_prove(&mut messages, &mut transcript, prover_state, &prover_setup);
+    assert!(extended_dory_inner_product_prove_with_cancellation(
+        &mut messages,
+        &mut transcript,
+        prover_state,
+        &prover_setup,
+        &|| true
+    ));
 
     messages.GT_messages.pop();
 
@@ -136,7 +155,13 @@ fn we_fail_to_verify_an_extended_dory_inner_product_when_there_are_too_many_GT_m
 
     let mut transcript = Transcript::new(b"extended_dory_inner_product_test");
     let mut messages = DoryMessages::default();
-    extended_dory_inner_product_prove(&mut messages, &mut transcript, prover_state, &prover_setup);
+    assert!(extended_dory_inner_product_prove_with_cancellation(
+        &mut messages,
+        &mut transcript,
+        prover_state,
+        &prover_setup,
+        &|| true
+    ));
 
     messages.GT_messages.push(GT::rand(&mut rng));
 
@@ -164,7 +189,13 @@ fn we_fail_to_verify_an_extended_dory_inner_product_when_there_are_too_few_G1_me
 
     let mut transcript = Transcript::new(b"extended_dory_inner_product_test");
     let mut messages = DoryMessages::default();
-    extended_dory_inner_product_prove(&mut messages, &mut transcript, prover_state, &prover_setup);
+    assert!(extended_dory_inner_product_prove_with_cancellation(
+        &mut messages,
+        &mut transcript,
+        prover_state,
+        &prover_setup,
+        &|| true
+    ));
 
     messages.G1_messages.pop();
 
@@ -192,7 +223,13 @@ fn we_fail_to_verify_an_extended_dory_inner_product_when_there_are_too_many_G1_m
 
     let mut transcript = Transcript::new(b"extended_dory_inner_product_test");
     let mut messages = DoryMessages::default();
-    extended_dory_inner_product_prove(&mut messages, &mut transcript, prover_state, &prover_setup);
+    assert!(extended_dory_inner_product_prove_with_cancellation(
+        &mut messages,
+        &mut transcript,
+        prover_state,
+        &prover_setup,
+        &|| true
+    ));
 
     messages.G1_messages.push(G1Affine::rand(&mut rng));
 
@@ -220,7 +257,13 @@ fn we_fail_to_verify_an_extended_dory_inner_product_when_the_transcripts_differ(
 
     let mut transcript = Transcript::new(b"extended_dory_inner_product_test_wrong");
     let mut messages = DoryMessages::default();
-    extended_dory_inner_product_prove(&mut messages, &mut transcript, prover_state, &prover_setup);
+    assert!(extended_dory_inner_product_prove_with_cancellation(
+        &mut messages,
+        &mut transcript,
+        prover_state,
+        &prover_setup,
+        &|| true
+    ));
 
     let mut transcript = Transcript::new(b"extended_dory_inner_product_test");
     assert!(!extended_dory_inner_product_verify(
@@ -247,7 +290,13 @@ fn we_fail_to_verify_an_extended_dory_inner_product_when_the_setups_differ() {
 
     let mut transcript = Transcript::new(b"extended_dory_inner_product_test");
     let mut messages = DoryMessages::default();
-    extended_dory_inner_product_prove(&mut messages, &mut transcript, prover_state, &prover_setup);
+    assert!(extended_dory_inner_product_prove_with_cancellation(
+        &mut messages,
+        &mut transcript,
+        prover_state,
+        &prover_setup,
+        &|| true
+    ));
 
     messages.GT_messages[0] = GT::rand(&mut rng);
 
@@ -277,7 +326,13 @@ fn we_fail_to_verify_an_extended_dory_inner_product_when_the_base_commitment_is_
 
     let mut transcript = Transcript::new(b"extended_dory_inner_product_test");
     let mut messages = DoryMessages::default();
-    extended_dory_inner_product_prove(&mut messages, &mut transcript, prover_state, &prover_setup);
+    assert!(extended_dory_inner_product_prove_with_cancellation(
+        &mut messages,
+        &mut transcript,
+        prover_state,
+        &prover_setup,
+        &|| true
+    ));
 
     let mut transcript = Transcript::new(b"extended_dory_inner_product_test");
     assert!(!extended_dory_inner_product_verify(
@@ -305,7 +360,13 @@ fn we_fail_to_verify_an_extended_dory_inner_product_when_a_scalar_commitment_is_
 
     let mut transcript = Transcript::new(b"extended_dory_inner_product_test");
     let mut messages = DoryMessages::default();
-    extended_dory_inner_product_prove(&mut messages, &mut transcript, prover_state, &prover_setup);
+    assert!(extended_dory_inner_product_prove_with_cancellation(
+        &mut messages,
+        &mut transcript,
+        prover_state,
+        &prover_setup,
+        &|| true
+    ));
 
     let mut transcript = Transcript::new(b"extended_dory_inner_product_test");
     assert!(!extended_dory_inner_product_verify(
@@ -79,7 +79,7 @@ mod fold_scalars_test;
 
 mod extended_dory_inner_product;
 pub(crate) use extended_dory_inner_product::{
-    extended_dory_inner_product_prove, extended_dory_inner_product_verify,
+    extended_dory_inner_product_prove_with_cancellation, extended_dory_inner_product_verify,
 };
 
 #[cfg(test)]
@@ -112,6 +112,8 @@ mod dory_commitment_test;
 #[cfg(not(feature = "blitzar"))]
 mod dory_commitment_helper_cpu;
 #[cfg(not(feature = "blitzar"))]
+mod row_commitment;
+#[cfg(not(feature = "blitzar"))]
 use dory_commitment_helper_cpu::compute_dory_commitments;
 #[cfg(feature = "blitzar")]
 mod dory_commitment_helper_gpu;
@@ -0,0 +1,62 @@
+use super::{DoryScalar, G1Affine, G1Projective};
+use alloc::vec::Vec;
+use ark_ec::VariableBaseMSM;
+
+/// Values that can be committed to by the CPU Dory commitment helpers, one row of the matrix at
+/// a time.
+pub(super) trait RowCommitment: Sized + Sync {
+    /// Computes `sum_i row[i] * bases[i]`, where `bases` is at least as long as `row`.
+    fn row_commitment(bases: &[G1Affine], row: &[Self]) -> G1Projective;
+}
+
+macro_rules! impl_row_commitment_with_msm {
+    ($($t:ty),*) => {
+        $(
+            impl RowCommitment for $t {
+                fn row_commitment(bases: &[G1Affine], row: &[Self]) -> G1Projective {
+                    G1Projective::msm_unchecked(
+                        bases,
+                        &Vec::from_iter(row.iter().map(|s| Into::<DoryScalar>::into(s).0)),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_row_commitment_with_msm!(u8, i8, i16, i32, i64, i128, u64, [u64; 4]);
+
+/// A boolean is either zero or one, so the commitment is the sum of the bases of the `true`
+/// values and no scalar multiplications are needed.
+impl RowCommitment for bool {
+    fn row_commitment(bases: &[G1Affine], row: &[Self]) -> G1Projective {
+        bases
+            .iter()
+            .zip(row)
+            .filter(|(_, &value)| value)
+            .map(|(base, _)| base)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_std::{test_rng, UniformRand};
+
+    #[test]
+    fn boolean_row_commitments_match_the_commitments_of_zeros_and_ones() {
+        let mut rng = test_rng();
+        let bases: Vec<G1Affine> = (0..100).map(|_| G1Affine::rand(&mut rng)).collect();
+        let row: Vec<bool> = (0..100).map(|i| i % 3 == 0 || i % 7 == 0).collect();
+        let as_integers: Vec<i64> = row.iter().map(|&b| i64::from(b)).collect();
+        assert_eq!(
+            bool::row_commitment(&bases, &row),
+            i64::row_commitment(&bases, &as_integers)
+        );
+        assert_eq!(
+            bool::row_commitment(&bases, &[false; 100]),
+            G1Projective::default()
+        );
+    }
+}
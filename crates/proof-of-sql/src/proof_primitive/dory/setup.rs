@@ -20,6 +20,15 @@ use std::{
 /// Note:
 /// We use nu = m and k = m-i or m-j.
 /// This indexing is more convenient for coding because lengths of the arrays used are typically 2^k rather than 2^i or 2^j.
+///
+/// # Sharing
+///
+/// A `ProverSetup` is large but is never mutated after construction, and it is `Send + Sync`.
+/// A single setup can therefore serve many concurrent proofs: wrap it in an `Arc` (or simply borrow
+/// it) and pass `&*setup`, or a [`DoryProverPublicSetup`](super::DoryProverPublicSetup) built from
+/// it, to each proving call instead of cloning it. Because the setup borrows its generators from
+/// [`PublicParameters`], the parameters must outlive every thread using the setup, e.g. by using
+/// scoped threads or by keeping the parameters in a `'static`.
 pub struct ProverSetup<'a> {
     /// `Gamma_1[k]` = Γ_1,(m-k) in the Dory paper.
     pub(super) Gamma_1: Vec<&'a [G1Affine]>,
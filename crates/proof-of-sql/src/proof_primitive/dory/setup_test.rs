@@ -1,4 +1,7 @@
-use super::{test_rng, ProverSetup, PublicParameters, VerifierSetup};
+use super::{
+    test_rng, DoryProverPublicSetup, DoryVerifierPublicSetup, ProverSetup, PublicParameters,
+    VerifierSetup,
+};
 use ark_ec::pairing::Pairing;
 use std::{fs, path::Path};
 
@@ -159,3 +162,12 @@ fn we_can_serialize_and_deserialize_verifier_setups() {
         assert_eq!(setup, deserialized);
     }
 }
+
+#[test]
+fn we_can_share_setups_across_threads() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<ProverSetup<'static>>();
+    assert_send_sync::<VerifierSetup>();
+    assert_send_sync::<DoryProverPublicSetup<'static>>();
+    assert_send_sync::<DoryVerifierPublicSetup<'static>>();
+}
@@ -0,0 +1,87 @@
+use crate::base::{
+    commitment::{CommitmentEvaluationProof, VecCommitmentExt},
+    polynomial::{compute_evaluation_vector, MultilinearExtension},
+    proof::{Keccak256Transcript, Transcript},
+    scalar::Scalar,
+};
+use alloc::{vec, vec::Vec};
+
+/// The label every evaluation proof transcript starts with
+const TRANSCRIPT_LABEL: &[u8] = b"proof_primitive::evaluation_proof";
+
+/// Create the transcript shared by the prover and the verifier
+fn new_transcript() -> Keccak256Transcript {
+    let mut transcript = Keccak256Transcript::new();
+    transcript.extend_as_le_from_refs([TRANSCRIPT_LABEL]);
+    transcript
+}
+
+/// Commit to `a`, whose first entry is at row `generators_offset`
+///
+/// Note: the Dory schemes only support a `generators_offset` of 0.
+///
+/// # Panics
+/// Panics if the commitment scheme does not return a commitment to `a`.
+pub fn commit<CP: CommitmentEvaluationProof>(
+    a: &[CP::Scalar],
+    generators_offset: usize,
+    setup: &CP::ProverPublicSetup<'_>,
+) -> CP::Commitment {
+    Vec::<CP::Commitment>::from_columns_with_offset([a], generators_offset, setup)
+        .pop()
+        .expect("There is one commitment for every column")
+}
+
+/// Evaluate the multilinear extension of `a` at `point`
+///
+/// Note: `2^point.len()` must be at least the length of `a`.
+pub fn evaluate<S: Scalar>(a: &[S], point: &[S]) -> S {
+    let mut evaluation_vector = vec![S::ZERO; a.len()];
+    compute_evaluation_vector(&mut evaluation_vector, point);
+    a.inner_product(&evaluation_vector)
+}
+
+/// Prove the evaluation of the multilinear extension of `a` at `point`
+///
+/// Returns the proof together with the evaluation. `a` must not be empty, and `2^point.len()`
+/// must be at least the length of `a`.
+pub fn prove<CP: CommitmentEvaluationProof>(
+    a: &[CP::Scalar],
+    point: &[CP::Scalar],
+    generators_offset: usize,
+    setup: &CP::ProverPublicSetup<'_>,
+) -> (CP, CP::Scalar) {
+    let proof = CP::new(
+        &mut new_transcript(),
+        a,
+        point,
+        generators_offset as u64,
+        setup,
+    );
+    (proof, evaluate(a, point))
+}
+
+/// Verify that `evaluation` is the evaluation at `point` of the multilinear extension of the
+/// `length` entries committed to in `commitment`
+///
+/// # Errors
+/// Returns the error of the commitment scheme if the proof does not verify.
+pub fn verify<CP: CommitmentEvaluationProof>(
+    proof: &CP,
+    commitment: &CP::Commitment,
+    evaluation: &CP::Scalar,
+    point: &[CP::Scalar],
+    generators_offset: usize,
+    length: usize,
+    setup: &CP::VerifierPublicSetup<'_>,
+) -> Result<(), CP::Error> {
+    proof.verify_proof(
+        &mut new_transcript(),
+        commitment,
+        evaluation,
+        point,
+        generators_offset as u64,
+        length,
+        setup,
+    )
+}
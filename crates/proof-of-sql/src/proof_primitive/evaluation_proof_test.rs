@@ -0,0 +1,102 @@
+use super::evaluation_proof::{commit, evaluate, prove, verify};
+use crate::{
+    base::{commitment::CommitmentEvaluationProof, scalar::Scalar},
+    proof_primitive::dory::{
+        test_rng, DoryEvaluationProof, DoryProverPublicSetup, DoryVerifierPublicSetup,
+        DynamicDoryEvaluationProof, ProverSetup, PublicParameters, VerifierSetup,
+    },
+};
+use ark_std::UniformRand;
+
+/// Prove and verify the evaluation of random data at a random point, then check that a wrong
+/// evaluation does not verify
+fn we_can_round_trip_a_random_evaluation<CP: CommitmentEvaluationProof>(
+    length: usize,
+    generators_offset: usize,
+    prover_setup: &CP::ProverPublicSetup<'_>,
+    verifier_setup: &CP::VerifierPublicSetup<'_>,
+) {
+    let nu = length.next_power_of_two().trailing_zeros() as usize;
+    let mut rng = test_rng();
+    let a = core::iter::repeat_with(|| CP::Scalar::rand(&mut rng))
+        .take(length)
+        .collect::<Vec<_>>();
+    let point = core::iter::repeat_with(|| CP::Scalar::rand(&mut rng))
+        .take(nu)
+        .collect::<Vec<_>>();
+
+    let commitment = commit::<CP>(&a, generators_offset, prover_setup);
+    let (proof, evaluation) = prove::<CP>(&a, &point, generators_offset, prover_setup);
+    assert!(verify(
+        &proof,
+        &commitment,
+        &evaluation,
+        &point,
+        generators_offset,
+        length,
+        verifier_setup
+    )
+    .is_ok());
+    assert!(verify(
+        &proof,
+        &commitment,
+        &(evaluation + CP::Scalar::ONE),
+        &point,
+        generators_offset,
+        length,
+        verifier_setup
+    )
+    .is_err());
+}
+
+#[test]
+fn we_can_evaluate_a_multilinear_extension() {
+    let a = [1, 2, 3].map(<DoryEvaluationProof as CommitmentEvaluationProof>::Scalar::from);
+    let point = [10, 100].map(<DoryEvaluationProof as CommitmentEvaluationProof>::Scalar::from);
+    // (1 - 10) * (1 - 100) * 1 + 10 * (1 - 100) * 2 + (1 - 10) * 100 * 3
+    assert_eq!(evaluate(&a, &point), (891 - 1980 - 2700).into());
+    assert_eq!(evaluate(&a[..1], &[]), a[0]);
+}
+
+#[test]
+fn we_can_round_trip_dory_evaluation_proofs() {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    for length in [1, 10, 64] {
+        we_can_round_trip_a_random_evaluation::<DoryEvaluationProof>(
+            length,
+            0,
+            &DoryProverPublicSetup::new(&prover_setup, 3),
+            &DoryVerifierPublicSetup::new(&verifier_setup, 3),
+        );
+    }
+}
+
+#[test]
+fn we_can_round_trip_dynamic_dory_evaluation_proofs() {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    for length in [1, 10, 64] {
+        we_can_round_trip_a_random_evaluation::<DynamicDoryEvaluationProof>(
+            length,
+            0,
+            &&prover_setup,
+            &&verifier_setup,
+        );
+    }
+}
+
+#[cfg(feature = "blitzar")]
+#[test]
+fn we_can_round_trip_inner_product_evaluation_proofs() {
+    for length in [1, 10, 64] {
+        we_can_round_trip_a_random_evaluation::<blitzar::proof::InnerProductProof>(
+            length,
+            0,
+            &(),
+            &(),
+        );
+    }
+}
@@ -2,5 +2,11 @@
 pub mod dory;
 /// Central location for any code that requires the use of a dynamic matrix (for now, hyrax and dynamic dory).
 pub(super) mod dynamic_matrix_utils;
+/// Commit to a vector, and prove and verify its evaluation at a point, with any commitment scheme.
+///
+/// This exposes the polynomial commitment schemes on their own, e.g. to benchmark them.
+pub mod evaluation_proof;
+#[cfg(test)]
+mod evaluation_proof_test;
 /// TODO: add docs
 pub(crate) mod sumcheck;
@@ -0,0 +1,96 @@
+use crate::{
+    base::database::{ColumnType, LiteralValue},
+    sql::proof_exprs::DynProofExpr,
+};
+use sqlparser::ast::BinaryOperator;
+
+/// Returns the value of `expr` if it is a literal.
+pub(super) fn as_literal(expr: &DynProofExpr) -> Option<&LiteralValue> {
+    match expr {
+        DynProofExpr::Literal(literal) => Some(&literal.value),
+        _ => None,
+    }
+}
+
+/// Returns the value of an integer literal.
+fn as_integer(value: &LiteralValue) -> Option<i128> {
+    match value {
+        LiteralValue::TinyInt(i) => Some((*i).into()),
+        LiteralValue::SmallInt(i) => Some((*i).into()),
+        LiteralValue::Int(i) => Some((*i).into()),
+        LiteralValue::BigInt(i) => Some((*i).into()),
+        LiteralValue::Int128(i) => Some(*i),
+        LiteralValue::UInt64(i) => Some((*i).into()),
+        _ => None,
+    }
+}
+
+/// Returns `value` as a literal of the integer type `column_type`, if it fits.
+fn integer_literal(value: i128, column_type: ColumnType) -> Option<LiteralValue> {
+    match column_type {
+        ColumnType::TinyInt => value.try_into().ok().map(LiteralValue::TinyInt),
+        ColumnType::SmallInt => value.try_into().ok().map(LiteralValue::SmallInt),
+        ColumnType::Int => value.try_into().ok().map(LiteralValue::Int),
+        ColumnType::BigInt => value.try_into().ok().map(LiteralValue::BigInt),
+        ColumnType::Int128 => Some(LiteralValue::Int128(value)),
+        ColumnType::UInt64 => value.try_into().ok().map(LiteralValue::UInt64),
+        _ => None,
+    }
+}
+
+/// Reads an integer literal that is combined with a `UINT64` operand as a `UINT64` literal, if it
+/// fits.
+///
+/// Integer literals are always signed, and signed and unsigned operands can't be mixed, so this is
+/// what allows e.g. `balance >= 100` on a `UINT64` column.
+pub(super) fn coerce_uint64_literal(expr: DynProofExpr, other_type: ColumnType) -> DynProofExpr {
+    if other_type != ColumnType::UInt64 {
+        return expr;
+    }
+    let literal = as_literal(&expr)
+        .and_then(as_integer)
+        .and_then(|value| integer_literal(value, ColumnType::UInt64));
+    literal.map_or(expr, DynProofExpr::new_literal)
+}
+
+/// Evaluates `left op right` at plan time, where `result_type` is the type of the already type
+/// checked operation.
+///
+/// Only operations whose result is exact are folded: boolean logic, comparisons of integers,
+/// booleans and strings, and integer arithmetic that doesn't overflow `result_type`. Anything else,
+/// e.g. decimal arithmetic, is left to be proven as usual.
+pub(super) fn fold_binary_literals(
+    op: &BinaryOperator,
+    left: &LiteralValue,
+    right: &LiteralValue,
+    result_type: ColumnType,
+) -> Option<LiteralValue> {
+    match (op, left, right) {
+        (BinaryOperator::And, LiteralValue::Boolean(l), LiteralValue::Boolean(r)) => {
+            Some(LiteralValue::Boolean(*l && *r))
+        }
+        (BinaryOperator::Or, LiteralValue::Boolean(l), LiteralValue::Boolean(r)) => {
+            Some(LiteralValue::Boolean(*l || *r))
+        }
+        (BinaryOperator::Eq, LiteralValue::Boolean(l), LiteralValue::Boolean(r)) => {
+            Some(LiteralValue::Boolean(l == r))
+        }
+        (BinaryOperator::Eq, LiteralValue::VarChar(l), LiteralValue::VarChar(r)) => {
+            Some(LiteralValue::Boolean(l == r))
+        }
+        _ => {
+            let (l, r) = (as_integer(left)?, as_integer(right)?);
+            match op {
+                BinaryOperator::Eq => Some(LiteralValue::Boolean(l == r)),
+                BinaryOperator::GtEq => Some(LiteralValue::Boolean(l >= r)),
+                BinaryOperator::LtEq => Some(LiteralValue::Boolean(l <= r)),
+                BinaryOperator::Plus => integer_literal(l.checked_add(r)?, result_type),
+                BinaryOperator::Minus => integer_literal(l.checked_sub(r)?, result_type),
+                BinaryOperator::Multiply => integer_literal(l.checked_mul(r)?, result_type),
+                BinaryOperator::Divide => integer_literal(l.checked_div(r)?, result_type),
+                BinaryOperator::Modulo => integer_literal(l.checked_rem(r)?, result_type),
+                _ => None,
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use super::ConversionError;
+use super::{type_check_integer_division, ConversionError};
 use crate::{
     base::{
         database::{ColumnRef, LiteralValue},
@@ -11,6 +11,7 @@ use crate::{
     },
     sql::{
         parse::{
+            constant_folding::{as_literal, coerce_uint64_literal, fold_binary_literals},
             dyn_proof_expr_builder::DecimalError::{InvalidPrecision, InvalidScale},
             ConversionError::DecimalConversionError,
         },
@@ -128,6 +129,9 @@ impl DynProofExprBuilder<'_> {
                     timestamp,
                 )))
             }
+            Literal::Date(date) => Ok(DynProofExpr::new_literal(LiteralValue::Date(
+                date.days_since_epoch(),
+            ))),
         }
     }
 
@@ -138,7 +142,13 @@ impl DynProofExprBuilder<'_> {
     ) -> Result<DynProofExpr, ConversionError> {
         let expr = self.visit_expr(expr);
         match op {
-            UnaryOperator::Not => DynProofExpr::try_new_not(expr?),
+            UnaryOperator::Not => {
+                let expr = expr?;
+                if let Some(LiteralValue::Boolean(b)) = as_literal(&expr) {
+                    return Ok(DynProofExpr::new_literal(LiteralValue::Boolean(!b)));
+                }
+                DynProofExpr::try_new_not(expr)
+            }
             // Handle unsupported operators
             _ => Err(ConversionError::UnsupportedOperation {
                 message: format!("{op:?}"),
@@ -152,57 +162,63 @@ impl DynProofExprBuilder<'_> {
         left: &Expression,
         right: &Expression,
     ) -> Result<DynProofExpr, ConversionError> {
-        match op {
-            BinaryOperator::And => {
-                let left = self.visit_expr(left);
-                let right = self.visit_expr(right);
-                DynProofExpr::try_new_and(left?, right?)
-            }
-            BinaryOperator::Or => {
-                let left = self.visit_expr(left);
-                let right = self.visit_expr(right);
-                DynProofExpr::try_new_or(left?, right?)
-            }
-            BinaryOperator::Eq => {
-                let left = self.visit_expr(left);
-                let right = self.visit_expr(right);
-                DynProofExpr::try_new_equals(left?, right?)
-            }
-            BinaryOperator::GtEq => {
-                let left = self.visit_expr(left);
-                let right = self.visit_expr(right);
-                DynProofExpr::try_new_inequality(left?, right?, false)
-            }
-            BinaryOperator::LtEq => {
-                let left = self.visit_expr(left);
-                let right = self.visit_expr(right);
-                DynProofExpr::try_new_inequality(left?, right?, true)
-            }
-            BinaryOperator::Plus => {
-                let left = self.visit_expr(left);
-                let right = self.visit_expr(right);
-                DynProofExpr::try_new_add(left?, right?)
-            }
-            BinaryOperator::Minus => {
-                let left = self.visit_expr(left);
-                let right = self.visit_expr(right);
-                DynProofExpr::try_new_subtract(left?, right?)
-            }
-            BinaryOperator::Multiply => {
-                let left = self.visit_expr(left);
-                let right = self.visit_expr(right);
-                DynProofExpr::try_new_multiply(left?, right?)
-            }
-            BinaryOperator::Divide => Err(ConversionError::Unprovable {
-                error: format!("Binary operator {op:?} is not supported at this location"),
-            }),
+        let left = self.visit_expr(left);
+        let right = self.visit_expr(right);
+        let (left, right) = match op {
+            BinaryOperator::And
+            | BinaryOperator::Or
+            | BinaryOperator::Eq
+            | BinaryOperator::GtEq
+            | BinaryOperator::LtEq
+            | BinaryOperator::Plus
+            | BinaryOperator::Minus
+            | BinaryOperator::Multiply
+            | BinaryOperator::Modulo => (left?, right?),
+            // Only integer division is provable, anything else is left to postprocessing
+            BinaryOperator::Divide => match (left?, right?) {
+                (left, right)
+                    if type_check_integer_division(left.data_type(), right.data_type()) =>
+                {
+                    (left, right)
+                }
+                _ => {
+                    return Err(ConversionError::Unprovable {
+                        error: format!("Binary operator {op:?} is not supported at this location"),
+                    })
+                }
+            },
             _ => {
                 // Handle unsupported binary operations
-                Err(ConversionError::UnsupportedOperation {
+                return Err(ConversionError::UnsupportedOperation {
                     message: format!("{op:?}"),
-                })
+                });
             }
-        }
+        };
+        let (left_type, right_type) = (left.data_type(), right.data_type());
+        let (left, right) = (
+            coerce_uint64_literal(left, right_type),
+            coerce_uint64_literal(right, left_type),
+        );
+        let literals = as_literal(&left).cloned().zip(as_literal(&right).cloned());
+        let expr = match op {
+            BinaryOperator::And => DynProofExpr::try_new_and(left, right),
+            BinaryOperator::Or => DynProofExpr::try_new_or(left, right),
+            BinaryOperator::Eq => DynProofExpr::try_new_equals(left, right),
+            BinaryOperator::GtEq => DynProofExpr::try_new_inequality(left, right, false),
+            BinaryOperator::LtEq => DynProofExpr::try_new_inequality(left, right, true),
+            BinaryOperator::Plus => DynProofExpr::try_new_add(left, right),
+            BinaryOperator::Minus => DynProofExpr::try_new_subtract(left, right),
+            BinaryOperator::Modulo => DynProofExpr::try_new_modulo(left, right),
+            BinaryOperator::Divide => DynProofExpr::try_new_divide(left, right),
+            BinaryOperator::Multiply => DynProofExpr::try_new_multiply(left, right),
+            _ => Err(ConversionError::UnsupportedOperation {
+                message: format!("{op:?}"),
+            }),
+        }?;
+        // Fold operations on literals so that they are not proven row by row
+        Ok(literals
+            .and_then(|(left, right)| fold_binary_literals(op, &left, &right, expr.data_type()))
+            .map_or(expr, DynProofExpr::new_literal))
     }
 
     fn visit_aggregate_expr(
@@ -15,6 +15,17 @@ use sqlparser::ast::Ident;
 /// Errors from converting an intermediate AST into a provable AST.
 #[derive(Snafu, Debug, PartialEq, Eq)]
 pub enum ConversionError {
+    #[snafu(display(
+        "Table '{resource_id}' was not found, the available tables are: [{available_tables}]"
+    ))]
+    /// The table is missing in the schema accessor
+    UnknownTable {
+        /// The missing table resource id
+        resource_id: Box<ResourceId>,
+        /// The comma separated resource ids of the tables that do exist
+        available_tables: String,
+    },
+
     #[snafu(display("Column '{identifier}' was not found in table '{resource_id}'"))]
     /// The column is missing in the table
     MissingColumn {
@@ -92,6 +103,24 @@ pub enum ConversionError {
         column: String,
     },
 
+    #[snafu(display(
+        "Ambiguous reference: '{alias}' is both a source column and the alias of a different result expression."
+    ))]
+    /// GROUP BY references a name that is both a source column and the alias of a different expression
+    AmbiguousAlias {
+        /// The ambiguous alias
+        alias: String,
+    },
+
+    #[snafu(display(
+        "Invalid group by: alias '{alias}' must refer to a column to be used in the group by expression."
+    ))]
+    /// GROUP BY clause references the alias of a result expression that is not a column
+    InvalidGroupByAlias {
+        /// The alias in the GROUP BY clause
+        alias: String,
+    },
+
     #[snafu(display("Invalid expression: {expression}"))]
     /// General error for invalid expressions
     InvalidExpression {
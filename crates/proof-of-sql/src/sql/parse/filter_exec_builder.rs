@@ -66,6 +66,14 @@ impl FilterExecBuilder {
         if has_nonprovable_column {
             // Has to keep them sorted to have deterministic order for tests
             for alias in self.column_mapping.keys().sorted() {
+                // Result columns must have distinct names, so skip columns that are already output
+                if self
+                    .filter_result_expr_list
+                    .iter()
+                    .any(|result| result.alias == *alias)
+                {
+                    continue;
+                }
                 let column_ref = self.column_mapping.get(alias).unwrap();
                 self.filter_result_expr_list.push(AliasedDynProofExpr {
                     expr: DynProofExpr::new_column(column_ref.clone()),
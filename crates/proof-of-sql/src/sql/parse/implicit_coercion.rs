@@ -0,0 +1,100 @@
+use crate::base::{database::ColumnType, math::decimal::Precision};
+use alloc::{boxed::Box, vec::Vec};
+use proof_of_sql_parser::intermediate_ast::Expression;
+use sqlparser::ast::BinaryOperator;
+
+/// The reason an operand was implicitly coerced
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoercionReason {
+    /// An integer was widened to the larger integer type of the other operand
+    IntegerWidening,
+    /// An integer was converted to a decimal to be combined with a decimal operand
+    IntegerToDecimal,
+    /// A decimal was rescaled to the larger scale of the other operand
+    DecimalRescale,
+}
+
+/// An implicit type coercion performed while planning a query
+///
+/// These are diagnostics only: the coercions happen regardless, but users may be surprised by the
+/// resulting types, so tooling can use these to warn them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImplicitCoercion {
+    /// The operand that was coerced, usually a column or a literal
+    pub expression: Box<Expression>,
+    /// The type of the operand before the coercion
+    pub from_type: ColumnType,
+    /// The type the operand was coerced to
+    pub to_type: ColumnType,
+    /// Why the coercion was needed
+    pub reason: CoercionReason,
+}
+
+/// Returns the coercions needed to apply `op` to `left` and `right`, which must already have been
+/// type checked.
+pub(crate) fn binary_operation_coercions(
+    op: &BinaryOperator,
+    (left, left_type): (&Expression, ColumnType),
+    (right, right_type): (&Expression, ColumnType),
+) -> Vec<ImplicitCoercion> {
+    [
+        (left, left_type, right_type),
+        (right, right_type, left_type),
+    ]
+    .into_iter()
+    .filter_map(|(expression, from_type, other_type)| {
+        coerced_operand_type(op, from_type, other_type).map(|(to_type, reason)| ImplicitCoercion {
+            expression: Box::new(expression.clone()),
+            from_type,
+            to_type,
+            reason,
+        })
+    })
+    .collect()
+}
+
+/// Returns the type `operand_type` is coerced to when combined with `other_type` by `op`, if any.
+fn coerced_operand_type(
+    op: &BinaryOperator,
+    operand_type: ColumnType,
+    other_type: ColumnType,
+) -> Option<(ColumnType, CoercionReason)> {
+    let rescales = match op {
+        BinaryOperator::Plus
+        | BinaryOperator::Minus
+        | BinaryOperator::Eq
+        | BinaryOperator::GtEq
+        | BinaryOperator::LtEq => true,
+        BinaryOperator::Multiply => false,
+        _ => return None,
+    };
+    if operand_type == other_type
+        || !operand_type.is_numeric()
+        || !other_type.is_numeric()
+        || operand_type == ColumnType::Scalar
+        || other_type == ColumnType::Scalar
+    {
+        return None;
+    }
+    if let Some(max_integer_type) = operand_type.max_integer_type(&other_type) {
+        return (max_integer_type != operand_type)
+            .then_some((max_integer_type, CoercionReason::IntegerWidening));
+    }
+    let operand_scale = operand_type.scale()?;
+    let scale = if rescales {
+        operand_scale.max(other_type.scale()?)
+    } else {
+        operand_scale
+    };
+    let reason = if operand_type.is_integer() {
+        CoercionReason::IntegerToDecimal
+    } else if scale > operand_scale {
+        CoercionReason::DecimalRescale
+    } else {
+        return None;
+    };
+    let precision_value =
+        i16::from(operand_type.precision_value()?) + i16::from(scale) - i16::from(operand_scale);
+    let precision = Precision::new(u8::try_from(precision_value).ok()?).ok()?;
+    Some((ColumnType::Decimal75(precision, scale), reason))
+}
@@ -0,0 +1,148 @@
+use super::{CoercionReason, ImplicitCoercion, QueryExpr};
+use crate::base::{
+    database::{ColumnType, TestSchemaAccessor},
+    map::indexmap,
+    math::decimal::Precision,
+};
+use proof_of_sql_parser::{
+    sql::SelectStatementParser,
+    utility::{add, col, lit, mul},
+};
+
+fn accessor() -> TestSchemaAccessor {
+    TestSchemaAccessor::new(indexmap! {
+        "sxt.t".parse().unwrap() => indexmap! {
+            "a".into() => ColumnType::Int,
+            "b".into() => ColumnType::BigInt,
+            "c".into() => ColumnType::SmallInt,
+            "d".into() => ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+            "e".into() => ColumnType::Decimal75(Precision::new(12).unwrap(), 4),
+        },
+    })
+}
+
+fn coercions_of(query: &str) -> Vec<ImplicitCoercion> {
+    let accessor = accessor();
+    let intermediate_ast = SelectStatementParser::new().parse(query).unwrap();
+    let (query_expr, coercions) =
+        QueryExpr::try_new_with_coercions(intermediate_ast.clone(), "sxt".into(), &accessor)
+            .unwrap();
+    assert_eq!(
+        query_expr,
+        QueryExpr::try_new(intermediate_ast, "sxt".into(), &accessor).unwrap()
+    );
+    coercions
+}
+
+#[test]
+fn we_can_record_integer_widenings_and_decimal_rescales() {
+    let coercions =
+        coercions_of("select a + b as ab, d + e as de from sxt.t where c = a and d >= 1");
+    assert_eq!(
+        coercions,
+        vec![
+            ImplicitCoercion {
+                expression: col("a"),
+                from_type: ColumnType::Int,
+                to_type: ColumnType::BigInt,
+                reason: CoercionReason::IntegerWidening,
+            },
+            ImplicitCoercion {
+                expression: col("d"),
+                from_type: ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+                to_type: ColumnType::Decimal75(Precision::new(12).unwrap(), 4),
+                reason: CoercionReason::DecimalRescale,
+            },
+            ImplicitCoercion {
+                expression: col("c"),
+                from_type: ColumnType::SmallInt,
+                to_type: ColumnType::Int,
+                reason: CoercionReason::IntegerWidening,
+            },
+            ImplicitCoercion {
+                expression: lit(1),
+                from_type: ColumnType::BigInt,
+                to_type: ColumnType::Decimal75(Precision::new(21).unwrap(), 2),
+                reason: CoercionReason::IntegerToDecimal,
+            },
+        ]
+    );
+}
+
+#[test]
+fn we_can_record_coercions_of_nested_expressions() {
+    let coercions = coercions_of("select a from sxt.t where b - a >= c and (a + c) * a = b");
+    assert_eq!(
+        coercions,
+        vec![
+            ImplicitCoercion {
+                expression: col("a"),
+                from_type: ColumnType::Int,
+                to_type: ColumnType::BigInt,
+                reason: CoercionReason::IntegerWidening,
+            },
+            ImplicitCoercion {
+                expression: col("c"),
+                from_type: ColumnType::SmallInt,
+                to_type: ColumnType::BigInt,
+                reason: CoercionReason::IntegerWidening,
+            },
+            ImplicitCoercion {
+                expression: col("c"),
+                from_type: ColumnType::SmallInt,
+                to_type: ColumnType::Int,
+                reason: CoercionReason::IntegerWidening,
+            },
+            ImplicitCoercion {
+                expression: mul(add(col("a"), col("c")), col("a")),
+                from_type: ColumnType::Int,
+                to_type: ColumnType::BigInt,
+                reason: CoercionReason::IntegerWidening,
+            },
+        ]
+    );
+}
+
+#[test]
+fn we_record_the_inferred_types_of_nested_arithmetic() {
+    // `c + a` is an `Int`, not a `SmallInt` like its left operand
+    let coercions = coercions_of("select (c + a) * e as ace from sxt.t where b - a >= c");
+    assert_eq!(
+        coercions,
+        vec![
+            ImplicitCoercion {
+                expression: col("c"),
+                from_type: ColumnType::SmallInt,
+                to_type: ColumnType::Int,
+                reason: CoercionReason::IntegerWidening,
+            },
+            // Multiplication does not rescale, so the integer keeps a scale of 0
+            ImplicitCoercion {
+                expression: add(col("c"), col("a")),
+                from_type: ColumnType::Int,
+                to_type: ColumnType::Decimal75(Precision::new(10).unwrap(), 0),
+                reason: CoercionReason::IntegerToDecimal,
+            },
+            ImplicitCoercion {
+                expression: col("a"),
+                from_type: ColumnType::Int,
+                to_type: ColumnType::BigInt,
+                reason: CoercionReason::IntegerWidening,
+            },
+            ImplicitCoercion {
+                expression: col("c"),
+                from_type: ColumnType::SmallInt,
+                to_type: ColumnType::BigInt,
+                reason: CoercionReason::IntegerWidening,
+            },
+        ]
+    );
+}
+
+#[test]
+fn we_record_no_coercions_when_the_types_already_match() {
+    assert_eq!(
+        coercions_of("select b, d + d as dd from sxt.t where b = 1 and e >= e"),
+        vec![]
+    );
+}
@@ -4,6 +4,10 @@ mod error;
 pub use error::ConversionError;
 pub(crate) use error::ConversionResult;
 
+mod implicit_coercion;
+pub(crate) use implicit_coercion::binary_operation_coercions;
+pub use implicit_coercion::{CoercionReason, ImplicitCoercion};
+
 mod enriched_expr;
 pub(crate) use enriched_expr::EnrichedExpr;
 
@@ -13,6 +17,11 @@ mod query_expr_tests;
 mod query_expr;
 pub use query_expr::QueryExpr;
 
+mod referenced_columns;
+pub use referenced_columns::get_referenced_columns;
+#[cfg(test)]
+mod referenced_columns_test;
+
 mod filter_exec_builder;
 pub(crate) use filter_exec_builder::FilterExecBuilder;
 
@@ -21,12 +30,19 @@ pub(crate) mod query_context;
 pub(crate) use query_context::QueryContext;
 
 mod query_context_builder;
-pub(crate) use query_context_builder::{type_check_binary_operation, QueryContextBuilder};
+pub(crate) use query_context_builder::{
+    type_check_binary_operation, type_check_integer_division, QueryContextBuilder,
+};
 
 mod dyn_proof_expr_builder;
 pub(crate) use dyn_proof_expr_builder::DynProofExprBuilder;
 
+mod constant_folding;
+
 mod where_expr_builder;
 pub(crate) use where_expr_builder::WhereExprBuilder;
 #[cfg(test)]
 mod where_expr_builder_tests;
+
+#[cfg(test)]
+mod implicit_coercion_test;
@@ -4,18 +4,22 @@ use crate::{
         map::{IndexMap, IndexSet},
     },
     sql::{
-        parse::{ConversionError, ConversionResult, DynProofExprBuilder, WhereExprBuilder},
+        parse::{
+            ConversionError, ConversionResult, DynProofExprBuilder, ImplicitCoercion,
+            WhereExprBuilder,
+        },
         proof_exprs::{AliasedDynProofExpr, ColumnExpr, DynProofExpr, TableExpr},
         proof_plans::GroupByExec,
     },
 };
-use alloc::{borrow::ToOwned, boxed::Box, string::ToString, vec::Vec};
+use alloc::{borrow::ToOwned, boxed::Box, format, string::ToString, vec::Vec};
 use proof_of_sql_parser::intermediate_ast::{
     AggregationOperator, AliasedResultExpr, Expression, OrderBy, Slice,
 };
 use sqlparser::ast::Ident;
 
 #[derive(Default, Debug)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct QueryContext {
     in_agg_scope: bool,
     agg_counter: usize,
@@ -26,11 +30,13 @@ pub struct QueryContext {
     has_visited_group_by: bool,
     order_by_exprs: Vec<OrderBy>,
     group_by_exprs: Vec<Ident>,
+    distinct: bool,
     where_expr: Option<Box<Expression>>,
     result_column_set: IndexSet<Ident>,
     res_aliased_exprs: Vec<AliasedResultExpr>,
     column_mapping: IndexMap<Ident, ColumnRef>,
     first_result_col_out_agg_scope: Option<Ident>,
+    implicit_coercions: Vec<ImplicitCoercion>,
 }
 
 impl QueryContext {
@@ -136,6 +142,15 @@ impl QueryContext {
         self.has_visited_group_by = true;
     }
 
+    pub fn set_distinct(&mut self, distinct: bool) {
+        self.distinct = distinct;
+    }
+
+    /// Whether duplicate rows are removed from the result, i.e. the query is a `SELECT DISTINCT`
+    pub(crate) fn is_distinct(&self) -> bool {
+        self.distinct
+    }
+
     pub fn set_order_by_exprs(&mut self, order_by_exprs: Vec<OrderBy>) {
         self.order_by_exprs = order_by_exprs;
     }
@@ -224,39 +239,98 @@ impl QueryContext {
     pub fn get_column_mapping(&self) -> IndexMap<Ident, ColumnRef> {
         self.column_mapping.clone()
     }
-}
 
-/// Converts a `QueryContext` into a `Option<GroupByExec>`.
-///
-/// We use Some if the query is provable and None if it is not
-/// We error out if the query is wrong
-impl TryFrom<&QueryContext> for Option<GroupByExec> {
-    type Error = ConversionError;
+    pub fn push_implicit_coercions(
+        &mut self,
+        coercions: impl IntoIterator<Item = ImplicitCoercion>,
+    ) {
+        self.implicit_coercions.extend(coercions);
+    }
 
-    fn try_from(value: &QueryContext) -> Result<Option<GroupByExec>, Self::Error> {
-        let where_clause = WhereExprBuilder::new(&value.column_mapping)
-            .build(value.where_expr.clone())?
-            .unwrap_or_else(|| DynProofExpr::new_literal(LiteralValue::Boolean(true)));
-        let table = value.table.map(|table_ref| TableExpr { table_ref }).ok_or(
+    /// The implicit coercions performed so far, in the order the expressions were visited.
+    pub fn get_implicit_coercions(&self) -> &[ImplicitCoercion] {
+        &self.implicit_coercions
+    }
+
+    /// Plans a `SELECT DISTINCT` query as a group by on the distinct columns.
+    ///
+    /// The `GroupByExec` has exactly one row per distinct combination of the result columns, plus
+    /// a count column which the caller has to remove from the final result.
+    pub(crate) fn try_get_distinct_group_by_exec(&self) -> ConversionResult<GroupByExec> {
+        if self.has_agg() {
+            return Err(ConversionError::UnsupportedOperation {
+                message: "DISTINCT together with aggregations or GROUP BY".to_string(),
+            });
+        }
+        let distinct_columns = self
+            .res_aliased_exprs
+            .iter()
+            .map(|res| match *res.expr {
+                Expression::Column(column) => Ok(Ident::from(column)),
+                _ => Err(ConversionError::UnsupportedOperation {
+                    message: format!("DISTINCT on the expression '{}'", res.alias),
+                }),
+            })
+            .collect::<ConversionResult<IndexSet<_>>>()?;
+        // The count is required by `GroupByExec`, so give it a name that no group column uses.
+        let mut count_alias = "__count__".to_string();
+        while distinct_columns.contains(&Ident::new(count_alias.as_str())) {
+            count_alias.insert(0, '_');
+        }
+        Ok(GroupByExec::new(
+            self.get_column_exprs(distinct_columns.iter())?,
+            Vec::new(),
+            Ident::new(count_alias),
+            self.get_table_expr()?,
+            self.get_where_clause()?,
+        ))
+    }
+
+    fn get_where_clause(&self) -> ConversionResult<DynProofExpr> {
+        Ok(WhereExprBuilder::new(&self.column_mapping)
+            .build(self.where_expr.clone())?
+            .unwrap_or_else(|| DynProofExpr::new_literal(LiteralValue::Boolean(true))))
+    }
+
+    fn get_table_expr(&self) -> ConversionResult<TableExpr> {
+        self.table.map(|table_ref| TableExpr { table_ref }).ok_or(
             ConversionError::InvalidExpression {
                 expression: "QueryContext has no table_ref".to_owned(),
             },
-        )?;
-        let resource_id = table.table_ref.resource_id();
-        let group_by_exprs = value
-            .group_by_exprs
-            .iter()
-            .map(|expr| -> Result<ColumnExpr, ConversionError> {
-                value
-                    .column_mapping
-                    .get(expr)
+        )
+    }
+
+    fn get_column_exprs<'a>(
+        &self,
+        columns: impl IntoIterator<Item = &'a Ident>,
+    ) -> ConversionResult<Vec<ColumnExpr>> {
+        let resource_id = self.get_table_expr()?.table_ref.resource_id();
+        columns
+            .into_iter()
+            .map(|column| {
+                self.column_mapping
+                    .get(column)
                     .ok_or(ConversionError::MissingColumn {
-                        identifier: Box::new((expr).clone()),
+                        identifier: Box::new(column.clone()),
                         resource_id: Box::new(resource_id),
                     })
                     .map(|column_ref| ColumnExpr::new(column_ref.clone()))
             })
-            .collect::<Result<Vec<ColumnExpr>, ConversionError>>()?;
+            .collect()
+    }
+}
+
+/// Converts a `QueryContext` into a `Option<GroupByExec>`.
+///
+/// We use Some if the query is provable and None if it is not
+/// We error out if the query is wrong
+impl TryFrom<&QueryContext> for Option<GroupByExec> {
+    type Error = ConversionError;
+
+    fn try_from(value: &QueryContext) -> Result<Option<GroupByExec>, Self::Error> {
+        let where_clause = value.get_where_clause()?;
+        let table = value.get_table_expr()?;
+        let group_by_exprs = value.get_column_exprs(&value.group_by_exprs)?;
         // For a query to be provable the result columns must be of one of three kinds below:
         // 1. Group by columns (it is mandatory to have all of them in the correct order)
         // 2. Sum(expr) expressions (it is optional to have any)
@@ -1,8 +1,8 @@
-use super::{ConversionError, ConversionResult, QueryContext};
+use super::{binary_operation_coercions, ConversionError, ConversionResult, QueryContext};
 use crate::base::{
     database::{
-        try_add_subtract_column_types, try_multiply_column_types, ColumnRef, ColumnType,
-        SchemaAccessor, TableRef,
+        group_by_util::var_pop_column_type, try_add_subtract_column_types, try_divide_column_types,
+        try_multiply_column_types, ColumnRef, ColumnType, SchemaAccessor, TableRef,
     },
     math::{
         decimal::{DecimalError, Precision},
@@ -13,7 +13,7 @@ use alloc::{boxed::Box, format, string::ToString, vec::Vec};
 use proof_of_sql_parser::{
     intermediate_ast::{
         AggregationOperator, AliasedResultExpr, Expression, Literal, OrderBy, SelectResultExpr,
-        Slice, TableExpression,
+        Slice, TableExpression, WildcardModifiers,
     },
     Identifier, ResourceId,
 };
@@ -38,16 +38,17 @@ impl<'a> QueryContextBuilder<'a> {
         mut self,
         table_expr: &[Box<TableExpression>],
         default_schema: Identifier,
-    ) -> Self {
+    ) -> ConversionResult<Self> {
         assert_eq!(table_expr.len(), 1);
         match *table_expr[0] {
             TableExpression::Named { table, schema } => {
                 let schema_identifier = schema.unwrap_or(default_schema);
-                self.context
-                    .set_table_ref(TableRef::new(ResourceId::new(schema_identifier, table)));
+                let table_ref = TableRef::new(ResourceId::new(schema_identifier, table));
+                self.check_table_exists(table_ref)?;
+                self.context.set_table_ref(table_ref);
             }
         }
-        self
+        Ok(self)
     }
 
     pub fn visit_where_expr(
@@ -68,7 +69,12 @@ impl<'a> QueryContextBuilder<'a> {
         self.context.toggle_result_scope();
         for column in result_exprs {
             match column {
-                SelectResultExpr::ALL => self.visit_select_all_expr()?,
+                SelectResultExpr::ALL => {
+                    self.visit_select_all_expr(&WildcardModifiers::default())?;
+                }
+                SelectResultExpr::ALLWithModifiers(modifiers) => {
+                    self.visit_select_all_expr(&modifiers)?;
+                }
                 SelectResultExpr::AliasedResultExpr(expr) => self.visit_aliased_expr(expr)?,
             }
         }
@@ -77,6 +83,11 @@ impl<'a> QueryContextBuilder<'a> {
         Ok(self)
     }
 
+    pub fn visit_distinct(mut self, distinct: bool) -> Self {
+        self.context.set_distinct(distinct);
+        self
+    }
+
     pub fn visit_order_by_exprs(mut self, order_by_exprs: Vec<OrderBy>) -> Self {
         self.context.set_order_by_exprs(order_by_exprs);
         self
@@ -87,7 +98,21 @@ impl<'a> QueryContextBuilder<'a> {
         self
     }
 
-    pub fn visit_group_by_exprs(mut self, group_by_exprs: Vec<Ident>) -> ConversionResult<Self> {
+    /// Visits the GROUP BY clause, which must be visited before the result expressions.
+    ///
+    /// GROUP BY references source columns. It may also reference the output alias of a column,
+    /// e.g. `x` in `SELECT a AS x ... GROUP BY x`, which is resolved to that column. A name that is
+    /// both a source column and the alias of a different non-aggregate expression is ambiguous and
+    /// an error.
+    pub fn visit_group_by_exprs(
+        mut self,
+        group_by_exprs: Vec<Ident>,
+        result_exprs: &[SelectResultExpr],
+    ) -> ConversionResult<Self> {
+        let group_by_exprs = group_by_exprs
+            .into_iter()
+            .map(|id| self.resolve_group_by_identifier(id, result_exprs))
+            .collect::<ConversionResult<Vec<_>>>()?;
         for id in &group_by_exprs {
             self.visit_column_identifier(id)?;
         }
@@ -103,6 +128,60 @@ impl<'a> QueryContextBuilder<'a> {
 
 // Private interface
 impl<'a> QueryContextBuilder<'a> {
+    /// Resolves a GROUP BY identifier that may be an output alias of a column to a source column.
+    fn resolve_group_by_identifier(
+        &self,
+        id: Ident,
+        result_exprs: &[SelectResultExpr],
+    ) -> ConversionResult<Ident> {
+        let Some((alias, expr)) = result_exprs
+            .iter()
+            .flat_map(|result_expr| match result_expr {
+                SelectResultExpr::AliasedResultExpr(aliased_expr) => {
+                    core::slice::from_ref(aliased_expr)
+                }
+                SelectResultExpr::ALLWithModifiers(modifiers) => modifiers.replace.as_slice(),
+                SelectResultExpr::ALL => &[],
+            })
+            .map(|aliased_expr| (&aliased_expr.alias, &*aliased_expr.expr))
+            .find(|(alias, _)| Ident::from(**alias) == id)
+        else {
+            return Ok(id);
+        };
+        let is_source_column = self
+            .schema_accessor
+            .lookup_column(*self.context.get_table_ref(), id.clone())
+            .is_some();
+        match expr {
+            Expression::Column(column) if column == alias || !is_source_column => {
+                Ok((*column).into())
+            }
+            // Aggregates can't be grouped by, so the name can only refer to the source column.
+            Expression::Aggregation { .. } if is_source_column => Ok(id),
+            _ if is_source_column => Err(ConversionError::AmbiguousAlias { alias: id.value }),
+            _ => Err(ConversionError::InvalidGroupByAlias { alias: id.value }),
+        }
+    }
+
+    /// Checks that the table is known to the schema accessor, so that its schema can be looked up.
+    ///
+    /// Accessors that do not list their tables are trusted to know the table.
+    fn check_table_exists(&self, table_ref: TableRef) -> ConversionResult<()> {
+        let tables = self.schema_accessor.lookup_tables();
+        if tables.is_empty() || tables.contains(&table_ref) {
+            Ok(())
+        } else {
+            Err(ConversionError::UnknownTable {
+                resource_id: Box::new(table_ref.resource_id()),
+                available_tables: tables
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            })
+        }
+    }
+
     #[allow(
         clippy::missing_panics_doc,
         reason = "The assertion ensures there is at least one column, and this is a fundamental requirement for schema retrieval."
@@ -114,15 +193,82 @@ impl<'a> QueryContextBuilder<'a> {
         columns
     }
 
-    fn visit_select_all_expr(&mut self) -> ConversionResult<()> {
-        for (column_name, _) in self.lookup_schema() {
+    /// Expands `*` into every column of the table, leaving out the excluded columns and swapping
+    /// in the replacements.
+    fn visit_select_all_expr(&mut self, modifiers: &WildcardModifiers) -> ConversionResult<()> {
+        let schema = self.lookup_schema();
+        self.check_wildcard_modifiers(&schema, modifiers)?;
+        let mut num_columns = 0;
+        for (column_name, column_type) in schema {
             let column_identifier = Identifier::try_from(column_name).map_err(|e| {
                 ConversionError::IdentifierConversionError {
                     error: format!("Failed to convert Ident to Identifier: {e}"),
                 }
             })?;
-            let col_expr = Expression::Column(column_identifier);
-            self.visit_aliased_expr(AliasedResultExpr::new(col_expr, column_identifier))?;
+            if modifiers.exclude.contains(&column_identifier) {
+                continue;
+            }
+            num_columns += 1;
+            if let Some(replacement) = modifiers
+                .replace
+                .iter()
+                .find(|replacement| replacement.alias == column_identifier)
+            {
+                // The replacement may change the numeric type, but not the kind of the column
+                let replacement_type = self.visit_expr(&replacement.expr)?;
+                if replacement_type != column_type
+                    && !(replacement_type.is_numeric() && column_type.is_numeric())
+                {
+                    return Err(ConversionError::InvalidDataType {
+                        expected: column_type,
+                        actual: replacement_type,
+                    });
+                }
+                self.context.push_aliased_result_expr(replacement.clone())?;
+            } else {
+                let col_expr = Expression::Column(column_identifier);
+                self.visit_aliased_expr(AliasedResultExpr::new(col_expr, column_identifier))?;
+            }
+        }
+        if num_columns == 0 {
+            return Err(ConversionError::InvalidExpression {
+                expression: "EXCLUDE cannot remove every column of the table".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks that every excluded or replaced column exists and is only modified once.
+    fn check_wildcard_modifiers(
+        &self,
+        schema: &[(Ident, ColumnType)],
+        modifiers: &WildcardModifiers,
+    ) -> ConversionResult<()> {
+        let modified_columns = modifiers
+            .exclude
+            .iter()
+            .chain(
+                modifiers
+                    .replace
+                    .iter()
+                    .map(|replacement| &replacement.alias),
+            )
+            .collect::<Vec<_>>();
+        for (i, column) in modified_columns.iter().enumerate() {
+            let column = Ident::from(**column);
+            if !schema.iter().any(|(column_name, _)| *column_name == column) {
+                return Err(ConversionError::MissingColumn {
+                    identifier: Box::new(column),
+                    resource_id: Box::new(self.context.get_table_ref().resource_id()),
+                });
+            }
+            if modified_columns[..i].contains(&modified_columns[i]) {
+                return Err(ConversionError::InvalidExpression {
+                    expression: format!(
+                        "column {column} is modified more than once by EXCLUDE or REPLACE"
+                    ),
+                });
+            }
         }
         Ok(())
     }
@@ -144,6 +290,12 @@ impl<'a> QueryContextBuilder<'a> {
                 self.visit_binary_expr(&(*op).into(), left, right)
             }
             Expression::Aggregation { op, expr } => self.visit_agg_expr(*op, expr),
+            Expression::StringTransform { op, expr } => {
+                self.visit_string_function_expr(&op.to_string(), expr)
+            }
+            Expression::Substring { expr, .. } => {
+                self.visit_string_function_expr("substring", expr)
+            }
         }
     }
 
@@ -166,17 +318,34 @@ impl<'a> QueryContextBuilder<'a> {
     ) -> ConversionResult<ColumnType> {
         let left_dtype = self.visit_expr(left)?;
         let right_dtype = self.visit_expr(right)?;
+        let (left_dtype, right_dtype) = (
+            uint64_literal_dtype(left, left_dtype, right_dtype),
+            uint64_literal_dtype(right, right_dtype, left_dtype),
+        );
         check_dtypes(left_dtype, right_dtype, op)?;
+        self.context
+            .push_implicit_coercions(binary_operation_coercions(
+                op,
+                (left, left_dtype),
+                (right, right_dtype),
+            ));
         match op {
             BinaryOperator::And
             | BinaryOperator::Or
             | BinaryOperator::Eq
             | BinaryOperator::GtEq
             | BinaryOperator::LtEq => Ok(ColumnType::Boolean),
-            BinaryOperator::Multiply
-            | BinaryOperator::Divide
-            | BinaryOperator::Minus
-            | BinaryOperator::Plus => Ok(left_dtype),
+            // The type check guarantees that these succeed
+            BinaryOperator::Minus | BinaryOperator::Plus => {
+                Ok(try_add_subtract_column_types(left_dtype, right_dtype).unwrap_or(left_dtype))
+            }
+            BinaryOperator::Multiply => {
+                Ok(try_multiply_column_types(left_dtype, right_dtype).unwrap_or(left_dtype))
+            }
+            BinaryOperator::Divide => {
+                Ok(try_divide_column_types(left_dtype, right_dtype).unwrap_or(left_dtype))
+            }
+            BinaryOperator::Modulo => Ok(right_dtype),
             _ => {
                 // Handle unsupported binary operations
                 Err(ConversionError::UnsupportedOperation {
@@ -186,6 +355,31 @@ impl<'a> QueryContextBuilder<'a> {
         }
     }
 
+    /// String functions such as `UPPER` or `SUBSTRING` are not provable, so they are only allowed
+    /// in the result expressions, where they can be applied to the verified result.
+    fn visit_string_function_expr(
+        &mut self,
+        function: &str,
+        expr: &Expression,
+    ) -> ConversionResult<ColumnType> {
+        if !self.context.is_in_result_scope() {
+            return Err(ConversionError::Unprovable {
+                error: format!(
+                    "{} can only be used in the selected columns",
+                    function.to_uppercase()
+                ),
+            });
+        }
+        let dtype = self.visit_expr(expr)?;
+        if dtype != ColumnType::VarChar {
+            return Err(ConversionError::InvalidDataType {
+                expected: ColumnType::VarChar,
+                actual: dtype,
+            });
+        }
+        Ok(ColumnType::VarChar)
+    }
+
     fn visit_unary_expr(
         &mut self,
         op: UnaryOperator,
@@ -219,7 +413,10 @@ impl<'a> QueryContextBuilder<'a> {
         let expr_dtype = self.visit_expr(expr)?;
 
         // We only support sum/max/min aggregations on numeric columns.
-        if op != AggregationOperator::Count && expr_dtype == ColumnType::VarChar {
+        // Sums of `UINT64` are not supported either, since they may not fit the column type.
+        if op != AggregationOperator::Count && expr_dtype == ColumnType::VarChar
+            || op == AggregationOperator::Sum && expr_dtype == ColumnType::UInt64
+        {
             return Err(ConversionError::non_numeric_expr_in_agg(
                 expr_dtype.to_string(),
                 op.to_string(),
@@ -228,11 +425,14 @@ impl<'a> QueryContextBuilder<'a> {
 
         self.context.set_in_agg_scope(false)?;
 
-        // Count aggregation always results in an integer type
-        if op == AggregationOperator::Count {
-            Ok(ColumnType::BigInt)
-        } else {
-            Ok(expr_dtype)
+        match op {
+            // Count aggregation always results in an integer type
+            AggregationOperator::Count => Ok(ColumnType::BigInt),
+            // Population variance results in a decimal, and is only defined for numeric types
+            AggregationOperator::VarPop => var_pop_column_type(expr_dtype).ok_or_else(|| {
+                ConversionError::non_numeric_expr_in_agg(expr_dtype.to_string(), op.to_string())
+            }),
+            _ => Ok(expr_dtype),
         }
     }
 
@@ -254,6 +454,7 @@ impl<'a> QueryContextBuilder<'a> {
                 ))
             }
             Literal::Timestamp(its) => Ok(ColumnType::TimestampTZ(its.timeunit(), its.timezone())),
+            Literal::Date(_) => Ok(ColumnType::Date),
         }
     }
 
@@ -304,7 +505,9 @@ pub(crate) fn type_check_binary_operation(
                 (left_dtype, right_dtype),
                 (ColumnType::VarChar, ColumnType::VarChar)
                     | (ColumnType::TimestampTZ(_, _), ColumnType::TimestampTZ(_, _))
+                    | (ColumnType::Date, ColumnType::Date)
                     | (ColumnType::Boolean, ColumnType::Boolean)
+                    | (ColumnType::UInt64, ColumnType::UInt64)
                     | (_, ColumnType::Scalar)
                     | (ColumnType::Scalar, _)
             ) || (left_dtype.is_numeric() && right_dtype.is_numeric())
@@ -329,6 +532,8 @@ pub(crate) fn type_check_binary_operation(
                     (left_dtype, right_dtype),
                     (ColumnType::Boolean, ColumnType::Boolean)
                         | (ColumnType::TimestampTZ(_, _), ColumnType::TimestampTZ(_, _))
+                        | (ColumnType::Date, ColumnType::Date)
+                        | (ColumnType::UInt64, ColumnType::UInt64)
                 )
         }
         BinaryOperator::Plus | BinaryOperator::Minus => {
@@ -336,6 +541,7 @@ pub(crate) fn type_check_binary_operation(
         }
         BinaryOperator::Multiply => try_multiply_column_types(left_dtype, right_dtype).is_ok(),
         BinaryOperator::Divide => left_dtype.is_numeric() && right_dtype.is_numeric(),
+        BinaryOperator::Modulo => type_check_integer_division(left_dtype, right_dtype),
         _ => {
             // Handle unsupported binary operations
             false
@@ -343,6 +549,39 @@ pub(crate) fn type_check_binary_operation(
     }
 }
 
+/// Checks that integer `/` or `%` can be proven for operands of the given types.
+///
+/// Both operands must be integers no wider than `BIGINT`. `/` of other numeric types is valid
+/// SQL, see [`type_check_binary_operation`], but is not provable.
+pub(crate) fn type_check_integer_division(left_dtype: ColumnType, right_dtype: ColumnType) -> bool {
+    left_dtype.is_integer()
+        && right_dtype.is_integer()
+        && left_dtype != ColumnType::Int128
+        && right_dtype != ColumnType::Int128
+}
+
+/// Returns the type of the operand `expr` of type `dtype` when combined with an operand of type
+/// `other_dtype`.
+///
+/// Integer literals are read as `UINT64` next to a `UINT64` operand if they fit, matching
+/// `DynProofExprBuilder`, since signed and unsigned operands can't be mixed.
+fn uint64_literal_dtype(
+    expr: &Expression,
+    dtype: ColumnType,
+    other_dtype: ColumnType,
+) -> ColumnType {
+    let fits_uint64 = match expr {
+        Expression::Literal(Literal::BigInt(i)) => u64::try_from(*i).is_ok(),
+        Expression::Literal(Literal::Int128(i)) => u64::try_from(*i).is_ok(),
+        _ => false,
+    };
+    if fits_uint64 && other_dtype == ColumnType::UInt64 {
+        ColumnType::UInt64
+    } else {
+        dtype
+    }
+}
+
 fn check_dtypes(
     left_dtype: ColumnType,
     right_dtype: ColumnType,
@@ -1,11 +1,11 @@
-use super::{EnrichedExpr, FilterExecBuilder, QueryContextBuilder};
+use super::{EnrichedExpr, FilterExecBuilder, ImplicitCoercion, QueryContext, QueryContextBuilder};
 use crate::{
-    base::database::SchemaAccessor,
+    base::database::{LiteralValue, SchemaAccessor},
     sql::{
         parse::ConversionResult,
         postprocessing::{
             GroupByPostprocessing, OrderByPostprocessing, OwnedTablePostprocessing,
-            PostprocessingError, SelectPostprocessing, SlicePostprocessing,
+            PostprocessingError, PostprocessingResult, SelectPostprocessing, SlicePostprocessing,
         },
         proof_plans::{DynProofPlan, GroupByExec},
     },
@@ -41,6 +41,34 @@ pub fn convert_ident_to_identifier(ident: Ident) -> Result<Identifier, Postproce
     })
 }
 
+/// Resolves the table and the columns referenced by `ast`, and type checks its expressions.
+pub(crate) fn build_query_context(
+    ast: SelectStatement,
+    default_schema: Ident,
+    schema_accessor: &dyn SchemaAccessor,
+) -> ConversionResult<QueryContext> {
+    match *ast.expr {
+        SetExpression::Query {
+            result_exprs,
+            from,
+            where_expr,
+            group_by,
+            distinct,
+        } => QueryContextBuilder::new(schema_accessor)
+            .visit_table_expr(&from, convert_ident_to_identifier(default_schema)?)?
+            .visit_group_by_exprs(
+                group_by.into_iter().map(Ident::from).collect(),
+                &result_exprs,
+            )?
+            .visit_result_exprs(result_exprs)?
+            .visit_where_expr(where_expr)?
+            .visit_distinct(distinct)
+            .visit_order_by_exprs(ast.order_by)
+            .visit_slice_expr(ast.slice)
+            .build(),
+    }
+}
+
 impl QueryExpr {
     /// Creates a new `QueryExpr` with the given `DynProofPlan` and `OwnedTablePostprocessing`.
     #[must_use]
@@ -57,21 +85,26 @@ impl QueryExpr {
         default_schema: Ident,
         schema_accessor: &dyn SchemaAccessor,
     ) -> ConversionResult<Self> {
-        let context = match *ast.expr {
-            SetExpression::Query {
-                result_exprs,
-                from,
-                where_expr,
-                group_by,
-            } => QueryContextBuilder::new(schema_accessor)
-                .visit_table_expr(&from, convert_ident_to_identifier(default_schema)?)
-                .visit_group_by_exprs(group_by.into_iter().map(Ident::from).collect())?
-                .visit_result_exprs(result_exprs)?
-                .visit_where_expr(where_expr)?
-                .visit_order_by_exprs(ast.order_by)
-                .visit_slice_expr(ast.slice)
-                .build()?,
-        };
+        Self::try_new_with_coercions(ast, default_schema, schema_accessor)
+            .map(|(query_expr, _)| query_expr)
+    }
+
+    /// Parse an intermediate AST `SelectStatement` into a `QueryExpr`, also returning every
+    /// implicit type coercion performed while planning it.
+    ///
+    /// The coercions are only diagnostics, e.g. to warn users about result types they may not
+    /// expect, and the `QueryExpr` is the same as the one returned by [`QueryExpr::try_new`].
+    pub fn try_new_with_coercions(
+        ast: SelectStatement,
+        default_schema: Ident,
+        schema_accessor: &dyn SchemaAccessor,
+    ) -> ConversionResult<(Self, Vec<ImplicitCoercion>)> {
+        let context = build_query_context(ast, default_schema, schema_accessor)?;
+        let query_expr = Self::try_new_from_context(&context)?;
+        Ok((query_expr, context.get_implicit_coercions().to_vec()))
+    }
+
+    fn try_new_from_context(context: &QueryContext) -> ConversionResult<Self> {
         let result_aliased_exprs = context.get_aliased_result_exprs()?.to_vec();
         let group_by = context.get_group_by_exprs();
         // Figure out the basic postprocessing steps.
@@ -84,11 +117,28 @@ impl QueryExpr {
         }
         if let Some(slice) = context.get_slice_expr() {
             postprocessing.push(OwnedTablePostprocessing::new_slice(
-                SlicePostprocessing::new(Some(slice.number_rows), Some(slice.offset_value)),
+                SlicePostprocessing::new_with_values(
+                    Some(slice.number_rows),
+                    Some(slice.offset_value),
+                ),
             ));
         }
-        if context.has_agg() {
-            if let Some(group_by_expr) = Option::<GroupByExec>::try_from(&context)? {
+        if context.is_distinct() {
+            // The group by has one row per distinct tuple of result columns, so we only need to
+            // drop its count column and apply the aliases.
+            let group_by_expr = context.try_get_distinct_group_by_exec()?;
+            postprocessing.insert(
+                0,
+                OwnedTablePostprocessing::new_select(SelectPostprocessing::new(
+                    result_aliased_exprs,
+                )),
+            );
+            Ok(Self {
+                proof_expr: DynProofPlan::GroupBy(group_by_expr),
+                postprocessing,
+            })
+        } else if context.has_agg() {
+            if let Some(group_by_expr) = Option::<GroupByExec>::try_from(context)? {
                 Ok(Self {
                     proof_expr: DynProofPlan::GroupBy(group_by_expr),
                     postprocessing,
@@ -176,4 +226,23 @@ impl QueryExpr {
     pub fn postprocessing(&self) -> &[OwnedTablePostprocessing] {
         &self.postprocessing
     }
+
+    /// Resolve the query parameters of this query, e.g. `$1` in `LIMIT $1`, against `params`,
+    /// where `$1` is `params[0]`.
+    ///
+    /// Only `LIMIT` and `OFFSET` clauses may be parameters, so the proof plan is the same for
+    /// every binding, and a query only needs to be planned once.
+    ///
+    /// # Errors
+    /// Returns an error if a parameter is not in `params`, or is not a non-negative integer.
+    pub fn try_bind_parameters(&self, params: &[LiteralValue]) -> PostprocessingResult<Self> {
+        Ok(Self {
+            proof_expr: self.proof_expr.clone(),
+            postprocessing: self
+                .postprocessing
+                .iter()
+                .map(|step| step.try_bind_parameters(params))
+                .collect::<PostprocessingResult<_>>()?,
+        })
+    }
 }
@@ -1,8 +1,11 @@
 use super::ConversionError;
 use crate::{
     base::{
-        database::{ColumnType, TableRef, TestSchemaAccessor},
+        database::{
+            ColumnOperationError, ColumnType, SchemaAccessor, TableRef, TestSchemaAccessor,
+        },
         map::{indexmap, IndexMap, IndexSet},
+        math::decimal::Precision,
     },
     sql::{
         parse::QueryExpr,
@@ -14,10 +17,11 @@ use crate::{
 use itertools::Itertools;
 use proof_of_sql_parser::{
     intermediate_ast::OrderByDirection::*,
+    posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
     sql::SelectStatementParser,
     utility::{
         add as padd, aliased_expr, col, count, count_all, lit, max, min, mul as pmul, sub as psub,
-        sum,
+        substring, sum, trim, upper, var_pop,
     },
 };
 use sqlparser::ast::Ident;
@@ -235,6 +239,98 @@ fn we_can_parse_all_result_columns_with_select_star() {
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_can_exclude_columns_from_select_star() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "internal_id".into() => ColumnType::BigInt,
+            "a".into() => ColumnType::BigInt,
+            "b".into() => ColumnType::VarChar,
+        },
+    );
+    let ast = query_to_provable_ast(t, "select * exclude (internal_id) from sxt_tab", &accessor);
+    let expected_ast = QueryExpr::new(
+        filter(
+            cols_expr_plan(t, &["a", "b"], &accessor),
+            tab(t),
+            const_bool(true),
+        ),
+        vec![],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_replace_columns_in_select_star() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "internal_id".into() => ColumnType::BigInt,
+            "a".into() => ColumnType::BigInt,
+            "b".into() => ColumnType::VarChar,
+        },
+    );
+    let ast = query_to_provable_ast(
+        t,
+        "select * exclude internal_id replace (a * 2 as a) from sxt_tab where b = 'x'",
+        &accessor,
+    );
+    let expected_ast = QueryExpr::new(
+        filter(
+            vec![
+                aliased_plan(multiply(column(t, "a", &accessor), const_bigint(2)), "a"),
+                col_expr_plan(t, "b", &accessor),
+            ],
+            tab(t),
+            equal(column(t, "b", &accessor), const_varchar("x")),
+        ),
+        vec![],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_convert_an_ast_with_invalid_select_star_modifiers() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".into() => ColumnType::BigInt,
+            "b".into() => ColumnType::VarChar,
+        },
+    );
+    let conversion_error = |query: &str| {
+        let intermediate_ast = SelectStatementParser::new().parse(query).unwrap();
+        QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor).unwrap_err()
+    };
+    assert!(matches!(
+        conversion_error("select * exclude (internal_id) from sxt_tab"),
+        ConversionError::MissingColumn { .. }
+    ));
+    assert!(matches!(
+        conversion_error("select * replace (a + 1 as c) from sxt_tab"),
+        ConversionError::MissingColumn { .. }
+    ));
+    assert!(matches!(
+        conversion_error("select * replace (a + 1 as b) from sxt_tab"),
+        ConversionError::InvalidDataType {
+            expected: ColumnType::VarChar,
+            actual: ColumnType::BigInt,
+        }
+    ));
+    assert!(matches!(
+        conversion_error("select * exclude (a) replace (a + 1 as a) from sxt_tab"),
+        ConversionError::InvalidExpression { .. }
+    ));
+    assert!(matches!(
+        conversion_error("select * exclude (a, b) from sxt_tab"),
+        ConversionError::InvalidExpression { .. }
+    ));
+}
+
 #[test]
 fn we_can_convert_an_ast_with_one_positive_cond() {
     let t = "sxt.sxt_tab".parse().unwrap();
@@ -537,6 +633,69 @@ fn we_cannot_convert_an_ast_with_a_nonexistent_column() {
     invalid_query_to_provable_ast(t, "select * from sxt_tab where a = 3", &accessor);
 }
 
+#[test]
+fn we_cannot_convert_an_ast_with_an_unknown_table() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = TestSchemaAccessor::new(indexmap! {
+        t => indexmap! {"a".into() => ColumnType::BigInt},
+        "eth.other_tab".parse().unwrap() => indexmap! {"b".into() => ColumnType::BigInt},
+    });
+    for query in [
+        "select * from missing_tab",
+        "select a from missing_tab where a = 3",
+        "select a from eth.sxt_tab",
+    ] {
+        let intermediate_ast = SelectStatementParser::new().parse(query).unwrap();
+        let result = QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor);
+        assert!(matches!(result, Err(ConversionError::UnknownTable { .. })));
+    }
+    let intermediate_ast = SelectStatementParser::new()
+        .parse("select * from missing_tab")
+        .unwrap();
+    let error = QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor).unwrap_err();
+    assert_eq!(
+        error,
+        ConversionError::UnknownTable {
+            resource_id: Box::new("sxt.missing_tab".parse().unwrap()),
+            available_tables: "sxt.sxt_tab, eth.other_tab".to_string(),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "Table 'sxt.missing_tab' was not found, the available tables are: [sxt.sxt_tab, eth.other_tab]"
+    );
+    // Tables in other schemas can still be queried when they are qualified
+    query_to_provable_ast(t, "select b from eth.other_tab", &accessor);
+}
+
+/// A schema accessor that keeps the default `lookup_tables`, as accessors predating it do
+struct UnlistedTablesAccessor(TestSchemaAccessor);
+
+impl SchemaAccessor for UnlistedTablesAccessor {
+    fn lookup_column(&self, table_ref: TableRef, column_id: Ident) -> Option<ColumnType> {
+        self.0.lookup_column(table_ref, column_id)
+    }
+
+    fn lookup_schema(&self, table_ref: TableRef) -> Vec<(Ident, ColumnType)> {
+        self.0.lookup_schema(table_ref)
+    }
+}
+
+#[test]
+fn we_can_convert_an_ast_with_an_accessor_that_does_not_list_its_tables() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = UnlistedTablesAccessor(TestSchemaAccessor::new(indexmap! {
+        t => indexmap! {"a".into() => ColumnType::BigInt},
+    }));
+    assert!(accessor.lookup_tables().is_empty());
+    let intermediate_ast = SelectStatementParser::new()
+        .parse("select a from sxt_tab where a = 3")
+        .unwrap();
+    let query = QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor).unwrap();
+    let expected = query_to_provable_ast(t, "select a from sxt_tab where a = 3", &accessor.0);
+    assert_eq!(query.proof_expr(), expected.proof_expr());
+}
+
 #[test]
 fn we_cannot_convert_an_ast_with_a_column_type_different_than_equal_literal() {
     let t = "sxt.sxt_tab".parse().unwrap();
@@ -1141,24 +1300,60 @@ fn group_by_expressions_are_parsed_before_an_order_by_referencing_an_aggregate_a
 }
 
 #[test]
-fn we_cannot_parse_non_aggregated_or_non_group_by_columns_in_the_select_clause() {
+fn we_can_parse_a_population_variance_as_a_group_by_postprocessing() {
+    let query_text = "select department, var_pop(salary) v from sxt.employees group by department";
+
     let t = "sxt.employees".parse().unwrap();
     let accessor = schema_accessor_from_table_ref_with_schema(
         t,
         indexmap! {
+            "department".into() => ColumnType::BigInt,
             "salary".into() => ColumnType::BigInt,
+        },
+    );
+
+    let intermediate_ast = SelectStatementParser::new().parse(query_text).unwrap();
+    let query = QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor).unwrap();
+
+    let expected_query = QueryExpr::new(
+        filter(
+            vec![
+                col_expr_plan(t, "department", &accessor),
+                col_expr_plan(t, "salary", &accessor),
+            ],
+            tab(t),
+            const_bool(true),
+        ),
+        vec![group_by_postprocessing(
+            &["department"],
+            &[
+                aliased_expr(col("department"), "department"),
+                aliased_expr(var_pop(col("salary")), "v"),
+            ],
+        )],
+    );
+    assert_eq!(query, expected_query);
+}
+
+#[test]
+fn we_cannot_parse_a_population_variance_of_a_varchar_column() {
+    let t = "sxt.employees".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
             "department".into() => ColumnType::BigInt,
+            "name".into() => ColumnType::VarChar,
         },
     );
     invalid_query_to_provable_ast(
         t,
-        "select department, salary from sxt.employees group by department",
+        "select department, var_pop(name) from sxt.employees group by department",
         &accessor,
     );
 }
 
 #[test]
-fn alias_references_are_not_allowed_in_the_group_by() {
+fn we_cannot_parse_non_aggregated_or_non_group_by_columns_in_the_select_clause() {
     let t = "sxt.employees".parse().unwrap();
     let accessor = schema_accessor_from_table_ref_with_schema(
         t,
@@ -1169,14 +1364,84 @@ fn alias_references_are_not_allowed_in_the_group_by() {
     );
     invalid_query_to_provable_ast(
         t,
-        "select department, min(salary) as min_salary from employees group by min_salary",
+        "select department, salary from sxt.employees group by department",
         &accessor,
     );
-    invalid_query_to_provable_ast(
+}
+
+#[test]
+fn aggregation_aliases_are_not_allowed_in_the_group_by() {
+    let t = "sxt.employees".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "salary".into() => ColumnType::BigInt,
+            "department".into() => ColumnType::BigInt,
+        },
+    );
+    let intermediate_ast = SelectStatementParser::new()
+        .parse("select department, min(salary) as min_salary from employees group by min_salary")
+        .unwrap();
+    let result = QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor);
+    assert!(matches!(
+        result,
+        Err(ConversionError::InvalidGroupByAlias { alias }) if alias == "min_salary"
+    ));
+}
+
+#[test]
+fn we_can_group_by_and_order_by_a_column_alias() {
+    let t = "sxt.employees".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "salary".into() => ColumnType::BigInt,
+            "department".into() => ColumnType::BigInt,
+        },
+    );
+    let ast = query_to_provable_ast(
         t,
-        "select salary as min_salary from employees group by min_salary",
+        "select department as d, sum(salary) as total from employees group by d order by d",
         &accessor,
     );
+    let expected_ast = QueryExpr::new(
+        filter(
+            cols_expr_plan(t, &["department", "salary"], &accessor),
+            tab(t),
+            const_bool(true),
+        ),
+        vec![
+            group_by_postprocessing(
+                &["department"],
+                &[
+                    aliased_expr(col("department"), "d"),
+                    aliased_expr(sum(col("salary")), "total"),
+                ],
+            ),
+            orders(&["d"], &[Asc]),
+        ],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_group_by_a_name_that_is_both_a_column_and_the_alias_of_another_column() {
+    let t = "sxt.employees".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "salary".into() => ColumnType::BigInt,
+            "department".into() => ColumnType::BigInt,
+        },
+    );
+    let intermediate_ast = SelectStatementParser::new()
+        .parse("select department as salary from employees group by salary")
+        .unwrap();
+    let result = QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor);
+    assert!(matches!(
+        result,
+        Err(ConversionError::AmbiguousAlias { alias }) if alias == "salary"
+    ));
 }
 
 #[test]
@@ -1652,6 +1917,33 @@ fn varchar_column_is_not_compatible_with_integer_column() {
     }
 }
 
+#[test]
+fn dates_can_only_be_compared_with_dates() {
+    let t = "sxt.events".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "d".into() => ColumnType::Date,
+        },
+    );
+
+    let query_text = "select d from sxt.events where d > DATE '2020-01-01'";
+    let intermediate_ast = SelectStatementParser::new().parse(query_text).unwrap();
+    assert!(QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor).is_ok());
+
+    let query_text = "select d from sxt.events where d > timestamp '2020-01-01T00:00:00Z'";
+    let intermediate_ast = SelectStatementParser::new().parse(query_text).unwrap();
+    let result = QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor);
+    assert_eq!(
+        result,
+        Err(ConversionError::DataTypeMismatch {
+            left_type: ColumnType::Date.to_string(),
+            right_type: ColumnType::TimestampTZ(PoSQLTimeUnit::Second, PoSQLTimeZone::utc())
+                .to_string(),
+        })
+    );
+}
+
 #[test]
 fn arithmetic_operations_are_not_allowed_with_varchar_column() {
     let t = "sxt.employees".parse().unwrap();
@@ -1676,6 +1968,33 @@ fn arithmetic_operations_are_not_allowed_with_varchar_column() {
     );
 }
 
+#[test]
+fn nested_arithmetic_is_type_checked_with_its_inferred_result_type() {
+    let t = "sxt.measurements".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".into() => ColumnType::Decimal75(Precision::new(30).unwrap(), 0),
+        },
+    );
+
+    let query_text = "select a * a as squares from sxt.measurements";
+    let intermediate_ast = SelectStatementParser::new().parse(query_text).unwrap();
+    assert!(QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor).is_ok());
+
+    // `a * a` has a precision of 61, so multiplying it by `a` again exceeds the maximum of 75
+    let query_text = "select (a * a) * a as cubes from sxt.measurements";
+    let intermediate_ast = SelectStatementParser::new().parse(query_text).unwrap();
+    let result = QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor);
+    assert_eq!(
+        result,
+        Err(ConversionError::DataTypeMismatch {
+            left_type: ColumnType::Decimal75(Precision::new(61).unwrap(), 0).to_string(),
+            right_type: ColumnType::Decimal75(Precision::new(30).unwrap(), 0).to_string(),
+        })
+    );
+}
+
 #[test]
 fn varchar_column_is_not_allowed_within_numeric_aggregations() {
     let t = "sxt.employees".parse().unwrap();
@@ -1845,6 +2164,112 @@ fn we_can_use_arithmetic_outside_agg_expressions_while_using_group_by() {
     assert_eq!(query, expected_query);
 }
 
+#[test]
+fn we_can_apply_string_transformations_to_the_selected_columns_as_postprocessing() {
+    let t = "sxt.employees".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "name".into() => ColumnType::VarChar,
+            "salary".into() => ColumnType::BigInt,
+        },
+    );
+    let query_text =
+        "select upper(name) as n, trim(name) as t from sxt.employees where salary >= 100";
+
+    let intermediate_ast = SelectStatementParser::new().parse(query_text).unwrap();
+    let query = QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor).unwrap();
+
+    let expected_query = QueryExpr::new(
+        filter(
+            cols_expr_plan(t, &["name", "salary"], &accessor),
+            tab(t),
+            gte(column(t, "salary", &accessor), const_bigint(100)),
+        ),
+        vec![select_expr(&[
+            aliased_expr(upper(col("name")), "n"),
+            aliased_expr(trim(col("name")), "t"),
+        ])],
+    );
+    assert_eq!(query, expected_query);
+}
+
+#[test]
+fn we_can_apply_substrings_to_the_selected_columns_as_postprocessing() {
+    let t = "sxt.cards".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "card_number".into() => ColumnType::VarChar,
+            "id".into() => ColumnType::BigInt,
+        },
+    );
+    let query_text = "select id, substring(card_number, 1, 4) as prefix from sxt.cards";
+
+    let intermediate_ast = SelectStatementParser::new().parse(query_text).unwrap();
+    let query = QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor).unwrap();
+
+    let expected_query = QueryExpr::new(
+        filter(
+            cols_expr_plan(t, &["id", "card_number"], &accessor),
+            tab(t),
+            const_bool(true),
+        ),
+        vec![select_expr(&[
+            aliased_expr(col("id"), "id"),
+            aliased_expr(substring(col("card_number"), 1, 4), "prefix"),
+        ])],
+    );
+    assert_eq!(query, expected_query);
+
+    let intermediate_ast = SelectStatementParser::new()
+        .parse("select id from sxt.cards where substring(card_number, 1, 1) = '4'")
+        .unwrap();
+    assert!(matches!(
+        QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor),
+        Err(ConversionError::Unprovable { .. })
+    ));
+    let intermediate_ast = SelectStatementParser::new()
+        .parse("select substring(id, 1, 1) as s from sxt.cards")
+        .unwrap();
+    assert!(matches!(
+        QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor),
+        Err(ConversionError::InvalidDataType {
+            expected: ColumnType::VarChar,
+            actual: ColumnType::BigInt
+        })
+    ));
+}
+
+#[test]
+fn we_cannot_use_string_transformations_outside_the_selected_columns() {
+    let t = "sxt.employees".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "name".into() => ColumnType::VarChar,
+            "salary".into() => ColumnType::BigInt,
+        },
+    );
+    let intermediate_ast = SelectStatementParser::new()
+        .parse("select name from sxt.employees where lower(name) = 'alice'")
+        .unwrap();
+    assert!(matches!(
+        QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor),
+        Err(ConversionError::Unprovable { .. })
+    ));
+    let intermediate_ast = SelectStatementParser::new()
+        .parse("select upper(salary) as s from sxt.employees")
+        .unwrap();
+    assert!(matches!(
+        QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor),
+        Err(ConversionError::InvalidDataType {
+            expected: ColumnType::VarChar,
+            actual: ColumnType::BigInt
+        })
+    ));
+}
+
 #[test]
 fn we_can_use_arithmetic_outside_agg_expressions_without_using_group_by() {
     let t = "sxt.employees".parse().unwrap();
@@ -2148,3 +2573,326 @@ fn we_can_serialize_list_of_filters_from_query_expr() {
     assert_eq!(filter_execs.len(), deserialized_as_ref.len());
     assert_eq!(filter_execs[0], deserialized_as_ref[0]);
 }
+
+#[test]
+fn we_fold_constant_predicates_to_boolean_literals() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".into() => ColumnType::BigInt,
+        },
+    );
+    let ast = query_to_provable_ast(t, "select a from sxt_tab where 1 = 1", &accessor);
+    let expected_ast = QueryExpr::new(
+        filter(
+            cols_expr_plan(t, &["a"], &accessor),
+            tab(t),
+            const_bool(true),
+        ),
+        vec![],
+    );
+    assert_eq!(ast, expected_ast);
+
+    let ast = query_to_provable_ast(
+        t,
+        "select a from sxt_tab where not (2 * 3 <= 5) and (a = 1 or 'x' = 'y')",
+        &accessor,
+    );
+    let expected_ast = QueryExpr::new(
+        filter(
+            cols_expr_plan(t, &["a"], &accessor),
+            tab(t),
+            and(
+                const_bool(true),
+                or(
+                    equal(column(t, "a", &accessor), const_bigint(1)),
+                    const_bool(false),
+                ),
+            ),
+        ),
+        vec![],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_fold_constant_projections_to_literal_columns() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".into() => ColumnType::BigInt,
+        },
+    );
+    let ast = query_to_provable_ast(
+        t,
+        "select a, 2 + 3 as five, a * (4 - 6) as b from sxt_tab",
+        &accessor,
+    );
+    let expected_ast = QueryExpr::new(
+        filter(
+            vec![
+                col_expr_plan(t, "a", &accessor),
+                aliased_plan(const_bigint(5), "five"),
+                aliased_plan(multiply(column(t, "a", &accessor), const_bigint(-2)), "b"),
+            ],
+            tab(t),
+            const_bool(true),
+        ),
+        vec![],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_do_not_fold_constant_expressions_that_overflow_or_are_decimal() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".into() => ColumnType::BigInt,
+        },
+    );
+    let ast = query_to_provable_ast(
+        t,
+        "select 9223372036854775807 + 1 as b, 1.5 + 1 as c from sxt_tab",
+        &accessor,
+    );
+    let expected_ast = QueryExpr::new(
+        filter(
+            vec![
+                aliased_plan(add(const_bigint(i64::MAX), const_bigint(1)), "b"),
+                aliased_plan(add(const_decimal75(2, 1, 15), const_bigint(1)), "c"),
+            ],
+            tab(t),
+            const_bool(true),
+        ),
+        vec![],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_convert_a_modulo_expression_but_not_a_modulo_by_a_zero_literal() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "id".into() => ColumnType::Int,
+            "b".into() => ColumnType::SmallInt,
+            "c".into() => ColumnType::Int128,
+        },
+    );
+    let ast = query_to_provable_ast(
+        t,
+        "select id % b as m from sxt_tab where id % 10 = 0",
+        &accessor,
+    );
+    let expected_ast = QueryExpr::new(
+        filter(
+            vec![aliased_plan(
+                modulo(column(t, "id", &accessor), column(t, "b", &accessor)),
+                "m",
+            )],
+            tab(t),
+            equal(
+                modulo(column(t, "id", &accessor), const_bigint(10)),
+                const_bigint(0),
+            ),
+        ),
+        vec![],
+    );
+    assert_eq!(ast, expected_ast);
+
+    let intermediate_ast = SelectStatementParser::new()
+        .parse("select id from sxt_tab where id % 0 = 1")
+        .unwrap();
+    assert_eq!(
+        QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor),
+        Err(ConversionError::ColumnOperationError {
+            source: ColumnOperationError::DivisionByZero
+        })
+    );
+    invalid_query_to_provable_ast(t, "select c % 2 as m from sxt_tab", &accessor);
+}
+
+#[test]
+fn we_can_convert_an_integer_division_but_leave_decimal_division_to_postprocessing() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "id".into() => ColumnType::Int,
+            "b".into() => ColumnType::SmallInt,
+            "d".into() => ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+        },
+    );
+    let ast = query_to_provable_ast(
+        t,
+        "select id / b as q from sxt_tab where id / 2 = -3",
+        &accessor,
+    );
+    let expected_ast = QueryExpr::new(
+        filter(
+            vec![aliased_plan(
+                divide(column(t, "id", &accessor), column(t, "b", &accessor)),
+                "q",
+            )],
+            tab(t),
+            equal(
+                divide(column(t, "id", &accessor), const_bigint(2)),
+                const_bigint(-3),
+            ),
+        ),
+        vec![],
+    );
+    assert_eq!(ast, expected_ast);
+
+    let ast = query_to_provable_ast(t, "select d / 2 as q from sxt_tab", &accessor);
+    assert_eq!(ast.postprocessing().len(), 1);
+
+    let intermediate_ast = SelectStatementParser::new()
+        .parse("select id from sxt_tab where id / 0 = 1")
+        .unwrap();
+    assert_eq!(
+        QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor),
+        Err(ConversionError::ColumnOperationError {
+            source: ColumnOperationError::DivisionByZero
+        })
+    );
+}
+
+#[test]
+fn we_can_plan_select_distinct_as_a_provable_group_by() {
+    let t = "sxt.employees".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "salary".into() => ColumnType::BigInt,
+            "department".into() => ColumnType::VarChar,
+            "__count__".into() => ColumnType::BigInt,
+        },
+    );
+    let ast = query_to_provable_ast(
+        t,
+        "select distinct department as dept, salary from employees where salary >= 5 order by dept",
+        &accessor,
+    );
+    let expected_ast = QueryExpr::new(
+        group_by(
+            cols_expr(t, &["department", "salary"], &accessor),
+            vec![],
+            "__count__",
+            tab(t),
+            gte(column(t, "salary", &accessor), const_bigint(5)),
+        ),
+        vec![
+            select_expr(&[
+                aliased_expr(col("department"), "dept"),
+                aliased_expr(col("salary"), "salary"),
+            ]),
+            orders(&["dept"], &[Asc]),
+        ],
+    );
+    assert_eq!(ast, expected_ast);
+
+    // The count column doesn't collide with a distinct column
+    let ast = query_to_provable_ast(
+        t,
+        "select distinct __count__, department from employees",
+        &accessor,
+    );
+    let expected_ast = QueryExpr::new(
+        group_by(
+            cols_expr(t, &["__count__", "department"], &accessor),
+            vec![],
+            "___count__",
+            tab(t),
+            const_bool(true),
+        ),
+        vec![select_expr(&[
+            aliased_expr(col("__count__"), "__count__"),
+            aliased_expr(col("department"), "department"),
+        ])],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_use_select_distinct_with_aggregations_or_expressions() {
+    let t = "sxt.employees".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "salary".into() => ColumnType::BigInt,
+            "department".into() => ColumnType::BigInt,
+        },
+    );
+    for query in [
+        "select distinct department, count(*) as c from employees group by department",
+        "select distinct sum(salary) as s from employees",
+        "select distinct salary + 1 as s from employees",
+    ] {
+        let intermediate_ast = SelectStatementParser::new().parse(query).unwrap();
+        assert!(matches!(
+            QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor),
+            Err(ConversionError::UnsupportedOperation { .. })
+        ));
+    }
+}
+
+#[test]
+fn we_can_compare_uint64_columns_with_nonnegative_integer_literals() {
+    let t = "sxt.accounts".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "id".into() => ColumnType::BigInt,
+            "balance".into() => ColumnType::UInt64,
+        },
+    );
+    let ast = query_to_provable_ast(
+        t,
+        "select id from accounts where balance >= 18446744073709551615 or balance = 0",
+        &accessor,
+    );
+    let expected_ast = QueryExpr::new(
+        filter(
+            cols_expr_plan(t, &["id"], &accessor),
+            tab(t),
+            or(
+                gte(column(t, "balance", &accessor), const_uint64(u64::MAX)),
+                equal(column(t, "balance", &accessor), const_uint64(0)),
+            ),
+        ),
+        vec![],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_mix_uint64_with_signed_operands() {
+    let t = "sxt.accounts".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "id".into() => ColumnType::BigInt,
+            "balance".into() => ColumnType::UInt64,
+        },
+    );
+    for query in [
+        "select id from accounts where balance = id",
+        "select id from accounts where balance >= -1",
+        "select id from accounts where balance <= 18446744073709551616",
+        "select id from accounts where balance >= 1.5",
+        "select balance + 1 as b from accounts",
+        "select sum(balance) as s from accounts",
+    ] {
+        let intermediate_ast = SelectStatementParser::new().parse(query).unwrap();
+        assert!(
+            QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor).is_err(),
+            "{query}"
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use super::{query_expr::build_query_context, ConversionResult};
+use crate::base::{
+    database::{SchemaAccessor, TableRef},
+    map::IndexSet,
+};
+use proof_of_sql_parser::SelectStatement;
+use sqlparser::ast::Ident;
+
+/// Returns the source columns that a query reads, e.g. so that permissions can be enforced
+/// before the query is proven.
+///
+/// Columns referenced anywhere in the query are included: in the `SELECT` list, including the
+/// columns of a `*` that are not excluded, and in the `WHERE` and `GROUP BY` clauses. `ORDER BY`
+/// references output columns, so it adds no further source columns. Aliases are resolved like
+/// [`QueryExpr::try_new`](super::QueryExpr::try_new) resolves them. This only resolves and type
+/// checks the query, without building its proof plan.
+///
+/// # Errors
+/// Returns an error if the query references a table or column that does not exist, or if it is
+/// otherwise invalid.
+pub fn get_referenced_columns(
+    ast: &SelectStatement,
+    default_schema: Ident,
+    schema_accessor: &dyn SchemaAccessor,
+) -> ConversionResult<IndexSet<(TableRef, Ident)>> {
+    let context = build_query_context(ast.clone(), default_schema, schema_accessor)?;
+    Ok(context
+        .get_column_mapping()
+        .into_values()
+        .map(|column_ref| (column_ref.table_ref(), column_ref.column_id()))
+        .collect())
+}
@@ -0,0 +1,106 @@
+use super::{get_referenced_columns, ConversionError};
+use crate::base::{
+    database::{ColumnType, TableRef, TestSchemaAccessor},
+    map::{indexmap, IndexSet},
+};
+use sqlparser::ast::Ident;
+
+fn accessor() -> TestSchemaAccessor {
+    TestSchemaAccessor::new(indexmap! {
+        "sxt.t".parse().unwrap() => indexmap! {
+            "a".into() => ColumnType::BigInt,
+            "b".into() => ColumnType::BigInt,
+            "c".into() => ColumnType::Int,
+            "d".into() => ColumnType::VarChar,
+            "e".into() => ColumnType::Boolean,
+        },
+        "other.u".parse().unwrap() => indexmap! {
+            "x".into() => ColumnType::BigInt,
+            "y".into() => ColumnType::VarChar,
+        },
+    })
+}
+
+fn referenced_columns(query: &str) -> IndexSet<(TableRef, Ident)> {
+    get_referenced_columns(&query.parse().unwrap(), "sxt".into(), &accessor()).unwrap()
+}
+
+fn columns(table: &str, names: &[&str]) -> IndexSet<(TableRef, Ident)> {
+    let table_ref: TableRef = table.parse().unwrap();
+    names.iter().map(|&name| (table_ref, name.into())).collect()
+}
+
+#[test]
+fn we_can_get_the_columns_referenced_by_select_and_where_clauses() {
+    assert_eq!(
+        referenced_columns("select a, b + c as s from t where e"),
+        columns("sxt.t", &["a", "b", "c", "e"])
+    );
+    assert_eq!(
+        referenced_columns("select a from t where d = 'x' and not e"),
+        columns("sxt.t", &["a", "d", "e"])
+    );
+    assert_eq!(
+        referenced_columns("select count(*) as n from t"),
+        IndexSet::default()
+    );
+}
+
+#[test]
+fn we_can_get_the_columns_referenced_by_a_wildcard() {
+    assert_eq!(
+        referenced_columns("select * from t"),
+        columns("sxt.t", &["a", "b", "c", "d", "e"])
+    );
+    assert_eq!(
+        referenced_columns("select * exclude (b, d) from t"),
+        columns("sxt.t", &["a", "c", "e"])
+    );
+    // An excluded column is still read if the filter references it
+    assert_eq!(
+        referenced_columns("select * exclude (b) from t where b = 1"),
+        columns("sxt.t", &["a", "b", "c", "d", "e"])
+    );
+}
+
+#[test]
+fn we_can_get_the_columns_referenced_by_group_by_and_order_by_clauses() {
+    assert_eq!(
+        referenced_columns("select a as k, sum(b) as total from t group by k order by total"),
+        columns("sxt.t", &["a", "b"])
+    );
+    assert_eq!(
+        referenced_columns("select d, count(c) as n from t where a > 0 group by d order by d"),
+        columns("sxt.t", &["d", "c", "a"])
+    );
+    // ORDER BY references the output alias `b`, not the source column `b`
+    assert_eq!(
+        referenced_columns("select a as b from t order by b"),
+        columns("sxt.t", &["a"])
+    );
+}
+
+#[test]
+fn we_can_get_the_columns_referenced_in_a_table_of_another_schema() {
+    assert_eq!(
+        referenced_columns("select y from other.u where x = 2"),
+        columns("other.u", &["y", "x"])
+    );
+}
+
+#[test]
+fn we_cannot_get_the_columns_referenced_by_an_invalid_query() {
+    let accessor = accessor();
+    assert!(matches!(
+        get_referenced_columns(&"select z from t".parse().unwrap(), "sxt".into(), &accessor),
+        Err(ConversionError::MissingColumn { .. })
+    ));
+    assert!(matches!(
+        get_referenced_columns(
+            &"select a from missing".parse().unwrap(),
+            "sxt".into(),
+            &accessor
+        ),
+        Err(ConversionError::UnknownTable { .. })
+    ));
+}
@@ -11,6 +11,22 @@ pub enum PostprocessingError {
         /// The overflowing index value
         index: i128,
     },
+    /// A query parameter is referenced but not bound
+    #[snafu(display("Query parameter ${index} is not bound"))]
+    UnboundQueryParameter {
+        /// The 1-based index of the parameter
+        index: usize,
+    },
+    /// A query parameter of a `LIMIT` or `OFFSET` clause is not a non-negative integer
+    #[snafu(display(
+        "Query parameter ${index} of a LIMIT or OFFSET clause must be a non-negative integer, got {value}"
+    ))]
+    InvalidSliceParameter {
+        /// The 1-based index of the parameter
+        index: usize,
+        /// The value of the parameter
+        value: String,
+    },
     /// Column not found
     #[snafu(display("Column not found: {column}"))]
     ColumnNotFound {
@@ -53,6 +69,18 @@ pub enum PostprocessingError {
         /// The underlying source error
         source: crate::base::database::OwnedColumnError,
     },
+    /// An aggregation cannot be computed on its input
+    #[snafu(display("Invalid aggregation: {error}"))]
+    InvalidAggregation {
+        /// The underlying error message
+        error: String,
+    },
+    /// A percentile is not between 0 and 100
+    #[snafu(display("Percentile must be between 0 and 100, got {percentile}"))]
+    InvalidPercentile {
+        /// The invalid percentile
+        percentile: u8,
+    },
     /// Nested aggregation in `GROUP BY` clause
     #[snafu(display("Nested aggregation in `GROUP BY` clause: {error}"))]
     NestedAggregationInGroupByClause {
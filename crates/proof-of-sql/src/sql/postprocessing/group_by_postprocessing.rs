@@ -1,6 +1,11 @@
 use super::{PostprocessingError, PostprocessingResult, PostprocessingStep};
 use crate::base::{
-    database::{group_by_util::aggregate_columns, Column, OwnedColumn, OwnedTable},
+    database::{
+        group_by_util::{
+            aggregate_columns, var_pop_column_type, var_pop_from_sums, var_pop_squares,
+        },
+        Column, OwnedColumn, OwnedTable,
+    },
     map::{indexmap, IndexMap, IndexSet},
     scalar::Scalar,
 };
@@ -39,7 +44,9 @@ fn contains_nested_aggregation(expr: &Expression, is_agg: bool) -> bool {
         Expression::Binary { left, right, .. } => {
             contains_nested_aggregation(left, is_agg) || contains_nested_aggregation(right, is_agg)
         }
-        Expression::Unary { expr, .. } => contains_nested_aggregation(expr, is_agg),
+        Expression::Unary { expr, .. }
+        | Expression::StringTransform { expr, .. }
+        | Expression::Substring { expr, .. } => contains_nested_aggregation(expr, is_agg),
     }
 }
 
@@ -56,7 +63,9 @@ fn get_free_identifiers_from_expr(expr: &Expression) -> IndexSet<Ident> {
             left_identifiers.extend(right_identifiers);
             left_identifiers
         }
-        Expression::Unary { expr, .. } => get_free_identifiers_from_expr(expr),
+        Expression::Unary { expr, .. }
+        | Expression::StringTransform { expr, .. }
+        | Expression::Substring { expr, .. } => get_free_identifiers_from_expr(expr),
     }
 }
 
@@ -118,6 +127,25 @@ fn get_aggregate_and_remainder_expressions(
                 expr: Box::new(remainder?),
             })
         }
+        Expression::StringTransform { op, expr } => {
+            let remainder = get_aggregate_and_remainder_expressions(*expr, aggregation_expr_map);
+            Ok(Expression::StringTransform {
+                op,
+                expr: Box::new(remainder?),
+            })
+        }
+        Expression::Substring {
+            expr,
+            start,
+            length,
+        } => {
+            let remainder = get_aggregate_and_remainder_expressions(*expr, aggregation_expr_map);
+            Ok(Expression::Substring {
+                expr: Box::new(remainder?),
+                start,
+                length,
+            })
+        }
     }
 }
 
@@ -269,14 +297,43 @@ impl<S: Scalar> PostprocessingStep<S> for GroupByPostprocessing {
                     .map(|(id, c)| (id.clone(), Column::<S>::from_owned_column(c, &alloc)))
                     .unzip()
             });
+        // `VAR_POP(x)` is computed from `SUM(x)`, `SUM(x * x)` and `COUNT(*)`, so we sum `x` and its
+        // squares along with the other sums and combine them after aggregating.
+        let (var_pop_identifiers, var_pop_columns): (Vec<_>, Vec<_>) = evaluated_columns
+            .get(&AggregationOperator::VarPop)
+            .map_or((vec![], vec![]), |tuple| {
+                tuple
+                    .iter()
+                    .map(|(id, c)| (id.clone(), Column::<S>::from_owned_column(c, &alloc)))
+                    .unzip()
+            });
+        let var_pop_sum_columns = var_pop_columns
+            .iter()
+            .zip(&var_pop_identifiers)
+            .map(|(column, id)| -> PostprocessingResult<_> {
+                if !column.column_type().is_numeric() {
+                    return Err(PostprocessingError::InvalidAggregation {
+                        error: format!("var_pop can not be applied to {}", column.column_type()),
+                    });
+                }
+                let squares = var_pop_squares(&alloc, &column.to_scalar_with_scaling(0))
+                    .ok_or_else(|| PostprocessingError::InvalidAggregation {
+                        error: format!("var_pop of {id} is out of range"),
+                    })?;
+                Ok([*column, Column::Scalar(squares)])
+            })
+            .flatten_ok()
+            .collect::<PostprocessingResult<Vec<_>>>()?;
         let aggregation_results = aggregate_columns(
             &alloc,
             &group_by_ins,
-            &sum_columns,
+            &[sum_columns.as_slice(), var_pop_sum_columns.as_slice()].concat(),
             &max_columns,
             &min_columns,
             &selection_in,
         )?;
+        let (sum_results, var_pop_sum_results) =
+            aggregation_results.sum_columns.split_at(sum_columns.len());
         // Finally do another round of evaluation to get the final result
         // Gather the results into a new OwnedTable
         let group_by_outs = aggregation_results
@@ -284,17 +341,13 @@ impl<S: Scalar> PostprocessingStep<S> for GroupByPostprocessing {
             .iter()
             .zip(self.group_by_identifiers.iter())
             .map(|(column, id)| Ok((id.clone(), OwnedColumn::from(column))));
-        let sum_outs = izip!(
-            aggregation_results.sum_columns,
-            sum_identifiers,
-            sum_columns,
-        )
-        .map(|(c_out, id, c_in)| {
-            Ok((
-                id,
-                OwnedColumn::try_from_scalars(c_out, c_in.column_type())?,
-            ))
-        });
+        let sum_outs =
+            izip!(sum_results, sum_identifiers, sum_columns,).map(|(c_out, id, c_in)| {
+                Ok((
+                    id,
+                    OwnedColumn::try_from_scalars(c_out, c_in.column_type())?,
+                ))
+            });
         let max_outs = izip!(
             aggregation_results.max_columns,
             max_identifiers,
@@ -317,6 +370,30 @@ impl<S: Scalar> PostprocessingStep<S> for GroupByPostprocessing {
                 OwnedColumn::try_from_option_scalars(c_out, c_in.column_type())?,
             ))
         });
+        let var_pop_outs = izip!(
+            var_pop_sum_results.chunks(2),
+            var_pop_identifiers,
+            var_pop_columns,
+        )
+        .map(|(sums, id, c_in)| -> PostprocessingResult<_> {
+            let column_type = var_pop_column_type(c_in.column_type())
+                .expect("the input type was checked to be numeric");
+            let scale_increase = column_type.scale().expect("decimals have a scale")
+                - 2 * c_in
+                    .column_type()
+                    .scale()
+                    .expect("numeric types have a scale");
+            let variances = var_pop_from_sums(
+                sums[0],
+                sums[1],
+                aggregation_results.count_column,
+                scale_increase,
+            )
+            .ok_or_else(|| PostprocessingError::InvalidAggregation {
+                error: format!("var_pop of {id} is out of range"),
+            })?;
+            Ok((id, OwnedColumn::try_from_scalars(&variances, column_type)?))
+        });
         //TODO: When we have NULLs we need to differentiate between count(1) and count(expression)
         let count_column = OwnedColumn::BigInt(aggregation_results.count_column.to_vec());
         let count_outs = evaluated_columns
@@ -329,6 +406,7 @@ impl<S: Scalar> PostprocessingStep<S> for GroupByPostprocessing {
             .chain(sum_outs)
             .chain(max_outs)
             .chain(min_outs)
+            .chain(var_pop_outs)
             .chain(count_outs)
             .process_results(|iter| OwnedTable::try_from_iter(iter))??;
         // If there are no columns at all we need to have the count column so that we can handle
@@ -202,6 +202,96 @@ fn we_can_do_simple_group_bys() {
 }
 
 #[allow(clippy::too_many_lines)]
+#[test]
+fn we_can_compute_population_variances_in_group_bys() {
+    // SELECT sector, VAR_POP(price) AS v, VAR_POP(d) AS vd, VAR_POP(price) * 9 AS v9 FROM tab GROUP BY sector
+    let table: OwnedTable<Curve25519Scalar> = owned_table([
+        varchar("sector", ["a", "b", "a", "b", "a"]),
+        bigint("price", [1_i64, 10, 2, 10, 4]),
+        decimal75("d", 5, 1, [15_i64, 20, 25, 40, 35]),
+    ]);
+    let postprocessing: [OwnedTablePostprocessing; 1] = [group_by_postprocessing(
+        &["sector"],
+        &[
+            aliased_expr(col("sector"), "sector"),
+            aliased_expr(var_pop(col("price")), "v"),
+            aliased_expr(var_pop(col("d")), "vd"),
+            aliased_expr(mul(var_pop(col("price")), lit(9)), "v9"),
+        ],
+    )];
+    // For sector a, VAR_POP(price) = 14 / 9 and VAR_POP(d) = 2 / 3
+    let expected_table = owned_table([
+        varchar("sector", ["a", "b"]),
+        decimal75("v", 44, 6, [1_555_556_i64, 0]),
+        decimal75("vd", 16, 8, [66_666_667_i64, 100_000_000]),
+        decimal75("v9", 64, 6, [14_000_004_i64, 0]),
+    ]);
+    let actual_table = apply_postprocessing_steps(table, &postprocessing).unwrap();
+    assert_eq!(actual_table, expected_table);
+}
+
+#[test]
+fn we_cannot_compute_the_population_variance_of_non_numeric_columns() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([
+        varchar("sector", ["a", "b"]),
+        boolean("flag", [true, false]),
+    ]);
+    let postprocessing: [OwnedTablePostprocessing; 1] = [group_by_postprocessing(
+        &[],
+        &[aliased_expr(var_pop(col("flag")), "v")],
+    )];
+    assert!(matches!(
+        apply_postprocessing_steps(table, &postprocessing),
+        Err(PostprocessingError::InvalidAggregation { .. })
+    ));
+}
+
+#[test]
+fn we_cannot_compute_the_population_variance_of_values_whose_squares_overflow() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([
+        varchar("sector", ["a", "a", "b"]),
+        int128("small", [1_i128 << 125, -(1_i128 << 125), 0]),
+        int128("large", [i128::MAX, 0, i128::MIN]),
+    ]);
+    let postprocessing: [OwnedTablePostprocessing; 1] = [group_by_postprocessing(
+        &["sector"],
+        &[aliased_expr(var_pop(col("small")), "v")],
+    )];
+    // 3 * (2^125)^2 exceeds the largest signed scalar even though no single group does
+    assert!(matches!(
+        apply_postprocessing_steps(table.clone(), &postprocessing),
+        Err(PostprocessingError::InvalidAggregation { .. })
+    ));
+    let postprocessing: [OwnedTablePostprocessing; 1] = [group_by_postprocessing(
+        &["sector"],
+        &[aliased_expr(var_pop(col("large")), "v")],
+    )];
+    assert!(matches!(
+        apply_postprocessing_steps(table, &postprocessing),
+        Err(PostprocessingError::InvalidAggregation { .. })
+    ));
+}
+
+#[test]
+fn we_can_compute_the_population_variance_of_large_int128_values() {
+    let table: OwnedTable<Curve25519Scalar> =
+        owned_table([int128("a", [1_i128 << 115, -(1_i128 << 115)])]);
+    let postprocessing: [OwnedTablePostprocessing; 1] = [group_by_postprocessing(
+        &[],
+        &[aliased_expr(var_pop(col("a")), "v")],
+    )];
+    // VAR_POP = (2^115)^2, which only fits in a scalar once squared
+    let value = Curve25519Scalar::from(1_i128 << 115);
+    let expected_table = owned_table([decimal75(
+        "v",
+        75,
+        6,
+        [value * value * Curve25519Scalar::from(1_000_000)],
+    )]);
+    let actual_table = apply_postprocessing_steps(table, &postprocessing).unwrap();
+    assert_eq!(actual_table, expected_table);
+}
+
 #[test]
 fn we_can_do_complex_group_bys() {
     // SELECT 2 * MAX(2 * a + 1) as max_a, MIN(b + 4) - 2.4 as min_b, SUM(c * 1.4) as sum_c, COUNT(d) + 3 as count_d FROM tab
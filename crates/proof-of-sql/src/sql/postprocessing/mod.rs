@@ -29,3 +29,8 @@ mod slice_postprocessing;
 pub use slice_postprocessing::SlicePostprocessing;
 #[cfg(test)]
 mod slice_postprocessing_test;
+
+mod percentile_expr;
+pub use percentile_expr::PercentileExpr;
+#[cfg(test)]
+mod percentile_expr_test;
@@ -68,6 +68,32 @@ fn we_can_transform_a_result_ordering_by_the_second_column_then_the_first_column
     assert_eq!(actual_table, expected_table);
 }
 
+#[test]
+fn we_can_order_by_two_columns_in_opposite_directions_keeping_ties_in_input_order() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([
+        varchar("dept", ["eng", "ops", "eng", "ops", "eng", "eng", "ops"]),
+        bigint("salary", [100_i64, 90, 120, 90, 100, 80, 95]),
+        int("id", [1, 2, 3, 4, 5, 6, 7]),
+    ]);
+    let postprocessing: [OwnedTablePostprocessing; 1] = [orders(&["dept", "salary"], &[Asc, Desc])];
+    let expected_table: OwnedTable<Curve25519Scalar> = owned_table([
+        varchar("dept", ["eng", "eng", "eng", "eng", "ops", "ops", "ops"]),
+        bigint("salary", [120_i64, 100, 100, 80, 95, 90, 90]),
+        int("id", [3, 1, 5, 6, 7, 2, 4]),
+    ]);
+    let actual_table = apply_postprocessing_steps(table.clone(), &postprocessing).unwrap();
+    assert_eq!(actual_table, expected_table);
+
+    let postprocessing: [OwnedTablePostprocessing; 1] = [orders(&["salary", "dept"], &[Desc, Asc])];
+    let expected_table: OwnedTable<Curve25519Scalar> = owned_table([
+        varchar("dept", ["eng", "eng", "eng", "ops", "ops", "ops", "eng"]),
+        bigint("salary", [120_i64, 100, 100, 95, 90, 90, 80]),
+        int("id", [3, 1, 5, 7, 2, 4, 6]),
+    ]);
+    let actual_table = apply_postprocessing_steps(table, &postprocessing).unwrap();
+    assert_eq!(actual_table, expected_table);
+}
+
 #[test]
 fn we_can_use_int128_columns_inside_order_by_in_desc_order() {
     let s = [
@@ -1,8 +1,11 @@
 use super::{
-    GroupByPostprocessing, OrderByPostprocessing, PostprocessingResult, PostprocessingStep,
-    SelectPostprocessing, SlicePostprocessing,
+    GroupByPostprocessing, OrderByPostprocessing, PercentileExpr, PostprocessingResult,
+    PostprocessingStep, SelectPostprocessing, SlicePostprocessing,
+};
+use crate::base::{
+    database::{LiteralValue, OwnedTable},
+    scalar::Scalar,
 };
-use crate::base::{database::OwnedTable, scalar::Scalar};
 use serde::{Deserialize, Serialize};
 
 /// An enum for nodes that can apply postprocessing to a `OwnedTable`.
@@ -16,6 +19,8 @@ pub enum OwnedTablePostprocessing {
     Select(SelectPostprocessing),
     /// Aggregate the `OwnedTable` with the given `GroupByPostprocessing`.
     GroupBy(GroupByPostprocessing),
+    /// Aggregate the `OwnedTable` with the given `PercentileExpr`.
+    Percentile(PercentileExpr),
 }
 
 impl<S: Scalar> PostprocessingStep<S> for OwnedTablePostprocessing {
@@ -26,6 +31,9 @@ impl<S: Scalar> PostprocessingStep<S> for OwnedTablePostprocessing {
             OwnedTablePostprocessing::OrderBy(order_by_expr) => order_by_expr.apply(owned_table),
             OwnedTablePostprocessing::Select(select_expr) => select_expr.apply(owned_table),
             OwnedTablePostprocessing::GroupBy(group_by_expr) => group_by_expr.apply(owned_table),
+            OwnedTablePostprocessing::Percentile(percentile_expr) => {
+                percentile_expr.apply(owned_table)
+            }
         }
     }
 }
@@ -51,6 +59,24 @@ impl OwnedTablePostprocessing {
     pub fn new_group_by(group_by_postprocessing: GroupByPostprocessing) -> Self {
         Self::GroupBy(group_by_postprocessing)
     }
+    /// Create a new `OwnedTablePostprocessing` with the given `PercentileExpr`.
+    #[must_use]
+    pub fn new_percentile(percentile_expr: PercentileExpr) -> Self {
+        Self::Percentile(percentile_expr)
+    }
+    /// Resolve the query parameters of this step against `params`, where `$1` is `params[0]`.
+    ///
+    /// # Errors
+    /// Returns an error if a parameter can not be bound, see
+    /// [`SlicePostprocessing::try_bind_parameters`].
+    pub fn try_bind_parameters(&self, params: &[LiteralValue]) -> PostprocessingResult<Self> {
+        Ok(match self {
+            OwnedTablePostprocessing::Slice(slice_expr) => {
+                Self::Slice(slice_expr.try_bind_parameters(params)?)
+            }
+            _ => self.clone(),
+        })
+    }
 }
 
 /// Apply a list of postprocessing steps to an `OwnedTable`.
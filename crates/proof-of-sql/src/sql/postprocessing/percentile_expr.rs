@@ -0,0 +1,182 @@
+use super::{PostprocessingError, PostprocessingResult, PostprocessingStep};
+use crate::base::{
+    database::{
+        group_by_util::aggregate_columns, order_by_util::compare_indexes_by_columns, Column,
+        ColumnType, OwnedColumn, OwnedTable,
+    },
+    math::decimal::{Precision, MAX_SUPPORTED_PRECISION},
+    scalar::Scalar,
+};
+use alloc::{format, string::ToString, vec, vec::Vec};
+use bumpalo::Bump;
+use serde::{Deserialize, Serialize};
+use sqlparser::ast::Ident;
+
+/// The number of digits by which the scale of a percentile exceeds the scale of its input.
+///
+/// Percentiles are given in whole percents, so interpolating between two values never needs
+/// more than two extra digits.
+const PERCENTILE_SCALE_INCREASE: u8 = 2;
+
+/// A postprocessing aggregate computing a percentile, e.g. the median, of a column.
+///
+/// Unlike the aggregates of [`GroupByPostprocessing`](super::GroupByPostprocessing), a
+/// percentile can not be computed from sums, so it needs every value of a group. This step
+/// therefore operates on the final result set that it is applied to: the groups are formed from
+/// the rows of that table, and every one of those rows is part of the verified result. If the
+/// column or a group by column is not in the result, e.g. because the query already aggregated
+/// it away, the step fails instead of computing a percentile from partial data.
+///
+/// The percentile is interpolated linearly between the two closest ranks, like
+/// `PERCENTILE_CONT`. The result is a decimal whose precision and scale are two more than those
+/// of the input, so that it is exact. The output table has the group by columns followed by the
+/// percentile, with one row per group in ascending order of the groups.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PercentileExpr {
+    /// The columns whose values form the groups
+    group_by_identifiers: Vec<Ident>,
+    /// The column of which to compute the percentile
+    column: Ident,
+    /// The percentile, between 0 and 100
+    percentile: u8,
+    /// The name of the percentile in the output
+    alias: Ident,
+}
+
+impl PercentileExpr {
+    /// Create a new percentile of `column` for each group of `group_by`, where `percentile` is
+    /// in whole percents.
+    ///
+    /// # Errors
+    /// Returns an error if `percentile` is more than 100.
+    pub fn try_new(
+        group_by: Vec<Ident>,
+        column: Ident,
+        percentile: u8,
+        alias: Ident,
+    ) -> PostprocessingResult<Self> {
+        if percentile > 100 {
+            return Err(PostprocessingError::InvalidPercentile { percentile });
+        }
+        Ok(Self {
+            group_by_identifiers: group_by,
+            column,
+            percentile,
+            alias,
+        })
+    }
+
+    /// Create a new median of `column` for each group of `group_by`.
+    #[must_use]
+    pub fn median(group_by: Vec<Ident>, column: Ident, alias: Ident) -> Self {
+        Self {
+            group_by_identifiers: group_by,
+            column,
+            percentile: 50,
+            alias,
+        }
+    }
+
+    /// Get the percentile, in whole percents
+    #[must_use]
+    pub fn percentile(&self) -> u8 {
+        self.percentile
+    }
+}
+
+/// The type of a percentile of a column of type `column_type`, if it has one.
+fn percentile_column_type(column_type: ColumnType) -> Option<ColumnType> {
+    if !column_type.is_numeric() {
+        return None;
+    }
+    let precision = column_type
+        .precision_value()?
+        .checked_add(PERCENTILE_SCALE_INCREASE)
+        .filter(|&precision| precision <= MAX_SUPPORTED_PRECISION)?;
+    let scale = column_type
+        .scale()?
+        .checked_add(PERCENTILE_SCALE_INCREASE.try_into().ok()?)?;
+    Some(ColumnType::Decimal75(
+        Precision::new(precision).ok()?,
+        scale,
+    ))
+}
+
+impl<S: Scalar> PostprocessingStep<S> for PercentileExpr {
+    /// Apply the percentile aggregation to the given `OwnedTable`.
+    fn apply(&self, owned_table: OwnedTable<S>) -> PostprocessingResult<OwnedTable<S>> {
+        let alloc = Bump::new();
+        let get_column = |id: &Ident| {
+            owned_table
+                .inner_table()
+                .get(id)
+                .map(|column| Column::<S>::from_owned_column(column, &alloc))
+                .ok_or(PostprocessingError::ColumnNotFound {
+                    column: id.to_string(),
+                })
+        };
+        let group_by_ins = self
+            .group_by_identifiers
+            .iter()
+            .map(get_column)
+            .collect::<PostprocessingResult<Vec<_>>>()?;
+        let column = get_column(&self.column)?;
+        let column_type = percentile_column_type(column.column_type()).ok_or_else(|| {
+            PostprocessingError::InvalidAggregation {
+                error: format!("percentile can not be applied to {}", column.column_type()),
+            }
+        })?;
+        // Sort the rows by group and then by value, so that every group is a sorted run of rows.
+        // The groups are in the same order as those of `aggregate_columns`.
+        let sort_columns = [group_by_ins.as_slice(), &[column]].concat();
+        let mut indexes: Vec<usize> = (0..owned_table.num_rows()).collect();
+        indexes.sort_unstable_by(|&a, &b| compare_indexes_by_columns(&sort_columns, a, b));
+        let aggregation_results = aggregate_columns(
+            &alloc,
+            &group_by_ins,
+            &[],
+            &[],
+            &[],
+            &vec![true; owned_table.num_rows()],
+        )?;
+        let values = column.to_scalar_with_scaling(0);
+        let hundred = S::from(100);
+        let mut group_start = 0;
+        let percentiles: Vec<S> = aggregation_results
+            .count_column
+            .iter()
+            .map(|&count| {
+                let count = usize::try_from(count).expect("counts are non-negative");
+                let group = &indexes[group_start..group_start + count];
+                group_start += count;
+                // The percentile is at rank `percentile * (count - 1) / 100`, which we split into
+                // a whole rank and a fraction in percents.
+                let rank = usize::from(self.percentile) * (count - 1);
+                let (lower, fraction) = (rank / 100, rank % 100);
+                let lower_value = values[group[lower]];
+                if fraction == 0 {
+                    lower_value * hundred
+                } else {
+                    let upper_value = values[group[lower + 1]];
+                    lower_value * hundred
+                        + (upper_value - lower_value)
+                            * S::from(
+                                i64::try_from(fraction).expect("the fraction is less than 100"),
+                            )
+                }
+            })
+            .collect();
+        let group_by_outs = aggregation_results
+            .group_by_columns
+            .iter()
+            .zip(&self.group_by_identifiers)
+            .map(|(column, id)| (id.clone(), OwnedColumn::from(column)));
+        let percentile_out = (
+            self.alias.clone(),
+            OwnedColumn::try_from_scalars(&percentiles, column_type)?,
+        );
+        Ok(OwnedTable::try_from_iter(
+            group_by_outs.chain(core::iter::once(percentile_out)),
+        )?)
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{
+    base::{
+        database::{owned_table_utility::*, OwnedTable},
+        scalar::Curve25519Scalar,
+    },
+    sql::postprocessing::{
+        apply_postprocessing_steps, test_utility::group_by_postprocessing,
+        OwnedTablePostprocessing, PercentileExpr, PostprocessingError, PostprocessingStep,
+    },
+};
+use proof_of_sql_parser::utility::{aliased_expr, col, sum};
+
+#[test]
+fn we_can_compute_the_median_of_an_odd_number_of_values() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([bigint("b", [7_i64, 1, 5])]);
+    let median = PercentileExpr::median(vec![], "b".into(), "m".into());
+    let expected_table = owned_table([decimal75("m", 21, 2, [500])]);
+    assert_eq!(median.apply(table).unwrap(), expected_table);
+}
+
+#[test]
+fn we_can_compute_the_median_of_an_even_number_of_values() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([bigint("b", [4_i64, 1, 3, 2])]);
+    let median = PercentileExpr::median(vec![], "b".into(), "m".into());
+    let expected_table = owned_table([decimal75("m", 21, 2, [250])]);
+    assert_eq!(median.apply(table).unwrap(), expected_table);
+}
+
+#[test]
+fn we_can_compute_the_90th_percentile() {
+    let table: OwnedTable<Curve25519Scalar> =
+        owned_table([int("b", [3, 10, 1, 7, 2, 9, 4, 8, 6, 5])]);
+    let p90 = PercentileExpr::try_new(vec![], "b".into(), 90, "p90".into()).unwrap();
+    let expected_table = owned_table([decimal75("p90", 12, 2, [910])]);
+    assert_eq!(p90.apply(table).unwrap(), expected_table);
+}
+
+#[test]
+fn we_can_compute_the_extreme_percentiles() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([bigint("b", [4_i64, -1, 3])]);
+    let p0 = PercentileExpr::try_new(vec![], "b".into(), 0, "p".into()).unwrap();
+    let p100 = PercentileExpr::try_new(vec![], "b".into(), 100, "p".into()).unwrap();
+    assert_eq!(
+        p0.apply(table.clone()).unwrap(),
+        owned_table([decimal75("p", 21, 2, [-100])])
+    );
+    assert_eq!(
+        p100.apply(table).unwrap(),
+        owned_table([decimal75("p", 21, 2, [400])])
+    );
+}
+
+#[test]
+fn we_can_compute_the_median_of_negative_decimals() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([decimal75("d", 5, 1, [25, -15])]);
+    let median = PercentileExpr::median(vec![], "d".into(), "m".into());
+    let expected_table = owned_table([decimal75("m", 7, 3, [500])]);
+    assert_eq!(median.apply(table).unwrap(), expected_table);
+}
+
+#[test]
+fn we_can_compute_the_median_of_each_group() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([
+        bigint("a", [2_i64, 1, 2, 1, 1, 3]),
+        bigint("b", [40_i64, 30, 20, 10, 20, 5]),
+        varchar("c", ["v", "w", "x", "y", "z", "zz"]),
+    ]);
+    let postprocessing = [OwnedTablePostprocessing::new_percentile(
+        PercentileExpr::median(vec!["a".into()], "b".into(), "m".into()),
+    )];
+    let expected_table = owned_table([
+        bigint("a", [1_i64, 2, 3]),
+        decimal75("m", 21, 2, [2000, 3000, 500]),
+    ]);
+    assert_eq!(
+        apply_postprocessing_steps(table, &postprocessing).unwrap(),
+        expected_table
+    );
+}
+
+#[test]
+fn we_get_no_percentiles_for_an_empty_result() {
+    let table: OwnedTable<Curve25519Scalar> =
+        owned_table([bigint("a", [0_i64; 0]), bigint("b", [0_i64; 0])]);
+    let expected_table = owned_table([bigint("a", [0_i64; 0]), decimal75("m", 21, 2, [0; 0])]);
+    let median = PercentileExpr::median(vec!["a".into()], "b".into(), "m".into());
+    assert_eq!(median.apply(table).unwrap(), expected_table);
+}
+
+#[test]
+fn we_cannot_create_a_percentile_above_100() {
+    assert_eq!(
+        PercentileExpr::try_new(vec![], "b".into(), 101, "p".into()),
+        Err(PostprocessingError::InvalidPercentile { percentile: 101 })
+    );
+}
+
+#[test]
+fn we_cannot_compute_a_percentile_of_a_column_not_in_the_result() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([bigint("a", [1_i64, 2])]);
+    let median = PercentileExpr::median(vec![], "b".into(), "m".into());
+    assert_eq!(
+        median.apply(table.clone()),
+        Err(PostprocessingError::ColumnNotFound {
+            column: "b".to_string()
+        })
+    );
+    let median = PercentileExpr::median(vec!["c".into()], "a".into(), "m".into());
+    assert_eq!(
+        median.apply(table),
+        Err(PostprocessingError::ColumnNotFound {
+            column: "c".to_string()
+        })
+    );
+}
+
+#[test]
+fn we_cannot_compute_a_percentile_of_a_column_that_is_aggregated_away() {
+    // SELECT a, SUM(b) AS s FROM tab GROUP BY a leaves no `b` to take the median of.
+    let table: OwnedTable<Curve25519Scalar> =
+        owned_table([bigint("a", [1_i64, 1, 2]), bigint("b", [1_i64, 2, 3])]);
+    let postprocessing = [
+        group_by_postprocessing(
+            &["a"],
+            &[
+                aliased_expr(col("a"), "a"),
+                aliased_expr(sum(col("b")), "s"),
+            ],
+        ),
+        OwnedTablePostprocessing::new_percentile(PercentileExpr::median(
+            vec!["a".into()],
+            "b".into(),
+            "m".into(),
+        )),
+    ];
+    assert_eq!(
+        apply_postprocessing_steps(table, &postprocessing),
+        Err(PostprocessingError::ColumnNotFound {
+            column: "b".to_string()
+        })
+    );
+}
+
+#[test]
+fn we_cannot_compute_a_percentile_of_a_non_numeric_column() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([varchar("b", ["x", "y"])]);
+    let median = PercentileExpr::median(vec![], "b".into(), "m".into());
+    assert!(matches!(
+        median.apply(table),
+        Err(PostprocessingError::InvalidAggregation { .. })
+    ));
+}
+
+#[test]
+fn we_cannot_compute_a_percentile_that_exceeds_the_max_precision() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([decimal75("d", 74, 0, [1, 2])]);
+    let median = PercentileExpr::median(vec![], "d".into(), "m".into());
+    assert!(matches!(
+        median.apply(table),
+        Err(PostprocessingError::InvalidAggregation { .. })
+    ));
+}
@@ -1,5 +1,10 @@
 use super::{PostprocessingError, PostprocessingResult, PostprocessingStep};
-use crate::base::{database::OwnedTable, scalar::Scalar};
+use crate::base::{
+    database::{LiteralValue, OwnedTable},
+    scalar::Scalar,
+};
+use alloc::format;
+use proof_of_sql_parser::intermediate_ast::SliceValue;
 use serde::{Deserialize, Serialize};
 
 /// A `SlicePostprocessing` represents a slice of an `OwnedTable`.
@@ -8,7 +13,7 @@ pub struct SlicePostprocessing {
     /// number of rows to return
     ///
     /// - if None, specify all rows
-    number_rows: Option<u64>,
+    number_rows: Option<SliceValue<u64>>,
 
     /// number of rows to skip
     ///
@@ -16,26 +21,101 @@ pub struct SlicePostprocessing {
     /// - if Some(nonnegative), specify the offset from the beginning
     /// - if Some(negative), specify the offset from the end
     ///   (e.g. -1 is the last row, -2 is the second to last row, etc.)
-    offset_value: Option<i64>,
+    offset_value: Option<SliceValue<i64>>,
 }
 
 impl SlicePostprocessing {
     /// Create a new `SlicePostprocessing` with the given `number_rows` and `offset`.
     #[must_use]
     pub fn new(number_rows: Option<u64>, offset_value: Option<i64>) -> Self {
+        Self {
+            number_rows: number_rows.map(SliceValue::Literal),
+            offset_value: offset_value.map(SliceValue::Literal),
+        }
+    }
+
+    /// Create a new `SlicePostprocessing` with the given `number_rows` and `offset`, either of
+    /// which may be a query parameter.
+    #[must_use]
+    pub fn new_with_values(
+        number_rows: Option<SliceValue<u64>>,
+        offset_value: Option<SliceValue<i64>>,
+    ) -> Self {
         Self {
             number_rows,
             offset_value,
         }
     }
+
+    /// Resolve the query parameters of this slice against `params`, where `$1` is `params[0]`.
+    ///
+    /// # Errors
+    /// Returns an error if a parameter is not in `params`, or is not a non-negative integer that
+    /// fits its clause.
+    pub fn try_bind_parameters(&self, params: &[LiteralValue]) -> PostprocessingResult<Self> {
+        Ok(Self::new(
+            self.number_rows
+                .map(|value| bind_value(value, params))
+                .transpose()?,
+            self.offset_value
+                .map(|value| bind_value(value, params))
+                .transpose()?,
+        ))
+    }
+}
+
+/// Resolves a `LIMIT` or `OFFSET` value against `params`
+///
+/// A query parameter must be a non-negative integer of any integer type, `UINT64` included,
+/// that fits in `T`.
+fn bind_value<T: TryFrom<i128>>(
+    value: SliceValue<T>,
+    params: &[LiteralValue],
+) -> PostprocessingResult<T> {
+    let index = match value {
+        SliceValue::Literal(value) => return Ok(value),
+        SliceValue::Parameter(index) => index,
+    };
+    let param = index
+        .checked_sub(1)
+        .and_then(|i| params.get(i))
+        .ok_or(PostprocessingError::UnboundQueryParameter { index })?;
+    let value = match param {
+        LiteralValue::TinyInt(i) => Some(i128::from(*i)),
+        LiteralValue::SmallInt(i) => Some(i128::from(*i)),
+        LiteralValue::Int(i) => Some(i128::from(*i)),
+        LiteralValue::BigInt(i) => Some(i128::from(*i)),
+        LiteralValue::Int128(i) => Some(*i),
+        LiteralValue::UInt64(i) => Some(i128::from(*i)),
+        _ => None,
+    };
+    value
+        .filter(|&value| value >= 0)
+        .and_then(|value| T::try_from(value).ok())
+        .ok_or_else(|| PostprocessingError::InvalidSliceParameter {
+            index,
+            value: format!("{param:?}"),
+        })
+}
+
+/// The literal value of a bound `LIMIT` or `OFFSET` clause
+fn literal_value<T>(value: Option<SliceValue<T>>) -> PostprocessingResult<Option<T>> {
+    value
+        .map(|value| match value {
+            SliceValue::Literal(value) => Ok(value),
+            SliceValue::Parameter(index) => {
+                Err(PostprocessingError::UnboundQueryParameter { index })
+            }
+        })
+        .transpose()
 }
 
 impl<S: Scalar> PostprocessingStep<S> for SlicePostprocessing {
     /// Apply the slice transformation to the given `OwnedTable`.
     fn apply(&self, owned_table: OwnedTable<S>) -> PostprocessingResult<OwnedTable<S>> {
         let num_rows = owned_table.num_rows();
-        let limit = self.number_rows.unwrap_or(num_rows as u64);
-        let offset = self.offset_value.unwrap_or(0);
+        let limit = literal_value(self.number_rows)?.unwrap_or(num_rows as u64);
+        let offset = literal_value(self.offset_value)?.unwrap_or(0);
         // Be permissive with data types at first so that computation can be done.
         // If the conversion fails, we will return None.
         let possible_starting_row = if offset < 0 {
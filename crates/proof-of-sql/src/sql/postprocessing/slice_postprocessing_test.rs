@@ -1,10 +1,14 @@
 use crate::{
     base::{
-        database::{owned_table_utility::*, OwnedTable},
+        database::{owned_table_utility::*, LiteralValue, OwnedTable},
         scalar::Curve25519Scalar,
     },
-    sql::postprocessing::{apply_postprocessing_steps, test_utility::*},
+    sql::postprocessing::{
+        apply_postprocessing_steps, test_utility::*, OwnedTablePostprocessing, PostprocessingError,
+        SlicePostprocessing,
+    },
 };
+use proof_of_sql_parser::intermediate_ast::SliceValue;
 
 #[test]
 fn we_can_slice_an_owned_table_using_only_a_positive_limit_value() {
@@ -111,3 +115,95 @@ fn we_can_slice_an_owned_table_using_both_limit_and_offset_values() {
     let actual_table = apply_postprocessing_steps(table, &postprocessing).unwrap();
     assert_eq!(actual_table, expected_table);
 }
+
+#[test]
+fn we_can_bind_query_parameters_of_a_slice() {
+    let data_a = [123_i64, 342, -234, 777, 123, 34];
+    let table: OwnedTable<Curve25519Scalar> = owned_table([bigint("a", data_a.to_vec())]);
+    let postprocessing = SlicePostprocessing::new_with_values(
+        Some(SliceValue::Parameter(2)),
+        Some(SliceValue::Parameter(1)),
+    );
+    let bound = postprocessing
+        .try_bind_parameters(&[LiteralValue::Int(1), LiteralValue::BigInt(3)])
+        .unwrap();
+    assert_eq!(bound, SlicePostprocessing::new(Some(3), Some(1)));
+    let actual_table =
+        apply_postprocessing_steps(table, &[OwnedTablePostprocessing::new_slice(bound)]).unwrap();
+    assert_eq!(
+        actual_table,
+        owned_table([bigint("a", data_a[1..4].to_vec())])
+    );
+
+    // Literal values are left as they are
+    let postprocessing = SlicePostprocessing::new_with_values(Some(SliceValue::Literal(2)), None);
+    assert_eq!(
+        postprocessing.try_bind_parameters(&[]).unwrap(),
+        SlicePostprocessing::new(Some(2), None)
+    );
+}
+
+#[test]
+fn we_cannot_bind_missing_or_invalid_query_parameters_of_a_slice() {
+    let postprocessing = SlicePostprocessing::new_with_values(Some(SliceValue::Parameter(2)), None);
+    assert!(matches!(
+        postprocessing.try_bind_parameters(&[LiteralValue::BigInt(3)]),
+        Err(PostprocessingError::UnboundQueryParameter { index: 2 })
+    ));
+    for param in [
+        LiteralValue::BigInt(-1),
+        LiteralValue::Int128(i128::MAX),
+        LiteralValue::VarChar("3".into()),
+        LiteralValue::Boolean(true),
+    ] {
+        assert!(matches!(
+            postprocessing.try_bind_parameters(&[LiteralValue::BigInt(3), param]),
+            Err(PostprocessingError::InvalidSliceParameter { index: 2, .. })
+        ));
+    }
+    let postprocessing = SlicePostprocessing::new_with_values(None, Some(SliceValue::Parameter(1)));
+    assert_eq!(
+        postprocessing
+            .try_bind_parameters(&[LiteralValue::BigInt(i64::MAX)])
+            .unwrap(),
+        SlicePostprocessing::new(None, Some(i64::MAX))
+    );
+    assert!(matches!(
+        postprocessing.try_bind_parameters(&[LiteralValue::Int128(i128::from(i64::MAX) + 1)]),
+        Err(PostprocessingError::InvalidSliceParameter { index: 1, .. })
+    ));
+}
+
+#[test]
+fn we_can_bind_uint64_query_parameters_of_a_slice_within_range() {
+    let postprocessing = SlicePostprocessing::new_with_values(
+        Some(SliceValue::Parameter(1)),
+        Some(SliceValue::Parameter(2)),
+    );
+    assert_eq!(
+        postprocessing
+            .try_bind_parameters(&[LiteralValue::UInt64(u64::MAX), LiteralValue::UInt64(5)])
+            .unwrap(),
+        SlicePostprocessing::new(Some(u64::MAX), Some(5))
+    );
+    // The offset is an i64, so a larger UINT64 does not fit
+    assert!(matches!(
+        postprocessing.try_bind_parameters(&[
+            LiteralValue::UInt64(3),
+            LiteralValue::UInt64(u64::try_from(i64::MAX).unwrap() + 1)
+        ]),
+        Err(PostprocessingError::InvalidSliceParameter { index: 2, .. })
+    ));
+}
+
+#[test]
+fn we_cannot_apply_a_slice_with_unbound_query_parameters() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([bigint("a", [1_i64, 2, 3])]);
+    let postprocessing = [OwnedTablePostprocessing::new_slice(
+        SlicePostprocessing::new_with_values(Some(SliceValue::Parameter(1)), None),
+    )];
+    assert!(matches!(
+        apply_postprocessing_steps(table, &postprocessing),
+        Err(PostprocessingError::UnboundQueryParameter { index: 1 })
+    ));
+}
@@ -4,6 +4,7 @@ use crate::{
         bit::BitDistribution,
         commitment::{Commitment, CommittableColumn, VecCommitmentExt},
         polynomial::MultilinearExtension,
+        proof::ProofError,
         scalar::Scalar,
     },
     utils::log,
@@ -25,6 +26,8 @@ pub struct FinalRoundBuilder<'a, S: Scalar> {
     /// Note: this vector is treated as a stack and the first
     /// challenge is the last entry in the vector.
     post_result_challenges: Vec<S>,
+    /// The first error that makes the data unprovable, e.g. a division by zero.
+    error: Option<ProofError>,
 }
 
 impl<'a, S: Scalar> FinalRoundBuilder<'a, S> {
@@ -36,6 +39,7 @@ impl<'a, S: Scalar> FinalRoundBuilder<'a, S> {
             pcs_proof_mles: Vec::new(),
             sumcheck_subpolynomials: Vec::new(),
             post_result_challenges,
+            error: None,
         }
     }
 
@@ -51,6 +55,18 @@ impl<'a, S: Scalar> FinalRoundBuilder<'a, S> {
         &self.pcs_proof_mles
     }
 
+    /// Record an error that makes the data unprovable, e.g. a division by zero.
+    ///
+    /// Only the first error is kept. The prover returns it once the final round is evaluated.
+    pub(crate) fn record_error(&mut self, error: ProofError) {
+        self.error.get_or_insert(error);
+    }
+
+    /// Take the first error recorded with [`Self::record_error`], if any.
+    pub(crate) fn take_error(&mut self) -> Option<ProofError> {
+        self.error.take()
+    }
+
     /// Produce a bit distribution that describes which bits are constant
     /// and which bits varying in a column of data
     pub fn produce_bit_distribution(&mut self, dist: BitDistribution) {
@@ -87,6 +103,11 @@ impl<'a, S: Scalar> FinalRoundBuilder<'a, S> {
             .push(SumcheckSubpolynomial::new(subpolynomial_type, terms));
     }
 
+    /// The number of intermediate MLEs, each of which needs a commitment.
+    pub(crate) fn num_intermediate_mles(&self) -> usize {
+        self.commitment_descriptor.len()
+    }
+
     /// Compute commitments of all the interemdiate MLEs used in sumcheck
     #[tracing::instrument(
         name = "FinalRoundBuilder::commit_intermediate_mles",
@@ -72,6 +72,11 @@ impl<'a, S: Scalar> FirstRoundBuilder<'a, S> {
         self.pcs_proof_mles.push(Box::new(data));
     }
 
+    /// The number of intermediate MLEs, each of which needs a commitment.
+    pub(crate) fn num_intermediate_mles(&self) -> usize {
+        self.commitment_descriptor.len()
+    }
+
     /// Compute commitments of all the interemdiate MLEs used in sumcheck
     #[tracing::instrument(
         name = "FirstRoundBuilder::commit_intermediate_mles",
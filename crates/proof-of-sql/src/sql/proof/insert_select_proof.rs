@@ -0,0 +1,117 @@
+use super::{
+    verifiable_query_result::make_empty_query_result, ProofPlan, QueryError, VerifiableQueryResult,
+};
+use crate::base::{
+    commitment::{
+        ColumnCommitmentsMismatch, Commitment, CommitmentEvaluationProof, QueryCommitments,
+        TableCommitment,
+    },
+    database::{DataAccessor, OwnedTable},
+};
+use serde::{Deserialize, Serialize};
+use snafu::Snafu;
+
+/// Errors that can occur when creating or verifying an [`InsertSelectProof`].
+#[derive(Snafu, Debug)]
+pub enum InsertSelectError {
+    /// The query result failed to verify, or is a verified error.
+    #[snafu(transparent)]
+    Query {
+        /// The underlying source error
+        source: QueryError,
+    },
+    /// The query result can not be appended to the target table, e.g. because its columns do not
+    /// match the columns of the target table.
+    #[snafu(display("the query result cannot be appended to the target table: {source}"))]
+    Append {
+        /// The underlying source error
+        source: ColumnCommitmentsMismatch,
+    },
+    /// The commitment to the target table after the insert is not the commitment before the
+    /// insert with the query result appended.
+    #[snafu(display("the target commitment does not match the query result appended to it"))]
+    CommitmentMismatch,
+}
+
+/// A proof that the rows appended to a table are exactly the result of a query, i.e. the
+/// provenance of `INSERT INTO <target> SELECT ... FROM <source>`.
+///
+/// The prover proves the query over the source tables and appends its result to the commitment
+/// of the target table. The verifier checks the query result against the source commitments and
+/// then checks that appending it to the target commitment from before the insert yields the
+/// target commitment after the insert. Downstream consumers can thus audit derived tables
+/// without trusting the pipeline that produced them.
+///
+/// The columns of the query result must have the names and types of the columns of the target
+/// table, in the same order.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct InsertSelectProof<CP: CommitmentEvaluationProof> {
+    /// The result of the query and the proof that it is valid.
+    pub(super) result: VerifiableQueryResult<CP>,
+}
+
+impl<CP: CommitmentEvaluationProof> InsertSelectProof<CP> {
+    /// Prove `expr` over the source tables in `accessor` and append its result to `target`.
+    ///
+    /// `commitment_setup` is used to commit to the appended rows. Upon success, the appended rows
+    /// are returned so that they can be inserted into the target table itself.
+    ///
+    /// # Errors
+    /// Returns [`InsertSelectError::Append`] if the query result does not match the columns of
+    /// `target`, in which case `target` is unchanged.
+    pub fn new(
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        target: &mut TableCommitment<CP::Commitment>,
+        prover_setup: &CP::ProverPublicSetup<'_>,
+        commitment_setup: &<CP::Commitment as Commitment>::PublicSetup<'_>,
+    ) -> Result<(Self, OwnedTable<CP::Scalar>), InsertSelectError> {
+        let result = VerifiableQueryResult::new(expr, accessor, prover_setup);
+        let rows = match &result.result {
+            Some(table) => table
+                .clone()
+                .try_coerce_with_fields(expr.get_column_result_fields())
+                .map_err(QueryError::from)?,
+            None => make_empty_query_result(&expr.get_column_result_fields())?.table,
+        };
+        if rows.num_rows() > 0 {
+            target
+                .append_owned_table(&rows, commitment_setup)
+                .map_err(|source| InsertSelectError::Append { source })?;
+        }
+        Ok((Self { result }, rows))
+    }
+
+    /// Verify that `after` is `before` with the result of `expr` over the source tables appended.
+    ///
+    /// `commitments` must contain the commitments to the source tables of `expr`, and
+    /// `commitment_setup` is used to commit to the verified query result. Upon success, the
+    /// verified appended rows are returned.
+    ///
+    /// # Errors
+    /// Returns [`InsertSelectError::Query`] if the query result fails to verify,
+    /// [`InsertSelectError::Append`] if it does not match the columns of the target table and
+    /// [`InsertSelectError::CommitmentMismatch`] if `after` is not the expected commitment.
+    pub fn verify(
+        self,
+        expr: &(impl ProofPlan + Serialize),
+        commitments: &QueryCommitments<CP::Commitment>,
+        before: &TableCommitment<CP::Commitment>,
+        after: &TableCommitment<CP::Commitment>,
+        verifier_setup: &CP::VerifierPublicSetup<'_>,
+        commitment_setup: &<CP::Commitment as Commitment>::PublicSetup<'_>,
+    ) -> Result<OwnedTable<CP::Scalar>, InsertSelectError> {
+        let rows = self.result.verify(expr, commitments, verifier_setup)?.table;
+        let mut expected_after = before.clone();
+        if rows.num_rows() > 0 {
+            expected_after
+                .append_owned_table(&rows, commitment_setup)
+                .map_err(|source| InsertSelectError::Append { source })?;
+        }
+        if expected_after == *after {
+            Ok(rows)
+        } else {
+            Err(InsertSelectError::CommitmentMismatch)
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use super::{InsertSelectError, InsertSelectProof};
+use crate::{
+    base::{
+        commitment::{
+            naive_commitment::NaiveCommitment, naive_evaluation_proof::NaiveEvaluationProof,
+            QueryCommitments, QueryCommitmentsExt, TableCommitment,
+        },
+        database::{owned_table_utility::*, OwnedTable, OwnedTableTestAccessor},
+        scalar::test_scalar::TestScalar,
+    },
+    sql::{parse::QueryExpr, proof::ProofPlan},
+};
+
+fn source_accessor() -> OwnedTableTestAccessor<'static, NaiveEvaluationProof> {
+    OwnedTableTestAccessor::<NaiveEvaluationProof>::new_from_table(
+        "sxt.src".parse().unwrap(),
+        owned_table([
+            bigint("id", [1, 2, 3, 4, 5]),
+            bigint("amount", [10, 20, 30, 40, 50]),
+            varchar("region", ["eu", "us", "eu", "eu", "us"]),
+        ]),
+        0,
+        (),
+    )
+}
+
+fn target_table() -> OwnedTable<TestScalar> {
+    owned_table([bigint("id", [100, 101]), bigint("amount", [7, 8])])
+}
+
+fn query(sql: &str, accessor: &OwnedTableTestAccessor<NaiveEvaluationProof>) -> QueryExpr {
+    QueryExpr::try_new(sql.parse().unwrap(), "sxt".into(), accessor).unwrap()
+}
+
+fn source_commitments(
+    query: &QueryExpr,
+    accessor: &OwnedTableTestAccessor<NaiveEvaluationProof>,
+) -> QueryCommitments<NaiveCommitment> {
+    QueryCommitments::from_accessor_with_max_bounds(
+        query.proof_expr().get_column_references(),
+        accessor,
+    )
+}
+
+#[test]
+fn we_can_prove_and_verify_an_insert_select() {
+    let accessor = source_accessor();
+    // INSERT INTO dst SELECT id, amount FROM src WHERE region = 'eu'
+    let query = query("SELECT id, amount FROM src WHERE region = 'eu'", &accessor);
+    let before = TableCommitment::from_owned_table_with_offset(&target_table(), 0, &());
+    let mut after = before.clone();
+    let (proof, rows) = InsertSelectProof::<NaiveEvaluationProof>::new(
+        query.proof_expr(),
+        &accessor,
+        &mut after,
+        &(),
+        &(),
+    )
+    .unwrap();
+    let expected_rows: OwnedTable<TestScalar> =
+        owned_table([bigint("id", [1, 3, 4]), bigint("amount", [10, 30, 40])]);
+    assert_eq!(rows, expected_rows);
+
+    // The updated commitment is the commitment to the target table with the rows inserted
+    let inserted_table: OwnedTable<TestScalar> = owned_table([
+        bigint("id", [100, 101, 1, 3, 4]),
+        bigint("amount", [7, 8, 10, 30, 40]),
+    ]);
+    assert_eq!(
+        after,
+        TableCommitment::from_owned_table_with_offset(&inserted_table, 0, &())
+    );
+
+    let verified_rows = proof
+        .verify(
+            query.proof_expr(),
+            &source_commitments(&query, &accessor),
+            &before,
+            &after,
+            &(),
+            &(),
+        )
+        .unwrap();
+    assert_eq!(verified_rows, expected_rows);
+}
+
+#[test]
+fn we_can_prove_and_verify_an_insert_select_without_rows() {
+    let accessor = source_accessor();
+    let query = query(
+        "SELECT id, amount FROM src WHERE region = 'asia'",
+        &accessor,
+    );
+    let before = TableCommitment::from_owned_table_with_offset(&target_table(), 0, &());
+    let mut after = before.clone();
+    let (proof, rows) = InsertSelectProof::<NaiveEvaluationProof>::new(
+        query.proof_expr(),
+        &accessor,
+        &mut after,
+        &(),
+        &(),
+    )
+    .unwrap();
+    assert_eq!(rows.num_rows(), 0);
+    assert_eq!(after, before);
+    let verified_rows = proof
+        .verify(
+            query.proof_expr(),
+            &source_commitments(&query, &accessor),
+            &before,
+            &after,
+            &(),
+            &(),
+        )
+        .unwrap();
+    assert_eq!(verified_rows.num_rows(), 0);
+}
+
+/// Proves the query and appends its result to the commitment to the target table
+fn prove_insert(
+    query: &QueryExpr,
+    accessor: &OwnedTableTestAccessor<NaiveEvaluationProof>,
+    before: &TableCommitment<NaiveCommitment>,
+) -> (
+    InsertSelectProof<NaiveEvaluationProof>,
+    TableCommitment<NaiveCommitment>,
+) {
+    let mut after = before.clone();
+    let (proof, _) = InsertSelectProof::<NaiveEvaluationProof>::new(
+        query.proof_expr(),
+        accessor,
+        &mut after,
+        &(),
+        &(),
+    )
+    .unwrap();
+    (proof, after)
+}
+
+#[test]
+fn we_cannot_verify_an_insert_select_if_the_target_commitment_has_other_rows() {
+    let accessor = source_accessor();
+    let query = query("SELECT id, amount FROM src WHERE region = 'eu'", &accessor);
+    let before = TableCommitment::from_owned_table_with_offset(&target_table(), 0, &());
+
+    // The pipeline inserted a row that is not part of the query result
+    let (proof, _) = prove_insert(&query, &accessor, &before);
+    let tampered_table: OwnedTable<TestScalar> = owned_table([
+        bigint("id", [100, 101, 1, 3, 5]),
+        bigint("amount", [7, 8, 10, 30, 50]),
+    ]);
+    let tampered_after = TableCommitment::from_owned_table_with_offset(&tampered_table, 0, &());
+    assert!(matches!(
+        proof.verify(
+            query.proof_expr(),
+            &source_commitments(&query, &accessor),
+            &before,
+            &tampered_after,
+            &(),
+            &(),
+        ),
+        Err(InsertSelectError::CommitmentMismatch)
+    ));
+
+    // The rows were appended to a different table
+    let (proof, after) = prove_insert(&query, &accessor, &before);
+    let other_before = TableCommitment::from_owned_table_with_offset(
+        &owned_table::<TestScalar>([bigint("id", [100]), bigint("amount", [7])]),
+        0,
+        &(),
+    );
+    assert!(matches!(
+        proof.verify(
+            query.proof_expr(),
+            &source_commitments(&query, &accessor),
+            &other_before,
+            &after,
+            &(),
+            &(),
+        ),
+        Err(InsertSelectError::CommitmentMismatch)
+    ));
+}
+
+#[test]
+fn we_cannot_insert_a_query_result_with_columns_unlike_the_target_table() {
+    let accessor = source_accessor();
+    let query = query("SELECT id, region FROM src WHERE amount >= 30", &accessor);
+    let before = TableCommitment::from_owned_table_with_offset(&target_table(), 0, &());
+    let mut after = before.clone();
+    assert!(matches!(
+        InsertSelectProof::<NaiveEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &mut after,
+            &(),
+            &(),
+        ),
+        Err(InsertSelectError::Append { .. })
+    ));
+    assert_eq!(after, before);
+}
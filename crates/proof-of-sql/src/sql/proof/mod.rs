@@ -30,7 +30,7 @@ mod sumcheck_random_scalars;
 pub(crate) use sumcheck_random_scalars::SumcheckRandomScalars;
 
 mod proof_plan;
-pub use proof_plan::ProofPlan;
+pub use proof_plan::{sort_column_references, ProofPlan};
 pub(crate) use proof_plan::{HonestProver, ProverEvaluate, ProverHonestyMarker};
 
 mod query_proof;
@@ -51,6 +51,52 @@ pub use verifiable_query_result::VerifiableQueryResult;
 #[cfg(all(test, feature = "blitzar"))]
 mod verifiable_query_result_test;
 
+#[cfg(test)]
+mod verifiable_query_result_cancellation_test;
+
+#[cfg(test)]
+mod verifiable_query_result_scheme_test;
+
+#[cfg(test)]
+mod verifiable_query_result_domain_separator_test;
+
+mod verifiable_query_result_cache;
+pub use verifiable_query_result_cache::VerifiableQueryResultCache;
+#[cfg(test)]
+mod verifiable_query_result_cache_test;
+
+mod proof_summary;
+pub use proof_summary::{ProofSizeBreakdown, ProofSizeEstimate, ProofSummary};
+#[cfg(test)]
+mod proof_summary_test;
+
+mod proof_compression;
+pub use proof_compression::{ProofCompression, ProofSerializationError};
+#[cfg(test)]
+mod proof_compression_test;
+
+#[cfg(feature = "protobuf")]
+mod proof_protobuf;
+#[cfg(all(test, feature = "protobuf"))]
+mod proof_protobuf_test;
+
+#[cfg(feature = "arrow")]
+mod query_verifier;
+#[cfg(feature = "arrow")]
+pub use query_verifier::{QueryVerifier, QueryVerifierError, SchemeVerifier};
+#[cfg(all(test, feature = "arrow"))]
+mod query_verifier_test;
+
+mod multi_query_proof;
+pub use multi_query_proof::{MultiQueryError, MultiQueryProof};
+#[cfg(all(test, feature = "blitzar"))]
+mod multi_query_proof_test;
+
+mod insert_select_proof;
+pub use insert_select_proof::{InsertSelectError, InsertSelectProof};
+#[cfg(test)]
+mod insert_select_proof_test;
+
 #[cfg(all(test, feature = "blitzar"))]
 mod verifiable_query_result_test_utility;
 #[cfg(all(test, feature = "blitzar"))]
@@ -0,0 +1,120 @@
+use super::{ProofPlan, QueryData, QueryError, VerifiableQueryResult};
+use crate::base::{
+    commitment::{CommitmentEvaluationProof, QueryCommitments},
+    database::DataAccessor,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use snafu::Snafu;
+
+/// Errors that can occur when verifying a [`MultiQueryProof`].
+#[derive(Snafu, Debug)]
+pub enum MultiQueryError {
+    /// The number of queries does not match the number of bundled results.
+    #[snafu(display("the proof bundles {expected} results but {actual} queries were given"))]
+    QueryCountMismatch {
+        /// The number of bundled results
+        expected: usize,
+        /// The number of queries passed to the verifier
+        actual: usize,
+    },
+    /// At least one of the bundled results failed to verify.
+    #[snafu(display(
+        "verification failed for the queries at indices {}",
+        failed_indices(failures)
+    ))]
+    VerificationFailed {
+        /// The index of every query that failed along with its error
+        failures: Vec<(usize, QueryError)>,
+    },
+}
+
+fn failed_indices(failures: &[(usize, QueryError)]) -> String {
+    failures
+        .iter()
+        .map(|(index, _)| index.to_string())
+        .join(", ")
+}
+
+/// A bundle of [`VerifiableQueryResult`]s for several queries over the same tables.
+///
+/// The prover proves every query against one accessor and the verifier checks all of them
+/// against one set of [`QueryCommitments`]. Verification is atomic: either every result is
+/// returned, or the indices of the queries that failed are reported.
+#[derive(Default, Clone, Serialize, Deserialize)]
+pub struct MultiQueryProof<CP: CommitmentEvaluationProof> {
+    /// The result and proof of every query, in the order the queries were given.
+    pub(super) results: Vec<VerifiableQueryResult<CP>>,
+}
+
+impl<CP: CommitmentEvaluationProof> MultiQueryProof<CP> {
+    /// Prove every query in `exprs` against the same accessor.
+    #[tracing::instrument(name = "MultiQueryProof::new", level = "info", skip_all)]
+    pub fn new(
+        exprs: &[impl ProofPlan + Serialize],
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+    ) -> Self {
+        Self {
+            results: exprs
+                .iter()
+                .map(|expr| VerifiableQueryResult::new(expr, accessor, setup))
+                .collect(),
+        }
+    }
+
+    /// The number of bundled query results.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    /// Whether the bundle contains no query results.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    /// Verify every bundled result against the same commitments.
+    ///
+    /// `exprs` must be the queries the bundle was created from, in the same order. Upon success,
+    /// the verified result of every query is returned in that order.
+    ///
+    /// Note: as with [`VerifiableQueryResult::verify`], a query whose result represents a verified
+    /// error (e.g. overflow) is reported as a failure.
+    ///
+    /// # Errors
+    /// Returns [`MultiQueryError::QueryCountMismatch`] if the number of queries does not match the
+    /// bundle and [`MultiQueryError::VerificationFailed`] with every failing query otherwise.
+    #[tracing::instrument(name = "MultiQueryProof::verify_all", level = "info", skip_all)]
+    pub fn verify_all(
+        self,
+        exprs: &[impl ProofPlan + Serialize],
+        commitments: &QueryCommitments<CP::Commitment>,
+        setup: &CP::VerifierPublicSetup<'_>,
+    ) -> Result<Vec<QueryData<CP::Scalar>>, MultiQueryError> {
+        if exprs.len() != self.results.len() {
+            return Err(MultiQueryError::QueryCountMismatch {
+                expected: self.results.len(),
+                actual: exprs.len(),
+            });
+        }
+        let mut query_data = Vec::with_capacity(exprs.len());
+        let mut failures = Vec::new();
+        for (index, (result, expr)) in self.results.into_iter().zip(exprs).enumerate() {
+            match result.verify(expr, commitments, setup) {
+                Ok(data) => query_data.push(data),
+                Err(error) => failures.push((index, error)),
+            }
+        }
+        if failures.is_empty() {
+            Ok(query_data)
+        } else {
+            Err(MultiQueryError::VerificationFailed { failures })
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use super::{MultiQueryError, MultiQueryProof};
+use crate::{
+    base::{
+        commitment::{
+            CommitmentEvaluationProof, InnerProductProof, QueryCommitments, QueryCommitmentsExt,
+        },
+        database::{owned_table_utility::*, OwnedTableTestAccessor, TestAccessor},
+    },
+    sql::{
+        proof::ProofPlan,
+        proof_exprs::test_utility::*,
+        proof_plans::{test_utility::*, DynProofPlan},
+    },
+};
+
+fn accessor_and_queries() -> (
+    OwnedTableTestAccessor<'static, InnerProductProof>,
+    Vec<DynProofPlan>,
+) {
+    let t = "sxt.t".parse().unwrap();
+    let u = "sxt.u".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(
+        t,
+        owned_table([
+            bigint("a", [1, 2, 3, 4, 5]),
+            varchar("b", ["x", "y", "x", "z", "x"]),
+        ]),
+        0,
+    );
+    accessor.add_table(u, owned_table([bigint("c", [10, 20, 30])]), 0);
+    let queries = vec![
+        filter(
+            cols_expr_plan(t, &["a"], &accessor),
+            tab(t),
+            equal(column(t, "b", &accessor), const_varchar("x")),
+        ),
+        projection(
+            vec![aliased_plan(
+                add(column(t, "a", &accessor), const_bigint(1)),
+                "a_plus_one",
+            )],
+            tab(t),
+        ),
+        filter(
+            cols_expr_plan(u, &["c"], &accessor),
+            tab(u),
+            lte(column(u, "c", &accessor), const_bigint(20)),
+        ),
+    ];
+    (accessor, queries)
+}
+
+fn commitments_for(
+    queries: &[DynProofPlan],
+    accessor: &OwnedTableTestAccessor<InnerProductProof>,
+) -> QueryCommitments<<InnerProductProof as CommitmentEvaluationProof>::Commitment> {
+    QueryCommitments::from_accessor_with_max_bounds(
+        queries
+            .iter()
+            .flat_map(ProofPlan::get_column_references)
+            .collect::<Vec<_>>(),
+        accessor,
+    )
+}
+
+#[test]
+fn we_can_verify_a_bundle_of_queries_against_one_set_of_commitments() {
+    let (accessor, queries) = accessor_and_queries();
+    let proof = MultiQueryProof::<InnerProductProof>::new(&queries, &accessor, &());
+    assert_eq!(proof.len(), 3);
+    let commitments = commitments_for(&queries, &accessor);
+    let tables = proof
+        .verify_all(&queries, &commitments, &())
+        .unwrap()
+        .into_iter()
+        .map(|data| data.table)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tables,
+        vec![
+            owned_table([bigint("a", [1, 3, 5])]),
+            owned_table([bigint("a_plus_one", [2, 3, 4, 5, 6])]),
+            owned_table([bigint("c", [10, 20])]),
+        ]
+    );
+}
+
+#[test]
+fn we_can_find_which_query_of_a_bundle_was_tampered_with() {
+    let (accessor, queries) = accessor_and_queries();
+    let mut proof = MultiQueryProof::<InnerProductProof>::new(&queries, &accessor, &());
+    proof.results[1].result = Some(owned_table([bigint("a_plus_one", [2, 3, 4, 5, 7])]));
+    let commitments = commitments_for(&queries, &accessor);
+    match proof.verify_all(&queries, &commitments, &()) {
+        Err(MultiQueryError::VerificationFailed { failures }) => {
+            assert_eq!(
+                failures.iter().map(|(index, _)| *index).collect::<Vec<_>>(),
+                vec![1]
+            );
+        }
+        _ => panic!("verification should fail for the tampered query"),
+    }
+}
+
+#[test]
+fn we_cannot_verify_a_bundle_with_the_wrong_number_of_queries() {
+    let (accessor, queries) = accessor_and_queries();
+    let proof = MultiQueryProof::<InnerProductProof>::new(&queries, &accessor, &());
+    let commitments = commitments_for(&queries, &accessor);
+    let err = proof
+        .verify_all(&queries[..2], &commitments, &())
+        .err()
+        .unwrap();
+    assert!(matches!(
+        err,
+        MultiQueryError::QueryCountMismatch {
+            expected: 3,
+            actual: 2
+        }
+    ));
+    assert_eq!(
+        err.to_string(),
+        "the proof bundles 3 results but 2 queries were given"
+    );
+}
@@ -0,0 +1,206 @@
+use super::VerifiableQueryResult;
+use crate::base::commitment::CommitmentEvaluationProof;
+use alloc::vec::Vec;
+use core::fmt;
+use serde::{de::DeserializeOwned, Serialize};
+use snafu::Snafu;
+
+/// The compression applied to a serialized [`VerifiableQueryResult`]
+///
+/// The serialized bytes start with a flag byte identifying the compression, so that
+/// [`VerifiableQueryResult::from_bytes`] can detect it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProofCompression {
+    /// No compression
+    #[default]
+    None,
+    /// Zstandard compression
+    #[cfg(feature = "zstd")]
+    Zstd,
+}
+
+impl ProofCompression {
+    /// Reads the compression of bytes written by [`VerifiableQueryResult::to_bytes`] from their
+    /// flag byte, without deserializing them.
+    ///
+    /// # Errors
+    /// Returns an error if the flag byte is missing or unsupported.
+    pub fn detect(bytes: &[u8]) -> Result<Self, ProofSerializationError> {
+        match bytes.first() {
+            None => Err(ProofSerializationError::MissingCompressionFlag),
+            Some(&UNCOMPRESSED_FLAG) => Ok(Self::None),
+            #[cfg(feature = "zstd")]
+            Some(&ZSTD_FLAG) => Ok(Self::Zstd),
+            #[cfg(not(feature = "zstd"))]
+            Some(&ZSTD_FLAG) => Err(ProofSerializationError::UnsupportedCompression {
+                flag: ZSTD_FLAG,
+                feature: "zstd",
+            }),
+            Some(&flag) => Err(ProofSerializationError::UnknownCompressionFlag { flag }),
+        }
+    }
+}
+
+impl fmt::Display for ProofCompression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            #[cfg(feature = "zstd")]
+            Self::Zstd => write!(f, "zstd"),
+        }
+    }
+}
+
+/// The flag byte of uncompressed results
+const UNCOMPRESSED_FLAG: u8 = 0;
+/// The flag byte of zstd compressed results
+const ZSTD_FLAG: u8 = 1;
+/// The maximum length of the decompressed payload of a zstd compressed result
+///
+/// This bounds the allocation that a small crafted frame can cause.
+#[cfg(feature = "zstd")]
+const MAX_DECOMPRESSED_LEN: usize = 1 << 30;
+
+/// Errors that can occur when serializing or deserializing a [`VerifiableQueryResult`]
+#[derive(Snafu, Debug)]
+pub enum ProofSerializationError {
+    /// The postcard (de)serialization failed.
+    #[snafu(display("postcard (de)serialization failed: {error}"))]
+    Postcard {
+        /// The underlying error
+        error: postcard::Error,
+    },
+    /// The input was empty, so there was no compression flag.
+    #[snafu(display("missing compression flag"))]
+    MissingCompressionFlag,
+    /// The compression flag is not one that is known.
+    #[snafu(display("unknown compression flag {flag}"))]
+    UnknownCompressionFlag {
+        /// The flag byte
+        flag: u8,
+    },
+    /// The input is compressed with a scheme whose feature is disabled.
+    #[snafu(display("compression flag {flag} requires the `{feature}` feature"))]
+    UnsupportedCompression {
+        /// The flag byte
+        flag: u8,
+        /// The feature required to decompress the input
+        feature: &'static str,
+    },
+    /// The zstd compression or decompression failed.
+    #[cfg(feature = "zstd")]
+    #[snafu(display("zstd (de)compression failed: {error}"))]
+    Zstd {
+        /// The underlying error
+        error: std::io::Error,
+    },
+    /// The decompressed payload is longer than the limit.
+    #[cfg(feature = "zstd")]
+    #[snafu(display("decompressed payload exceeds the limit of {limit} bytes"))]
+    DecompressedTooLarge {
+        /// The maximum length of the decompressed payload
+        limit: usize,
+    },
+    /// The protobuf decoding failed.
+    #[cfg(feature = "protobuf")]
+    #[snafu(display("protobuf decoding failed: {error}"))]
+    Protobuf {
+        /// The underlying error
+        error: prost::DecodeError,
+    },
+    /// The protobuf message is well-formed but does not describe a valid result.
+    #[cfg(feature = "protobuf")]
+    #[snafu(display("invalid protobuf message: {reason}"))]
+    InvalidProtobuf {
+        /// What is wrong with the message
+        reason: &'static str,
+    },
+}
+
+impl From<postcard::Error> for ProofSerializationError {
+    fn from(error: postcard::Error) -> Self {
+        Self::Postcard { error }
+    }
+}
+
+#[cfg(feature = "zstd")]
+impl From<std::io::Error> for ProofSerializationError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Zstd { error }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<prost::DecodeError> for ProofSerializationError {
+    fn from(error: prost::DecodeError) -> Self {
+        Self::Protobuf { error }
+    }
+}
+
+impl<CP: CommitmentEvaluationProof + Serialize> VerifiableQueryResult<CP> {
+    /// Serializes this result with postcard, optionally compressing the serialized bytes.
+    ///
+    /// The output starts with a flag byte identifying `compression`.
+    ///
+    /// # Errors
+    /// Returns an error if the serialization or compression fails.
+    pub fn to_bytes(
+        &self,
+        compression: ProofCompression,
+    ) -> Result<Vec<u8>, ProofSerializationError> {
+        match compression {
+            ProofCompression::None => {
+                Ok(postcard::to_extend(self, Vec::from([UNCOMPRESSED_FLAG]))?)
+            }
+            #[cfg(feature = "zstd")]
+            ProofCompression::Zstd => {
+                let serialized = postcard::to_allocvec(self)?;
+                let mut bytes = Vec::from([ZSTD_FLAG]);
+                bytes.extend(zstd::encode_all(
+                    serialized.as_slice(),
+                    zstd::DEFAULT_COMPRESSION_LEVEL,
+                )?);
+                Ok(bytes)
+            }
+        }
+    }
+}
+
+impl<CP: CommitmentEvaluationProof + DeserializeOwned> VerifiableQueryResult<CP> {
+    /// Deserializes a result written by [`VerifiableQueryResult::to_bytes`], detecting the
+    /// compression from the flag byte.
+    ///
+    /// # Errors
+    /// Returns an error if the flag byte is missing or unsupported, if the decompression or
+    /// deserialization fails, or if the decompressed payload exceeds 2^30 bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ProofSerializationError> {
+        let compression = ProofCompression::detect(bytes)?;
+        let payload = &bytes[1..];
+        match compression {
+            ProofCompression::None => Ok(postcard::from_bytes(payload)?),
+            #[cfg(feature = "zstd")]
+            ProofCompression::Zstd => {
+                let serialized = zstd_decompress(payload, MAX_DECOMPRESSED_LEN)?;
+                Ok(postcard::from_bytes(&serialized)?)
+            }
+        }
+    }
+}
+
+/// Decompresses a zstd frame, failing once the output exceeds `limit` bytes instead of
+/// allocating whatever length the frame claims.
+#[cfg(feature = "zstd")]
+pub(super) fn zstd_decompress(
+    payload: &[u8],
+    limit: usize,
+) -> Result<Vec<u8>, ProofSerializationError> {
+    use std::io::Read;
+    let mut decompressed = Vec::new();
+    zstd::stream::read::Decoder::with_buffer(payload)?
+        .take(u64::try_from(limit).unwrap_or(u64::MAX).saturating_add(1))
+        .read_to_end(&mut decompressed)?;
+    if decompressed.len() > limit {
+        return Err(ProofSerializationError::DecompressedTooLarge { limit });
+    }
+    Ok(decompressed)
+}
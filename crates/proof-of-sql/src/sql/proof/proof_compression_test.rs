@@ -0,0 +1,147 @@
+use super::{ProofCompression, ProofSerializationError, VerifiableQueryResult};
+use crate::{
+    base::database::{owned_table_utility::*, OwnedTable, OwnedTableTestAccessor},
+    proof_primitive::dory::{
+        DoryEvaluationProof, DoryProverPublicSetup, DoryScalar, DoryVerifierPublicSetup,
+        ProverSetup, PublicParameters, VerifierSetup,
+    },
+    sql::{proof_exprs::test_utility::*, proof_plans::test_utility::*},
+};
+use ark_std::test_rng;
+
+#[test]
+fn we_can_round_trip_a_verifiable_query_result_through_bytes() {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([
+            bigint("a", (0..64).map(|i| i % 4)),
+            varchar(
+                "b",
+                (0..64).map(|i| if i % 2 == 0 { "even" } else { "odd" }),
+            ),
+        ]),
+        0,
+        dory_prover_setup,
+    );
+    let ast = filter(
+        cols_expr_plan(t, &["a", "b"], &accessor),
+        tab(t),
+        equal(column(t, "b", &accessor), const_varchar("even")),
+    );
+    let verifiable_res =
+        VerifiableQueryResult::<DoryEvaluationProof>::new(&ast, &accessor, &dory_prover_setup);
+    let expected_res: OwnedTable<DoryScalar> = owned_table([
+        bigint("a", (0..32).map(|i| (2 * i) % 4)),
+        varchar("b", ["even"; 32]),
+    ]);
+
+    let bytes = verifiable_res.to_bytes(ProofCompression::None).unwrap();
+    assert_eq!(bytes[0], 0);
+    assert_eq!(bytes[1..], postcard::to_allocvec(&verifiable_res).unwrap());
+    let res = VerifiableQueryResult::<DoryEvaluationProof>::from_bytes(&bytes)
+        .unwrap()
+        .verify(&ast, &accessor, &dory_verifier_setup)
+        .unwrap()
+        .table;
+    assert_eq!(res, expected_res);
+
+    #[cfg(feature = "zstd")]
+    {
+        let compressed_bytes = verifiable_res.to_bytes(ProofCompression::Zstd).unwrap();
+        assert_eq!(compressed_bytes[0], 1);
+        assert!(
+            compressed_bytes.len() < bytes.len(),
+            "zstd did not shrink the {} bytes of the proof, it produced {} bytes",
+            bytes.len(),
+            compressed_bytes.len()
+        );
+        let res = VerifiableQueryResult::<DoryEvaluationProof>::from_bytes(&compressed_bytes)
+            .unwrap()
+            .verify(&ast, &accessor, &dory_verifier_setup)
+            .unwrap()
+            .table;
+        assert_eq!(res, expected_res);
+    }
+}
+
+#[test]
+fn we_cannot_deserialize_bytes_without_a_known_compression_flag() {
+    assert!(matches!(
+        VerifiableQueryResult::<DoryEvaluationProof>::from_bytes(&[]),
+        Err(ProofSerializationError::MissingCompressionFlag)
+    ));
+    assert!(matches!(
+        VerifiableQueryResult::<DoryEvaluationProof>::from_bytes(&[2, 0, 0]),
+        Err(ProofSerializationError::UnknownCompressionFlag { flag: 2 })
+    ));
+    assert!(matches!(
+        VerifiableQueryResult::<DoryEvaluationProof>::from_bytes(&[0, 2]),
+        Err(ProofSerializationError::Postcard { .. })
+    ));
+    #[cfg(not(feature = "zstd"))]
+    assert!(matches!(
+        VerifiableQueryResult::<DoryEvaluationProof>::from_bytes(&[1, 0]),
+        Err(ProofSerializationError::UnsupportedCompression {
+            flag: 1,
+            feature: "zstd"
+        })
+    ));
+    #[cfg(feature = "zstd")]
+    assert!(matches!(
+        VerifiableQueryResult::<DoryEvaluationProof>::from_bytes(&[1, 0]),
+        Err(ProofSerializationError::Zstd { .. })
+    ));
+}
+
+#[test]
+fn we_can_detect_the_compression_of_serialized_bytes() {
+    assert_eq!(
+        ProofCompression::detect(&[0, 2, 3]).unwrap(),
+        ProofCompression::None
+    );
+    assert_eq!(ProofCompression::None.to_string(), "none");
+    #[cfg(feature = "zstd")]
+    {
+        assert_eq!(
+            ProofCompression::detect(&[1]).unwrap(),
+            ProofCompression::Zstd
+        );
+        assert_eq!(ProofCompression::Zstd.to_string(), "zstd");
+    }
+    #[cfg(not(feature = "zstd"))]
+    assert!(matches!(
+        ProofCompression::detect(&[1]),
+        Err(ProofSerializationError::UnsupportedCompression {
+            flag: 1,
+            feature: "zstd"
+        })
+    ));
+    assert!(matches!(
+        ProofCompression::detect(&[]),
+        Err(ProofSerializationError::MissingCompressionFlag)
+    ));
+    assert!(matches!(
+        ProofCompression::detect(&[2]),
+        Err(ProofSerializationError::UnknownCompressionFlag { flag: 2 })
+    ));
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn we_cannot_decompress_a_zstd_frame_beyond_the_limit() {
+    use super::proof_compression::zstd_decompress;
+    let payload = vec![0u8; 1025];
+    let compressed = zstd::encode_all(payload.as_slice(), zstd::DEFAULT_COMPRESSION_LEVEL).unwrap();
+    assert!(compressed.len() < 100);
+    assert_eq!(zstd_decompress(&compressed, 1025).unwrap(), payload);
+    assert!(matches!(
+        zstd_decompress(&compressed, 1024),
+        Err(ProofSerializationError::DecompressedTooLarge { limit: 1024 })
+    ));
+}
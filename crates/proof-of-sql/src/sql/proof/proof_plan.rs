@@ -25,6 +25,10 @@ pub trait ProofPlan: Debug + Send + Sync + ProverEvaluate {
     fn get_column_result_fields(&self) -> Vec<ColumnField>;
 
     /// Return all the columns referenced in the Query
+    ///
+    /// Every column appears once, and the columns are sorted by table and then by column name,
+    /// so the result only depends on which columns are referenced and not on where. Implementors
+    /// can use [`sort_column_references`] to uphold this.
     fn get_column_references(&self) -> IndexSet<ColumnRef>;
 
     /// Return all the tables referenced in the Query
@@ -55,6 +59,18 @@ pub trait ProverEvaluate {
     ) -> Table<'a, S>;
 }
 
+/// Sort column references the way [`ProofPlan::get_column_references`] returns them, i.e. by table
+/// and then by column name.
+#[must_use]
+pub fn sort_column_references(mut columns: IndexSet<ColumnRef>) -> IndexSet<ColumnRef> {
+    columns.sort_by(|a, b| {
+        a.table_ref()
+            .cmp(&b.table_ref())
+            .then_with(|| a.column_id().cmp(&b.column_id()))
+    });
+    columns
+}
+
 /// Marker used as a trait bound for generic [`ProofPlan`] types to indicate the honesty of their implementation.
 ///
 /// This allows us to define alternative prover implementations that misbehave, and test that the verifier rejects their results.
@@ -0,0 +1,346 @@
+//! Conversion of a [`VerifiableQueryResult`] to and from the protobuf messages described by
+//! `proto/verifiable_query_result.proto`.
+use super::{ProofSerializationError, QueryProof, VerifiableQueryResult};
+use crate::base::{
+    commitment::CommitmentEvaluationProof,
+    database::{OwnedColumn, OwnedTable},
+    map::IndexMap,
+    math::decimal::Precision,
+    scalar::Scalar,
+};
+use alloc::{string::String, vec::Vec};
+use core::mem;
+use proof_of_sql_parser::posql_time::{PoSQLTimeUnit, PoSQLTimeZone};
+use prost::Message;
+use serde::{de::DeserializeOwned, Serialize};
+use sqlparser::ast::Ident;
+
+/// The `VerifiableQueryResult` message
+#[derive(Clone, PartialEq, Message)]
+pub(super) struct VerifiableQueryResultMessage {
+    #[prost(message, optional, tag = "1")]
+    pub(super) result: Option<TableMessage>,
+    #[prost(message, optional, tag = "2")]
+    pub(super) proof: Option<QueryProofMessage>,
+}
+
+/// The `Table` message
+#[derive(Clone, PartialEq, Message)]
+pub(super) struct TableMessage {
+    #[prost(message, repeated, tag = "1")]
+    pub(super) columns: Vec<ColumnMessage>,
+}
+
+/// The `ColumnKind` enum
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub(super) enum ColumnKind {
+    Unspecified = 0,
+    Boolean = 1,
+    TinyInt = 2,
+    SmallInt = 3,
+    Int = 4,
+    BigInt = 5,
+    Int128 = 6,
+    VarChar = 7,
+    Decimal75 = 8,
+    Scalar = 9,
+    TimestampTz = 10,
+    Date = 11,
+    UInt64 = 12,
+}
+
+/// The `TimeUnit` enum
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub(super) enum TimeUnit {
+    Unspecified = 0,
+    Second = 1,
+    Millisecond = 2,
+    Microsecond = 3,
+    Nanosecond = 4,
+}
+
+/// The `Column` message
+#[derive(Clone, PartialEq, Message)]
+pub(super) struct ColumnMessage {
+    #[prost(string, tag = "1")]
+    pub(super) name: String,
+    #[prost(enumeration = "ColumnKind", tag = "2")]
+    pub(super) kind: i32,
+    #[prost(uint32, tag = "3")]
+    pub(super) precision: u32,
+    #[prost(sint32, tag = "4")]
+    pub(super) scale: i32,
+    #[prost(enumeration = "TimeUnit", tag = "5")]
+    pub(super) time_unit: i32,
+    #[prost(sint32, tag = "6")]
+    pub(super) time_zone_offset: i32,
+    #[prost(bool, repeated, tag = "7")]
+    pub(super) booleans: Vec<bool>,
+    #[prost(sint64, repeated, tag = "8")]
+    pub(super) integers: Vec<i64>,
+    #[prost(string, repeated, tag = "9")]
+    pub(super) strings: Vec<String>,
+    #[prost(bytes = "vec", repeated, tag = "10")]
+    pub(super) wide_integers: Vec<Vec<u8>>,
+    #[prost(uint64, repeated, tag = "11")]
+    pub(super) unsigned_integers: Vec<u64>,
+}
+
+/// The `QueryProof` message
+#[derive(Clone, PartialEq, Message)]
+pub(super) struct QueryProofMessage {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub(super) first_round_commitments: Vec<Vec<u8>>,
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub(super) final_round_commitments: Vec<Vec<u8>>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub(super) proof: Vec<u8>,
+}
+
+/// The error of a message that is well-formed protobuf but does not describe a valid result
+const fn invalid(reason: &'static str) -> ProofSerializationError {
+    ProofSerializationError::InvalidProtobuf { reason }
+}
+
+fn scalars_to_bytes<S: Scalar>(scalars: &[S]) -> Vec<Vec<u8>> {
+    scalars
+        .iter()
+        .map(|&scalar| {
+            let limbs: [u64; 4] = scalar.into();
+            limbs.iter().flat_map(|limb| limb.to_le_bytes()).collect()
+        })
+        .collect()
+}
+
+#[allow(
+    clippy::missing_panics_doc,
+    reason = "32 bytes always split into four 8 byte chunks"
+)]
+fn bytes_to_scalars<S: Scalar>(bytes: &[Vec<u8>]) -> Result<Vec<S>, ProofSerializationError> {
+    bytes
+        .iter()
+        .map(|bytes| {
+            let bytes: &[u8; 32] = bytes
+                .as_slice()
+                .try_into()
+                .map_err(|_| invalid("scalars must be 32 bytes long"))?;
+            let mut limbs = [0; 4];
+            for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+                *limb = u64::from_le_bytes(chunk.try_into().expect("chunks are 8 bytes long"));
+            }
+            Ok(S::from(limbs))
+        })
+        .collect()
+}
+
+/// Narrows the integers of a column to the type of the column
+fn narrow_integers<T: TryFrom<i64>>(integers: &[i64]) -> Result<Vec<T>, ProofSerializationError> {
+    integers
+        .iter()
+        .map(|&integer| {
+            T::try_from(integer).map_err(|_| invalid("integer is out of range for its column"))
+        })
+        .collect()
+}
+
+fn column_to_message<S: Scalar>(name: &Ident, column: &OwnedColumn<S>) -> ColumnMessage {
+    let mut message = ColumnMessage {
+        name: name.value.clone(),
+        ..ColumnMessage::default()
+    };
+    match column {
+        OwnedColumn::Boolean(col) => {
+            message.set_kind(ColumnKind::Boolean);
+            message.booleans.clone_from(col);
+        }
+        OwnedColumn::TinyInt(col) => {
+            message.set_kind(ColumnKind::TinyInt);
+            message.integers = col.iter().copied().map(i64::from).collect();
+        }
+        OwnedColumn::SmallInt(col) => {
+            message.set_kind(ColumnKind::SmallInt);
+            message.integers = col.iter().copied().map(i64::from).collect();
+        }
+        OwnedColumn::Int(col) => {
+            message.set_kind(ColumnKind::Int);
+            message.integers = col.iter().copied().map(i64::from).collect();
+        }
+        OwnedColumn::BigInt(col) => {
+            message.set_kind(ColumnKind::BigInt);
+            message.integers.clone_from(col);
+        }
+        OwnedColumn::Int128(col) => {
+            message.set_kind(ColumnKind::Int128);
+            message.wide_integers = col.iter().map(|i| i.to_le_bytes().to_vec()).collect();
+        }
+        OwnedColumn::VarChar(col) => {
+            message.set_kind(ColumnKind::VarChar);
+            message.strings.clone_from(col);
+        }
+        OwnedColumn::Decimal75(precision, scale, col) => {
+            message.set_kind(ColumnKind::Decimal75);
+            message.precision = precision.value().into();
+            message.scale = (*scale).into();
+            message.wide_integers = scalars_to_bytes(col);
+        }
+        OwnedColumn::Scalar(col) => {
+            message.set_kind(ColumnKind::Scalar);
+            message.wide_integers = scalars_to_bytes(col);
+        }
+        OwnedColumn::TimestampTZ(time_unit, time_zone, col) => {
+            message.set_kind(ColumnKind::TimestampTz);
+            message.set_time_unit(match time_unit {
+                PoSQLTimeUnit::Second => TimeUnit::Second,
+                PoSQLTimeUnit::Millisecond => TimeUnit::Millisecond,
+                PoSQLTimeUnit::Microsecond => TimeUnit::Microsecond,
+                PoSQLTimeUnit::Nanosecond => TimeUnit::Nanosecond,
+            });
+            message.time_zone_offset = time_zone.offset();
+            message.integers.clone_from(col);
+        }
+        OwnedColumn::Date(col) => {
+            message.set_kind(ColumnKind::Date);
+            message.integers = col.iter().copied().map(i64::from).collect();
+        }
+        OwnedColumn::UInt64(col) => {
+            message.set_kind(ColumnKind::UInt64);
+            message.unsigned_integers.clone_from(col);
+        }
+    }
+    message
+}
+
+fn message_to_column<S: Scalar>(
+    message: ColumnMessage,
+) -> Result<(Ident, OwnedColumn<S>), ProofSerializationError> {
+    let kind = ColumnKind::try_from(message.kind).unwrap_or(ColumnKind::Unspecified);
+    let column = match kind {
+        ColumnKind::Unspecified => Err(invalid("column has an unknown kind"))?,
+        ColumnKind::Boolean => OwnedColumn::Boolean(message.booleans),
+        ColumnKind::TinyInt => OwnedColumn::TinyInt(narrow_integers(&message.integers)?),
+        ColumnKind::SmallInt => OwnedColumn::SmallInt(narrow_integers(&message.integers)?),
+        ColumnKind::Int => OwnedColumn::Int(narrow_integers(&message.integers)?),
+        ColumnKind::BigInt => OwnedColumn::BigInt(message.integers),
+        ColumnKind::Int128 => OwnedColumn::Int128(
+            message
+                .wide_integers
+                .iter()
+                .map(|bytes| {
+                    Ok(i128::from_le_bytes(bytes.as_slice().try_into().map_err(
+                        |_| invalid("128 bit integers must be 16 bytes long"),
+                    )?))
+                })
+                .collect::<Result<_, ProofSerializationError>>()?,
+        ),
+        ColumnKind::VarChar => OwnedColumn::VarChar(message.strings),
+        ColumnKind::Decimal75 => OwnedColumn::Decimal75(
+            u8::try_from(message.precision)
+                .ok()
+                .and_then(|precision| Precision::new(precision).ok())
+                .ok_or(invalid("decimal precision is out of range"))?,
+            i8::try_from(message.scale).map_err(|_| invalid("decimal scale is out of range"))?,
+            bytes_to_scalars(&message.wide_integers)?,
+        ),
+        ColumnKind::Scalar => OwnedColumn::Scalar(bytes_to_scalars(&message.wide_integers)?),
+        ColumnKind::TimestampTz => OwnedColumn::TimestampTZ(
+            match TimeUnit::try_from(message.time_unit).unwrap_or(TimeUnit::Unspecified) {
+                TimeUnit::Unspecified => Err(invalid("timestamp has an unknown time unit"))?,
+                TimeUnit::Second => PoSQLTimeUnit::Second,
+                TimeUnit::Millisecond => PoSQLTimeUnit::Millisecond,
+                TimeUnit::Microsecond => PoSQLTimeUnit::Microsecond,
+                TimeUnit::Nanosecond => PoSQLTimeUnit::Nanosecond,
+            },
+            PoSQLTimeZone::new(message.time_zone_offset),
+            message.integers,
+        ),
+        ColumnKind::Date => OwnedColumn::Date(narrow_integers(&message.integers)?),
+        ColumnKind::UInt64 => OwnedColumn::UInt64(message.unsigned_integers),
+    };
+    Ok((message.name.as_str().into(), column))
+}
+
+impl<CP: CommitmentEvaluationProof + Serialize + Clone> VerifiableQueryResult<CP> {
+    /// Encodes this result as the `VerifiableQueryResult` protobuf message described by
+    /// `proto/verifiable_query_result.proto`.
+    ///
+    /// The result table is encoded column by column, and the commitments of the proof are listed
+    /// separately from the rest of the proof, which is serialized with postcard.
+    ///
+    /// # Errors
+    /// Returns an error if the postcard serialization of the proof fails.
+    pub fn to_protobuf(&self) -> Result<Vec<u8>, ProofSerializationError> {
+        let result = self.result.as_ref().map(|table| TableMessage {
+            columns: table
+                .inner_table()
+                .iter()
+                .map(|(name, column)| column_to_message(name, column))
+                .collect(),
+        });
+        let proof = self
+            .proof
+            .as_ref()
+            .map(|proof| {
+                let mut proof = proof.clone();
+                let serialize_all = |commitments: Vec<CP::Commitment>| {
+                    commitments
+                        .iter()
+                        .map(postcard::to_allocvec)
+                        .collect::<Result<Vec<_>, _>>()
+                };
+                Ok::<_, ProofSerializationError>(QueryProofMessage {
+                    first_round_commitments: serialize_all(mem::take(
+                        &mut proof.first_round_commitments,
+                    ))?,
+                    final_round_commitments: serialize_all(mem::take(
+                        &mut proof.final_round_commitments,
+                    ))?,
+                    proof: postcard::to_allocvec(&proof)?,
+                })
+            })
+            .transpose()?;
+        Ok(VerifiableQueryResultMessage { result, proof }.encode_to_vec())
+    }
+}
+
+impl<CP: CommitmentEvaluationProof + DeserializeOwned> VerifiableQueryResult<CP> {
+    /// Decodes a result encoded by [`VerifiableQueryResult::to_protobuf`].
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not a valid `VerifiableQueryResult` message, or if the
+    /// proof or its commitments can not be deserialized.
+    pub fn from_protobuf(bytes: &[u8]) -> Result<Self, ProofSerializationError> {
+        let message = VerifiableQueryResultMessage::decode(bytes)?;
+        let result = message
+            .result
+            .map(|table| {
+                let mut columns = IndexMap::default();
+                for column in table.columns {
+                    let (name, column) = message_to_column(column)?;
+                    if columns.insert(name, column).is_some() {
+                        Err(invalid("column names must be unique"))?;
+                    }
+                }
+                OwnedTable::try_new(columns)
+                    .map_err(|_| invalid("columns must have the same length"))
+            })
+            .transpose()?;
+        let proof = message
+            .proof
+            .map(|message| {
+                let deserialize_all = |commitments: &[Vec<u8>]| {
+                    commitments
+                        .iter()
+                        .map(|bytes| postcard::from_bytes(bytes))
+                        .collect::<Result<Vec<_>, _>>()
+                };
+                let mut proof: QueryProof<CP> = postcard::from_bytes(&message.proof)?;
+                proof.first_round_commitments = deserialize_all(&message.first_round_commitments)?;
+                proof.final_round_commitments = deserialize_all(&message.final_round_commitments)?;
+                Ok::<_, ProofSerializationError>(proof)
+            })
+            .transpose()?;
+        Ok(Self { result, proof })
+    }
+}
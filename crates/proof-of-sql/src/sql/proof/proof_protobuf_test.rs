@@ -0,0 +1,230 @@
+use super::{
+    proof_protobuf::{
+        ColumnKind, ColumnMessage, QueryProofMessage, TableMessage, VerifiableQueryResultMessage,
+    },
+    ProofSerializationError, VerifiableQueryResult,
+};
+use crate::{
+    base::{
+        database::{owned_table_utility::*, OwnedTable, OwnedTableTestAccessor},
+        scalar::Scalar,
+    },
+    proof_primitive::dory::{
+        DoryEvaluationProof, DoryProverPublicSetup, DoryScalar, DoryVerifierPublicSetup,
+        ProverSetup, PublicParameters, VerifierSetup,
+    },
+    sql::{proof::QueryError, proof_exprs::test_utility::*, proof_plans::test_utility::*},
+};
+use ark_std::test_rng;
+use proof_of_sql_parser::posql_time::{PoSQLTimeUnit, PoSQLTimeZone};
+use prost::Message;
+
+#[test]
+fn we_can_verify_a_dory_proof_decoded_from_protobuf() {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([
+            bigint("a", (0..64).map(|i| i % 4)),
+            varchar(
+                "b",
+                (0..64).map(|i| if i % 2 == 0 { "even" } else { "odd" }),
+            ),
+        ]),
+        0,
+        dory_prover_setup,
+    );
+    let ast = filter(
+        cols_expr_plan(t, &["a", "b"], &accessor),
+        tab(t),
+        equal(column(t, "b", &accessor), const_varchar("even")),
+    );
+    let verifiable_res =
+        VerifiableQueryResult::<DoryEvaluationProof>::new(&ast, &accessor, &dory_prover_setup);
+    let expected_res: OwnedTable<DoryScalar> = owned_table([
+        bigint("a", (0..32).map(|i| (2 * i) % 4)),
+        varchar("b", ["even"; 32]),
+    ]);
+
+    let bytes = verifiable_res.to_protobuf().unwrap();
+    let message = VerifiableQueryResultMessage::decode(bytes.as_slice()).unwrap();
+    let result = message.result.unwrap();
+    assert_eq!(result.columns.len(), 2);
+    assert_eq!(result.columns[1].name, "b");
+    assert_eq!(result.columns[1].kind(), ColumnKind::VarChar);
+    assert_eq!(result.columns[1].strings, ["even"; 32]);
+    let proof = message.proof.unwrap();
+    assert_eq!(
+        proof.final_round_commitments.len(),
+        verifiable_res
+            .proof
+            .as_ref()
+            .unwrap()
+            .final_round_commitments
+            .len()
+    );
+
+    let res = VerifiableQueryResult::<DoryEvaluationProof>::from_protobuf(&bytes)
+        .unwrap()
+        .verify(&ast, &accessor, &dory_verifier_setup)
+        .unwrap()
+        .table;
+    assert_eq!(res, expected_res);
+
+    // A tampered result table no longer verifies
+    let mut message = VerifiableQueryResultMessage::decode(bytes.as_slice()).unwrap();
+    message.result.as_mut().unwrap().columns[0].integers[0] = 1;
+    let tampered_res =
+        VerifiableQueryResult::<DoryEvaluationProof>::from_protobuf(&message.encode_to_vec())
+            .unwrap();
+    assert!(matches!(
+        tampered_res.verify(&ast, &accessor, &dory_verifier_setup),
+        Err(QueryError::ProofError { .. })
+    ));
+}
+
+#[test]
+fn we_can_round_trip_every_column_type_through_protobuf() {
+    let table = owned_table::<DoryScalar>([
+        boolean("boolean", [true, false]),
+        tinyint("tinyint", [i8::MIN, i8::MAX]),
+        smallint("smallint", [i16::MIN, i16::MAX]),
+        int("int", [i32::MIN, i32::MAX]),
+        bigint("bigint", [i64::MIN, i64::MAX]),
+        int128("int128", [i128::MIN, i128::MAX]),
+        varchar("varchar", ["", "ünïcödé"]),
+        decimal75(
+            "decimal",
+            75,
+            -3,
+            [-DoryScalar::MAX_SIGNED, DoryScalar::ONE],
+        ),
+        scalar("scalar", [-DoryScalar::ONE, DoryScalar::MAX_SIGNED]),
+        timestamptz(
+            "timestamp",
+            PoSQLTimeUnit::Microsecond,
+            PoSQLTimeZone::new(-3600),
+            [i64::MIN, 1_231_006_505_000_001],
+        ),
+        date("date", [i32::MIN, -1]),
+        uint64("uint64", [0, u64::MAX]),
+    ]);
+    let verifiable_res = VerifiableQueryResult::<DoryEvaluationProof> {
+        result: Some(table.clone()),
+        proof: None,
+    };
+    let res = VerifiableQueryResult::<DoryEvaluationProof>::from_protobuf(
+        &verifiable_res.to_protobuf().unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.result, Some(table));
+    assert!(res.proof.is_none());
+}
+
+#[test]
+fn we_can_round_trip_an_empty_verifiable_query_result_through_protobuf() {
+    let verifiable_res = VerifiableQueryResult::<DoryEvaluationProof> {
+        result: None,
+        proof: None,
+    };
+    let bytes = verifiable_res.to_protobuf().unwrap();
+    assert!(bytes.is_empty());
+    let res = VerifiableQueryResult::<DoryEvaluationProof>::from_protobuf(&bytes).unwrap();
+    assert!(res.result.is_none());
+    assert!(res.proof.is_none());
+}
+
+#[test]
+fn we_cannot_decode_invalid_protobuf_messages() {
+    assert!(matches!(
+        VerifiableQueryResult::<DoryEvaluationProof>::from_protobuf(&[0x0a, 0x05, 0x01]),
+        Err(ProofSerializationError::Protobuf { .. })
+    ));
+    let invalid_columns = [
+        ColumnMessage {
+            name: "a".into(),
+            ..ColumnMessage::default()
+        },
+        ColumnMessage {
+            name: "a".into(),
+            kind: ColumnKind::TinyInt.into(),
+            integers: vec![128],
+            ..ColumnMessage::default()
+        },
+        ColumnMessage {
+            name: "a".into(),
+            kind: ColumnKind::Int128.into(),
+            wide_integers: vec![vec![0; 15]],
+            ..ColumnMessage::default()
+        },
+        ColumnMessage {
+            name: "a".into(),
+            kind: ColumnKind::Decimal75.into(),
+            precision: 76,
+            wide_integers: vec![vec![0; 32]],
+            ..ColumnMessage::default()
+        },
+        ColumnMessage {
+            name: "a".into(),
+            kind: ColumnKind::Scalar.into(),
+            wide_integers: vec![vec![0; 33]],
+            ..ColumnMessage::default()
+        },
+        ColumnMessage {
+            name: "a".into(),
+            kind: ColumnKind::TimestampTz.into(),
+            integers: vec![0],
+            ..ColumnMessage::default()
+        },
+    ];
+    for column in invalid_columns {
+        let bytes = VerifiableQueryResultMessage {
+            result: Some(TableMessage {
+                columns: vec![column],
+            }),
+            proof: None,
+        }
+        .encode_to_vec();
+        assert!(matches!(
+            VerifiableQueryResult::<DoryEvaluationProof>::from_protobuf(&bytes),
+            Err(ProofSerializationError::InvalidProtobuf { .. })
+        ));
+    }
+    let bigint_column = |name: &str, integers: Vec<i64>| ColumnMessage {
+        name: name.into(),
+        kind: ColumnKind::BigInt.into(),
+        integers,
+        ..ColumnMessage::default()
+    };
+    for columns in [
+        vec![bigint_column("a", vec![1]), bigint_column("a", vec![2])],
+        vec![bigint_column("a", vec![1]), bigint_column("b", vec![1, 2])],
+    ] {
+        let bytes = VerifiableQueryResultMessage {
+            result: Some(TableMessage { columns }),
+            proof: None,
+        }
+        .encode_to_vec();
+        assert!(matches!(
+            VerifiableQueryResult::<DoryEvaluationProof>::from_protobuf(&bytes),
+            Err(ProofSerializationError::InvalidProtobuf { .. })
+        ));
+    }
+    let bytes = VerifiableQueryResultMessage {
+        result: None,
+        proof: Some(QueryProofMessage {
+            proof: vec![0xff; 4],
+            ..QueryProofMessage::default()
+        }),
+    }
+    .encode_to_vec();
+    assert!(matches!(
+        VerifiableQueryResult::<DoryEvaluationProof>::from_protobuf(&bytes),
+        Err(ProofSerializationError::Postcard { .. })
+    ));
+}
@@ -0,0 +1,186 @@
+use super::{ProofPlan, QueryProof, VerifiableQueryResult};
+use crate::base::{
+    commitment::{CommitmentEvaluationProof, CommitmentSchemeId},
+    database::{ColumnField, DataAccessor, OwnedTable},
+};
+use alloc::vec::Vec;
+use core::fmt;
+use serde::Serialize;
+
+/// The serialized size in bytes of each part of a [`VerifiableQueryResult`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofSizeBreakdown {
+    /// The claimed result in intermediate form
+    pub result: usize,
+    /// The commitments of both rounds
+    pub commitments: usize,
+    /// The sumcheck proof
+    pub sumcheck_proof: usize,
+    /// The claimed evaluations of the committed columns
+    pub pcs_proof_evaluations: usize,
+    /// The evaluation proof of the commitment scheme
+    pub evaluation_proof: usize,
+    /// The whole serialized [`VerifiableQueryResult`]
+    pub total: usize,
+}
+
+/// An estimate of the cost of proving a query, computed without creating the proof
+///
+/// See [`VerifiableQueryResult::estimate_size`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofSizeEstimate {
+    /// The estimated serialized size of each part of the [`VerifiableQueryResult`]
+    pub size: ProofSizeBreakdown,
+    /// The number of multi-scalar multiplications used to commit to intermediate columns
+    pub num_msms: usize,
+}
+
+/// A summary of the structure of a [`VerifiableQueryResult`]
+///
+/// This is meant for debugging results received from other parties and does not verify anything.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofSummary {
+    /// The commitment scheme recorded in the proof, if there is one
+    ///
+    /// This is read from the proof itself, so it differs from the scheme of the
+    /// [`VerifiableQueryResult`] type if the proof was created with another scheme.
+    pub commitment_scheme: Option<CommitmentSchemeId>,
+    /// The schema of the claimed result, if there is one
+    pub schema: Option<Vec<ColumnField>>,
+    /// The number of rows of the claimed result, if there is one
+    pub num_rows: Option<usize>,
+    /// The number of commitments in the proof
+    pub num_commitments: usize,
+    /// The number of claimed evaluations of committed columns
+    pub num_pcs_proof_evaluations: usize,
+    /// The serialized size of each part of the result and proof
+    pub size: ProofSizeBreakdown,
+}
+
+/// The postcard serialized size of `value`
+pub(super) fn serialized_size(value: &impl Serialize) -> usize {
+    postcard::to_allocvec(value).map_or(0, |bytes| bytes.len())
+}
+
+impl<CP: CommitmentEvaluationProof + Serialize> VerifiableQueryResult<CP> {
+    /// Estimates the serialized size of the result that [`Self::new`] would create and the
+    /// number of MSMs it would compute, without committing to anything or creating the proof.
+    ///
+    /// The plan is still evaluated, since the size of the result and the shape of the proof
+    /// depend on the data. The estimate is exact for the Dory schemes as long as the setup is
+    /// large enough for the query.
+    #[must_use]
+    pub fn estimate_size(
+        expr: &impl ProofPlan,
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+    ) -> ProofSizeEstimate {
+        if expr
+            .get_table_references()
+            .into_iter()
+            .all(|table_ref| accessor.get_length(table_ref) == 0)
+        {
+            let none_size = serialized_size(&None::<OwnedTable<CP::Scalar>>);
+            return ProofSizeEstimate {
+                size: ProofSizeBreakdown {
+                    result: none_size,
+                    commitments: 0,
+                    sumcheck_proof: 0,
+                    pcs_proof_evaluations: 0,
+                    evaluation_proof: 0,
+                    total: 2 * none_size,
+                },
+                num_msms: 0,
+            };
+        }
+        QueryProof::<CP>::estimate_size(expr, accessor, setup)
+    }
+
+    /// Summarizes the structure of this result and its proof without verifying it.
+    #[must_use]
+    pub fn summary(&self) -> ProofSummary {
+        let schema = self.result.as_ref().map(|table| {
+            table
+                .inner_table()
+                .iter()
+                .map(|(name, column)| ColumnField::new(name.clone(), column.column_type()))
+                .collect()
+        });
+        let num_rows = self.result.as_ref().map(OwnedTable::num_rows);
+        let (num_commitments, num_pcs_proof_evaluations) =
+            self.proof.as_ref().map_or((0, 0), |proof| {
+                (
+                    proof.first_round_commitments.len() + proof.final_round_commitments.len(),
+                    proof.first_round_pcs_proof_evaluations.len()
+                        + proof.final_round_pcs_proof_evaluations.len(),
+                )
+            });
+        let size = ProofSizeBreakdown {
+            result: serialized_size(&self.result),
+            commitments: self.proof.as_ref().map_or(0, |proof| {
+                serialized_size(&proof.first_round_commitments)
+                    + serialized_size(&proof.final_round_commitments)
+            }),
+            sumcheck_proof: self
+                .proof
+                .as_ref()
+                .map_or(0, |proof| serialized_size(&proof.sumcheck_proof)),
+            pcs_proof_evaluations: self.proof.as_ref().map_or(0, |proof| {
+                serialized_size(&proof.first_round_pcs_proof_evaluations)
+                    + serialized_size(&proof.final_round_pcs_proof_evaluations)
+            }),
+            evaluation_proof: self
+                .proof
+                .as_ref()
+                .map_or(0, |proof| serialized_size(&proof.evaluation_proof)),
+            total: serialized_size(self),
+        };
+        ProofSummary {
+            commitment_scheme: self.proof.as_ref().map(|proof| proof.scheme),
+            schema,
+            num_rows,
+            num_commitments,
+            num_pcs_proof_evaluations,
+            size,
+        }
+    }
+}
+
+impl fmt::Display for ProofSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.commitment_scheme {
+            Some(scheme) => writeln!(f, "commitment scheme: {scheme}")?,
+            None => writeln!(f, "commitment scheme: none (no proof)")?,
+        }
+        match (&self.schema, self.num_rows) {
+            (Some(schema), Some(num_rows)) => {
+                writeln!(f, "schema:")?;
+                for field in schema {
+                    writeln!(f, "  {}: {}", field.name(), field.data_type())?;
+                }
+                writeln!(f, "rows: {num_rows}")?;
+            }
+            _ => writeln!(f, "result: none (all queried tables are empty)")?,
+        }
+        writeln!(f, "commitments: {}", self.num_commitments)?;
+        writeln!(
+            f,
+            "pcs proof evaluations: {}",
+            self.num_pcs_proof_evaluations
+        )?;
+        writeln!(f, "size: {} bytes", self.size.total)?;
+        writeln!(f, "  result: {} bytes", self.size.result)?;
+        writeln!(f, "  commitments: {} bytes", self.size.commitments)?;
+        writeln!(f, "  sumcheck proof: {} bytes", self.size.sumcheck_proof)?;
+        writeln!(
+            f,
+            "  pcs proof evaluations: {} bytes",
+            self.size.pcs_proof_evaluations
+        )?;
+        writeln!(
+            f,
+            "  evaluation proof: {} bytes",
+            self.size.evaluation_proof
+        )
+    }
+}
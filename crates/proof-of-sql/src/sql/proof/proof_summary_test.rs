@@ -0,0 +1,243 @@
+use super::{ProofPlan, VerifiableQueryResult};
+use crate::{
+    base::{
+        commitment::{CommitmentEvaluationProof, CommitmentSchemeId},
+        database::{
+            owned_table_utility::*, ColumnField, ColumnType, CommitmentAccessor, DataAccessor,
+            OwnedTableTestAccessor, TestAccessor,
+        },
+    },
+    proof_primitive::dory::{
+        DoryEvaluationProof, DoryProverPublicSetup, DynamicDoryEvaluationProof, ProverSetup,
+        PublicParameters,
+    },
+    sql::{proof_exprs::test_utility::*, proof_plans::test_utility::*},
+};
+use ark_std::test_rng;
+use serde::Serialize;
+
+/// Checks the estimate against the proof that is actually created.
+///
+/// Every scalar, commitment and Dory message serializes to a fixed size, so the tolerance for the
+/// Dory schemes is zero bytes.
+fn assert_estimate_matches_proof<CP: CommitmentEvaluationProof + Serialize>(
+    expr: &(impl ProofPlan + Serialize),
+    accessor: &(impl DataAccessor<CP::Scalar> + CommitmentAccessor<CP::Commitment>),
+    setup: &CP::ProverPublicSetup<'_>,
+) {
+    let estimate = VerifiableQueryResult::<CP>::estimate_size(expr, accessor, setup);
+    let summary = VerifiableQueryResult::<CP>::new(expr, accessor, setup).summary();
+    assert_eq!(estimate.size, summary.size);
+    assert_eq!(estimate.num_msms, summary.num_commitments);
+}
+
+#[test]
+fn we_can_summarize_a_dory_proof() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        t,
+        owned_table([
+            bigint("a", [1, 2, 3, 4]),
+            varchar("b", ["x", "y", "x", "z"]),
+        ]),
+        0,
+    );
+    let ast = filter(
+        cols_expr_plan(t, &["a", "b"], &accessor),
+        tab(t),
+        equal(column(t, "b", &accessor), const_varchar("x")),
+    );
+    let verifiable_res =
+        VerifiableQueryResult::<DoryEvaluationProof>::new(&ast, &accessor, &dory_prover_setup);
+
+    // Inspect the result as received from another party
+    let bytes = postcard::to_allocvec(&verifiable_res).unwrap();
+    let received: VerifiableQueryResult<DoryEvaluationProof> =
+        postcard::from_bytes(&bytes).unwrap();
+    let summary = received.summary();
+
+    assert_eq!(summary.commitment_scheme, Some(CommitmentSchemeId::Dory));
+    assert_eq!(
+        summary.schema,
+        Some(vec![
+            ColumnField::new("a".into(), ColumnType::BigInt),
+            ColumnField::new("b".into(), ColumnType::VarChar),
+        ])
+    );
+    assert_eq!(summary.num_rows, Some(2));
+    assert!(summary.num_commitments > 0);
+    assert!(summary.num_pcs_proof_evaluations > 0);
+    assert_eq!(summary.size.total, bytes.len());
+    let parts = [
+        summary.size.result,
+        summary.size.commitments,
+        summary.size.sumcheck_proof,
+        summary.size.pcs_proof_evaluations,
+        summary.size.evaluation_proof,
+    ];
+    assert!(parts.iter().all(|&size| size > 0));
+    assert!(parts.iter().sum::<usize>() <= summary.size.total);
+
+    let display = summary.to_string();
+    assert!(display.contains("commitment scheme: Dory"));
+    assert!(display.contains("  a: BIGINT\n  b: VARCHAR\nrows: 2"));
+}
+
+#[test]
+fn we_can_see_the_scheme_of_a_proof_created_with_another_scheme_in_its_summary() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3])]),
+        0,
+        dory_prover_setup,
+    );
+    let ast = projection(cols_expr_plan(t, &["a"], &accessor), tab(t));
+    let mut verifiable_res =
+        VerifiableQueryResult::<DoryEvaluationProof>::new(&ast, &accessor, &dory_prover_setup);
+    verifiable_res.proof.as_mut().unwrap().scheme = CommitmentSchemeId::DynamicDory;
+    let summary = verifiable_res.summary();
+    assert_eq!(
+        summary.commitment_scheme,
+        Some(CommitmentSchemeId::DynamicDory)
+    );
+    assert!(summary
+        .to_string()
+        .contains("commitment scheme: DynamicDory"));
+}
+
+#[test]
+fn we_can_summarize_a_result_over_empty_tables() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [0; 0])]),
+        0,
+        dory_prover_setup,
+    );
+    let ast = projection(cols_expr_plan(t, &["a"], &accessor), tab(t));
+    let summary =
+        VerifiableQueryResult::<DoryEvaluationProof>::new(&ast, &accessor, &dory_prover_setup)
+            .summary();
+    assert_eq!(summary.commitment_scheme, None);
+    assert_eq!(summary.schema, None);
+    assert_eq!(summary.num_rows, None);
+    assert_eq!(summary.num_commitments, 0);
+    assert_eq!(summary.size.evaluation_proof, 0);
+    assert!(summary
+        .to_string()
+        .contains("result: none (all queried tables are empty)"));
+}
+
+#[test]
+fn we_can_estimate_the_size_of_dory_proofs_of_several_plan_shapes() {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([
+            bigint("a", [1, 2, 3, 4, 5]),
+            bigint("b", [-3, 7, 0, 4, 9]),
+            varchar("c", ["x", "y", "x", "z", "y"]),
+        ]),
+        0,
+        dory_prover_setup,
+    );
+
+    let filter_ast = filter(
+        cols_expr_plan(t, &["a", "c"], &accessor),
+        tab(t),
+        lte(column(t, "b", &accessor), const_bigint(4)),
+    );
+    let projection_ast = projection(
+        vec![aliased_plan(
+            multiply(column(t, "a", &accessor), column(t, "b", &accessor)),
+            "ab",
+        )],
+        tab(t),
+    );
+    let group_by_ast = group_by(
+        cols_expr(t, &["c"], &accessor),
+        vec![sum_expr(column(t, "b", &accessor), "sum_b")],
+        "__count__",
+        tab(t),
+        gte(column(t, "a", &accessor), const_bigint(2)),
+    );
+    for ast in [filter_ast, projection_ast, group_by_ast] {
+        assert_estimate_matches_proof::<DoryEvaluationProof>(&ast, &accessor, &dory_prover_setup);
+    }
+}
+
+#[test]
+fn we_can_estimate_the_size_of_a_dory_proof_with_more_rows_than_the_sigma_covers() {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 2);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", 0..100), bigint("b", (0..100).map(|i| i % 7))]),
+        0,
+        dory_prover_setup,
+    );
+    let ast = filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        equal(column(t, "b", &accessor), const_bigint(3)),
+    );
+    assert_estimate_matches_proof::<DoryEvaluationProof>(&ast, &accessor, &dory_prover_setup);
+}
+
+#[test]
+fn we_can_estimate_the_size_of_a_dynamic_dory_proof() {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DynamicDoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3, 4, 5]), bigint("b", [-3, 7, 0, 4, 9])]),
+        0,
+        &prover_setup,
+    );
+    let ast = filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        gte(column(t, "b", &accessor), const_bigint(0)),
+    );
+    assert_estimate_matches_proof::<DynamicDoryEvaluationProof>(&ast, &accessor, &&prover_setup);
+}
+
+#[test]
+fn we_can_estimate_the_size_of_a_result_over_empty_tables() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [0; 0])]),
+        0,
+        dory_prover_setup,
+    );
+    let ast = projection(cols_expr_plan(t, &["a"], &accessor), tab(t));
+    let estimate = VerifiableQueryResult::<DoryEvaluationProof>::estimate_size(
+        &ast,
+        &accessor,
+        &dory_prover_setup,
+    );
+    assert_eq!(estimate.num_msms, 0);
+    assert_estimate_matches_proof::<DoryEvaluationProof>(&ast, &accessor, &dory_prover_setup);
+}
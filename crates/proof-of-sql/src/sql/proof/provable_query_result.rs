@@ -113,7 +113,9 @@ impl ProvableQueryResult {
                     ColumnType::Boolean => decode_and_convert::<bool, S>(&self.data[offset..]),
                     ColumnType::TinyInt => decode_and_convert::<i8, S>(&self.data[offset..]),
                     ColumnType::SmallInt => decode_and_convert::<i16, S>(&self.data[offset..]),
-                    ColumnType::Int => decode_and_convert::<i32, S>(&self.data[offset..]),
+                    ColumnType::Int | ColumnType::Date => {
+                        decode_and_convert::<i32, S>(&self.data[offset..])
+                    }
                     ColumnType::BigInt => decode_and_convert::<i64, S>(&self.data[offset..]),
                     ColumnType::Int128 => decode_and_convert::<i128, S>(&self.data[offset..]),
                     ColumnType::Decimal75(_, _) | ColumnType::Scalar => {
@@ -124,6 +126,7 @@ impl ProvableQueryResult {
                     ColumnType::TimestampTZ(_, _) => {
                         decode_and_convert::<i64, S>(&self.data[offset..])
                     }
+                    ColumnType::UInt64 => decode_and_convert::<u64, S>(&self.data[offset..]),
                 }?;
                 val += *entry * x;
                 offset += sz;
@@ -209,6 +212,16 @@ impl ProvableQueryResult {
                         offset += num_read;
                         Ok((field.name(), OwnedColumn::TimestampTZ(tu, tz, col)))
                     }
+                    ColumnType::Date => {
+                        let (col, num_read) = decode_multiple_elements(&self.data[offset..], n)?;
+                        offset += num_read;
+                        Ok((field.name(), OwnedColumn::Date(col)))
+                    }
+                    ColumnType::UInt64 => {
+                        let (col, num_read) = decode_multiple_elements(&self.data[offset..], n)?;
+                        offset += num_read;
+                        Ok((field.name(), OwnedColumn::UInt64(col)))
+                    }
                 })
                 .collect::<Result<_, QueryError>>()?,
         )?;
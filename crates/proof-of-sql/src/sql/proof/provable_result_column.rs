@@ -34,11 +34,12 @@ impl<S: Scalar> ProvableResultColumn for Column<'_, S> {
             Column::Boolean(col) => col.num_bytes(length),
             Column::TinyInt(col) => col.num_bytes(length),
             Column::SmallInt(col) => col.num_bytes(length),
-            Column::Int(col) => col.num_bytes(length),
+            Column::Int(col) | Column::Date(col) => col.num_bytes(length),
             Column::BigInt(col) | Column::TimestampTZ(_, _, col) => col.num_bytes(length),
             Column::Int128(col) => col.num_bytes(length),
             Column::Decimal75(_, _, col) | Column::Scalar(col) => col.num_bytes(length),
             Column::VarChar((col, _)) => col.num_bytes(length),
+            Column::UInt64(col) => col.num_bytes(length),
         }
     }
 
@@ -47,11 +48,12 @@ impl<S: Scalar> ProvableResultColumn for Column<'_, S> {
             Column::Boolean(col) => col.write(out, length),
             Column::TinyInt(col) => col.write(out, length),
             Column::SmallInt(col) => col.write(out, length),
-            Column::Int(col) => col.write(out, length),
+            Column::Int(col) | Column::Date(col) => col.write(out, length),
             Column::BigInt(col) | Column::TimestampTZ(_, _, col) => col.write(out, length),
             Column::Int128(col) => col.write(out, length),
             Column::Decimal75(_, _, col) | Column::Scalar(col) => col.write(out, length),
             Column::VarChar((col, _)) => col.write(out, length),
+            Column::UInt64(col) => col.write(out, length),
         }
     }
 }
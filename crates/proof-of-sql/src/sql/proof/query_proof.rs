@@ -1,12 +1,13 @@
 use super::{
-    make_sumcheck_state::make_sumcheck_prover_state, FinalRoundBuilder, FirstRoundBuilder,
-    ProofPlan, QueryData, QueryResult, SumcheckMleEvaluations, SumcheckRandomScalars,
-    VerificationBuilder,
+    make_sumcheck_state::make_sumcheck_prover_state,
+    proof_summary::{serialized_size, ProofSizeBreakdown, ProofSizeEstimate},
+    FinalRoundBuilder, FirstRoundBuilder, ProofPlan, QueryData, QueryResult,
+    SumcheckMleEvaluations, SumcheckRandomScalars, SumcheckSubpolynomial, VerificationBuilder,
 };
 use crate::{
     base::{
         bit::BitDistribution,
-        commitment::{Commitment, CommitmentEvaluationProof},
+        commitment::{Commitment, CommitmentEvaluationProof, CommitmentSchemeId},
         database::{
             ColumnRef, CommitmentAccessor, DataAccessor, MetadataAccessor, OwnedColumn, OwnedTable,
             Table, TableRef,
@@ -46,6 +47,25 @@ fn get_index_range<'a>(
         .unwrap_or((0, 1))
 }
 
+/// Fetch the columns that `expr` references from each table that it references
+fn get_table_map<'a, S: Scalar>(
+    expr: &impl ProofPlan,
+    accessor: &'a impl DataAccessor<S>,
+) -> IndexMap<TableRef, Table<'a, S>> {
+    let total_col_refs = expr.get_column_references();
+    expr.get_table_references()
+        .into_iter()
+        .map(|table_ref| {
+            let col_refs: IndexSet<ColumnRef> = total_col_refs
+                .iter()
+                .filter(|col_ref| col_ref.table_ref() == table_ref)
+                .cloned()
+                .collect();
+            (table_ref, accessor.get_table(table_ref, &col_refs))
+        })
+        .collect()
+}
+
 /// The proof for a query.
 ///
 /// Note: Because the class is deserialized from untrusted data, it
@@ -53,6 +73,8 @@ fn get_index_range<'a>(
 /// all public so as to allow for easy manipulation for testing.
 #[derive(Clone, Serialize, Deserialize)]
 pub(super) struct QueryProof<CP: CommitmentEvaluationProof> {
+    /// The commitment scheme the proof was created with, serialized first
+    pub scheme: CommitmentSchemeId,
     /// Bit distributions
     pub bit_distributions: Vec<BitDistribution>,
     /// One evaluation lengths
@@ -76,32 +98,56 @@ pub(super) struct QueryProof<CP: CommitmentEvaluationProof> {
 }
 
 impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
-    /// Create a new `QueryProof`.
+    /// Create a new `QueryProof` like [`Self::new_with_cancellation`], without cancellation.
+    #[cfg(all(test, feature = "blitzar"))]
     #[tracing::instrument(name = "QueryProof::new", level = "debug", skip_all)]
     pub fn new(
         expr: &(impl ProofPlan + Serialize),
         accessor: &impl DataAccessor<CP::Scalar>,
         setup: &CP::ProverPublicSetup<'_>,
+        domain_separator: &[u8],
     ) -> (Self, OwnedTable<CP::Scalar>) {
+        match Self::new_with_cancellation(expr, accessor, setup, domain_separator, &|| true) {
+            Ok(proof_and_result) => proof_and_result,
+            Err(error) => panic!("proving failed: {error}"),
+        }
+    }
+
+    /// Create a new `QueryProof` whose transcript starts with `domain_separator`, checking
+    /// `should_continue` between the phases of the prover and between the rounds of the
+    /// evaluation proof.
+    ///
+    /// A proof created with one domain separator only verifies with the same domain separator.
+    /// The empty domain separator is not absorbed at all, so it leaves the transcript unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProofError::Cancelled`] if `should_continue` returns `false`, and
+    /// [`ProofError::DivisionByZero`] if the query divides by zero.
+    #[tracing::instrument(name = "QueryProof::new_with_cancellation", level = "debug", skip_all)]
+    pub fn new_with_cancellation(
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+        domain_separator: &[u8],
+        should_continue: &dyn Fn() -> bool,
+    ) -> Result<(Self, OwnedTable<CP::Scalar>), ProofError> {
         log::log_memory_usage("Start");
 
+        let check_cancelled = || {
+            if should_continue() {
+                Ok(())
+            } else {
+                Err(ProofError::Cancelled)
+            }
+        };
+
         let (min_row_num, max_row_num) = get_index_range(accessor, &expr.get_table_references());
         let initial_range_length = max_row_num - min_row_num;
         let alloc = Bump::new();
 
         let total_col_refs = expr.get_column_references();
-        let table_map: IndexMap<TableRef, Table<CP::Scalar>> = expr
-            .get_table_references()
-            .into_iter()
-            .map(|table_ref| {
-                let col_refs: IndexSet<ColumnRef> = total_col_refs
-                    .iter()
-                    .filter(|col_ref| col_ref.table_ref() == table_ref)
-                    .cloned()
-                    .collect();
-                (table_ref, accessor.get_table(table_ref, &col_refs))
-            })
-            .collect();
+        let table_map = get_table_map(expr, accessor);
 
         // Prover First Round: Evaluate the query && get the right number of post result challenges
         let mut first_round_builder = FirstRoundBuilder::new(initial_range_length);
@@ -119,9 +165,11 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         // commit to any intermediate MLEs
         let first_round_commitments =
             first_round_builder.commit_intermediate_mles(min_row_num, setup);
+        check_cancelled()?;
 
         // construct a transcript for the proof
         let mut transcript: Keccak256Transcript = make_transcript(
+            domain_separator,
             expr,
             &owned_table_result,
             range_length,
@@ -149,12 +197,16 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         }
 
         expr.final_round_evaluate(&mut final_round_builder, &alloc, &table_map);
+        if let Some(error) = final_round_builder.take_error() {
+            return Err(error);
+        }
 
         let num_sumcheck_variables = final_round_builder.num_sumcheck_variables();
 
         // commit to any intermediate MLEs
         let final_round_commitments =
             final_round_builder.commit_intermediate_mles(min_row_num, setup);
+        check_cancelled()?;
 
         // add the commitments, bit distributions and one evaluation lengths to the proof
         extend_transcript_with_commitments(
@@ -179,6 +231,7 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         // create the sumcheck proof -- this is the main part of proving a query
         let mut evaluation_point = vec![Zero::zero(); state.num_vars];
         let sumcheck_proof = SumcheckProof::create(&mut transcript, &mut evaluation_point, state);
+        check_cancelled()?;
 
         // evaluate the MLEs used in sumcheck except for the result columns
         let mut evaluation_vec = vec![Zero::zero(); range_length];
@@ -217,15 +270,18 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         }
 
         // finally, form the inner product proof of the MLEs' evaluations
-        let evaluation_proof = CP::new(
+        let evaluation_proof = CP::new_with_cancellation(
             &mut transcript,
             &folded_mle,
             &evaluation_point,
             min_row_num as u64,
             setup,
-        );
+            should_continue,
+        )
+        .ok_or(ProofError::Cancelled)?;
 
         let proof = Self {
+            scheme: CP::SCHEME_ID,
             bit_distributions: final_round_builder.bit_distributions().to_vec(),
             one_evaluation_lengths: one_evaluation_lengths.to_vec(),
             first_round_commitments,
@@ -241,20 +297,115 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
 
         log::log_memory_usage("End");
 
-        (proof, provable_result)
+        Ok((proof, provable_result))
+    }
+
+    /// Estimate the serialized size of the result and the proof that [`Self::new`] would create,
+    /// along with the number of MSMs it would compute.
+    ///
+    /// This evaluates the plan to learn the shape of the proof, but skips committing, the
+    /// sumcheck protocol and, unless the commitment scheme can size it directly, the evaluation
+    /// proof, which dominate the cost of proving. Every scalar and commitment serializes to a
+    /// fixed size, so the estimate is exact unless the commitment scheme gives up on the
+    /// evaluation proof, e.g. because the setup is too small.
+    #[tracing::instrument(name = "QueryProof::estimate_size", level = "debug", skip_all)]
+    pub fn estimate_size(
+        expr: &impl ProofPlan,
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+    ) -> ProofSizeEstimate
+    where
+        CP: Serialize,
+    {
+        let (min_row_num, max_row_num) = get_index_range(accessor, &expr.get_table_references());
+        let alloc = Bump::new();
+        let table_map = get_table_map(expr, accessor);
+
+        let mut first_round_builder = FirstRoundBuilder::new(max_row_num - min_row_num);
+        let query_result = expr.first_round_evaluate(&mut first_round_builder, &alloc, &table_map);
+        let range_length = first_round_builder.range_length();
+        let num_sumcheck_variables = cmp::max(log2_up(range_length), 1);
+        let post_result_challenge_count = first_round_builder.num_post_result_challenges();
+
+        // The values of the challenges do not change the shape of the proof.
+        let mut transcript = Keccak256Transcript::new();
+        let post_result_challenges =
+            core::iter::repeat_with(|| transcript.scalar_challenge_as_be())
+                .take(post_result_challenge_count)
+                .collect();
+        let mut final_round_builder =
+            FinalRoundBuilder::new(num_sumcheck_variables, post_result_challenges);
+        for col_ref in expr.get_column_references() {
+            final_round_builder.produce_anchored_mle(accessor.get_column(col_ref));
+        }
+        expr.final_round_evaluate(&mut final_round_builder, &alloc, &table_map);
+
+        let scalars_size = |len| serialized_size(&vec![CP::Scalar::ZERO; len]);
+        let commitments_size = |len| serialized_size(&vec![CP::Commitment::default(); len]);
+        let sumcheck_degree = final_round_builder
+            .sumcheck_subpolynomials()
+            .iter()
+            .map(SumcheckSubpolynomial::max_multiplicands)
+            // The composite polynomial always contains the random scalars times an MLE.
+            .fold(2, cmp::max);
+
+        let result = serialized_size(&Some(OwnedTable::from(&query_result)));
+        let commitments = commitments_size(first_round_builder.num_intermediate_mles())
+            + commitments_size(final_round_builder.num_intermediate_mles());
+        let sumcheck_proof = scalars_size(num_sumcheck_variables * (sumcheck_degree + 1));
+        let pcs_proof_evaluations = scalars_size(first_round_builder.pcs_proof_mles().len())
+            + scalars_size(final_round_builder.pcs_proof_mles().len());
+        let evaluation_proof =
+            CP::serialized_proof_size(range_length, num_sumcheck_variables, setup);
+        let remaining_fields = serialized_size(&CP::SCHEME_ID)
+            + serialized_size(&final_round_builder.bit_distributions())
+            + serialized_size(&first_round_builder.one_evaluation_lengths())
+            + serialized_size(&range_length)
+            + serialized_size(&final_round_builder.num_sumcheck_subpolynomials())
+            + serialized_size(&post_result_challenge_count);
+        // `Some(proof)` is tagged with a single byte.
+        let total = result
+            + 1
+            + commitments
+            + sumcheck_proof
+            + pcs_proof_evaluations
+            + evaluation_proof
+            + remaining_fields;
+
+        ProofSizeEstimate {
+            size: ProofSizeBreakdown {
+                result,
+                commitments,
+                sumcheck_proof,
+                pcs_proof_evaluations,
+                evaluation_proof,
+                total,
+            },
+            num_msms: first_round_builder.num_intermediate_mles()
+                + final_round_builder.num_intermediate_mles(),
+        }
     }
 
     #[tracing::instrument(name = "QueryProof::verify", level = "debug", skip_all, err)]
-    /// Verify a `QueryProof`. Note: This does NOT transform the result!
+    /// Verify a `QueryProof` created with the given domain separator, see [`Self::new`].
+    /// Note: This does NOT transform the result!
     pub fn verify(
         self,
         expr: &(impl ProofPlan + Serialize),
         accessor: &impl CommitmentAccessor<CP::Commitment>,
         result: OwnedTable<CP::Scalar>,
         setup: &CP::VerifierPublicSetup<'_>,
+        domain_separator: &[u8],
     ) -> QueryResult<CP::Scalar> {
         log::log_memory_usage("Start");
 
+        if self.scheme != CP::SCHEME_ID {
+            Err(ProofError::SchemeMismatch {
+                expected: CP::SCHEME_ID,
+                found: self.scheme,
+            })?;
+        }
+
         let table_refs = expr.get_table_references();
         let (min_row_num, _) = get_index_range(accessor, &table_refs);
         let num_sumcheck_variables = cmp::max(log2_up(self.range_length), 1);
@@ -277,6 +428,7 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
 
         // construct a transcript for the proof
         let mut transcript: Keccak256Transcript = make_transcript(
+            domain_separator,
             expr,
             &result,
             self.range_length,
@@ -444,11 +596,12 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
 /// Constructs a transcript for the proof process.
 ///
 /// This function initializes a transcript and extends it with various elements
-/// such as the result table columns, the proof plan expression, the range length,
-/// the minimum row number, and the one evaluation lengths.
+/// such as the domain separator, the result table columns, the proof plan expression,
+/// the range length, the minimum row number, and the one evaluation lengths.
 ///
 /// # Arguments
 ///
+/// * `domain_separator` - The domain separator, absorbed first unless it is empty.
 /// * `expr` - The proof plan expression.
 /// * `result` - The result table containing the query result.
 /// * `range_length` - The length of the range of generators used.
@@ -460,7 +613,9 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
 /// # Returns
 ///
 /// A transcript initialized with the provided data.
+#[allow(clippy::too_many_arguments)]
 fn make_transcript<C: Commitment, T: Transcript>(
+    domain_separator: &[u8],
     expr: &(impl ProofPlan + Serialize),
     result: &OwnedTable<C::Scalar>,
     range_length: usize,
@@ -470,6 +625,9 @@ fn make_transcript<C: Commitment, T: Transcript>(
     first_round_commitments: &[C],
 ) -> T {
     let mut transcript = T::new();
+    if !domain_separator.is_empty() {
+        transcript.extend_serialize_as_le(domain_separator);
+    }
     extend_transcript_with_owned_table(&mut transcript, result);
     transcript.extend_serialize_as_le(expr);
     transcript.extend_serialize_as_le(&range_length);
@@ -500,7 +658,9 @@ fn extend_transcript_with_owned_table<S: Scalar, T: Transcript>(
             OwnedColumn::Boolean(col) => transcript.extend_as_be(col.iter().map(|&b| u8::from(b))),
             OwnedColumn::TinyInt(col) => transcript.extend_as_be_from_refs(col),
             OwnedColumn::SmallInt(col) => transcript.extend_as_be_from_refs(col),
-            OwnedColumn::Int(col) => transcript.extend_as_be_from_refs(col),
+            OwnedColumn::Int(col) | OwnedColumn::Date(col) => {
+                transcript.extend_as_be_from_refs(col);
+            }
             OwnedColumn::BigInt(col) => transcript.extend_as_be_from_refs(col),
             OwnedColumn::VarChar(col) => {
                 transcript.extend_as_le_from_refs(col.iter().map(String::as_str));
@@ -519,6 +679,7 @@ fn extend_transcript_with_owned_table<S: Scalar, T: Transcript>(
                 transcript.extend_as_be([po_sqltime_zone.offset()]);
                 transcript.extend_as_be_from_refs(col);
             }
+            OwnedColumn::UInt64(col) => transcript.extend_as_be_from_refs(col),
         }
     }
 }
@@ -129,13 +129,13 @@ fn verify_a_trivial_query_proof_with_given_offset(n: usize, offset_generators: u
         offset_generators,
         (),
     );
-    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &(), &[]);
     let QueryData {
         verification_hash,
         table,
     } = proof
         .clone()
-        .verify(&expr, &accessor, result.clone(), &())
+        .verify(&expr, &accessor, result.clone(), &(), &[])
         .unwrap();
     assert_ne!(verification_hash, [0; 32]);
     let expected_result = owned_table([bigint("a1", column)]);
@@ -156,6 +156,27 @@ fn we_can_verify_a_trivial_query_proof_with_a_non_zero_offset() {
     }
 }
 
+#[test]
+fn verify_fails_if_the_domain_separator_doesnt_match() {
+    let expr = TrivialTestProofPlan::default();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("a1", [0_i64; 2])]),
+        0,
+        (),
+    );
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &(), b"domain-a");
+    assert!(proof
+        .clone()
+        .verify(&expr, &accessor, result.clone(), &(), b"domain-a")
+        .is_ok());
+    assert!(proof
+        .clone()
+        .verify(&expr, &accessor, result.clone(), &(), b"domain-b")
+        .is_err());
+    assert!(proof.verify(&expr, &accessor, result, &(), &[]).is_err());
+}
+
 #[test]
 fn verify_fails_if_the_summation_in_sumcheck_isnt_zero() {
     // set up a proof for an artificial polynomial that doesn't sum to zero
@@ -169,8 +190,8 @@ fn verify_fails_if_the_summation_in_sumcheck_isnt_zero() {
         0,
         (),
     );
-    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
-    assert!(proof.verify(&expr, &accessor, result, &()).is_err());
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &(), &[]);
+    assert!(proof.verify(&expr, &accessor, result, &(), &[]).is_err());
 }
 
 #[test]
@@ -187,8 +208,8 @@ fn verify_fails_if_the_sumcheck_evaluation_isnt_correct() {
         0,
         (),
     );
-    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
-    assert!(proof.verify(&expr, &accessor, result, &()).is_err());
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &(), &[]);
+    assert!(proof.verify(&expr, &accessor, result, &(), &[]).is_err());
 }
 
 #[test]
@@ -205,8 +226,8 @@ fn verify_fails_if_counts_dont_match() {
         0,
         (),
     );
-    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
-    assert!(proof.verify(&expr, &accessor, result, &()).is_err());
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &(), &[]);
+    assert!(proof.verify(&expr, &accessor, result, &(), &[]).is_err());
 }
 
 #[test]
@@ -221,8 +242,8 @@ fn verify_fails_if_the_number_of_bit_distributions_is_not_enough() {
         0,
         (),
     );
-    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
-    assert!(proof.verify(&expr, &accessor, result, &()).is_err());
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &(), &[]);
+    assert!(proof.verify(&expr, &accessor, result, &(), &[]).is_err());
 }
 
 #[test]
@@ -240,8 +261,8 @@ fn verify_fails_if_a_bit_distribution_is_invalid() {
         0,
         (),
     );
-    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
-    assert!(proof.verify(&expr, &accessor, result, &()).is_err());
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &(), &[]);
+    assert!(proof.verify(&expr, &accessor, result, &(), &[]).is_err());
 }
 
 /// prove and verify an artificial query where
@@ -350,13 +371,13 @@ fn verify_a_proof_with_an_anchored_commitment_and_given_offset(offset_generators
         offset_generators,
         (),
     );
-    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &(), &[]);
     let QueryData {
         verification_hash,
         table,
     } = proof
         .clone()
-        .verify(&expr, &accessor, result.clone(), &())
+        .verify(&expr, &accessor, result.clone(), &(), &[])
         .unwrap();
     assert_ne!(verification_hash, [0; 32]);
     let expected_result = owned_table([bigint("a1", [9, 25])]);
@@ -369,7 +390,7 @@ fn verify_a_proof_with_an_anchored_commitment_and_given_offset(offset_generators
         offset_generators + 1,
         (),
     );
-    assert!(proof.verify(&expr, &accessor, result, &()).is_err());
+    assert!(proof.verify(&expr, &accessor, result, &(), &[]).is_err());
 }
 
 #[test]
@@ -399,8 +420,8 @@ fn verify_fails_if_the_result_doesnt_satisfy_an_anchored_equation() {
         0,
         (),
     );
-    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
-    assert!(proof.verify(&expr, &accessor, result, &()).is_err());
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &(), &[]);
+    assert!(proof.verify(&expr, &accessor, result, &(), &[]).is_err());
 }
 
 #[test]
@@ -418,8 +439,8 @@ fn verify_fails_if_the_anchored_commitment_doesnt_match() {
         0,
         (),
     );
-    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
-    assert!(proof.verify(&expr, &accessor, result, &()).is_err());
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &(), &[]);
+    assert!(proof.verify(&expr, &accessor, result, &(), &[]).is_err());
 }
 
 // prove and verify an artificial query where
@@ -552,13 +573,13 @@ fn verify_a_proof_with_an_intermediate_commitment_and_given_offset(offset_genera
         offset_generators,
         (),
     );
-    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &(), &[]);
     let QueryData {
         verification_hash,
         table,
     } = proof
         .clone()
-        .verify(&expr, &accessor, result.clone(), &())
+        .verify(&expr, &accessor, result.clone(), &(), &[])
         .unwrap();
     assert_ne!(verification_hash, [0; 32]);
     let expected_result = owned_table([bigint("a1", [81, 625])]);
@@ -571,7 +592,7 @@ fn verify_a_proof_with_an_intermediate_commitment_and_given_offset(offset_genera
         offset_generators + 1,
         (),
     );
-    assert!(proof.verify(&expr, &accessor, result, &()).is_err());
+    assert!(proof.verify(&expr, &accessor, result, &(), &[]).is_err());
 }
 
 #[test]
@@ -599,10 +620,10 @@ fn verify_fails_if_an_intermediate_commitment_doesnt_match() {
         0,
         (),
     );
-    let (mut proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let (mut proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &(), &[]);
     proof.final_round_commitments[0] =
         proof.final_round_commitments[0] * Curve25519Scalar::from(2u64);
-    assert!(proof.verify(&expr, &accessor, result, &()).is_err());
+    assert!(proof.verify(&expr, &accessor, result, &(), &[]).is_err());
 }
 
 #[test]
@@ -622,8 +643,8 @@ fn verify_fails_if_an_intermediate_equation_isnt_satified() {
         0,
         (),
     );
-    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
-    assert!(proof.verify(&expr, &accessor, result, &()).is_err());
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &(), &[]);
+    assert!(proof.verify(&expr, &accessor, result, &(), &[]).is_err());
 }
 
 #[test]
@@ -644,8 +665,8 @@ fn verify_fails_the_result_doesnt_satisfy_an_intermediate_equation() {
         0,
         (),
     );
-    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
-    assert!(proof.verify(&expr, &accessor, result, &()).is_err());
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &(), &[]);
+    assert!(proof.verify(&expr, &accessor, result, &(), &[]).is_err());
 }
 
 #[derive(Debug, Serialize)]
@@ -743,13 +764,13 @@ fn verify_a_proof_with_a_post_result_challenge_and_given_offset(offset_generator
         offset_generators,
         (),
     );
-    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &(), &[]);
     let QueryData {
         verification_hash,
         table,
     } = proof
         .clone()
-        .verify(&expr, &accessor, result.clone(), &())
+        .verify(&expr, &accessor, result.clone(), &(), &[])
         .unwrap();
     assert_ne!(verification_hash, [0; 32]);
     let expected_result = owned_table([bigint("a1", [9, 25])]);
@@ -762,7 +783,7 @@ fn verify_a_proof_with_a_post_result_challenge_and_given_offset(offset_generator
         offset_generators + 1,
         (),
     );
-    assert!(proof.verify(&expr, &accessor, result, &()).is_err());
+    assert!(proof.verify(&expr, &accessor, result, &(), &[]).is_err());
 }
 
 #[test]
@@ -885,13 +906,13 @@ fn verify_a_proof_with_a_commitment_and_given_offset(offset_generators: usize) {
         offset_generators,
         (),
     );
-    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &(), &[]);
     let QueryData {
         verification_hash,
         table,
     } = proof
         .clone()
-        .verify(&expr, &accessor, result.clone(), &())
+        .verify(&expr, &accessor, result.clone(), &(), &[])
         .unwrap();
     assert_ne!(verification_hash, [0; 32]);
     let expected_result = owned_table([bigint("a1", [9, 25])]);
@@ -904,7 +925,7 @@ fn verify_a_proof_with_a_commitment_and_given_offset(offset_generators: usize) {
         offset_generators + 1,
         (),
     );
-    assert!(proof.verify(&expr, &accessor, result, &()).is_err());
+    assert!(proof.verify(&expr, &accessor, result, &(), &[]).is_err());
 }
 
 #[test]
@@ -934,8 +955,8 @@ fn verify_fails_if_the_result_doesnt_satisfy_an_equation() {
         0,
         (),
     );
-    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
-    assert!(proof.verify(&expr, &accessor, result, &()).is_err());
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &(), &[]);
+    assert!(proof.verify(&expr, &accessor, result, &(), &[]).is_err());
 }
 
 #[test]
@@ -953,6 +974,6 @@ fn verify_fails_if_the_commitment_doesnt_match() {
         0,
         (),
     );
-    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
-    assert!(proof.verify(&expr, &accessor, result, &()).is_err());
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &(), &[]);
+    assert!(proof.verify(&expr, &accessor, result, &(), &[]).is_err());
 }
@@ -0,0 +1,92 @@
+use super::{ProofSerializationError, QueryError, VerifiableQueryResult};
+use crate::{
+    base::{
+        commitment::{CommitmentEvaluationProof, CommitmentSchemeId},
+        database::CommitmentAccessor,
+    },
+    sql::proof_plans::DynProofPlan,
+};
+use arrow::{error::ArrowError, record_batch::RecordBatch};
+use serde::de::DeserializeOwned;
+use snafu::Snafu;
+
+/// Errors that can occur when verifying a query result through a [`QueryVerifier`]
+#[derive(Snafu, Debug)]
+pub enum QueryVerifierError {
+    /// The result could not be deserialized.
+    #[snafu(transparent)]
+    Deserialization {
+        /// The underlying source error
+        source: ProofSerializationError,
+    },
+    /// The result failed to verify.
+    #[snafu(transparent)]
+    Verification {
+        /// The underlying source error
+        source: QueryError,
+    },
+    /// The verified result could not be converted to a [`RecordBatch`].
+    #[snafu(transparent)]
+    ArrowConversion {
+        /// The underlying source error
+        source: ArrowError,
+    },
+}
+
+/// An object-safe verifier of serialized query results for a single commitment scheme
+///
+/// Unlike [`VerifiableQueryResult::verify`], this is not generic over the commitment scheme, so
+/// applications supporting several schemes can store the verifiers of all of them together, e.g.
+/// as `Box<dyn QueryVerifier>`s keyed by [`QueryVerifier::scheme_id`], and dispatch at verify
+/// time.
+pub trait QueryVerifier {
+    /// The commitment scheme of the results this verifies
+    fn scheme_id(&self) -> CommitmentSchemeId;
+
+    /// Verifies a result serialized with [`VerifiableQueryResult::to_bytes`] and returns it as a
+    /// [`RecordBatch`].
+    ///
+    /// # Errors
+    /// Returns an error if the result can't be deserialized, fails to verify or can't be converted
+    /// to a [`RecordBatch`].
+    fn verify_bytes(
+        &self,
+        expr: &DynProofPlan,
+        bytes: &[u8],
+    ) -> Result<RecordBatch, QueryVerifierError>;
+}
+
+/// A [`QueryVerifier`] for the commitment scheme `CP`, made of its verifier setup and an accessor
+/// of the commitments of the queried tables
+pub struct SchemeVerifier<'a, CP: CommitmentEvaluationProof, A> {
+    setup: CP::VerifierPublicSetup<'a>,
+    accessor: &'a A,
+}
+
+impl<'a, CP: CommitmentEvaluationProof, A> SchemeVerifier<'a, CP, A> {
+    /// Creates a new [`SchemeVerifier`].
+    pub fn new(setup: CP::VerifierPublicSetup<'a>, accessor: &'a A) -> Self {
+        Self { setup, accessor }
+    }
+}
+
+impl<CP, A> QueryVerifier for SchemeVerifier<'_, CP, A>
+where
+    CP: CommitmentEvaluationProof + DeserializeOwned,
+    A: CommitmentAccessor<CP::Commitment>,
+{
+    fn scheme_id(&self) -> CommitmentSchemeId {
+        CP::SCHEME_ID
+    }
+
+    fn verify_bytes(
+        &self,
+        expr: &DynProofPlan,
+        bytes: &[u8],
+    ) -> Result<RecordBatch, QueryVerifierError> {
+        let table = VerifiableQueryResult::<CP>::from_bytes(bytes)?
+            .verify(expr, self.accessor, &self.setup)?
+            .table;
+        Ok(RecordBatch::try_from(table)?)
+    }
+}
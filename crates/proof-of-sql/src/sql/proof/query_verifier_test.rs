@@ -0,0 +1,100 @@
+use super::{ProofCompression, QueryError, QueryVerifier, QueryVerifierError, SchemeVerifier};
+use crate::{
+    base::{
+        commitment::CommitmentSchemeId,
+        database::{owned_table_utility::*, OwnedTableTestAccessor},
+        map::IndexMap,
+        proof::ProofError,
+    },
+    proof_primitive::dory::{
+        DoryEvaluationProof, DoryProverPublicSetup, DoryVerifierPublicSetup,
+        DynamicDoryEvaluationProof, ProverSetup, PublicParameters, VerifierSetup,
+    },
+    record_batch,
+    sql::{
+        proof::VerifiableQueryResult, proof_exprs::test_utility::*, proof_plans::test_utility::*,
+    },
+};
+use alloc::boxed::Box;
+use ark_std::test_rng;
+
+#[test]
+fn we_can_verify_results_of_different_schemes_through_dynamic_dispatch() {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t = "sxt.t".parse().unwrap();
+    let table = owned_table([
+        bigint("a", [1, 2, 3, 4]),
+        varchar("b", ["x", "y", "x", "z"]),
+    ]);
+    let dory_accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        table.clone(),
+        0,
+        dory_prover_setup,
+    );
+    let dynamic_dory_accessor =
+        OwnedTableTestAccessor::<DynamicDoryEvaluationProof>::new_from_table(
+            t,
+            table,
+            0,
+            &prover_setup,
+        );
+    let ast = filter(
+        cols_expr_plan(t, &["a", "b"], &dory_accessor),
+        tab(t),
+        equal(column(t, "b", &dory_accessor), const_varchar("x")),
+    );
+
+    // The verifiers of both schemes are stored in one collection
+    let verifiers: IndexMap<CommitmentSchemeId, Box<dyn QueryVerifier + '_>> = [
+        Box::new(SchemeVerifier::<DoryEvaluationProof, _>::new(
+            dory_verifier_setup,
+            &dory_accessor,
+        )) as Box<dyn QueryVerifier>,
+        Box::new(SchemeVerifier::<DynamicDoryEvaluationProof, _>::new(
+            &verifier_setup,
+            &dynamic_dory_accessor,
+        )),
+    ]
+    .into_iter()
+    .map(|verifier| (verifier.scheme_id(), verifier))
+    .collect();
+
+    let dory_bytes =
+        VerifiableQueryResult::<DoryEvaluationProof>::new(&ast, &dory_accessor, &dory_prover_setup)
+            .to_bytes(ProofCompression::None)
+            .unwrap();
+    let dynamic_dory_bytes = VerifiableQueryResult::<DynamicDoryEvaluationProof>::new(
+        &ast,
+        &dynamic_dory_accessor,
+        &&prover_setup,
+    )
+    .to_bytes(ProofCompression::None)
+    .unwrap();
+
+    let expected_res = record_batch!("a" => [1_i64, 3], "b" => ["x", "x"]);
+    for (scheme, bytes) in [
+        (CommitmentSchemeId::Dory, &dory_bytes),
+        (CommitmentSchemeId::DynamicDory, &dynamic_dory_bytes),
+    ] {
+        let res = verifiers[&scheme].verify_bytes(&ast, bytes).unwrap();
+        assert_eq!(res, expected_res);
+    }
+
+    // A result is rejected by the verifier of another scheme
+    assert!(matches!(
+        verifiers[&CommitmentSchemeId::DynamicDory].verify_bytes(&ast, &dory_bytes),
+        Err(QueryVerifierError::Verification {
+            source: QueryError::ProofError {
+                source: ProofError::SchemeMismatch {
+                    expected: CommitmentSchemeId::DynamicDory,
+                    found: CommitmentSchemeId::Dory,
+                }
+            }
+        })
+    ));
+}
@@ -58,6 +58,20 @@ impl<'a, S: Scalar> SumcheckSubpolynomial<'a, S> {
         }
     }
 
+    /// The largest number of multiplicands of a term once composed, which is one more than the
+    /// number of MLEs of an [`Identity`](SumcheckSubpolynomialType::Identity) term because of the
+    /// random scalars that it is multiplied by.
+    pub(crate) fn max_multiplicands(&self) -> usize {
+        self.terms
+            .iter()
+            .map(|(_, term)| match self.subpolynomial_type {
+                SumcheckSubpolynomialType::Identity => term.len() + 1,
+                SumcheckSubpolynomialType::ZeroSum => term.len(),
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
     #[allow(dead_code)]
     pub(crate) fn subpolynomial_type(&self) -> SumcheckSubpolynomialType {
         self.subpolynomial_type
@@ -82,12 +82,70 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
     ///
     /// This function both computes the result of a query and constructs a proof of the results
     /// validity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the query divides by zero. Use [`Self::try_new_with_cancellation`] to get an
+    /// error instead.
     #[tracing::instrument(name = "VerifiableQueryResult::new", level = "info", skip_all)]
     pub fn new(
         expr: &(impl ProofPlan + Serialize),
         accessor: &impl DataAccessor<CP::Scalar>,
         setup: &CP::ProverPublicSetup<'_>,
     ) -> Self {
+        Self::new_with_domain_separator(expr, accessor, setup, &[])
+    }
+
+    /// Form a `VerifiableQueryResult` from a query expression like [`Self::new`], starting the
+    /// transcript of the proof with `domain_separator`.
+    ///
+    /// Services that must never accept each other's proofs can use distinct domain separators.
+    /// The result then only verifies with [`Self::verify_with_domain_separator`] and the same
+    /// domain separator. The empty domain separator yields the same proof as [`Self::new`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the query divides by zero, like [`Self::new`].
+    #[tracing::instrument(
+        name = "VerifiableQueryResult::new_with_domain_separator",
+        level = "info",
+        skip_all
+    )]
+    pub fn new_with_domain_separator(
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+        domain_separator: &[u8],
+    ) -> Self {
+        match Self::try_new_with_cancellation(expr, accessor, setup, domain_separator, &|| true) {
+            Ok(verifiable_result) => verifiable_result,
+            Err(error) => panic!("proving failed: {error}"),
+        }
+    }
+
+    /// Form a `VerifiableQueryResult` from a query expression like
+    /// [`Self::new_with_domain_separator`], but give up if `should_continue` returns `false`.
+    ///
+    /// `should_continue` is checked between the phases of the prover and between the rounds of
+    /// the evaluation proof, so it can be used to cancel proving or to enforce a timeout. It is
+    /// not checked within a phase, so cancellation is not immediate.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProofError::Cancelled` if `should_continue` returns `false`, and
+    /// `ProofError::DivisionByZero` if the query divides by zero.
+    #[tracing::instrument(
+        name = "VerifiableQueryResult::try_new_with_cancellation",
+        level = "info",
+        skip_all
+    )]
+    pub fn try_new_with_cancellation(
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+        domain_separator: &[u8],
+        should_continue: &dyn Fn() -> bool,
+    ) -> Result<Self, ProofError> {
         log::log_memory_usage("Start");
 
         // a query must have at least one result column; if not, it should
@@ -99,20 +157,26 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
             .into_iter()
             .all(|table_ref| accessor.get_length(table_ref) == 0)
         {
-            return VerifiableQueryResult {
+            return Ok(VerifiableQueryResult {
                 result: None,
                 proof: None,
-            };
+            });
         }
 
-        let (proof, res) = QueryProof::new(expr, accessor, setup);
+        let (proof, res) = QueryProof::new_with_cancellation(
+            expr,
+            accessor,
+            setup,
+            domain_separator,
+            should_continue,
+        )?;
 
         log::log_memory_usage("End");
 
-        Self {
+        Ok(Self {
             result: Some(res),
             proof: Some(proof),
-        }
+        })
     }
 
     /// Verify a `VerifiableQueryResult`. Upon success, this function returns the finalized form of
@@ -128,6 +192,25 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
         expr: &(impl ProofPlan + Serialize),
         accessor: &impl CommitmentAccessor<CP::Commitment>,
         setup: &CP::VerifierPublicSetup<'_>,
+    ) -> QueryResult<CP::Scalar> {
+        self.verify_with_domain_separator(expr, accessor, setup, &[])
+    }
+
+    /// Verify a `VerifiableQueryResult` formed with
+    /// [`Self::new_with_domain_separator`] and the same `domain_separator`.
+    ///
+    /// Note: This does NOT transform the result!
+    #[tracing::instrument(
+        name = "VerifiableQueryResult::verify_with_domain_separator",
+        level = "info",
+        skip_all
+    )]
+    pub fn verify_with_domain_separator(
+        self,
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        setup: &CP::VerifierPublicSetup<'_>,
+        domain_separator: &[u8],
     ) -> QueryResult<CP::Scalar> {
         log::log_memory_usage("Start");
 
@@ -136,7 +219,7 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
                 let QueryData {
                     table,
                     verification_hash,
-                } = proof.verify(expr, accessor, result, setup)?;
+                } = proof.verify(expr, accessor, result, setup, domain_separator)?;
                 Ok(QueryData {
                     table: table.try_coerce_with_fields(expr.get_column_result_fields())?,
                     verification_hash,
@@ -156,9 +239,33 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
             })?,
         }
     }
+
+    /// Verify a `VerifiableQueryResult` and return the finalized result in canonical row order.
+    ///
+    /// The rows of the verified result are sorted by all of its columns (see
+    /// [`OwnedTable::into_canonical_row_order`]), so the same logical data yields the same verified
+    /// table regardless of the order in which the prover stores it. Since the verifier sorts the
+    /// result after verifying it, this is as trustworthy as the result itself.
+    ///
+    /// Note: the verification hash still depends on the order in which the rows were proven.
+    pub fn verify_in_canonical_order(
+        self,
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        setup: &CP::VerifierPublicSetup<'_>,
+    ) -> QueryResult<CP::Scalar> {
+        let QueryData {
+            table,
+            verification_hash,
+        } = self.verify(expr, accessor, setup)?;
+        Ok(QueryData {
+            table: table.into_canonical_row_order(),
+            verification_hash,
+        })
+    }
 }
 
-fn make_empty_query_result<S: Scalar>(result_fields: &[ColumnField]) -> QueryResult<S> {
+pub(super) fn make_empty_query_result<S: Scalar>(result_fields: &[ColumnField]) -> QueryResult<S> {
     let table = OwnedTable::try_new(
         result_fields
             .iter()
@@ -178,6 +285,8 @@ fn make_empty_query_result<S: Scalar>(result_fields: &[ColumnField]) -> QueryRes
                         ColumnType::Scalar => OwnedColumn::Scalar(vec![]),
                         ColumnType::VarChar => OwnedColumn::VarChar(vec![]),
                         ColumnType::TimestampTZ(tu, tz) => OwnedColumn::TimestampTZ(tu, tz, vec![]),
+                        ColumnType::Date => OwnedColumn::Date(vec![]),
+                        ColumnType::UInt64 => OwnedColumn::UInt64(vec![]),
                     },
                 )
             })
@@ -0,0 +1,129 @@
+use super::{ProofPlan, VerifiableQueryResult};
+use crate::base::{
+    commitment::{Commitment, CommitmentEvaluationProof},
+    database::{CommitmentAccessor, DataAccessor},
+    map::IndexMap,
+    proof::{Keccak256Transcript, Transcript},
+};
+use serde::Serialize;
+
+/// A cached [`VerifiableQueryResult`] along with the version of the commitments it was proven
+/// against.
+struct CachedResult<CP: CommitmentEvaluationProof> {
+    commitments_version: [u8; 32],
+    result: VerifiableQueryResult<CP>,
+}
+
+/// An in-memory cache of [`VerifiableQueryResult`]s for repeated queries against unchanged data.
+///
+/// Results are keyed by a hash of the serialized query plan. Each cached result also records a
+/// hash of the commitments to the columns that the plan reads, along with the offsets and lengths
+/// of their tables, which acts as the version of the data the result was proven against. A
+/// cached result is only returned while that version is unchanged. Once the data changes, the next
+/// lookup of the plan misses and the stale result is replaced by a newly proven one.
+///
+/// This is a host-side optimization for the prover. A cached result is exactly the result that
+/// would have been proven, so it verifies as usual.
+pub struct VerifiableQueryResultCache<CP: CommitmentEvaluationProof> {
+    results: IndexMap<[u8; 32], CachedResult<CP>>,
+}
+
+impl<CP: CommitmentEvaluationProof> Default for VerifiableQueryResultCache<CP> {
+    fn default() -> Self {
+        Self {
+            results: IndexMap::default(),
+        }
+    }
+}
+
+impl<CP: CommitmentEvaluationProof> VerifiableQueryResultCache<CP> {
+    /// Create an empty cache.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of cached results.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    /// Returns `true` if there are no cached results.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    /// Removes all cached results.
+    pub fn clear(&mut self) {
+        self.results.clear();
+    }
+
+    /// Returns the cached result of `expr`, if there is one for the current commitments in
+    /// `accessor`.
+    pub fn get(
+        &self,
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+    ) -> Option<&VerifiableQueryResult<CP>> {
+        self.results
+            .get(&plan_key(expr))
+            .filter(|cached| cached.commitments_version == commitments_version(expr, accessor))
+            .map(|cached| &cached.result)
+    }
+
+    /// Returns the cached result of `expr` if the commitments in `accessor` are unchanged since
+    /// it was proven. Otherwise, proves `expr` with [`VerifiableQueryResult::new`] and caches the
+    /// new result in place of any stale one.
+    pub fn get_or_prove(
+        &mut self,
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &(impl DataAccessor<CP::Scalar> + CommitmentAccessor<CP::Commitment>),
+        setup: &CP::ProverPublicSetup<'_>,
+    ) -> &VerifiableQueryResult<CP> {
+        let commitments_version = commitments_version(expr, accessor);
+        let cached = self
+            .results
+            .entry(plan_key(expr))
+            .or_insert_with(|| CachedResult {
+                commitments_version,
+                result: VerifiableQueryResult::new(expr, accessor, setup),
+            });
+        if cached.commitments_version != commitments_version {
+            *cached = CachedResult {
+                commitments_version,
+                result: VerifiableQueryResult::new(expr, accessor, setup),
+            };
+        }
+        &cached.result
+    }
+}
+
+/// Hashes the serialized plan.
+fn plan_key(expr: &(impl ProofPlan + Serialize)) -> [u8; 32] {
+    let mut transcript = Keccak256Transcript::new();
+    transcript.extend_serialize_as_le(expr);
+    transcript.challenge_as_le()
+}
+
+/// Hashes the offsets and lengths of the tables that `expr` reads along with the commitments to
+/// the columns that it reads.
+fn commitments_version<C: Commitment>(
+    expr: &impl ProofPlan,
+    accessor: &impl CommitmentAccessor<C>,
+) -> [u8; 32] {
+    let mut transcript = Keccak256Transcript::new();
+    for table_ref in expr.get_table_references() {
+        transcript.extend_as_le([
+            accessor.get_offset(table_ref) as u64,
+            accessor.get_length(table_ref) as u64,
+        ]);
+    }
+    for column_ref in expr.get_column_references() {
+        accessor
+            .get_commitment(column_ref)
+            .append_to_transcript(&mut transcript);
+    }
+    transcript.challenge_as_le()
+}
@@ -0,0 +1,112 @@
+use super::VerifiableQueryResultCache;
+use crate::{
+    base::database::{owned_table_utility::*, OwnedTableTestAccessor, TestAccessor},
+    proof_primitive::dory::{
+        DoryEvaluationProof, DoryProverPublicSetup, DoryScalar, DoryVerifierPublicSetup,
+        ProverSetup, PublicParameters, VerifierSetup,
+    },
+    sql::parse::QueryExpr,
+};
+use ark_std::test_rng;
+
+fn query(sql: &str, accessor: &OwnedTableTestAccessor<DoryEvaluationProof>) -> QueryExpr {
+    QueryExpr::try_new(sql.parse().unwrap(), "sxt".into(), accessor).unwrap()
+}
+
+#[test]
+fn a_cache_hit_returns_the_same_verifiable_result() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        "sxt.t".parse().unwrap(),
+        owned_table([bigint("id", [1, 2, 3]), bigint("amount", [10, 20, 30])]),
+        0,
+        dory_prover_setup,
+    );
+    let query = query("SELECT id FROM t WHERE amount >= 20", &accessor);
+    let mut cache = VerifiableQueryResultCache::<DoryEvaluationProof>::new();
+    assert!(cache.is_empty());
+    assert!(cache.get(query.proof_expr(), &accessor).is_none());
+
+    let proven = postcard::to_allocvec(cache.get_or_prove(
+        query.proof_expr(),
+        &accessor,
+        &dory_prover_setup,
+    ))
+    .unwrap();
+    assert_eq!(cache.len(), 1);
+    let cached = cache.get(query.proof_expr(), &accessor).unwrap();
+    assert_eq!(postcard::to_allocvec(cached).unwrap(), proven);
+    let hit = cache
+        .get_or_prove(query.proof_expr(), &accessor, &dory_prover_setup)
+        .clone();
+    assert_eq!(postcard::to_allocvec(&hit).unwrap(), proven);
+    assert_eq!(cache.len(), 1);
+
+    let table = hit
+        .verify(query.proof_expr(), &accessor, &dory_verifier_setup)
+        .unwrap()
+        .table;
+    assert_eq!(table, owned_table::<DoryScalar>([bigint("id", [2, 3])]));
+}
+
+#[test]
+fn a_commitment_change_misses_and_replaces_the_cached_result() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let mut accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        "sxt.t".parse().unwrap(),
+        owned_table([bigint("id", [1, 2, 3]), bigint("amount", [10, 20, 30])]),
+        0,
+        dory_prover_setup,
+    );
+    let query = query("SELECT id FROM t WHERE amount >= 20", &accessor);
+    let mut cache = VerifiableQueryResultCache::<DoryEvaluationProof>::new();
+    cache.get_or_prove(query.proof_expr(), &accessor, &dory_prover_setup);
+
+    accessor.add_table(
+        "sxt.t".parse().unwrap(),
+        owned_table([bigint("id", [1, 2, 3]), bigint("amount", [10, 20, 5])]),
+        0,
+    );
+    assert!(cache.get(query.proof_expr(), &accessor).is_none());
+    let table = cache
+        .get_or_prove(query.proof_expr(), &accessor, &dory_prover_setup)
+        .clone()
+        .verify(query.proof_expr(), &accessor, &dory_verifier_setup)
+        .unwrap()
+        .table;
+    assert_eq!(table, owned_table::<DoryScalar>([bigint("id", [2])]));
+    assert_eq!(cache.len(), 1);
+    assert!(cache.get(query.proof_expr(), &accessor).is_some());
+}
+
+#[test]
+fn different_plans_are_cached_separately() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        "sxt.t".parse().unwrap(),
+        owned_table([bigint("id", [1, 2, 3]), bigint("amount", [10, 20, 30])]),
+        0,
+        dory_prover_setup,
+    );
+    let first_query = query("SELECT id FROM t WHERE amount >= 20", &accessor);
+    let second_query = query("SELECT id FROM t WHERE amount >= 30", &accessor);
+    let mut cache = VerifiableQueryResultCache::<DoryEvaluationProof>::new();
+    cache.get_or_prove(first_query.proof_expr(), &accessor, &dory_prover_setup);
+    assert!(cache.get(second_query.proof_expr(), &accessor).is_none());
+    cache.get_or_prove(second_query.proof_expr(), &accessor, &dory_prover_setup);
+    assert_eq!(cache.len(), 2);
+
+    cache.clear();
+    assert!(cache.is_empty());
+    assert!(cache.get(first_query.proof_expr(), &accessor).is_none());
+}
@@ -0,0 +1,120 @@
+use super::VerifiableQueryResult;
+use crate::{
+    base::{
+        database::{owned_table_utility::*, OwnedTableTestAccessor},
+        proof::ProofError,
+    },
+    proof_primitive::dory::{
+        DoryEvaluationProof, DoryProverPublicSetup, DoryScalar, DoryVerifierPublicSetup,
+        ProverSetup, PublicParameters, VerifierSetup,
+    },
+    sql::{proof_exprs::test_utility::*, proof_plans::test_utility::*},
+};
+use ark_std::test_rng;
+use core::cell::Cell;
+
+#[test]
+fn we_can_cancel_proving_after_the_first_round() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3]), bigint("b", [10, 20, 30])]),
+        0,
+        dory_prover_setup,
+    );
+    let ast = filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        gte(column(t, "b", &accessor), const_bigint(20)),
+    );
+    let checks = Cell::new(0);
+    let should_continue = || {
+        checks.set(checks.get() + 1);
+        false
+    };
+    let res = VerifiableQueryResult::<DoryEvaluationProof>::try_new_with_cancellation(
+        &ast,
+        &accessor,
+        &dory_prover_setup,
+        &[],
+        &should_continue,
+    );
+    assert!(matches!(res, Err(ProofError::Cancelled)));
+    assert_eq!(checks.get(), 1);
+}
+
+#[test]
+fn we_can_cancel_proving_between_rounds_of_the_dory_inner_product() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3]), bigint("b", [10, 20, 30])]),
+        0,
+        dory_prover_setup,
+    );
+    let ast = filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        gte(column(t, "b", &accessor), const_bigint(20)),
+    );
+    // The three prover phases pass, followed by the first of the three Dory reductions.
+    let checks = Cell::new(0);
+    let should_continue = || {
+        checks.set(checks.get() + 1);
+        checks.get() <= 4
+    };
+    let res = VerifiableQueryResult::<DoryEvaluationProof>::try_new_with_cancellation(
+        &ast,
+        &accessor,
+        &dory_prover_setup,
+        &[],
+        &should_continue,
+    );
+    assert!(matches!(res, Err(ProofError::Cancelled)));
+    assert_eq!(checks.get(), 5);
+}
+
+#[test]
+fn a_proof_that_is_not_cancelled_verifies_with_its_domain_separator() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3]), bigint("b", [10, 20, 30])]),
+        0,
+        dory_prover_setup,
+    );
+    let ast = filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        gte(column(t, "b", &accessor), const_bigint(20)),
+    );
+    let checks = Cell::new(0);
+    let should_continue = || {
+        checks.set(checks.get() + 1);
+        true
+    };
+    let table = VerifiableQueryResult::<DoryEvaluationProof>::try_new_with_cancellation(
+        &ast,
+        &accessor,
+        &dory_prover_setup,
+        b"domain",
+        &should_continue,
+    )
+    .unwrap()
+    .verify_with_domain_separator(&ast, &accessor, &dory_verifier_setup, b"domain")
+    .unwrap()
+    .table;
+    assert_eq!(table, owned_table::<DoryScalar>([bigint("a", [2, 3])]));
+    assert_eq!(checks.get(), 6);
+}
@@ -0,0 +1,85 @@
+use super::VerifiableQueryResult;
+use crate::{
+    base::database::{owned_table_utility::*, OwnedTableTestAccessor},
+    proof_primitive::dory::{
+        DoryEvaluationProof, DoryProverPublicSetup, DoryVerifierPublicSetup, ProverSetup,
+        PublicParameters, VerifierSetup,
+    },
+    sql::{proof_exprs::test_utility::*, proof_plans::test_utility::*},
+};
+use ark_std::test_rng;
+
+#[test]
+fn we_can_only_verify_a_proof_with_the_domain_separator_it_was_created_with() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3]), bigint("b", [10, 20, 30])]),
+        0,
+        dory_prover_setup,
+    );
+    let ast = filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        gte(column(t, "b", &accessor), const_bigint(20)),
+    );
+    let res = VerifiableQueryResult::<DoryEvaluationProof>::new_with_domain_separator(
+        &ast,
+        &accessor,
+        &dory_prover_setup,
+        b"service-a",
+    );
+
+    let table = res
+        .clone()
+        .verify_with_domain_separator(&ast, &accessor, &dory_verifier_setup, b"service-a")
+        .unwrap()
+        .table;
+    assert_eq!(table, owned_table([bigint("a", [2, 3])]));
+    assert!(res
+        .clone()
+        .verify_with_domain_separator(&ast, &accessor, &dory_verifier_setup, b"service-b")
+        .is_err());
+    assert!(res.verify(&ast, &accessor, &dory_verifier_setup).is_err());
+}
+
+#[test]
+fn the_empty_domain_separator_does_not_change_the_proof() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3]), bigint("b", [10, 20, 30])]),
+        0,
+        dory_prover_setup,
+    );
+    let ast = filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        gte(column(t, "b", &accessor), const_bigint(20)),
+    );
+    let default_hash =
+        VerifiableQueryResult::<DoryEvaluationProof>::new(&ast, &accessor, &dory_prover_setup)
+            .verify_with_domain_separator(&ast, &accessor, &dory_verifier_setup, &[])
+            .unwrap()
+            .verification_hash;
+    let empty_hash = VerifiableQueryResult::<DoryEvaluationProof>::new_with_domain_separator(
+        &ast,
+        &accessor,
+        &dory_prover_setup,
+        &[],
+    )
+    .verify(&ast, &accessor, &dory_verifier_setup)
+    .unwrap()
+    .verification_hash;
+    assert_eq!(default_hash, empty_hash);
+}
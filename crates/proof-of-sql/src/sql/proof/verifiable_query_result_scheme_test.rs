@@ -0,0 +1,129 @@
+use super::{QueryError, VerifiableQueryResult};
+use crate::{
+    base::{
+        commitment::{CommitmentEvaluationProof, CommitmentSchemeId},
+        database::{owned_table_utility::*, OwnedTable, OwnedTableTestAccessor},
+        proof::ProofError,
+    },
+    proof_primitive::dory::{
+        DoryEvaluationProof, DoryProverPublicSetup, DoryScalar, DoryVerifierPublicSetup,
+        DynamicDoryEvaluationProof, ProverSetup, PublicParameters, VerifierSetup,
+    },
+    sql::{proof_exprs::test_utility::*, proof_plans::test_utility::*},
+};
+use ark_std::test_rng;
+
+fn table() -> OwnedTable<DoryScalar> {
+    owned_table([bigint("a", [1, 2, 3]), bigint("b", [10, 20, 30])])
+}
+
+#[test]
+fn we_cannot_verify_a_dory_proof_with_a_dynamic_dory_verifier() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let t = "sxt.t".parse().unwrap();
+    let dory_accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        table(),
+        0,
+        dory_prover_setup,
+    );
+    let dynamic_dory_accessor =
+        OwnedTableTestAccessor::<DynamicDoryEvaluationProof>::new_from_table(
+            t,
+            table(),
+            0,
+            &prover_setup,
+        );
+    let ast = filter(
+        cols_expr_plan(t, &["a"], &dory_accessor),
+        tab(t),
+        gte(column(t, "b", &dory_accessor), const_bigint(20)),
+    );
+    let dory_result =
+        VerifiableQueryResult::<DoryEvaluationProof>::new(&ast, &dory_accessor, &dory_prover_setup);
+    let bytes = postcard::to_allocvec(&dory_result).unwrap();
+
+    let res: VerifiableQueryResult<DynamicDoryEvaluationProof> =
+        postcard::from_bytes(&bytes).unwrap();
+    assert!(matches!(
+        res.verify(&ast, &dynamic_dory_accessor, &&verifier_setup),
+        Err(QueryError::ProofError {
+            source: ProofError::SchemeMismatch {
+                expected: CommitmentSchemeId::DynamicDory,
+                found: CommitmentSchemeId::Dory,
+            }
+        })
+    ));
+}
+
+#[test]
+fn we_cannot_verify_a_dynamic_dory_proof_with_a_dory_verifier() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t = "sxt.t".parse().unwrap();
+    let dory_accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        table(),
+        0,
+        dory_prover_setup,
+    );
+    let dynamic_dory_accessor =
+        OwnedTableTestAccessor::<DynamicDoryEvaluationProof>::new_from_table(
+            t,
+            table(),
+            0,
+            &prover_setup,
+        );
+    let ast = filter(
+        cols_expr_plan(t, &["a"], &dynamic_dory_accessor),
+        tab(t),
+        gte(column(t, "b", &dynamic_dory_accessor), const_bigint(20)),
+    );
+    let dynamic_dory_result = VerifiableQueryResult::<DynamicDoryEvaluationProof>::new(
+        &ast,
+        &dynamic_dory_accessor,
+        &&prover_setup,
+    );
+    let bytes = postcard::to_allocvec(&dynamic_dory_result).unwrap();
+
+    let res: VerifiableQueryResult<DoryEvaluationProof> = postcard::from_bytes(&bytes).unwrap();
+    assert!(matches!(
+        res.verify(&ast, &dory_accessor, &dory_verifier_setup),
+        Err(QueryError::ProofError {
+            source: ProofError::SchemeMismatch {
+                expected: CommitmentSchemeId::Dory,
+                found: CommitmentSchemeId::DynamicDory,
+            }
+        })
+    ));
+}
+
+#[test]
+fn serialized_proofs_start_with_the_scheme_id() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DynamicDoryEvaluationProof>::new_from_table(
+        t,
+        table(),
+        0,
+        &prover_setup,
+    );
+    let ast = filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        gte(column(t, "b", &accessor), const_bigint(20)),
+    );
+    let res =
+        VerifiableQueryResult::<DynamicDoryEvaluationProof>::new(&ast, &accessor, &&prover_setup);
+    let proof_bytes = postcard::to_allocvec(res.proof.as_ref().unwrap()).unwrap();
+    let scheme_bytes = postcard::to_allocvec(&DynamicDoryEvaluationProof::SCHEME_ID).unwrap();
+    assert_eq!(scheme_bytes.len(), 1);
+    assert!(proof_bytes.starts_with(&scheme_bytes));
+}
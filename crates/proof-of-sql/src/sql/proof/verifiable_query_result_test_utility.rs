@@ -113,10 +113,11 @@ fn append_single_row_to_column<S: Scalar>(column: &OwnedColumn<S>) -> OwnedColum
         OwnedColumn::Boolean(col) => col.push(false),
         OwnedColumn::TinyInt(col) => col.push(0),
         OwnedColumn::SmallInt(col) => col.push(0),
-        OwnedColumn::Int(col) => col.push(0),
+        OwnedColumn::Int(col) | OwnedColumn::Date(col) => col.push(0),
         OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => col.push(0),
         OwnedColumn::VarChar(col) => col.push(String::new()),
         OwnedColumn::Int128(col) => col.push(0),
+        OwnedColumn::UInt64(col) => col.push(0),
         OwnedColumn::Decimal75(_, _, col) | OwnedColumn::Scalar(col) => col.push(S::ZERO),
     }
     column
@@ -146,12 +147,13 @@ pub fn tamper_first_row_of_column<S: Scalar>(column: &OwnedColumn<S>) -> OwnedCo
         OwnedColumn::Boolean(col) => col[0] ^= true,
         OwnedColumn::TinyInt(col) => col[0] = col[0].wrapping_add(1),
         OwnedColumn::SmallInt(col) => col[0] = col[0].wrapping_add(1),
-        OwnedColumn::Int(col) => col[0] = col[0].wrapping_add(1),
+        OwnedColumn::Int(col) | OwnedColumn::Date(col) => col[0] = col[0].wrapping_add(1),
         OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => {
             col[0] = col[0].wrapping_add(1);
         }
         OwnedColumn::VarChar(col) => col[0].push('1'),
         OwnedColumn::Int128(col) => col[0] = col[0].wrapping_add(1),
+        OwnedColumn::UInt64(col) => col[0] = col[0].wrapping_add(1),
         OwnedColumn::Decimal75(_, _, col) | OwnedColumn::Scalar(col) => col[0] += S::ONE,
     }
     column
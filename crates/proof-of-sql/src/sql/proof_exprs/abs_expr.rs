@@ -0,0 +1,155 @@
+use super::{DynProofExpr, ProofExpr};
+use crate::{
+    base::{
+        database::{Column, ColumnRef, ColumnType, Table},
+        map::{IndexMap, IndexSet},
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::{
+        proof::{FinalRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder},
+        proof_gadgets::{prover_evaluate_sign, result_evaluate_sign, verifier_evaluate_sign},
+    },
+    utils::log,
+};
+use alloc::{boxed::Box, vec};
+use bumpalo::Bump;
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+/// Provable `ABS(expr)` expression for signed integers and decimals
+///
+/// The sign bit `s` of `expr` is proven with a sign decomposition, and the result is
+/// `out = expr * (1 - 2 * s)`, which is committed. So `out` is `expr` multiplied by a sign in
+/// `{-1, 1}`, and it is non-negative because `s` is the proven sign bit.
+///
+/// The result has the type of `expr`. The absolute value of the minimum of an integer type does
+/// not fit in that type, so it is returned as a scalar and fails verification with an overflow.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AbsExpr {
+    expr: Box<DynProofExpr>,
+}
+
+impl AbsExpr {
+    /// Create a new `ABS` expression
+    pub fn new(expr: Box<DynProofExpr>) -> Self {
+        Self { expr }
+    }
+
+    /// Compute `expr * (1 - 2 * s)` for every row
+    fn abs<'a, S: Scalar>(alloc: &'a Bump, values: &[S], signs: &[bool]) -> &'a [S] {
+        alloc.alloc_slice_fill_iter(
+            values
+                .iter()
+                .zip(signs)
+                .map(|(&value, &sign)| if sign { -value } else { value }),
+        )
+    }
+}
+
+impl ProofExpr for AbsExpr {
+    fn data_type(&self) -> ColumnType {
+        self.expr.data_type()
+    }
+
+    #[tracing::instrument(name = "AbsExpr::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        log::log_memory_usage("Start");
+
+        let values = alloc.alloc_slice_copy(
+            &self
+                .expr
+                .result_evaluate(alloc, table)
+                .to_scalar_with_scaling(0),
+        );
+        let signs = result_evaluate_sign(table.num_rows(), alloc, values);
+        let res = Column::Scalar(Self::abs(alloc, values, signs));
+
+        log::log_memory_usage("End");
+
+        res
+    }
+
+    #[tracing::instrument(name = "AbsExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        log::log_memory_usage("Start");
+
+        let values = alloc.alloc_slice_copy(
+            &self
+                .expr
+                .prover_evaluate(builder, alloc, table)
+                .to_scalar_with_scaling(0),
+        );
+
+        // sign(expr) == -1
+        let signs = prover_evaluate_sign(
+            builder,
+            alloc,
+            values,
+            #[cfg(test)]
+            false,
+        );
+
+        // out
+        let abs = Self::abs(alloc, values, signs);
+        builder.produce_intermediate_mle(abs);
+
+        // subpolynomial: out - expr + 2 * expr * s
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (S::one(), vec![Box::new(abs)]),
+                (-S::one(), vec![Box::new(values as &[_])]),
+                (S::TWO, vec![Box::new(values as &[_]), Box::new(signs)]),
+            ],
+        );
+        let res = Column::Scalar(abs);
+
+        log::log_memory_usage("End");
+
+        res
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut VerificationBuilder<S>,
+        accessor: &IndexMap<ColumnRef, S>,
+        one_eval: S,
+    ) -> Result<S, ProofError> {
+        let expr_eval = self.expr.verifier_evaluate(builder, accessor, one_eval)?;
+
+        // sign(expr) == -1
+        let sign_eval = verifier_evaluate_sign(builder, expr_eval, one_eval)?;
+
+        // out
+        let abs_eval = builder.try_consume_final_round_mle_evaluation()?;
+
+        // subpolynomial: out - expr + 2 * expr * s
+        builder.try_produce_sumcheck_subpolynomial_evaluation(
+            SumcheckSubpolynomialType::Identity,
+            abs_eval - expr_eval + S::TWO * expr_eval * sign_eval,
+            2,
+        )?;
+
+        Ok(abs_eval)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.expr.get_column_references(columns);
+    }
+}
+
+impl fmt::Display for AbsExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "abs({})", self.expr)
+    }
+}
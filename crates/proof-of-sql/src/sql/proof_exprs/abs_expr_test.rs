@@ -0,0 +1,145 @@
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, OwnedTableTestAccessor},
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, QueryError, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, DynProofExpr},
+        proof_plans::test_utility::*,
+    },
+};
+use rand::{
+    distributions::{Distribution, Uniform},
+    rngs::StdRng,
+};
+use rand_core::SeedableRng;
+
+// select a, abs(a) as b from sxt.t where abs(a) <= 99 for every signed integer type
+#[test]
+fn we_can_prove_a_filter_on_the_absolute_value_of_signed_integers() {
+    let data = owned_table([
+        tinyint("a", [-100_i8, -99, -1, 0, 1, 99, 100, i8::MAX]),
+        smallint("b", [-100_i16, -99, -1, 0, 1, 99, 100, i16::MAX]),
+        int("c", [-100_i32, -99, -1, 0, 1, 99, 100, i32::MAX]),
+        bigint("d", [-100_i64, -99, -1, 0, 1, 99, 100, i64::MAX]),
+        int128("e", [-100_i128, -99, -1, 0, 1, 99, 100, i128::MAX]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    for name in ["a", "b", "c", "d", "e"] {
+        let ast = filter(
+            vec![
+                col_expr_plan(t, name, &accessor),
+                aliased_plan(abs(column(t, name, &accessor)), "abs"),
+            ],
+            tab(t),
+            lte(abs(column(t, name, &accessor)), const_bigint(99)),
+        );
+        let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+        exercise_verification(&verifiable_res, &ast, &accessor, t);
+        let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+        let expected_res = match name {
+            "a" => owned_table([
+                tinyint("a", [-99_i8, -1, 0, 1, 99]),
+                tinyint("abs", [99_i8, 1, 0, 1, 99]),
+            ]),
+            "b" => owned_table([
+                smallint("b", [-99_i16, -1, 0, 1, 99]),
+                smallint("abs", [99_i16, 1, 0, 1, 99]),
+            ]),
+            "c" => owned_table([
+                int("c", [-99_i32, -1, 0, 1, 99]),
+                int("abs", [99_i32, 1, 0, 1, 99]),
+            ]),
+            "d" => owned_table([
+                bigint("d", [-99_i64, -1, 0, 1, 99]),
+                bigint("abs", [99_i64, 1, 0, 1, 99]),
+            ]),
+            _ => owned_table([
+                int128("e", [-99_i128, -1, 0, 1, 99]),
+                int128("abs", [99_i128, 1, 0, 1, 99]),
+            ]),
+        };
+        assert_eq!(res, expected_res);
+    }
+}
+
+// select abs(a) as b from sxt.t, where a is a decimal with a negative scale
+#[test]
+fn we_can_prove_the_absolute_value_of_a_decimal_with_a_negative_scale() {
+    let data = owned_table([decimal75("a", 10, -2, [-12_345, 0, 678, -1])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(abs(column(t, "a", &accessor)), "b")],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([decimal75("b", 10, -2, [12_345, 0, 678, 1])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_the_absolute_value_of_random_data() {
+    let dist = Uniform::new(-1_000_i64, 1_000);
+    let mut rng = StdRng::from_seed([0u8; 32]);
+    let values: Vec<i64> = dist.sample_iter(&mut rng).take(40).collect();
+    let data = owned_table([bigint("a", values.clone())]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(abs(column(t, "a", &accessor)), "b")],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint(
+        "b",
+        values.into_iter().map(i64::abs).collect::<Vec<_>>(),
+    )]);
+    assert_eq!(res, expected_res);
+}
+
+// select abs(a) as b from sxt.t, where abs(i64::MIN) does not fit in a BIGINT
+#[test]
+fn we_cannot_verify_the_absolute_value_of_the_minimum_integer() {
+    let data = owned_table([
+        bigint("a", [1_i64, i64::MIN]),
+        tinyint("c", [1_i8, i8::MIN]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    for name in ["a", "c"] {
+        let ast = projection(
+            vec![aliased_plan(abs(column(t, name, &accessor)), "b")],
+            tab(t),
+        );
+        let verifiable_res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+        assert!(matches!(
+            verifiable_res.verify(&ast, &accessor, &()),
+            Err(QueryError::Overflow)
+        ));
+    }
+}
+
+#[test]
+fn we_cannot_create_the_absolute_value_of_unsigned_or_non_numeric_types() {
+    let data = owned_table([
+        boolean("a", [true, false]),
+        varchar("b", ["a", "b"]),
+        scalar("c", [1, 2]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    for name in ["a", "b", "c"] {
+        assert!(matches!(
+            DynProofExpr::try_new_abs(column(t, name, &accessor)),
+            Err(ConversionError::InvalidExpression { .. })
+        ));
+    }
+}
@@ -11,6 +11,7 @@ use crate::{
 };
 use alloc::boxed::Box;
 use bumpalo::Bump;
+use core::fmt;
 use serde::{Deserialize, Serialize};
 
 /// Provable numerical `+` / `-` expression
@@ -104,3 +105,10 @@ impl ProofExpr for AddSubtractExpr {
         self.rhs.get_column_references(columns);
     }
 }
+
+impl fmt::Display for AddSubtractExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let op = if self.is_subtract { "-" } else { "+" };
+        write!(f, "({} {op} {})", self.lhs, self.rhs)
+    }
+}
@@ -11,6 +11,7 @@ use crate::{
 };
 use alloc::boxed::Box;
 use bumpalo::Bump;
+use core::fmt;
 use proof_of_sql_parser::intermediate_ast::AggregationOperator;
 use serde::{Deserialize, Serialize};
 
@@ -20,7 +21,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AggregateExpr {
     op: AggregationOperator,
-    expr: Box<DynProofExpr>,
+    pub(crate) expr: Box<DynProofExpr>,
 }
 
 impl AggregateExpr {
@@ -84,3 +85,9 @@ impl ProofExpr for AggregateExpr {
         self.expr.get_column_references(columns);
     }
 }
+
+impl fmt::Display for AggregateExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({})", self.op, self.expr)
+    }
+}
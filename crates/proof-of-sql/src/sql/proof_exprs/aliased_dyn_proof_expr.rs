@@ -1,4 +1,5 @@
 use super::DynProofExpr;
+use core::fmt;
 use serde::{Deserialize, Serialize};
 use sqlparser::ast::Ident;
 
@@ -8,3 +9,14 @@ pub struct AliasedDynProofExpr {
     pub expr: DynProofExpr,
     pub alias: Ident,
 }
+
+impl fmt::Display for AliasedDynProofExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.expr {
+            DynProofExpr::Column(column) if column.column_id() == self.alias => {
+                write!(f, "{}", self.alias)
+            }
+            expr => write!(f, "{expr} as {}", self.alias),
+        }
+    }
+}
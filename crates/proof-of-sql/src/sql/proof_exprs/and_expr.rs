@@ -11,6 +11,7 @@ use crate::{
 };
 use alloc::{boxed::Box, vec};
 use bumpalo::Bump;
+use core::fmt;
 use serde::{Deserialize, Serialize};
 
 /// Provable logical AND expression
@@ -115,3 +116,9 @@ impl ProofExpr for AndExpr {
         self.rhs.get_column_references(columns);
     }
 }
+
+impl fmt::Display for AndExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({} and {})", self.lhs, self.rhs)
+    }
+}
@@ -0,0 +1,129 @@
+use super::{DynProofExpr, ProofExpr};
+use crate::{
+    base::{
+        database::{Column, ColumnRef, ColumnType, Table},
+        map::{IndexMap, IndexSet},
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::proof::{FinalRoundBuilder, VerificationBuilder},
+    utils::log,
+};
+use alloc::{boxed::Box, vec::Vec};
+use bumpalo::Bump;
+use core::fmt;
+use proof_of_sql_parser::posql_time::{PoSQLTimeUnit, PoSQLTimeZone};
+use serde::{Deserialize, Serialize};
+
+/// Provable `<timestamp> AT TIME ZONE <time_zone>` expression
+///
+/// `TimestampTZ` columns store the UTC epoch. This expression converts them to the wall-clock
+/// time of `time_zone`, returned as a timestamp in UTC, by adding the offset of `time_zone` to
+/// the epoch. Since this is a linear shift of the committed value, no additional commitments
+/// are needed to prove it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AtTimeZoneExpr {
+    expr: Box<DynProofExpr>,
+    time_zone: PoSQLTimeZone,
+}
+
+impl AtTimeZoneExpr {
+    /// Create a new `AT TIME ZONE` expression
+    pub fn new(expr: Box<DynProofExpr>, time_zone: PoSQLTimeZone) -> Self {
+        Self { expr, time_zone }
+    }
+
+    /// The time unit of the underlying timestamp
+    ///
+    /// # Panics
+    /// Panics if the underlying expression is not a timestamp, which the constructor of
+    /// [`DynProofExpr::AtTimeZone`] rules out.
+    fn time_unit(&self) -> PoSQLTimeUnit {
+        match self.expr.data_type() {
+            ColumnType::TimestampTZ(time_unit, _) => time_unit,
+            _ => panic!("AT TIME ZONE requires a timestamp"),
+        }
+    }
+
+    /// The offset of the time zone in the time unit of the timestamp
+    fn offset<S: Scalar>(&self) -> S {
+        S::from(i64::from(self.time_zone.offset()) * self.time_unit().units_per_second())
+    }
+
+    /// Shift every timestamp by the offset of the time zone
+    ///
+    /// If a shifted timestamp no longer fits in an `i64` the scalars are returned instead so that
+    /// the overflow is reported when the result is coerced.
+    fn shift_column<'a, S: Scalar>(&self, alloc: &'a Bump, column: Column<'a, S>) -> Column<'a, S> {
+        let offset = self.offset::<S>();
+        let values: Vec<S> = column
+            .to_scalar_with_scaling(0)
+            .into_iter()
+            .map(|value| value + offset)
+            .collect();
+        match values
+            .iter()
+            .map(|value| TryInto::<i64>::try_into(*value))
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(timestamps) => Column::TimestampTZ(
+                self.time_unit(),
+                PoSQLTimeZone::utc(),
+                alloc.alloc_slice_copy(&timestamps),
+            ),
+            Err(_) => Column::Scalar(alloc.alloc_slice_copy(&values)),
+        }
+    }
+}
+
+impl ProofExpr for AtTimeZoneExpr {
+    fn data_type(&self) -> ColumnType {
+        ColumnType::TimestampTZ(self.time_unit(), PoSQLTimeZone::utc())
+    }
+
+    fn result_evaluate<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        let column = self.expr.result_evaluate(alloc, table);
+        self.shift_column(alloc, column)
+    }
+
+    #[tracing::instrument(name = "AtTimeZoneExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        log::log_memory_usage("Start");
+
+        let column = self.expr.prover_evaluate(builder, alloc, table);
+        let res = self.shift_column(alloc, column);
+
+        log::log_memory_usage("End");
+
+        res
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut VerificationBuilder<S>,
+        accessor: &IndexMap<ColumnRef, S>,
+        one_eval: S,
+    ) -> Result<S, ProofError> {
+        let eval = self.expr.verifier_evaluate(builder, accessor, one_eval)?;
+        Ok(eval + self.offset::<S>() * one_eval)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.expr.get_column_references(columns);
+    }
+}
+
+impl fmt::Display for AtTimeZoneExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({} at time zone '{}')", self.expr, self.time_zone)
+    }
+}
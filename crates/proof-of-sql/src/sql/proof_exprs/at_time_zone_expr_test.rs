@@ -0,0 +1,169 @@
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, LiteralValue, OwnedTableTestAccessor},
+    },
+    sql::{
+        parse::ConversionError,
+        postprocessing::{apply_postprocessing_steps, test_utility::*, OwnedTablePostprocessing},
+        proof::{exercise_verification, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, DynProofExpr},
+        proof_plans::test_utility::*,
+    },
+};
+use proof_of_sql_parser::{
+    posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
+    utility::{aliased_expr, col, count},
+};
+
+/// 2024-06-20T00:00:00Z
+const DAY_START: i64 = 1_718_841_600;
+/// +05:30
+const IST: PoSQLTimeZone = PoSQLTimeZone::new(19_800);
+
+/// UTC epochs of the given local times of 2024-06-20 in +05:30
+fn utc_epochs_of_local_times(local_times: &[(i64, i64)]) -> Vec<i64> {
+    local_times
+        .iter()
+        .map(|(hour, minute)| DAY_START + hour * 3600 + minute * 60 - 19_800)
+        .collect()
+}
+
+// select width_bucket(t at time zone '+05:30', '2024-06-20', '2024-06-21', 24) as local_hour from sxt.t
+// followed by select local_hour, count(local_hour) as frequency group by local_hour
+#[test]
+fn we_can_prove_a_histogram_of_local_hours() {
+    let local_times = [
+        (0, 15),
+        (5, 59),
+        (6, 0),
+        (6, 45),
+        (13, 30),
+        (23, 59),
+        (24, 0),
+        (-1, 0),
+        (13, 0),
+    ];
+    let data = owned_table([timestamptz(
+        "t",
+        PoSQLTimeUnit::Second,
+        PoSQLTimeZone::utc(),
+        utc_epochs_of_local_times(&local_times),
+    )]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            width_bucket(
+                at_time_zone(column(t, "t", &accessor), IST),
+                DAY_START,
+                DAY_START + 86_400,
+                24,
+            ),
+            "local_hour",
+        )],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    // Bucket `h + 1` holds local hour `h`, 0 and 25 hold the previous and the next day
+    let expected_res = owned_table([bigint("local_hour", [1_i64, 6, 7, 7, 14, 24, 25, 0, 14])]);
+    assert_eq!(res, expected_res);
+
+    let postprocessing: [OwnedTablePostprocessing; 1] = [group_by_postprocessing(
+        &["local_hour"],
+        &[
+            aliased_expr(col("local_hour"), "local_hour"),
+            aliased_expr(count(col("local_hour")), "frequency"),
+        ],
+    )];
+    let histogram = apply_postprocessing_steps(res, &postprocessing).unwrap();
+    let expected_histogram = owned_table([
+        bigint("local_hour", [0_i64, 1, 6, 7, 14, 24, 25]),
+        bigint("frequency", [1_i64, 1, 1, 2, 2, 1, 1]),
+    ]);
+    assert_eq!(histogram, expected_histogram);
+}
+
+// select t at time zone '+05:30' as local_t from sxt.t where t at time zone '+05:30' >= timestamp '2024-06-20T06:00:00'
+#[test]
+fn we_can_prove_a_filter_on_a_local_time() {
+    let utc_epochs = utc_epochs_of_local_times(&[(0, 15), (5, 59), (6, 0), (23, 59)]);
+    let data = owned_table([timestamptz(
+        "t",
+        PoSQLTimeUnit::Second,
+        PoSQLTimeZone::utc(),
+        utc_epochs.clone(),
+    )]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        vec![aliased_plan(
+            at_time_zone(column(t, "t", &accessor), IST),
+            "local_t",
+        )],
+        tab(t),
+        gte(
+            at_time_zone(column(t, "t", &accessor), IST),
+            DynProofExpr::new_literal(LiteralValue::TimeStampTZ(
+                PoSQLTimeUnit::Second,
+                PoSQLTimeZone::utc(),
+                DAY_START + 6 * 3600,
+            )),
+        ),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([timestamptz(
+        "local_t",
+        PoSQLTimeUnit::Second,
+        PoSQLTimeZone::utc(),
+        [DAY_START + 6 * 3600, DAY_START + 23 * 3600 + 59 * 60],
+    )]);
+    assert_eq!(res, expected_res);
+}
+
+// select t at time zone '-08:00' as local_t from sxt.t
+#[test]
+fn we_can_prove_a_negative_offset_in_milliseconds() {
+    let data = owned_table([timestamptz(
+        "t",
+        PoSQLTimeUnit::Millisecond,
+        PoSQLTimeZone::new(3600),
+        [0_i64, 1_718_841_600_123],
+    )]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            at_time_zone(column(t, "t", &accessor), PoSQLTimeZone::new(-28_800)),
+            "local_t",
+        )],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([timestamptz(
+        "local_t",
+        PoSQLTimeUnit::Millisecond,
+        PoSQLTimeZone::utc(),
+        [-28_800_000_i64, 1_718_841_600_123 - 28_800_000],
+    )]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_convert_a_non_timestamp_to_a_time_zone() {
+    let data = owned_table([bigint("a", [1_i64, 2]), varchar("b", ["a", "b"])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    for name in ["a", "b"] {
+        assert!(matches!(
+            DynProofExpr::try_new_at_time_zone(column(t, name, &accessor), IST),
+            Err(ConversionError::InvalidExpression { .. })
+        ));
+    }
+}
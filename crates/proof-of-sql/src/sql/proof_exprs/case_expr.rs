@@ -0,0 +1,261 @@
+use super::{DynProofExpr, ProofExpr};
+use crate::{
+    base::{
+        database::{
+            filter_util::filter_column_by_index, union_util::column_union, Column, ColumnRef,
+            ColumnType, Table,
+        },
+        map::{IndexMap, IndexSet},
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::proof::{
+        FinalRoundBuilder, SumcheckSubpolynomialTerm, SumcheckSubpolynomialType,
+        VerificationBuilder,
+    },
+    utils::log,
+};
+use alloc::{boxed::Box, vec, vec::Vec};
+use bumpalo::Bump;
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+/// Provable searched `CASE WHEN c_1 THEN t_1 ... WHEN c_n THEN t_n ELSE e END` expression
+///
+/// Every row takes the value of the first branch whose condition holds, or `e` if none does.
+/// The first matching branch is selected by `s_i = c_i * (1 - (s_1 + ... + s_{i-1}))`, so at
+/// most one selector is set in every row. `s_1 = c_1`, and every other selector is committed.
+/// The result is then `e + sum_i s_i * (t_i - e)`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CaseExpr {
+    when_then: Vec<(DynProofExpr, DynProofExpr)>,
+    else_expr: Box<DynProofExpr>,
+}
+
+impl CaseExpr {
+    /// Create a new `CASE` expression from its `WHEN` and `THEN` pairs and its `ELSE` value
+    pub fn new(when_then: Vec<(DynProofExpr, DynProofExpr)>, else_expr: Box<DynProofExpr>) -> Self {
+        Self {
+            when_then,
+            else_expr,
+        }
+    }
+
+    /// Compute the selector `s_i` of every branch
+    fn selectors<'a>(alloc: &'a Bump, length: usize, conditions: &[&'a [bool]]) -> Vec<&'a [bool]> {
+        let matched: &mut [bool] = alloc.alloc_slice_fill_copy(length, false);
+        conditions
+            .iter()
+            .map(|condition| {
+                let selector: &[bool] =
+                    alloc.alloc_slice_fill_with(length, |j| condition[j] && !matched[j]);
+                for (matched, selected) in matched.iter_mut().zip(selector) {
+                    *matched |= *selected;
+                }
+                selector
+            })
+            .collect()
+    }
+
+    /// Pick the value of the selected branch, or the `ELSE` value, in every row
+    ///
+    /// # Panics
+    /// Panics if columns of the same type can not be concatenated.
+    fn select<'a, S: Scalar>(
+        alloc: &'a Bump,
+        length: usize,
+        selectors: &[&[bool]],
+        values: &[Column<'a, S>],
+    ) -> Column<'a, S> {
+        let indexes = (0..length)
+            .map(|j| {
+                let branch = selectors
+                    .iter()
+                    .position(|selector| selector[j])
+                    .unwrap_or(selectors.len());
+                branch * length + j
+            })
+            .collect::<Vec<_>>();
+        // Arithmetic results are scalar columns, so branches of the same type may still be
+        // evaluated to columns of different types.
+        let column_type = values[0].column_type();
+        let values = if values
+            .iter()
+            .all(|value| value.column_type() == column_type)
+        {
+            column_union(&values.iter().collect::<Vec<_>>(), alloc, column_type)
+                .expect("Every branch has the same column type")
+        } else {
+            let scalars = values
+                .iter()
+                .flat_map(|value| value.to_scalar_with_scaling(0))
+                .collect::<Vec<_>>();
+            Column::Scalar(alloc.alloc_slice_copy(&scalars))
+        };
+        filter_column_by_index(alloc, &values, &indexes)
+    }
+}
+
+impl ProofExpr for CaseExpr {
+    fn data_type(&self) -> ColumnType {
+        self.else_expr.data_type()
+    }
+
+    #[tracing::instrument(name = "CaseExpr::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        log::log_memory_usage("Start");
+
+        let (conditions, mut values): (Vec<_>, Vec<_>) = self
+            .when_then
+            .iter()
+            .map(|(when, then)| {
+                let condition = when
+                    .result_evaluate(alloc, table)
+                    .as_boolean()
+                    .expect("condition is not boolean");
+                (condition, then.result_evaluate(alloc, table))
+            })
+            .unzip();
+        values.push(self.else_expr.result_evaluate(alloc, table));
+        let selectors = Self::selectors(alloc, table.num_rows(), &conditions);
+        let res = Self::select(alloc, table.num_rows(), &selectors, &values);
+
+        log::log_memory_usage("End");
+
+        res
+    }
+
+    #[tracing::instrument(name = "CaseExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        log::log_memory_usage("Start");
+
+        let (conditions, mut values): (Vec<_>, Vec<_>) = self
+            .when_then
+            .iter()
+            .map(|(when, then)| {
+                let condition = when
+                    .prover_evaluate(builder, alloc, table)
+                    .as_boolean()
+                    .expect("condition is not boolean");
+                (condition, then.prover_evaluate(builder, alloc, table))
+            })
+            .unzip();
+        let else_column = self.else_expr.prover_evaluate(builder, alloc, table);
+        values.push(else_column);
+        let selectors = Self::selectors(alloc, table.num_rows(), &conditions);
+
+        // subpolynomial: s_i - c_i + c_i * (s_1 + ... + s_{i-1})
+        for (i, (&condition, &selector)) in conditions.iter().zip(&selectors).enumerate().skip(1) {
+            builder.produce_intermediate_mle(selector);
+            let mut terms: Vec<SumcheckSubpolynomialTerm<'a, S>> = vec![
+                (S::one(), vec![Box::new(selector)]),
+                (-S::one(), vec![Box::new(condition)]),
+            ];
+            for &previous in &selectors[..i] {
+                terms.push((S::one(), vec![Box::new(condition), Box::new(previous)]));
+            }
+            builder.produce_sumcheck_subpolynomial(SumcheckSubpolynomialType::Identity, terms);
+        }
+
+        let res = Self::select(alloc, table.num_rows(), &selectors, &values);
+        builder.produce_intermediate_mle(res);
+
+        // subpolynomial: res - e - sum_i s_i * (t_i - e)
+        let mut terms: Vec<SumcheckSubpolynomialTerm<'a, S>> = vec![
+            (S::one(), vec![Box::new(res)]),
+            (-S::one(), vec![Box::new(else_column)]),
+        ];
+        for (&selector, &value) in selectors.iter().zip(&values) {
+            terms.push((-S::one(), vec![Box::new(selector), Box::new(value)]));
+            terms.push((S::one(), vec![Box::new(selector), Box::new(else_column)]));
+        }
+        builder.produce_sumcheck_subpolynomial(SumcheckSubpolynomialType::Identity, terms);
+
+        log::log_memory_usage("End");
+
+        res
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut VerificationBuilder<S>,
+        accessor: &IndexMap<ColumnRef, S>,
+        one_eval: S,
+    ) -> Result<S, ProofError> {
+        let (conditions, values) = self
+            .when_then
+            .iter()
+            .map(|(when, then)| {
+                Ok((
+                    when.verifier_evaluate(builder, accessor, one_eval)?,
+                    then.verifier_evaluate(builder, accessor, one_eval)?,
+                ))
+            })
+            .collect::<Result<(Vec<_>, Vec<_>), ProofError>>()?;
+        let else_eval = self
+            .else_expr
+            .verifier_evaluate(builder, accessor, one_eval)?;
+
+        // subpolynomial: s_i - c_i + c_i * (s_1 + ... + s_{i-1})
+        let mut selectors = Vec::with_capacity(conditions.len());
+        let mut matched = S::zero();
+        for (i, &condition) in conditions.iter().enumerate() {
+            let selector = if i == 0 {
+                condition
+            } else {
+                let selector = builder.try_consume_final_round_mle_evaluation()?;
+                builder.try_produce_sumcheck_subpolynomial_evaluation(
+                    SumcheckSubpolynomialType::Identity,
+                    selector - condition + condition * matched,
+                    2,
+                )?;
+                selector
+            };
+            matched += selector;
+            selectors.push(selector);
+        }
+
+        let res = builder.try_consume_final_round_mle_evaluation()?;
+
+        // subpolynomial: res - e - sum_i s_i * (t_i - e)
+        let selected = selectors
+            .iter()
+            .zip(&values)
+            .map(|(&selector, &value)| selector * (value - else_eval))
+            .sum::<S>();
+        builder.try_produce_sumcheck_subpolynomial_evaluation(
+            SumcheckSubpolynomialType::Identity,
+            res - else_eval - selected,
+            2,
+        )?;
+
+        Ok(res)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        for (when, then) in &self.when_then {
+            when.get_column_references(columns);
+            then.get_column_references(columns);
+        }
+        self.else_expr.get_column_references(columns);
+    }
+}
+
+impl fmt::Display for CaseExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "case")?;
+        for (when, then) in &self.when_then {
+            write!(f, " when {when} then {then}")?;
+        }
+        write!(f, " else {} end", self.else_expr)
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, OwnedTableTestAccessor},
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, DynProofExpr},
+        proof_plans::test_utility::*,
+    },
+};
+use rand::{
+    distributions::{Distribution, Uniform},
+    rngs::StdRng,
+};
+use rand_core::SeedableRng;
+
+// select case when a > 5 then 1 when a > 2 then 2 when a > 5 then 3 else 4 end as c from sxt.t
+#[test]
+fn we_can_prove_a_case_expression_with_overlapping_conditions() {
+    let data = owned_table([bigint("a", [0_i64, 3, 5, 6, 9, 2])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let a = || column(t, "a", &accessor);
+    let ast = projection(
+        vec![aliased_plan(
+            case(
+                vec![
+                    (not(lte(a(), const_bigint(5))), const_bigint(1)),
+                    (not(lte(a(), const_bigint(2))), const_bigint(2)),
+                    (not(lte(a(), const_bigint(5))), const_bigint(3)),
+                ],
+                const_bigint(4),
+            ),
+            "c",
+        )],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("c", [4_i64, 2, 2, 1, 1, 4])]);
+    assert_eq!(res, expected_res);
+}
+
+// select case when b then case when a < 3 then a * 10 else a end else 0 - a end as c
+// from sxt.t where case when a = 4 then false else true end
+#[test]
+fn we_can_prove_a_nested_case_expression() {
+    let data = owned_table([
+        bigint("a", [1_i64, 2, 3, 4, 5, 6]),
+        boolean("b", [true, false, true, true, true, false]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let a = || column(t, "a", &accessor);
+    let ast = filter(
+        vec![
+            col_expr_plan(t, "a", &accessor),
+            aliased_plan(
+                case(
+                    vec![(
+                        column(t, "b", &accessor),
+                        case(
+                            vec![(
+                                not(gte(a(), const_bigint(3))),
+                                multiply(a(), const_bigint(10)),
+                            )],
+                            a(),
+                        ),
+                    )],
+                    subtract(const_bigint(0), a()),
+                ),
+                "c",
+            ),
+        ],
+        tab(t),
+        case(
+            vec![(equal(a(), const_bigint(4)), const_bool(false))],
+            const_bool(true),
+        ),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        bigint("a", [1_i64, 2, 3, 5, 6]),
+        bigint("c", [10_i64, -2, 3, 5, -6]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+// select case when a = 1 then 'one' when b then c else 'many' end as d from sxt.t
+#[test]
+fn we_can_prove_a_case_expression_with_varchar_values() {
+    let data = owned_table([
+        int("a", [1_i32, 1, 2, 3]),
+        boolean("b", [true, false, true, false]),
+        varchar("c", ["w", "x", "y", "z"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            case(
+                vec![
+                    (
+                        equal(column(t, "a", &accessor), const_int(1)),
+                        const_varchar("one"),
+                    ),
+                    (column(t, "b", &accessor), column(t, "c", &accessor)),
+                ],
+                const_varchar("many"),
+            ),
+            "d",
+        )],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([varchar("d", ["one", "one", "y", "many"])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_case_expressions_over_random_data() {
+    let dist = Uniform::new(-10_i64, 10);
+    let mut rng = StdRng::from_seed([0u8; 32]);
+    let (a, b): (Vec<i64>, Vec<i64>) = (0..40)
+        .map(|_| (dist.sample(&mut rng), dist.sample(&mut rng)))
+        .unzip();
+    let data = owned_table([bigint("a", a.clone()), bigint("b", b.clone())]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            case(
+                vec![
+                    (
+                        lte(column(t, "a", &accessor), column(t, "b", &accessor)),
+                        column(t, "a", &accessor),
+                    ),
+                    (
+                        gte(column(t, "a", &accessor), const_bigint(0)),
+                        column(t, "b", &accessor),
+                    ),
+                ],
+                const_bigint(100),
+            ),
+            "c",
+        )],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint(
+        "c",
+        a.into_iter()
+            .zip(b)
+            .map(|(a, b)| {
+                if a <= b {
+                    a
+                } else if a >= 0 {
+                    b
+                } else {
+                    100
+                }
+            })
+            .collect::<Vec<_>>(),
+    )]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_create_invalid_case_expressions() {
+    let data = owned_table([
+        bigint("a", [1_i64, 2]),
+        int("b", [1_i32, 2]),
+        boolean("c", [true, false]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    assert!(matches!(
+        DynProofExpr::try_new_case(vec![], column(t, "a", &accessor)),
+        Err(ConversionError::InvalidExpression { .. })
+    ));
+    assert!(matches!(
+        DynProofExpr::try_new_case(
+            vec![(column(t, "a", &accessor), column(t, "a", &accessor))],
+            column(t, "a", &accessor)
+        ),
+        Err(ConversionError::InvalidDataType { .. })
+    ));
+    assert!(matches!(
+        DynProofExpr::try_new_case(
+            vec![(column(t, "c", &accessor), column(t, "b", &accessor))],
+            column(t, "a", &accessor)
+        ),
+        Err(ConversionError::DataTypeMismatch { .. })
+    ));
+}
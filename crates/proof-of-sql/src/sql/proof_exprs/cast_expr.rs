@@ -0,0 +1,126 @@
+use super::{DynProofExpr, ProofExpr};
+use crate::{
+    base::{
+        database::{Column, ColumnRef, ColumnType, Table},
+        map::{IndexMap, IndexSet},
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::proof::{FinalRoundBuilder, VerificationBuilder},
+    utils::log,
+};
+use alloc::boxed::Box;
+use bumpalo::Bump;
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+/// Provable `CAST(expr AS type)` expression for lossless integer casts
+///
+/// Any integer, signed or `UINT64`, can be cast to `INT128` or to a `DECIMAL(p, 0)` with enough
+/// digits to hold every value of its type. This is how unsigned and signed integers are compared,
+/// since they are never mixed implicitly.
+///
+/// Every integer type maps a value `v` to the scalar `v`, and so does a decimal with a scale of 0,
+/// so the cast column has the same multilinear extension as `expr` and nothing needs to be proven.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CastExpr {
+    expr: Box<DynProofExpr>,
+    to_type: ColumnType,
+}
+
+impl CastExpr {
+    /// Create a new `CAST` expression
+    pub fn new(expr: Box<DynProofExpr>, to_type: ColumnType) -> Self {
+        Self { expr, to_type }
+    }
+
+    /// Whether a value of `from_type` can be cast to `to_type` without changing its scalar
+    pub(crate) fn is_lossless(from_type: ColumnType, to_type: ColumnType) -> bool {
+        let is_cast_target = matches!(to_type, ColumnType::Int128 | ColumnType::Decimal75(_, 0));
+        (from_type.is_integer() || from_type == ColumnType::UInt64)
+            && is_cast_target
+            && from_type != to_type
+            && from_type.precision_value() <= to_type.precision_value()
+    }
+
+    /// Cast an integer column to `self.to_type`
+    ///
+    /// # Panics
+    /// Panics if `column` is not an integer column, which is checked on construction.
+    fn cast<'a, S: Scalar>(&self, alloc: &'a Bump, column: Column<'a, S>) -> Column<'a, S> {
+        match self.to_type {
+            ColumnType::Int128 => Column::Int128(match column {
+                Column::TinyInt(col) => alloc.alloc_slice_fill_iter(col.iter().map(|&v| v.into())),
+                Column::SmallInt(col) => alloc.alloc_slice_fill_iter(col.iter().map(|&v| v.into())),
+                Column::Int(col) => alloc.alloc_slice_fill_iter(col.iter().map(|&v| v.into())),
+                Column::BigInt(col) => alloc.alloc_slice_fill_iter(col.iter().map(|&v| v.into())),
+                Column::UInt64(col) => alloc.alloc_slice_fill_iter(col.iter().map(|&v| v.into())),
+                _ => panic!("only integer columns can be cast"),
+            }),
+            ColumnType::Decimal75(precision, scale) => Column::Decimal75(
+                precision,
+                scale,
+                alloc.alloc_slice_copy(&column.to_scalar_with_scaling(0)),
+            ),
+            _ => panic!("only casts to INT128 or DECIMAL are supported"),
+        }
+    }
+}
+
+impl ProofExpr for CastExpr {
+    fn data_type(&self) -> ColumnType {
+        self.to_type
+    }
+
+    #[tracing::instrument(name = "CastExpr::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        log::log_memory_usage("Start");
+
+        let column = self.expr.result_evaluate(alloc, table);
+        let res = self.cast(alloc, column);
+
+        log::log_memory_usage("End");
+
+        res
+    }
+
+    #[tracing::instrument(name = "CastExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        log::log_memory_usage("Start");
+
+        let column = self.expr.prover_evaluate(builder, alloc, table);
+        let res = self.cast(alloc, column);
+
+        log::log_memory_usage("End");
+
+        res
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut VerificationBuilder<S>,
+        accessor: &IndexMap<ColumnRef, S>,
+        one_eval: S,
+    ) -> Result<S, ProofError> {
+        self.expr.verifier_evaluate(builder, accessor, one_eval)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.expr.get_column_references(columns);
+    }
+}
+
+impl fmt::Display for CastExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CAST({} AS {})", self.expr, self.to_type)
+    }
+}
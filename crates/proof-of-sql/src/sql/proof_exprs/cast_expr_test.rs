@@ -0,0 +1,117 @@
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, ColumnType, OwnedTableTestAccessor},
+        math::decimal::Precision,
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, DynProofExpr},
+        proof_plans::test_utility::*,
+    },
+};
+
+fn decimal_type(precision: u8) -> ColumnType {
+    ColumnType::Decimal75(Precision::new(precision).unwrap(), 0)
+}
+
+// select u, b from sxt.t where cast(u as decimal(20, 0)) <= b
+#[test]
+fn we_can_compare_uint64_and_signed_columns_through_a_cast() {
+    let data = owned_table([
+        uint64("u", [0_u64, 1, 5, u64::MAX, 1 << 63, 7]),
+        bigint("b", [-1_i64, 1, 10, i64::MAX, i64::MIN, i64::MAX]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        cols_expr_plan(t, &["u", "b"], &accessor),
+        tab(t),
+        lte(
+            cast(column(t, "u", &accessor), decimal_type(20)),
+            column(t, "b", &accessor),
+        ),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        uint64("u", [1_u64, 5, 7]),
+        bigint("b", [1_i64, 10, i64::MAX]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+// select cast(u as int128) as w, cast(b as decimal(19, 0)) as d, cast(s as int128) as x from sxt.t
+#[test]
+fn we_can_prove_lossless_casts_of_integers() {
+    let data = owned_table([
+        uint64("u", [0_u64, 1, u64::MAX]),
+        bigint("b", [i64::MIN, -1, i64::MAX]),
+        smallint("s", [i16::MIN, 0, i16::MAX]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![
+            aliased_plan(cast(column(t, "u", &accessor), ColumnType::Int128), "w"),
+            aliased_plan(cast(column(t, "b", &accessor), decimal_type(19)), "d"),
+            aliased_plan(cast(column(t, "s", &accessor), ColumnType::Int128), "x"),
+        ],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        int128("w", [0_i128, 1, u64::MAX.into()]),
+        decimal75("d", 19, 0, [i64::MIN, -1, i64::MAX]),
+        int128("x", [i16::MIN.into(), 0, i16::MAX.into()]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_compare_uint64_and_signed_columns_without_a_cast() {
+    let data = owned_table([uint64("u", [1_u64]), bigint("b", [1_i64])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    assert!(matches!(
+        DynProofExpr::try_new_inequality(
+            column(t, "u", &accessor),
+            column(t, "b", &accessor),
+            true
+        ),
+        Err(ConversionError::DataTypeMismatch { .. })
+    ));
+}
+
+#[test]
+fn we_cannot_create_a_lossy_or_non_integer_cast() {
+    let data = owned_table([
+        uint64("u", [1_u64]),
+        bigint("b", [1_i64]),
+        int128("i", [1_i128]),
+        varchar("v", ["1"]),
+        decimal75("d", 10, 0, [1]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    for (name, to_type) in [
+        ("u", decimal_type(19)),
+        ("u", ColumnType::BigInt),
+        ("b", decimal_type(18)),
+        ("b", ColumnType::Decimal75(Precision::new(25).unwrap(), 2)),
+        ("b", ColumnType::UInt64),
+        ("i", ColumnType::Int128),
+        ("i", decimal_type(38)),
+        ("v", ColumnType::Int128),
+        ("d", ColumnType::Int128),
+    ] {
+        assert!(matches!(
+            DynProofExpr::try_new_cast(column(t, name, &accessor), to_type),
+            Err(ConversionError::InvalidExpression { .. })
+        ));
+    }
+}
@@ -9,6 +9,7 @@ use crate::{
     sql::proof::{FinalRoundBuilder, VerificationBuilder},
 };
 use bumpalo::Bump;
+use core::fmt;
 use serde::{Deserialize, Serialize};
 use sqlparser::ast::Ident;
 /// Provable expression for a column
@@ -102,3 +103,9 @@ impl ProofExpr for ColumnExpr {
         columns.insert(self.column_ref.clone());
     }
 }
+
+impl fmt::Display for ColumnExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.column_ref.column_id())
+    }
+}
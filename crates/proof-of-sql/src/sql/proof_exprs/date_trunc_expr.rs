@@ -0,0 +1,469 @@
+use super::{DynProofExpr, ProofExpr};
+use crate::{
+    base::{
+        database::{Column, ColumnRef, ColumnType, Table},
+        map::{IndexMap, IndexSet},
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{FinalRoundBuilder, VerificationBuilder},
+        proof_gadgets::{
+            prover_evaluate_floor_division, prover_evaluate_sign, verifier_evaluate_floor_division,
+            verifier_evaluate_sign,
+        },
+    },
+    utils::log,
+};
+use alloc::{boxed::Box, format, vec::Vec};
+use bumpalo::Bump;
+use core::{fmt, str::FromStr};
+use proof_of_sql_parser::posql_time::{PoSQLTimeUnit, PoSQLTimeZone};
+use serde::{Deserialize, Serialize};
+
+/// The number of seconds in a minute
+pub(super) const SECONDS_PER_MINUTE: i128 = 60;
+/// The number of seconds in an hour
+pub(super) const SECONDS_PER_HOUR: i128 = 3_600;
+/// The number of seconds in a day
+pub(super) const SECONDS_PER_DAY: i128 = 86_400;
+/// The number of days from 0000-03-01 to 1970-01-01 in the proleptic Gregorian calendar
+const DAYS_FROM_MARCH_0000_TO_EPOCH: i128 = 719_468;
+
+/// The granularity to which `DATE_TRUNC` truncates a timestamp
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DateTruncGranularity {
+    /// Truncate to the first instant of the year
+    Year,
+    /// Truncate to the first instant of the month
+    Month,
+    /// Truncate to midnight
+    Day,
+    /// Truncate to the full hour
+    Hour,
+}
+
+impl FromStr for DateTruncGranularity {
+    type Err = ConversionError;
+
+    fn from_str(granularity: &str) -> Result<Self, Self::Err> {
+        match granularity.to_lowercase().as_str() {
+            "year" => Ok(Self::Year),
+            "month" => Ok(Self::Month),
+            "day" => Ok(Self::Day),
+            "hour" => Ok(Self::Hour),
+            _ => Err(ConversionError::InvalidExpression {
+                expression: format!("date_trunc does not support the granularity '{granularity}'"),
+            }),
+        }
+    }
+}
+
+impl DateTruncGranularity {
+    /// For months and years, how to compute the month of the committed index `k`, as `a * k + b`,
+    /// and the number of months until the next index
+    ///
+    /// The index of a month is the month itself and the index of a year is the year itself.
+    fn month_index_coefficients(self) -> (i128, i128, i128) {
+        match self {
+            Self::Month => (1, 0, 1),
+            // January of year `k` is month 10 of the year `k - 1` starting in March.
+            Self::Year => (12, -2, 12),
+            Self::Day | Self::Hour => unreachable!("days and hours have a fixed length"),
+        }
+    }
+
+    /// The committed index of the month or year of a day since the epoch, see
+    /// [`Self::month_index_coefficients`]
+    pub(super) fn month_index(self, day: i128) -> i128 {
+        let month = month_of_day(day);
+        match self {
+            Self::Year => (month + 2).div_euclid(12),
+            _ => month,
+        }
+    }
+}
+
+impl fmt::Display for DateTruncGranularity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Year => "year",
+            Self::Month => "month",
+            Self::Day => "day",
+            Self::Hour => "hour",
+        })
+    }
+}
+
+/// The first day of a month, in days since the epoch
+///
+/// Months are counted from 0000-03, so that every year starts in March and the leap day is the
+/// last day of a year. Writing `y = floor(m / 12)` and `p = m - 12 * y` for a month `m`, its first
+/// day is `365 * y + floor(y / 4) - floor(y / 100) + floor(y / 400) + floor((153 * p + 2) / 5)`
+/// days after 0000-03-01.
+pub(super) fn month_start(month: i128) -> i128 {
+    let year = month.div_euclid(12);
+    let month_of_year = month - 12 * year;
+    365 * year + year.div_euclid(4) - year.div_euclid(100)
+        + year.div_euclid(400)
+        + (153 * month_of_year + 2).div_euclid(5)
+        - DAYS_FROM_MARCH_0000_TO_EPOCH
+}
+
+/// The month of a day since the epoch, in months since 0000-03
+///
+/// This is the inverse of [`month_start`], following Howard Hinnant's `civil_from_days`.
+fn month_of_day(day: i128) -> i128 {
+    let days = day + DAYS_FROM_MARCH_0000_TO_EPOCH;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    12 * (era * 400 + year_of_era) + (5 * day_of_year + 2) / 153
+}
+
+/// The timestamps of a column
+///
+/// # Panics
+/// Panics if a value does not fit in an `i128`, which cannot happen for timestamps.
+pub(super) fn timestamps<S: Scalar>(column: &Column<'_, S>) -> Vec<i128> {
+    column
+        .to_scalar_with_scaling(0)
+        .into_iter()
+        .map(|value| {
+            value
+                .try_into()
+                .unwrap_or_else(|_| panic!("timestamps fit in an i128"))
+        })
+        .collect()
+}
+
+/// Prove the first day of every month in `months`, see [`month_start`]
+fn prover_evaluate_month_start<'a, S: Scalar + 'a>(
+    builder: &mut FinalRoundBuilder<'a, S>,
+    alloc: &'a Bump,
+    months: &[i128],
+) -> Vec<i128> {
+    let years = prover_evaluate_floor_division(builder, alloc, months, 12);
+    let month_day_numerators: Vec<i128> = months
+        .iter()
+        .zip(years.iter())
+        .map(|(month, year)| 153 * (month - 12 * year) + 2)
+        .collect();
+    let leap_days_4 = prover_evaluate_floor_division(builder, alloc, years, 4);
+    let leap_days_100 = prover_evaluate_floor_division(builder, alloc, years, 100);
+    let leap_days_400 = prover_evaluate_floor_division(builder, alloc, years, 400);
+    let month_days = prover_evaluate_floor_division(builder, alloc, &month_day_numerators, 5);
+    (0..months.len())
+        .map(|i| {
+            365 * years[i] + leap_days_4[i] - leap_days_100[i] + leap_days_400[i] + month_days[i]
+                - DAYS_FROM_MARCH_0000_TO_EPOCH
+        })
+        .collect()
+}
+
+/// Verify the first day of every month, see [`prover_evaluate_month_start`]
+fn verifier_evaluate_month_start<S: Scalar>(
+    builder: &mut VerificationBuilder<S>,
+    month_eval: S,
+    one_eval: S,
+) -> Result<S, ProofError> {
+    let year_eval = verifier_evaluate_floor_division(builder, month_eval, 12, one_eval)?;
+    let month_day_numerator_eval =
+        S::from(153) * (month_eval - S::from(12) * year_eval) + S::from(2) * one_eval;
+    let leap_days_4_eval = verifier_evaluate_floor_division(builder, year_eval, 4, one_eval)?;
+    let leap_days_100_eval = verifier_evaluate_floor_division(builder, year_eval, 100, one_eval)?;
+    let leap_days_400_eval = verifier_evaluate_floor_division(builder, year_eval, 400, one_eval)?;
+    let month_days_eval =
+        verifier_evaluate_floor_division(builder, month_day_numerator_eval, 5, one_eval)?;
+    Ok(
+        S::from(365) * year_eval + leap_days_4_eval - leap_days_100_eval
+            + leap_days_400_eval
+            + month_days_eval
+            - S::from(DAYS_FROM_MARCH_0000_TO_EPOCH) * one_eval,
+    )
+}
+
+/// Prove that every timestamp lies between the first instants of its month or year and of the
+/// next one
+///
+/// Returns the indexes of the months or years, see
+/// [`DateTruncGranularity::month_index_coefficients`], and their first instants.
+pub(super) fn prover_evaluate_month_truncation<'a, S: Scalar + 'a>(
+    builder: &mut FinalRoundBuilder<'a, S>,
+    alloc: &'a Bump,
+    granularity: DateTruncGranularity,
+    units_per_day: i128,
+    timestamps: &[i128],
+) -> (Vec<i128>, Vec<i128>) {
+    let (a, b, step) = granularity.month_index_coefficients();
+    let indexes: Vec<i128> = timestamps
+        .iter()
+        .map(|t| granularity.month_index(t.div_euclid(units_per_day)))
+        .collect();
+    let index: &[S] = alloc.alloc_slice_fill_iter(indexes.iter().map(|&k| S::from(k)));
+    builder.produce_intermediate_mle(index);
+    prover_evaluate_sign(
+        builder,
+        alloc,
+        index,
+        #[cfg(test)]
+        false,
+    );
+    let months: Vec<i128> = indexes.iter().map(|k| a * k + b).collect();
+    let next_months: Vec<i128> = months.iter().map(|month| month + step).collect();
+    let starts: Vec<i128> = prover_evaluate_month_start(builder, alloc, &months)
+        .into_iter()
+        .map(|day| day * units_per_day)
+        .collect();
+    let ends: Vec<i128> = prover_evaluate_month_start(builder, alloc, &next_months)
+        .into_iter()
+        .map(|day| day * units_per_day)
+        .collect();
+    let lower: &[S] = alloc.alloc_slice_fill_iter(
+        timestamps
+            .iter()
+            .zip(&starts)
+            .map(|(t, start)| S::from(t - start)),
+    );
+    let upper: &[S] = alloc.alloc_slice_fill_iter(
+        timestamps
+            .iter()
+            .zip(&ends)
+            .map(|(t, end)| S::from(end - 1 - t)),
+    );
+    for column in [lower, upper] {
+        prover_evaluate_sign(
+            builder,
+            alloc,
+            column,
+            #[cfg(test)]
+            false,
+        );
+    }
+    (indexes, starts)
+}
+
+/// Verify the truncation to months or years, returning the evaluations of the indexes and of the
+/// first instants, see [`prover_evaluate_month_truncation`]
+pub(super) fn verifier_evaluate_month_truncation<S: Scalar>(
+    builder: &mut VerificationBuilder<S>,
+    granularity: DateTruncGranularity,
+    units_per_day: i128,
+    timestamp_eval: S,
+    one_eval: S,
+) -> Result<(S, S), ProofError> {
+    let (a, b, step) = granularity.month_index_coefficients();
+    let units_per_day = S::from(units_per_day);
+    let index_eval = builder.try_consume_final_round_mle_evaluation()?;
+    // The sign of the index is not needed, only that it decomposes into few enough bits.
+    verifier_evaluate_sign(builder, index_eval, one_eval)?;
+    let month_eval = S::from(a) * index_eval + S::from(b) * one_eval;
+    let next_month_eval = month_eval + S::from(step) * one_eval;
+    let start_eval = verifier_evaluate_month_start(builder, month_eval, one_eval)? * units_per_day;
+    let end_eval =
+        verifier_evaluate_month_start(builder, next_month_eval, one_eval)? * units_per_day;
+    for eval in [
+        timestamp_eval - start_eval,
+        end_eval - one_eval - timestamp_eval,
+    ] {
+        if verifier_evaluate_sign(builder, eval, one_eval)? != S::zero() {
+            return Err(ProofError::VerificationError {
+                error: "timestamp is not within the truncated month or year",
+            });
+        }
+    }
+    Ok((index_eval, start_eval))
+}
+
+/// Provable `DATE_TRUNC(granularity, timestamp)` expression
+///
+/// Truncates a timestamp to the start of its year, month, day or hour in UTC, keeping its time
+/// unit and time zone. To truncate to local boundaries, the timestamp can be converted with
+/// `AT TIME ZONE` first.
+///
+/// For hours and days, which have a fixed length `d`, the result is `q * d` for a committed
+/// `q = floor(t / d)`. Months and years do not have a fixed length, so instead the index of the
+/// month or year is committed, the first days of it and of the next month or year are computed
+/// from the index by floor divisions with constant divisors, and the proof establishes that the
+/// timestamp lies between the two. Every floor division is proven by committing to the quotient
+/// and proving that the remainder is in range.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DateTruncExpr {
+    granularity: DateTruncGranularity,
+    expr: Box<DynProofExpr>,
+}
+
+impl DateTruncExpr {
+    /// Create a new `DATE_TRUNC` expression
+    pub fn new(granularity: DateTruncGranularity, expr: Box<DynProofExpr>) -> Self {
+        Self { granularity, expr }
+    }
+
+    /// The time unit and time zone of the underlying timestamp
+    ///
+    /// # Panics
+    /// Panics if the underlying expression is not a timestamp, which the constructor of
+    /// [`DynProofExpr::DateTrunc`] rules out.
+    fn time_unit_and_zone(&self) -> (PoSQLTimeUnit, PoSQLTimeZone) {
+        match self.expr.data_type() {
+            ColumnType::TimestampTZ(time_unit, time_zone) => (time_unit, time_zone),
+            _ => panic!("DATE_TRUNC requires a timestamp"),
+        }
+    }
+
+    /// Convert a number of seconds to the time unit of the timestamp
+    fn units_per(&self, seconds: i128) -> i128 {
+        seconds * i128::from(self.time_unit_and_zone().0.units_per_second())
+    }
+
+    /// Truncate a timestamp
+    fn truncate(&self, timestamp: i128) -> i128 {
+        match self.granularity {
+            DateTruncGranularity::Hour | DateTruncGranularity::Day => {
+                let period = self.period();
+                timestamp.div_euclid(period) * period
+            }
+            DateTruncGranularity::Month | DateTruncGranularity::Year => {
+                let units_per_day = self.units_per(SECONDS_PER_DAY);
+                let (a, b, _) = self.granularity.month_index_coefficients();
+                let index = self
+                    .granularity
+                    .month_index(timestamp.div_euclid(units_per_day));
+                month_start(a * index + b) * units_per_day
+            }
+        }
+    }
+
+    /// The length of an hour or a day in the time unit of the timestamp
+    fn period(&self) -> i128 {
+        match self.granularity {
+            DateTruncGranularity::Hour => self.units_per(SECONDS_PER_HOUR),
+            DateTruncGranularity::Day => self.units_per(SECONDS_PER_DAY),
+            DateTruncGranularity::Month | DateTruncGranularity::Year => {
+                unreachable!("months and years do not have a fixed length")
+            }
+        }
+    }
+
+    /// The column of the truncated timestamps
+    ///
+    /// If a truncated timestamp no longer fits in an `i64` the scalars are returned instead so
+    /// that the overflow is reported when the result is coerced.
+    fn truncated_column<'a, S: Scalar + 'a>(
+        &self,
+        alloc: &'a Bump,
+        values: &[i128],
+    ) -> Column<'a, S> {
+        let (time_unit, time_zone) = self.time_unit_and_zone();
+        match values
+            .iter()
+            .map(|&value| i64::try_from(value))
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(timestamps) => {
+                Column::TimestampTZ(time_unit, time_zone, alloc.alloc_slice_copy(&timestamps))
+            }
+            Err(_) => Column::Scalar(
+                alloc.alloc_slice_fill_iter(values.iter().map(|&value| S::from(value))),
+            ),
+        }
+    }
+}
+
+impl ProofExpr for DateTruncExpr {
+    fn data_type(&self) -> ColumnType {
+        let (time_unit, time_zone) = self.time_unit_and_zone();
+        ColumnType::TimestampTZ(time_unit, time_zone)
+    }
+
+    fn result_evaluate<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        let column = self.expr.result_evaluate(alloc, table);
+        let truncated: Vec<i128> = timestamps(&column)
+            .into_iter()
+            .map(|t| self.truncate(t))
+            .collect();
+        self.truncated_column(alloc, &truncated)
+    }
+
+    #[tracing::instrument(name = "DateTruncExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        log::log_memory_usage("Start");
+
+        let column = self.expr.prover_evaluate(builder, alloc, table);
+        let timestamps = timestamps(&column);
+        let truncated: Vec<i128> = match self.granularity {
+            DateTruncGranularity::Hour | DateTruncGranularity::Day => {
+                let period = self.period();
+                prover_evaluate_floor_division(builder, alloc, &timestamps, period)
+                    .iter()
+                    .map(|q| q * period)
+                    .collect()
+            }
+            DateTruncGranularity::Month | DateTruncGranularity::Year => {
+                prover_evaluate_month_truncation(
+                    builder,
+                    alloc,
+                    self.granularity,
+                    self.units_per(SECONDS_PER_DAY),
+                    &timestamps,
+                )
+                .1
+            }
+        };
+        let res = self.truncated_column(alloc, &truncated);
+
+        log::log_memory_usage("End");
+
+        res
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut VerificationBuilder<S>,
+        accessor: &IndexMap<ColumnRef, S>,
+        one_eval: S,
+    ) -> Result<S, ProofError> {
+        let eval = self.expr.verifier_evaluate(builder, accessor, one_eval)?;
+        match self.granularity {
+            DateTruncGranularity::Hour | DateTruncGranularity::Day => {
+                let period = self.period();
+                Ok(
+                    verifier_evaluate_floor_division(builder, eval, period, one_eval)?
+                        * S::from(period),
+                )
+            }
+            DateTruncGranularity::Month | DateTruncGranularity::Year => {
+                let (_, start_eval) = verifier_evaluate_month_truncation(
+                    builder,
+                    self.granularity,
+                    self.units_per(SECONDS_PER_DAY),
+                    eval,
+                    one_eval,
+                )?;
+                Ok(start_eval)
+            }
+        }
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.expr.get_column_references(columns);
+    }
+}
+
+impl fmt::Display for DateTruncExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "date_trunc('{}', {})", self.granularity, self.expr)
+    }
+}
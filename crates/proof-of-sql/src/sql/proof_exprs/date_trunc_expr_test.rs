@@ -0,0 +1,274 @@
+use super::DateTruncGranularity;
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, OwnedTableTestAccessor},
+    },
+    sql::{
+        parse::ConversionError,
+        postprocessing::{apply_postprocessing_steps, test_utility::*, OwnedTablePostprocessing},
+        proof::{exercise_verification, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, DynProofExpr},
+        proof_plans::test_utility::*,
+    },
+};
+use chrono::{TimeZone, Utc};
+use proof_of_sql_parser::{
+    posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
+    utility::{aliased_expr, col, count},
+};
+
+/// The UTC epoch of the given date and time in seconds
+fn epoch(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> i64 {
+    Utc.with_ymd_and_hms(year, month, day, hour, minute, second)
+        .unwrap()
+        .timestamp()
+}
+
+// select date_trunc('day', t) as day from sxt.t
+// followed by select day, count(day) as frequency group by day
+#[test]
+fn we_can_prove_a_histogram_of_days() {
+    let data = owned_table([timestamptz(
+        "t",
+        PoSQLTimeUnit::Second,
+        PoSQLTimeZone::utc(),
+        [
+            epoch(2024, 6, 20, 0, 0, 0),
+            epoch(2024, 6, 20, 23, 59, 59),
+            epoch(2024, 6, 21, 0, 0, 0),
+            epoch(2024, 6, 19, 23, 59, 59),
+            epoch(2024, 6, 20, 12, 0, 0),
+            epoch(1969, 12, 31, 23, 59, 59),
+        ],
+    )]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            date_trunc("day", column(t, "t", &accessor)),
+            "day",
+        )],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([timestamptz(
+        "day",
+        PoSQLTimeUnit::Second,
+        PoSQLTimeZone::utc(),
+        [
+            epoch(2024, 6, 20, 0, 0, 0),
+            epoch(2024, 6, 20, 0, 0, 0),
+            epoch(2024, 6, 21, 0, 0, 0),
+            epoch(2024, 6, 19, 0, 0, 0),
+            epoch(2024, 6, 20, 0, 0, 0),
+            epoch(1969, 12, 31, 0, 0, 0),
+        ],
+    )]);
+    assert_eq!(res, expected_res);
+
+    let postprocessing: [OwnedTablePostprocessing; 1] = [group_by_postprocessing(
+        &["day"],
+        &[
+            aliased_expr(col("day"), "day"),
+            aliased_expr(count(col("day")), "frequency"),
+        ],
+    )];
+    let histogram = apply_postprocessing_steps(res, &postprocessing).unwrap();
+    let expected_histogram = owned_table([
+        timestamptz(
+            "day",
+            PoSQLTimeUnit::Second,
+            PoSQLTimeZone::utc(),
+            [
+                epoch(1969, 12, 31, 0, 0, 0),
+                epoch(2024, 6, 19, 0, 0, 0),
+                epoch(2024, 6, 20, 0, 0, 0),
+                epoch(2024, 6, 21, 0, 0, 0),
+            ],
+        ),
+        bigint("frequency", [1_i64, 1, 3, 1]),
+    ]);
+    assert_eq!(histogram, expected_histogram);
+}
+
+// select date_trunc('hour', t) as hour from sxt.t
+#[test]
+fn we_can_prove_a_truncation_to_hours_in_nanoseconds() {
+    let data = owned_table([timestamptz(
+        "t",
+        PoSQLTimeUnit::Nanosecond,
+        PoSQLTimeZone::new(3600),
+        [-1_i64, 0, 3_599_999_999_999, 3_600_000_000_001],
+    )]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            date_trunc("HOUR", column(t, "t", &accessor)),
+            "hour",
+        )],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([timestamptz(
+        "hour",
+        PoSQLTimeUnit::Nanosecond,
+        PoSQLTimeZone::new(3600),
+        [-3_600_000_000_000_i64, 0, 0, 3_600_000_000_000],
+    )]);
+    assert_eq!(res, expected_res);
+}
+
+// select date_trunc('month', t) as month from sxt.t
+#[test]
+fn we_can_prove_a_truncation_to_months() {
+    let millis = |seconds: i64, millis: i64| seconds * 1000 + millis;
+    let data = owned_table([timestamptz(
+        "t",
+        PoSQLTimeUnit::Millisecond,
+        PoSQLTimeZone::utc(),
+        [
+            millis(epoch(2024, 2, 29, 23, 59, 59), 999),
+            millis(epoch(2024, 3, 1, 0, 0, 0), 0),
+            millis(epoch(2023, 12, 31, 23, 59, 59), 999),
+            millis(epoch(2024, 1, 1, 0, 0, 0), 0),
+            millis(epoch(1969, 12, 31, 23, 59, 59), 999),
+            millis(epoch(1900, 2, 28, 12, 0, 0), 0),
+            millis(epoch(2000, 2, 29, 12, 0, 0), 0),
+        ],
+    )]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            date_trunc("month", column(t, "t", &accessor)),
+            "month",
+        )],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([timestamptz(
+        "month",
+        PoSQLTimeUnit::Millisecond,
+        PoSQLTimeZone::utc(),
+        [
+            millis(epoch(2024, 2, 1, 0, 0, 0), 0),
+            millis(epoch(2024, 3, 1, 0, 0, 0), 0),
+            millis(epoch(2023, 12, 1, 0, 0, 0), 0),
+            millis(epoch(2024, 1, 1, 0, 0, 0), 0),
+            millis(epoch(1969, 12, 1, 0, 0, 0), 0),
+            millis(epoch(1900, 2, 1, 0, 0, 0), 0),
+            millis(epoch(2000, 2, 1, 0, 0, 0), 0),
+        ],
+    )]);
+    assert_eq!(res, expected_res);
+}
+
+// select date_trunc('year', t) as year from sxt.t
+#[test]
+fn we_can_prove_a_truncation_to_years() {
+    let data = owned_table([timestamptz(
+        "t",
+        PoSQLTimeUnit::Second,
+        PoSQLTimeZone::utc(),
+        [
+            epoch(2023, 12, 31, 23, 59, 59),
+            epoch(2024, 1, 1, 0, 0, 0),
+            epoch(2024, 2, 29, 0, 0, 0),
+            epoch(2024, 3, 1, 0, 0, 0),
+            epoch(1969, 6, 15, 0, 0, 0),
+            epoch(1900, 12, 31, 23, 59, 59),
+            epoch(1600, 3, 1, 0, 0, 0),
+        ],
+    )]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            date_trunc("year", column(t, "t", &accessor)),
+            "year",
+        )],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([timestamptz(
+        "year",
+        PoSQLTimeUnit::Second,
+        PoSQLTimeZone::utc(),
+        [
+            epoch(2023, 1, 1, 0, 0, 0),
+            epoch(2024, 1, 1, 0, 0, 0),
+            epoch(2024, 1, 1, 0, 0, 0),
+            epoch(2024, 1, 1, 0, 0, 0),
+            epoch(1969, 1, 1, 0, 0, 0),
+            epoch(1900, 1, 1, 0, 0, 0),
+            epoch(1600, 1, 1, 0, 0, 0),
+        ],
+    )]);
+    assert_eq!(res, expected_res);
+}
+
+// select date_trunc('day', t at time zone '-08:00') as local_day from sxt.t
+#[test]
+fn we_can_prove_a_truncation_to_local_days() {
+    let data = owned_table([timestamptz(
+        "t",
+        PoSQLTimeUnit::Second,
+        PoSQLTimeZone::utc(),
+        [epoch(2024, 6, 20, 7, 59, 59), epoch(2024, 6, 20, 8, 0, 0)],
+    )]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            date_trunc(
+                "day",
+                at_time_zone(column(t, "t", &accessor), PoSQLTimeZone::new(-28_800)),
+            ),
+            "local_day",
+        )],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([timestamptz(
+        "local_day",
+        PoSQLTimeUnit::Second,
+        PoSQLTimeZone::utc(),
+        [epoch(2024, 6, 19, 0, 0, 0), epoch(2024, 6, 20, 0, 0, 0)],
+    )]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_truncate_to_an_unsupported_granularity() {
+    for granularity in ["week", "quarter", "minute", ""] {
+        assert!(matches!(
+            granularity.parse::<DateTruncGranularity>(),
+            Err(ConversionError::InvalidExpression { .. })
+        ));
+    }
+}
+
+#[test]
+fn we_cannot_truncate_a_non_timestamp() {
+    let data = owned_table([bigint("a", [1_i64, 2]), varchar("b", ["a", "b"])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    for name in ["a", "b"] {
+        assert!(matches!(
+            DynProofExpr::try_new_date_trunc(DateTruncGranularity::Day, column(t, name, &accessor)),
+            Err(ConversionError::InvalidExpression { .. })
+        ));
+    }
+}
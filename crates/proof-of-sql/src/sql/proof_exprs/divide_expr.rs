@@ -0,0 +1,131 @@
+use super::{modulo_expr::integer_column, DynProofExpr, ProofExpr};
+use crate::{
+    base::{
+        database::{Column, ColumnRef, ColumnType, Table},
+        map::{IndexMap, IndexSet},
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::{
+        proof::{FinalRoundBuilder, VerificationBuilder},
+        proof_gadgets::{
+            prover_evaluate_truncated_division, truncated_division,
+            verifier_evaluate_truncated_division,
+        },
+    },
+    utils::log,
+};
+use alloc::boxed::Box;
+use bumpalo::Bump;
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+/// Provable integer `/` expression
+///
+/// The quotient is truncated toward zero, as with Rust's `/` on integers, so e.g. `-7 / 2 = -3`
+/// and `7 / -2 = -3`. The result has the wider of the two operand types.
+///
+/// The quotient `q` and remainder `r` of a dividend `a` and a divisor `b` are committed to, and
+/// the proof establishes that `a = q * b + r` over the integers, that `r` is zero or has the sign
+/// of `a`, and that `|r| < |b|`, which also rules out `b = 0`. The prover rejects a zero divisor
+/// with [`ProofError::DivisionByZero`]. A quotient that does not fit in the result type (e.g.
+/// `i64::MIN / -1`) cannot be verified.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DivideExpr {
+    lhs: Box<DynProofExpr>,
+    rhs: Box<DynProofExpr>,
+    /// Shift every committed quotient by this amount, adjusting the remainders so that the
+    /// division identity still holds, to test the range checks against a dishonest prover
+    #[cfg(test)]
+    pub(crate) quotient_shift: i128,
+}
+
+impl DivideExpr {
+    /// Create integer `/` expression
+    pub fn new(lhs: Box<DynProofExpr>, rhs: Box<DynProofExpr>) -> Self {
+        Self {
+            lhs,
+            rhs,
+            #[cfg(test)]
+            quotient_shift: 0,
+        }
+    }
+}
+
+impl ProofExpr for DivideExpr {
+    #[allow(
+        clippy::missing_panics_doc,
+        reason = "the operands are checked to be integers on construction"
+    )]
+    fn data_type(&self) -> ColumnType {
+        self.lhs
+            .data_type()
+            .max_integer_type(&self.rhs.data_type())
+            .expect("both operands are integers")
+    }
+
+    fn result_evaluate<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        let lhs_column: Column<'a, S> = self.lhs.result_evaluate(alloc, table);
+        let rhs_column: Column<'a, S> = self.rhs.result_evaluate(alloc, table);
+        let (quotients, _) = truncated_division(alloc, &lhs_column, &rhs_column, self.data_type());
+        integer_column(alloc, quotients, self.data_type())
+    }
+
+    #[tracing::instrument(
+        name = "proofs.sql.ast.divide_expr.prover_evaluate",
+        level = "info",
+        skip_all
+    )]
+    fn prover_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        log::log_memory_usage("Start");
+
+        let lhs_column: Column<'a, S> = self.lhs.prover_evaluate(builder, alloc, table);
+        let rhs_column: Column<'a, S> = self.rhs.prover_evaluate(builder, alloc, table);
+        let (quotients, _) = prover_evaluate_truncated_division(
+            builder,
+            alloc,
+            lhs_column,
+            rhs_column,
+            self.data_type(),
+            #[cfg(test)]
+            self.quotient_shift,
+        );
+        let res = integer_column(alloc, quotients, self.data_type());
+
+        log::log_memory_usage("End");
+
+        res
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut VerificationBuilder<S>,
+        accessor: &IndexMap<ColumnRef, S>,
+        one_eval: S,
+    ) -> Result<S, ProofError> {
+        let lhs = self.lhs.verifier_evaluate(builder, accessor, one_eval)?;
+        let rhs = self.rhs.verifier_evaluate(builder, accessor, one_eval)?;
+        let (quotient, _) = verifier_evaluate_truncated_division(builder, lhs, rhs, one_eval)?;
+        Ok(quotient)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.lhs.get_column_references(columns);
+        self.rhs.get_column_references(columns);
+    }
+}
+
+impl fmt::Display for DivideExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({} / {})", self.lhs, self.rhs)
+    }
+}
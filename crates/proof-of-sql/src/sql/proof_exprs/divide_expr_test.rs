@@ -0,0 +1,228 @@
+use super::DivideExpr;
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, ColumnOperationError, OwnedTableTestAccessor},
+        proof::ProofError,
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, QueryError, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, DynProofExpr},
+        proof_plans::test_utility::*,
+    },
+};
+use rand::{
+    distributions::{Distribution, Uniform},
+    rngs::StdRng,
+};
+use rand_core::SeedableRng;
+
+// select a / b as q from sxt.t
+#[test]
+fn we_can_prove_division_truncating_toward_zero_for_every_sign_combination() {
+    let a = [
+        7_i64,
+        -7,
+        7,
+        -7,
+        6,
+        -6,
+        0,
+        0,
+        1,
+        -1,
+        i64::MAX,
+        i64::MIN,
+        i64::MIN,
+    ];
+    let b = [2_i64, 2, -2, -2, 3, -3, 5, -5, 9, 9, -1, 1, i64::MAX];
+    let data = owned_table([bigint("a", a), bigint("b", b)]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            divide(column(t, "a", &accessor), column(t, "b", &accessor)),
+            "q",
+        )],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint(
+        "q",
+        [3_i64, -3, -3, 3, 2, 2, 0, 0, 0, 0, -i64::MAX, i64::MIN, -1],
+    )]);
+    assert_eq!(res, expected_res);
+}
+
+// select id from sxt.t where id / 3 = -2
+#[test]
+fn we_can_prove_a_filter_on_a_division_by_a_literal() {
+    let data = owned_table([int("id", [-9_i32, -8, -7, -6, -5, -3, 5, 6, 7, 8])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        cols_expr_plan(t, &["id"], &accessor),
+        tab(t),
+        equal(
+            divide(column(t, "id", &accessor), const_bigint(3)),
+            const_bigint(-2),
+        ),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([int("id", [-8_i32, -7, -6])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_division_of_random_data_with_mixed_integer_types() {
+    let dividends = Uniform::new_inclusive(i8::MIN, i8::MAX);
+    let divisors = Uniform::new_inclusive(1_i32, 40);
+    let mut rng = StdRng::from_seed([0u8; 32]);
+    let a: Vec<i8> = dividends.sample_iter(&mut rng).take(40).collect();
+    let b: Vec<i32> = divisors
+        .sample_iter(&mut rng)
+        .take(40)
+        .enumerate()
+        .map(|(i, b)| if i % 2 == 0 { b } else { -b })
+        .collect();
+    let data = owned_table([tinyint("a", a.clone()), int("b", b.clone())]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            divide(column(t, "a", &accessor), column(t, "b", &accessor)),
+            "q",
+        )],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([int(
+        "q",
+        a.iter()
+            .zip(&b)
+            .map(|(&a, &b)| i32::from(a) / b)
+            .collect::<Vec<_>>(),
+    )]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_prove_division_by_a_column_containing_zero() {
+    let data = owned_table([bigint("a", [7_i64, 8, 9]), bigint("b", [2_i64, 0, 4])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            divide(column(t, "a", &accessor), column(t, "b", &accessor)),
+            "q",
+        )],
+        tab(t),
+    );
+    assert!(matches!(
+        VerifiableQueryResult::<InnerProductProof>::try_new_with_cancellation(
+            &ast,
+            &accessor,
+            &(),
+            &[],
+            &|| true
+        ),
+        Err(ProofError::DivisionByZero)
+    ));
+}
+
+#[test]
+#[should_panic(expected = "proving failed: Division by zero")]
+fn we_cannot_create_a_verifiable_result_for_division_by_a_column_containing_zero() {
+    let data = owned_table([bigint("a", [7_i64]), bigint("b", [0_i64])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            divide(column(t, "a", &accessor), column(t, "b", &accessor)),
+            "q",
+        )],
+        tab(t),
+    );
+    VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+}
+
+#[test]
+fn we_cannot_verify_a_division_that_overflows() {
+    let data = owned_table([bigint("a", [i64::MIN]), bigint("b", [-1_i64])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            divide(column(t, "a", &accessor), column(t, "b", &accessor)),
+            "q",
+        )],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    assert!(matches!(
+        verifiable_res.verify(&ast, &accessor, &()),
+        Err(QueryError::ProofError { .. })
+    ));
+}
+
+// select a / b as q from sxt.t, with a prover that claims 7 / -2^62 = 2^62 and a remainder of
+// 7 + 2^124, which satisfies a = q * b + r but is far from smaller than the divisor
+#[test]
+fn we_cannot_verify_a_division_with_a_wrapped_remainder_from_a_dishonest_prover() {
+    let data = owned_table([bigint("a", [7_i64]), bigint("b", [-(1_i64 << 62)])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let mut dishonest_divide = DivideExpr::new(
+        Box::new(column(t, "a", &accessor)),
+        Box::new(column(t, "b", &accessor)),
+    );
+    dishonest_divide.quotient_shift = 1 << 62;
+    let ast = projection(
+        vec![aliased_plan(DynProofExpr::Divide(dishonest_divide), "q")],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    assert!(matches!(
+        verifiable_res.verify(&ast, &accessor, &()),
+        Err(QueryError::ProofError {
+            source: ProofError::VerificationError {
+                error: "division remainder is out of range"
+            }
+        })
+    ));
+}
+
+#[test]
+fn we_cannot_create_division_by_a_zero_literal_or_of_non_integer_types() {
+    let data = owned_table([
+        bigint("a", [1_i64, 2]),
+        int128("b", [1_i128, 2]),
+        varchar("c", ["a", "b"]),
+        decimal75("d", 10, 2, [1, 2]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    assert!(matches!(
+        DynProofExpr::try_new_divide(column(t, "a", &accessor), const_bigint(0)),
+        Err(ConversionError::ColumnOperationError {
+            source: ColumnOperationError::DivisionByZero
+        })
+    ));
+    for name in ["b", "c", "d"] {
+        assert!(matches!(
+            DynProofExpr::try_new_divide(column(t, "a", &accessor), column(t, name, &accessor)),
+            Err(ConversionError::DataTypeMismatch { .. })
+        ));
+        assert!(matches!(
+            DynProofExpr::try_new_divide(column(t, name, &accessor), column(t, "a", &accessor)),
+            Err(ConversionError::DataTypeMismatch { .. })
+        ));
+    }
+}
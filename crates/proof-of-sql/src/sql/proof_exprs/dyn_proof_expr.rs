@@ -1,23 +1,28 @@
 use super::{
-    AddSubtractExpr, AggregateExpr, AndExpr, ColumnExpr, EqualsExpr, InequalityExpr, LiteralExpr,
-    MultiplyExpr, NotExpr, OrExpr, ProofExpr,
+    AbsExpr, AddSubtractExpr, AggregateExpr, AndExpr, AtTimeZoneExpr, CaseExpr, CastExpr,
+    ColumnExpr, DateTruncExpr, DateTruncGranularity, DivideExpr, EqualsExpr, ExtractExpr,
+    ExtractField, InequalityExpr, LiteralExpr, ModuloExpr, MultiplyExpr, NotExpr, OrExpr,
+    ProofExpr, WidthBucketExpr, MAX_BUCKET_COUNT,
 };
 use crate::{
     base::{
-        database::{Column, ColumnRef, ColumnType, LiteralValue, Table},
+        database::{Column, ColumnOperationError, ColumnRef, ColumnType, LiteralValue, Table},
         map::{IndexMap, IndexSet},
         proof::ProofError,
         scalar::Scalar,
     },
     sql::{
-        parse::{type_check_binary_operation, ConversionError, ConversionResult},
+        parse::{
+            type_check_binary_operation, type_check_integer_division, ConversionError,
+            ConversionResult,
+        },
         proof::{FinalRoundBuilder, VerificationBuilder},
     },
 };
-use alloc::{boxed::Box, string::ToString};
+use alloc::{boxed::Box, format, string::ToString, vec, vec::Vec};
 use bumpalo::Bump;
-use core::fmt::Debug;
-use proof_of_sql_parser::intermediate_ast::AggregationOperator;
+use core::fmt::{self, Debug};
+use proof_of_sql_parser::{intermediate_ast::AggregationOperator, posql_time::PoSQLTimeZone};
 use serde::{Deserialize, Serialize};
 use sqlparser::ast::BinaryOperator;
 
@@ -45,6 +50,24 @@ pub enum DynProofExpr {
     Multiply(MultiplyExpr),
     /// Provable aggregate expression
     Aggregate(AggregateExpr),
+    /// Provable `WIDTH_BUCKET` expression
+    WidthBucket(WidthBucketExpr),
+    /// Provable `AT TIME ZONE` expression
+    AtTimeZone(AtTimeZoneExpr),
+    /// Provable `DATE_TRUNC` expression
+    DateTrunc(DateTruncExpr),
+    /// Provable `EXTRACT` expression
+    Extract(ExtractExpr),
+    /// Provable searched `CASE` expression
+    Case(CaseExpr),
+    /// Provable `ABS` expression
+    Abs(AbsExpr),
+    /// Provable lossless `CAST` expression
+    Cast(CastExpr),
+    /// Provable integer `%` expression
+    Modulo(ModuloExpr),
+    /// Provable integer division expression
+    Divide(DivideExpr),
 }
 impl DynProofExpr {
     /// Create column expression
@@ -160,11 +183,243 @@ impl DynProofExpr {
         }
     }
 
+    /// Create a new modulo expression
+    ///
+    /// Both sides must be integers no wider than `BIGINT`, and the divisor cannot be a literal
+    /// zero.
+    pub fn try_new_modulo(lhs: DynProofExpr, rhs: DynProofExpr) -> ConversionResult<Self> {
+        let lhs_datatype = lhs.data_type();
+        let rhs_datatype = rhs.data_type();
+        if !type_check_binary_operation(lhs_datatype, rhs_datatype, &BinaryOperator::Modulo) {
+            return Err(ConversionError::DataTypeMismatch {
+                left_type: lhs_datatype.to_string(),
+                right_type: rhs_datatype.to_string(),
+            });
+        }
+        if let Self::Literal(LiteralExpr {
+            value:
+                LiteralValue::TinyInt(0)
+                | LiteralValue::SmallInt(0)
+                | LiteralValue::Int(0)
+                | LiteralValue::BigInt(0),
+        }) = rhs
+        {
+            return Err(ColumnOperationError::DivisionByZero.into());
+        }
+        Ok(Self::Modulo(ModuloExpr::new(Box::new(lhs), Box::new(rhs))))
+    }
+
+    /// Create a new integer division expression
+    ///
+    /// Both sides must be integers no wider than `BIGINT`, and the divisor cannot be a literal
+    /// zero.
+    pub fn try_new_divide(lhs: DynProofExpr, rhs: DynProofExpr) -> ConversionResult<Self> {
+        let lhs_datatype = lhs.data_type();
+        let rhs_datatype = rhs.data_type();
+        if !type_check_integer_division(lhs_datatype, rhs_datatype) {
+            return Err(ConversionError::DataTypeMismatch {
+                left_type: lhs_datatype.to_string(),
+                right_type: rhs_datatype.to_string(),
+            });
+        }
+        if let Self::Literal(LiteralExpr {
+            value:
+                LiteralValue::TinyInt(0)
+                | LiteralValue::SmallInt(0)
+                | LiteralValue::Int(0)
+                | LiteralValue::BigInt(0),
+        }) = rhs
+        {
+            return Err(ColumnOperationError::DivisionByZero.into());
+        }
+        Ok(Self::Divide(DivideExpr::new(Box::new(lhs), Box::new(rhs))))
+    }
+
     /// Create a new aggregate expression
     pub fn new_aggregate(op: AggregationOperator, expr: DynProofExpr) -> Self {
         Self::Aggregate(AggregateExpr::new(op, Box::new(expr)))
     }
 
+    /// Create a new `WIDTH_BUCKET` expression
+    ///
+    /// The expression must be an integer no wider than `BIGINT` or a timestamp, `low` must be less
+    /// than `high` and there must be at least one and at most 1024 buckets. The bounds of a
+    /// timestamp are given in its time unit.
+    pub fn try_new_width_bucket(
+        expr: DynProofExpr,
+        low: i64,
+        high: i64,
+        bucket_count: u32,
+    ) -> ConversionResult<Self> {
+        let datatype = expr.data_type();
+        if !matches!(
+            datatype,
+            ColumnType::TinyInt
+                | ColumnType::SmallInt
+                | ColumnType::Int
+                | ColumnType::BigInt
+                | ColumnType::TimestampTZ(_, _)
+        ) {
+            return Err(ConversionError::InvalidExpression {
+                expression: format!("width_bucket is not supported for type {datatype}"),
+            });
+        }
+        if low >= high || bucket_count == 0 {
+            return Err(ConversionError::InvalidExpression {
+                expression: format!(
+                    "width_bucket requires low < high and a positive bucket count, got low = {low}, high = {high}, bucket_count = {bucket_count}"
+                ),
+            });
+        }
+        if bucket_count > MAX_BUCKET_COUNT {
+            return Err(ConversionError::InvalidExpression {
+                expression: format!(
+                    "width_bucket supports at most {MAX_BUCKET_COUNT} buckets, got {bucket_count}"
+                ),
+            });
+        }
+        Ok(Self::WidthBucket(WidthBucketExpr::new(
+            Box::new(expr),
+            low,
+            high,
+            bucket_count,
+        )))
+    }
+
+    /// Create a new `AT TIME ZONE` expression
+    ///
+    /// The expression must be a timestamp.
+    pub fn try_new_at_time_zone(
+        expr: DynProofExpr,
+        time_zone: PoSQLTimeZone,
+    ) -> ConversionResult<Self> {
+        let datatype = expr.data_type();
+        if !matches!(datatype, ColumnType::TimestampTZ(_, _)) {
+            return Err(ConversionError::InvalidExpression {
+                expression: format!("AT TIME ZONE is not supported for type {datatype}"),
+            });
+        }
+        Ok(Self::AtTimeZone(AtTimeZoneExpr::new(
+            Box::new(expr),
+            time_zone,
+        )))
+    }
+
+    /// Create a new `DATE_TRUNC` expression
+    ///
+    /// The expression must be a timestamp. The granularity can be parsed from its SQL name, which
+    /// rejects unsupported granularities.
+    pub fn try_new_date_trunc(
+        granularity: DateTruncGranularity,
+        expr: DynProofExpr,
+    ) -> ConversionResult<Self> {
+        let datatype = expr.data_type();
+        if !matches!(datatype, ColumnType::TimestampTZ(_, _)) {
+            return Err(ConversionError::InvalidExpression {
+                expression: format!("date_trunc is not supported for type {datatype}"),
+            });
+        }
+        Ok(Self::DateTrunc(DateTruncExpr::new(
+            granularity,
+            Box::new(expr),
+        )))
+    }
+
+    /// Create a new `EXTRACT` expression
+    ///
+    /// The expression must be a timestamp. The field can be parsed from its SQL name, which
+    /// rejects unsupported fields.
+    pub fn try_new_extract(field: ExtractField, expr: DynProofExpr) -> ConversionResult<Self> {
+        let datatype = expr.data_type();
+        if !matches!(datatype, ColumnType::TimestampTZ(_, _)) {
+            return Err(ConversionError::InvalidExpression {
+                expression: format!("extract is not supported for type {datatype}"),
+            });
+        }
+        Ok(Self::Extract(ExtractExpr::new(field, Box::new(expr))))
+    }
+
+    /// Create a new `ABS` expression
+    ///
+    /// The expression must be a signed integer or a decimal.
+    pub fn try_new_abs(expr: DynProofExpr) -> ConversionResult<Self> {
+        let datatype = expr.data_type();
+        if !matches!(
+            datatype,
+            ColumnType::TinyInt
+                | ColumnType::SmallInt
+                | ColumnType::Int
+                | ColumnType::BigInt
+                | ColumnType::Int128
+                | ColumnType::Decimal75(_, _)
+        ) {
+            return Err(ConversionError::InvalidExpression {
+                expression: format!("ABS is not supported for type {datatype}"),
+            });
+        }
+        Ok(Self::Abs(AbsExpr::new(Box::new(expr))))
+    }
+
+    /// Create a new `CAST(expr AS to_type)` expression
+    ///
+    /// Only lossless casts are supported: the expression must be an integer, signed or `UINT64`,
+    /// and `to_type` must be `INT128` or a `DECIMAL` with a scale of 0 and enough digits for every
+    /// value of the expression's type, e.g. `DECIMAL(20, 0)` for a `UINT64`.
+    pub fn try_new_cast(expr: DynProofExpr, to_type: ColumnType) -> ConversionResult<Self> {
+        let datatype = expr.data_type();
+        if !CastExpr::is_lossless(datatype, to_type) {
+            return Err(ConversionError::InvalidExpression {
+                expression: format!("CAST from {datatype} to {to_type} is not supported"),
+            });
+        }
+        Ok(Self::Cast(CastExpr::new(Box::new(expr), to_type)))
+    }
+
+    /// Create a new searched `CASE WHEN ... THEN ... ELSE ... END` expression
+    ///
+    /// There must be at least one `WHEN` branch, every condition must be boolean, and every `THEN`
+    /// value must have the type of the `ELSE` value. The `ELSE` value is required, since there are
+    /// no `NULL` values to default to.
+    pub fn try_new_case(
+        when_then: Vec<(DynProofExpr, DynProofExpr)>,
+        else_expr: DynProofExpr,
+    ) -> ConversionResult<Self> {
+        if when_then.is_empty() {
+            return Err(ConversionError::InvalidExpression {
+                expression: "CASE requires at least one WHEN branch".to_string(),
+            });
+        }
+        let datatype = else_expr.data_type();
+        for (when, then) in &when_then {
+            when.check_data_type(ColumnType::Boolean)?;
+            let then_datatype = then.data_type();
+            if then_datatype != datatype {
+                return Err(ConversionError::DataTypeMismatch {
+                    left_type: then_datatype.to_string(),
+                    right_type: datatype.to_string(),
+                });
+            }
+        }
+        Ok(Self::Case(CaseExpr::new(when_then, Box::new(else_expr))))
+    }
+
+    /// Split the argument of an aggregate into the factors of its top level product
+    ///
+    /// Nested products are flattened, so `SUM(a * (b * c))` yields `[a, b, c]`. Any other
+    /// expression is its own single factor. This allows sums of products to be proven without
+    /// materializing the product column.
+    pub(crate) fn product_factors(&self) -> Vec<&DynProofExpr> {
+        match self {
+            Self::Aggregate(aggregate_expr) => aggregate_expr.expr.product_factors(),
+            Self::Multiply(multiply_expr) => {
+                let mut factors = multiply_expr.lhs.product_factors();
+                factors.extend(multiply_expr.rhs.product_factors());
+                factors
+            }
+            _ => vec![self],
+        }
+    }
+
     /// Check that the plan has the correct data type
     fn check_data_type(&self, data_type: ColumnType) -> ConversionResult<()> {
         if self.data_type() == data_type {
@@ -177,3 +432,29 @@ impl DynProofExpr {
         }
     }
 }
+
+impl fmt::Display for DynProofExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DynProofExpr::Column(expr) => write!(f, "{expr}"),
+            DynProofExpr::And(expr) => write!(f, "{expr}"),
+            DynProofExpr::Or(expr) => write!(f, "{expr}"),
+            DynProofExpr::Not(expr) => write!(f, "{expr}"),
+            DynProofExpr::Literal(expr) => write!(f, "{expr}"),
+            DynProofExpr::Equals(expr) => write!(f, "{expr}"),
+            DynProofExpr::Inequality(expr) => write!(f, "{expr}"),
+            DynProofExpr::AddSubtract(expr) => write!(f, "{expr}"),
+            DynProofExpr::Multiply(expr) => write!(f, "{expr}"),
+            DynProofExpr::Modulo(expr) => write!(f, "{expr}"),
+            DynProofExpr::Divide(expr) => write!(f, "{expr}"),
+            DynProofExpr::Aggregate(expr) => write!(f, "{expr}"),
+            DynProofExpr::WidthBucket(expr) => write!(f, "{expr}"),
+            DynProofExpr::AtTimeZone(expr) => write!(f, "{expr}"),
+            DynProofExpr::DateTrunc(expr) => write!(f, "{expr}"),
+            DynProofExpr::Extract(expr) => write!(f, "{expr}"),
+            DynProofExpr::Case(expr) => write!(f, "{expr}"),
+            DynProofExpr::Abs(expr) => write!(f, "{expr}"),
+            DynProofExpr::Cast(expr) => write!(f, "{expr}"),
+        }
+    }
+}
@@ -12,6 +12,7 @@ use crate::{
 };
 use alloc::{boxed::Box, vec};
 use bumpalo::Bump;
+use core::fmt;
 use serde::{Deserialize, Serialize};
 
 /// Provable AST expression for an equals expression
@@ -181,3 +182,9 @@ pub fn verifier_evaluate_equals_zero<S: Scalar>(
 
     Ok(selection_eval)
 }
+
+impl fmt::Display for EqualsExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({} = {})", self.lhs, self.rhs)
+    }
+}
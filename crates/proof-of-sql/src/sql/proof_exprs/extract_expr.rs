@@ -0,0 +1,316 @@
+use super::{
+    date_trunc_expr::{
+        month_start, prover_evaluate_month_truncation, timestamps,
+        verifier_evaluate_month_truncation, SECONDS_PER_DAY, SECONDS_PER_HOUR, SECONDS_PER_MINUTE,
+    },
+    DateTruncGranularity, DynProofExpr, ProofExpr,
+};
+use crate::{
+    base::{
+        database::{Column, ColumnRef, ColumnType, Table},
+        map::{IndexMap, IndexSet},
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{FinalRoundBuilder, VerificationBuilder},
+        proof_gadgets::{prover_evaluate_floor_division, verifier_evaluate_floor_division},
+    },
+    utils::log,
+};
+use alloc::{boxed::Box, format, vec::Vec};
+use bumpalo::Bump;
+use core::{fmt, str::FromStr};
+use serde::{Deserialize, Serialize};
+
+/// The field of a timestamp that `EXTRACT` returns
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExtractField {
+    /// The year
+    Year,
+    /// The month of the year, from 1 to 12
+    Month,
+    /// The day of the month, from 1 to 31
+    Day,
+    /// The hour of the day, from 0 to 23
+    Hour,
+    /// The minute of the hour, from 0 to 59
+    Minute,
+    /// The second of the minute, from 0 to 59, without its fractional part
+    Second,
+}
+
+impl FromStr for ExtractField {
+    type Err = ConversionError;
+
+    fn from_str(field: &str) -> Result<Self, Self::Err> {
+        match field.to_lowercase().as_str() {
+            "year" => Ok(Self::Year),
+            "month" => Ok(Self::Month),
+            "day" => Ok(Self::Day),
+            "hour" => Ok(Self::Hour),
+            "minute" => Ok(Self::Minute),
+            "second" => Ok(Self::Second),
+            _ => Err(ConversionError::InvalidExpression {
+                expression: format!("extract does not support the field '{field}'"),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for ExtractField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Year => "year",
+            Self::Month => "month",
+            Self::Day => "day",
+            Self::Hour => "hour",
+            Self::Minute => "minute",
+            Self::Second => "second",
+        })
+    }
+}
+
+/// Provable `EXTRACT(field FROM timestamp)` expression
+///
+/// Returns the year, month, day, hour, minute or second of a timestamp in UTC as a `BigInt`. To
+/// extract local fields, the timestamp can be converted with `AT TIME ZONE` first.
+///
+/// The year and the month are the committed indexes of the year or month that the timestamp is
+/// proven to lie in, as for `DATE_TRUNC`. The day of the month is one more than the number of
+/// days since the proven first instant of the month. The hour, minute and second are remainders
+/// of floor divisions of the timestamp.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExtractExpr {
+    field: ExtractField,
+    expr: Box<DynProofExpr>,
+}
+
+impl ExtractExpr {
+    /// Create a new `EXTRACT` expression
+    pub fn new(field: ExtractField, expr: Box<DynProofExpr>) -> Self {
+        Self { field, expr }
+    }
+
+    /// Convert a number of seconds to the time unit of the timestamp
+    ///
+    /// # Panics
+    /// Panics if the underlying expression is not a timestamp, which the constructor of
+    /// [`DynProofExpr::Extract`] rules out.
+    fn units_per(&self, seconds: i128) -> i128 {
+        match self.expr.data_type() {
+            ColumnType::TimestampTZ(time_unit, _) => {
+                seconds * i128::from(time_unit.units_per_second())
+            }
+            _ => panic!("EXTRACT requires a timestamp"),
+        }
+    }
+
+    /// For the hour, minute and second, the length of the field in the time unit of the
+    /// timestamp and the number of values that the field takes
+    fn period_and_modulus(&self) -> (i128, i128) {
+        match self.field {
+            ExtractField::Hour => (self.units_per(SECONDS_PER_HOUR), 24),
+            ExtractField::Minute => (self.units_per(SECONDS_PER_MINUTE), 60),
+            ExtractField::Second => (self.units_per(1), 60),
+            ExtractField::Year | ExtractField::Month | ExtractField::Day => {
+                unreachable!("years, months and days do not have a fixed length")
+            }
+        }
+    }
+
+    /// Extract the field of a timestamp
+    fn extract(&self, timestamp: i128) -> i128 {
+        let units_per_day = self.units_per(SECONDS_PER_DAY);
+        let day = timestamp.div_euclid(units_per_day);
+        match self.field {
+            ExtractField::Year => DateTruncGranularity::Year.month_index(day),
+            ExtractField::Month => {
+                (DateTruncGranularity::Month.month_index(day) + 2).rem_euclid(12) + 1
+            }
+            ExtractField::Day => {
+                day - month_start(DateTruncGranularity::Month.month_index(day)) + 1
+            }
+            ExtractField::Hour | ExtractField::Minute | ExtractField::Second => {
+                let (period, modulus) = self.period_and_modulus();
+                timestamp.div_euclid(period).rem_euclid(modulus)
+            }
+        }
+    }
+
+    /// The column of the extracted fields
+    ///
+    /// # Panics
+    /// Panics if a field does not fit in an `i64`, which cannot happen for fields of timestamps.
+    fn extracted_column<'a, S: Scalar>(alloc: &'a Bump, values: &[i128]) -> Column<'a, S> {
+        Column::BigInt(
+            alloc.alloc_slice_fill_iter(
+                values.iter().map(|&value| {
+                    i64::try_from(value).expect("fields of timestamps fit in an i64")
+                }),
+            ),
+        )
+    }
+}
+
+impl ProofExpr for ExtractExpr {
+    fn data_type(&self) -> ColumnType {
+        ColumnType::BigInt
+    }
+
+    fn result_evaluate<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        let column = self.expr.result_evaluate(alloc, table);
+        let fields: Vec<i128> = timestamps(&column)
+            .into_iter()
+            .map(|t| self.extract(t))
+            .collect();
+        Self::extracted_column(alloc, &fields)
+    }
+
+    #[tracing::instrument(name = "ExtractExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        log::log_memory_usage("Start");
+
+        let column = self.expr.prover_evaluate(builder, alloc, table);
+        let timestamps = timestamps(&column);
+        let units_per_day = self.units_per(SECONDS_PER_DAY);
+        let fields: Vec<i128> = match self.field {
+            ExtractField::Year => {
+                prover_evaluate_month_truncation(
+                    builder,
+                    alloc,
+                    DateTruncGranularity::Year,
+                    units_per_day,
+                    &timestamps,
+                )
+                .0
+            }
+            ExtractField::Month => {
+                let (months, _) = prover_evaluate_month_truncation(
+                    builder,
+                    alloc,
+                    DateTruncGranularity::Month,
+                    units_per_day,
+                    &timestamps,
+                );
+                let shifted_months: Vec<i128> = months.iter().map(|month| month + 2).collect();
+                let years = prover_evaluate_floor_division(builder, alloc, &shifted_months, 12);
+                shifted_months
+                    .iter()
+                    .zip(years.iter())
+                    .map(|(month, year)| month - 12 * year + 1)
+                    .collect()
+            }
+            ExtractField::Day => {
+                let (_, starts) = prover_evaluate_month_truncation(
+                    builder,
+                    alloc,
+                    DateTruncGranularity::Month,
+                    units_per_day,
+                    &timestamps,
+                );
+                let elapsed: Vec<i128> = timestamps
+                    .iter()
+                    .zip(&starts)
+                    .map(|(t, start)| t - start)
+                    .collect();
+                prover_evaluate_floor_division(builder, alloc, &elapsed, units_per_day)
+                    .iter()
+                    .map(|days| days + 1)
+                    .collect()
+            }
+            ExtractField::Hour | ExtractField::Minute | ExtractField::Second => {
+                let (period, modulus) = self.period_and_modulus();
+                let quotients = prover_evaluate_floor_division(builder, alloc, &timestamps, period);
+                let wraps = prover_evaluate_floor_division(builder, alloc, quotients, modulus);
+                quotients
+                    .iter()
+                    .zip(wraps.iter())
+                    .map(|(q, wrap)| q - modulus * wrap)
+                    .collect()
+            }
+        };
+        let res = Self::extracted_column(alloc, &fields);
+
+        log::log_memory_usage("End");
+
+        res
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut VerificationBuilder<S>,
+        accessor: &IndexMap<ColumnRef, S>,
+        one_eval: S,
+    ) -> Result<S, ProofError> {
+        let eval = self.expr.verifier_evaluate(builder, accessor, one_eval)?;
+        let units_per_day = self.units_per(SECONDS_PER_DAY);
+        match self.field {
+            ExtractField::Year => Ok(verifier_evaluate_month_truncation(
+                builder,
+                DateTruncGranularity::Year,
+                units_per_day,
+                eval,
+                one_eval,
+            )?
+            .0),
+            ExtractField::Month => {
+                let (month_eval, _) = verifier_evaluate_month_truncation(
+                    builder,
+                    DateTruncGranularity::Month,
+                    units_per_day,
+                    eval,
+                    one_eval,
+                )?;
+                let shifted_month_eval = month_eval + S::from(2) * one_eval;
+                let year_eval =
+                    verifier_evaluate_floor_division(builder, shifted_month_eval, 12, one_eval)?;
+                Ok(shifted_month_eval - S::from(12) * year_eval + one_eval)
+            }
+            ExtractField::Day => {
+                let (_, start_eval) = verifier_evaluate_month_truncation(
+                    builder,
+                    DateTruncGranularity::Month,
+                    units_per_day,
+                    eval,
+                    one_eval,
+                )?;
+                let days_eval = verifier_evaluate_floor_division(
+                    builder,
+                    eval - start_eval,
+                    units_per_day,
+                    one_eval,
+                )?;
+                Ok(days_eval + one_eval)
+            }
+            ExtractField::Hour | ExtractField::Minute | ExtractField::Second => {
+                let (period, modulus) = self.period_and_modulus();
+                let quotient_eval =
+                    verifier_evaluate_floor_division(builder, eval, period, one_eval)?;
+                let wrap_eval =
+                    verifier_evaluate_floor_division(builder, quotient_eval, modulus, one_eval)?;
+                Ok(quotient_eval - S::from(modulus) * wrap_eval)
+            }
+        }
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.expr.get_column_references(columns);
+    }
+}
+
+impl fmt::Display for ExtractExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "extract({} from {})", self.field, self.expr)
+    }
+}
@@ -0,0 +1,181 @@
+use super::ExtractField;
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, OwnedTableTestAccessor},
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, DynProofExpr},
+        proof_plans::test_utility::*,
+    },
+};
+use chrono::{TimeZone, Utc};
+use proof_of_sql_parser::posql_time::{PoSQLTimeUnit, PoSQLTimeZone};
+
+/// The UTC epoch of the given date and time in seconds
+fn epoch(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> i64 {
+    Utc.with_ymd_and_hms(year, month, day, hour, minute, second)
+        .unwrap()
+        .timestamp()
+}
+
+// select extract(year from t) as year, extract(month from t) as month from sxt.t
+#[test]
+fn we_can_prove_an_extraction_of_years_and_months() {
+    let data = owned_table([timestamptz(
+        "t",
+        PoSQLTimeUnit::Second,
+        PoSQLTimeZone::utc(),
+        [
+            epoch(2023, 12, 31, 23, 59, 59),
+            epoch(2024, 1, 1, 0, 0, 0),
+            epoch(2024, 2, 29, 23, 59, 59),
+            epoch(2024, 3, 1, 0, 0, 0),
+            epoch(1969, 12, 31, 23, 59, 59),
+            epoch(1970, 1, 1, 0, 0, 0),
+            epoch(1600, 6, 15, 12, 0, 0),
+        ],
+    )]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![
+            aliased_plan(extract("YEAR", column(t, "t", &accessor)), "year"),
+            aliased_plan(extract("month", column(t, "t", &accessor)), "month"),
+        ],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        bigint("year", [2023_i64, 2024, 2024, 2024, 1969, 1970, 1600]),
+        bigint("month", [12_i64, 1, 2, 3, 12, 1, 6]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+// select extract(year from t) as year from sxt.t
+#[test]
+fn we_can_prove_an_extraction_of_years_in_milliseconds_just_before_a_new_year() {
+    let millis = |seconds: i64, millis: i64| seconds * 1000 + millis;
+    let data = owned_table([timestamptz(
+        "t",
+        PoSQLTimeUnit::Millisecond,
+        PoSQLTimeZone::utc(),
+        [
+            millis(epoch(1999, 12, 31, 23, 59, 59), 999),
+            millis(epoch(2000, 1, 1, 0, 0, 0), 0),
+            millis(epoch(1969, 12, 31, 23, 59, 59), 999),
+        ],
+    )]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            extract("year", column(t, "t", &accessor)),
+            "year",
+        )],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("year", [1999_i64, 2000, 1969])]);
+    assert_eq!(res, expected_res);
+}
+
+// select extract(day from t) as day, extract(hour from t) as hour,
+// extract(minute from t) as minute, extract(second from t) as second from sxt.t
+#[test]
+fn we_can_prove_an_extraction_of_days_and_times_of_day() {
+    let micros = |seconds: i64, micros: i64| seconds * 1_000_000 + micros;
+    let data = owned_table([timestamptz(
+        "t",
+        PoSQLTimeUnit::Microsecond,
+        PoSQLTimeZone::utc(),
+        [
+            micros(epoch(2024, 2, 29, 23, 59, 59), 999_999),
+            micros(epoch(2024, 3, 1, 0, 0, 0), 0),
+            micros(epoch(2024, 6, 20, 13, 45, 30), 500_000),
+            micros(epoch(1969, 12, 31, 23, 59, 59), 999_999),
+        ],
+    )]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![
+            aliased_plan(extract("day", column(t, "t", &accessor)), "day"),
+            aliased_plan(extract("hour", column(t, "t", &accessor)), "hour"),
+            aliased_plan(extract("minute", column(t, "t", &accessor)), "minute"),
+            aliased_plan(extract("second", column(t, "t", &accessor)), "second"),
+        ],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        bigint("day", [29_i64, 1, 20, 31]),
+        bigint("hour", [23_i64, 0, 13, 23]),
+        bigint("minute", [59_i64, 0, 45, 59]),
+        bigint("second", [59_i64, 0, 30, 59]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+// select extract(year from t at time zone '+01:00') as local_year from sxt.t
+#[test]
+fn we_can_prove_an_extraction_of_local_years() {
+    let data = owned_table([timestamptz(
+        "t",
+        PoSQLTimeUnit::Second,
+        PoSQLTimeZone::utc(),
+        [
+            epoch(2023, 12, 31, 22, 59, 59),
+            epoch(2023, 12, 31, 23, 0, 0),
+        ],
+    )]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            extract(
+                "year",
+                at_time_zone(column(t, "t", &accessor), PoSQLTimeZone::new(3600)),
+            ),
+            "local_year",
+        )],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("local_year", [2023_i64, 2024])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_extract_an_unsupported_field() {
+    for field in ["week", "quarter", "epoch", ""] {
+        assert!(matches!(
+            field.parse::<ExtractField>(),
+            Err(ConversionError::InvalidExpression { .. })
+        ));
+    }
+}
+
+#[test]
+fn we_cannot_extract_from_a_non_timestamp() {
+    let data = owned_table([bigint("a", [1_i64, 2]), varchar("b", ["a", "b"])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    for name in ["a", "b"] {
+        assert!(matches!(
+            DynProofExpr::try_new_extract(ExtractField::Year, column(t, name, &accessor)),
+            Err(ConversionError::InvalidExpression { .. })
+        ));
+    }
+}
@@ -18,6 +18,7 @@ use crate::{
 };
 use alloc::boxed::Box;
 use bumpalo::Bump;
+use core::fmt;
 use serde::{Deserialize, Serialize};
 
 /// Provable AST expression for an inequality expression
@@ -155,3 +156,10 @@ impl ProofExpr for InequalityExpr {
         self.rhs.get_column_references(columns);
     }
 }
+
+impl fmt::Display for InequalityExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let op = if self.is_lte { "<=" } else { ">=" };
+        write!(f, "({} {op} {})", self.lhs, self.rhs)
+    }
+}
@@ -10,6 +10,7 @@ use crate::{
     utils::log,
 };
 use bumpalo::Bump;
+use core::fmt;
 use serde::{Deserialize, Serialize};
 
 /// Provable CONST expression
@@ -83,3 +84,9 @@ impl ProofExpr for LiteralExpr {
 
     fn get_column_references(&self, _columns: &mut IndexSet<ColumnRef>) {}
 }
+
+impl fmt::Display for LiteralExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
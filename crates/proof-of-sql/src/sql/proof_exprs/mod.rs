@@ -20,6 +20,16 @@ use multiply_expr::MultiplyExpr;
 #[cfg(all(test, feature = "blitzar"))]
 mod multiply_expr_test;
 
+mod modulo_expr;
+use modulo_expr::ModuloExpr;
+#[cfg(all(test, feature = "blitzar"))]
+mod modulo_expr_test;
+
+mod divide_expr;
+use divide_expr::DivideExpr;
+#[cfg(all(test, feature = "blitzar"))]
+mod divide_expr_test;
+
 mod dyn_proof_expr;
 pub(crate) use dyn_proof_expr::DynProofExpr;
 
@@ -64,6 +74,43 @@ use equals_expr::{
 #[cfg(all(test, feature = "blitzar"))]
 mod equals_expr_test;
 
+mod width_bucket_expr;
+use width_bucket_expr::{WidthBucketExpr, MAX_BUCKET_COUNT};
+#[cfg(all(test, feature = "blitzar"))]
+mod width_bucket_expr_test;
+
+mod at_time_zone_expr;
+use at_time_zone_expr::AtTimeZoneExpr;
+#[cfg(all(test, feature = "blitzar"))]
+mod at_time_zone_expr_test;
+
+mod date_trunc_expr;
+use date_trunc_expr::DateTruncExpr;
+pub(crate) use date_trunc_expr::DateTruncGranularity;
+#[cfg(all(test, feature = "blitzar"))]
+mod date_trunc_expr_test;
+
+mod extract_expr;
+use extract_expr::ExtractExpr;
+pub(crate) use extract_expr::ExtractField;
+#[cfg(all(test, feature = "blitzar"))]
+mod extract_expr_test;
+
+mod abs_expr;
+use abs_expr::AbsExpr;
+#[cfg(all(test, feature = "blitzar"))]
+mod abs_expr_test;
+
+mod case_expr;
+use case_expr::CaseExpr;
+#[cfg(all(test, feature = "blitzar"))]
+mod case_expr_test;
+
+mod cast_expr;
+use cast_expr::CastExpr;
+#[cfg(all(test, feature = "blitzar"))]
+mod cast_expr_test;
+
 mod table_expr;
 pub(crate) use table_expr::TableExpr;
 
@@ -0,0 +1,146 @@
+use super::{DynProofExpr, ProofExpr};
+use crate::{
+    base::{
+        database::{Column, ColumnRef, ColumnType, Table},
+        map::{IndexMap, IndexSet},
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::{
+        proof::{FinalRoundBuilder, VerificationBuilder},
+        proof_gadgets::{
+            prover_evaluate_truncated_division, truncated_division,
+            verifier_evaluate_truncated_division,
+        },
+    },
+    utils::log,
+};
+use alloc::boxed::Box;
+use bumpalo::Bump;
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+/// Provable integer `%` expression
+///
+/// The remainder has the sign of the dividend, as with Rust's `%` on integers and matching the
+/// truncating `/`, so e.g. `-7 % 3 = -1` and `7 % -3 = 1`. The result has the type of the divisor,
+/// which always fits the remainder.
+///
+/// The quotient `q` and remainder `r` of a dividend `a` and a divisor `b` are committed to, and
+/// the proof establishes that `a = q * b + r` over the integers, that `r` is zero or has the sign
+/// of `a`, and that `|r| < |b|`, which also rules out `b = 0`. The prover rejects a zero divisor
+/// with [`ProofError::DivisionByZero`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModuloExpr {
+    lhs: Box<DynProofExpr>,
+    rhs: Box<DynProofExpr>,
+}
+
+/// Build a column of type `column_type` from values that fit in it
+///
+/// # Panics
+/// Panics if `column_type` is not an integer type no wider than `BIGINT`, or if a value does not
+/// fit in it.
+pub(super) fn integer_column<'a, S: Scalar>(
+    alloc: &'a Bump,
+    values: &[i128],
+    column_type: ColumnType,
+) -> Column<'a, S> {
+    let fits = "the values fit in the column type";
+    match column_type {
+        ColumnType::TinyInt => Column::TinyInt(
+            alloc.alloc_slice_fill_iter(values.iter().map(|&v| i8::try_from(v).expect(fits))),
+        ),
+        ColumnType::SmallInt => Column::SmallInt(
+            alloc.alloc_slice_fill_iter(values.iter().map(|&v| i16::try_from(v).expect(fits))),
+        ),
+        ColumnType::Int => Column::Int(
+            alloc.alloc_slice_fill_iter(values.iter().map(|&v| i32::try_from(v).expect(fits))),
+        ),
+        ColumnType::BigInt => Column::BigInt(
+            alloc.alloc_slice_fill_iter(values.iter().map(|&v| i64::try_from(v).expect(fits))),
+        ),
+        _ => panic!("only integer columns no wider than BIGINT are supported"),
+    }
+}
+
+impl ModuloExpr {
+    /// Create integer `%` expression
+    pub fn new(lhs: Box<DynProofExpr>, rhs: Box<DynProofExpr>) -> Self {
+        Self { lhs, rhs }
+    }
+}
+
+impl ProofExpr for ModuloExpr {
+    fn data_type(&self) -> ColumnType {
+        self.rhs.data_type()
+    }
+
+    fn result_evaluate<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        let lhs_column: Column<'a, S> = self.lhs.result_evaluate(alloc, table);
+        let rhs_column: Column<'a, S> = self.rhs.result_evaluate(alloc, table);
+        let (_, remainders) =
+            truncated_division(alloc, &lhs_column, &rhs_column, ColumnType::Int128);
+        integer_column(alloc, remainders, self.data_type())
+    }
+
+    #[tracing::instrument(
+        name = "proofs.sql.ast.modulo_expr.prover_evaluate",
+        level = "info",
+        skip_all
+    )]
+    fn prover_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        log::log_memory_usage("Start");
+
+        let lhs_column: Column<'a, S> = self.lhs.prover_evaluate(builder, alloc, table);
+        let rhs_column: Column<'a, S> = self.rhs.prover_evaluate(builder, alloc, table);
+        // Every quotient of integers no wider than `BIGINT` fits in an `INT128`, so e.g.
+        // `i64::MIN % -1 = 0` can be proven.
+        let (_, remainders) = prover_evaluate_truncated_division(
+            builder,
+            alloc,
+            lhs_column,
+            rhs_column,
+            ColumnType::Int128,
+            #[cfg(test)]
+            0,
+        );
+        let res = integer_column(alloc, remainders, self.data_type());
+
+        log::log_memory_usage("End");
+
+        res
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut VerificationBuilder<S>,
+        accessor: &IndexMap<ColumnRef, S>,
+        one_eval: S,
+    ) -> Result<S, ProofError> {
+        let lhs = self.lhs.verifier_evaluate(builder, accessor, one_eval)?;
+        let rhs = self.rhs.verifier_evaluate(builder, accessor, one_eval)?;
+        let (_, remainder) = verifier_evaluate_truncated_division(builder, lhs, rhs, one_eval)?;
+        Ok(remainder)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.lhs.get_column_references(columns);
+        self.rhs.get_column_references(columns);
+    }
+}
+
+impl fmt::Display for ModuloExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({} % {})", self.lhs, self.rhs)
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, ColumnOperationError, OwnedTableTestAccessor},
+        proof::ProofError,
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, DynProofExpr},
+        proof_plans::test_utility::*,
+    },
+};
+use rand::{
+    distributions::{Distribution, Uniform},
+    rngs::StdRng,
+};
+use rand_core::SeedableRng;
+
+// select a % b as r from sxt.t
+#[test]
+fn we_can_prove_modulo_with_the_sign_of_the_dividend() {
+    let data = owned_table([
+        bigint("a", [-7_i64, 7, -7, 7, 0, -1, -12, i64::MIN, i64::MAX]),
+        bigint("b", [3_i64, 3, -3, -3, 5, 2, 4, -1, -2]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            modulo(column(t, "a", &accessor), column(t, "b", &accessor)),
+            "r",
+        )],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("r", [-1_i64, 1, -1, 1, 0, -1, 0, 0, 1])]);
+    assert_eq!(res, expected_res);
+}
+
+// select id from sxt.t where id % 10 = 0
+#[test]
+fn we_can_prove_a_filter_on_a_modulo_of_mixed_integer_types() {
+    let data = owned_table([int("id", [-20_i32, -15, -10, 0, 5, 10, 25, 30])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        cols_expr_plan(t, &["id"], &accessor),
+        tab(t),
+        equal(
+            modulo(column(t, "id", &accessor), const_bigint(10)),
+            const_bigint(0),
+        ),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([int("id", [-20_i32, -10, 0, 10, 30])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_modulo_of_random_data() {
+    let dividends = Uniform::new(-300_i64, 300);
+    let divisors = Uniform::new_inclusive(1_i8, 20);
+    let mut rng = StdRng::from_seed([0u8; 32]);
+    let a: Vec<i64> = dividends.sample_iter(&mut rng).take(40).collect();
+    let b: Vec<i8> = divisors
+        .sample_iter(&mut rng)
+        .take(40)
+        .enumerate()
+        .map(|(i, b)| if i % 2 == 0 { b } else { -b })
+        .collect();
+    let data = owned_table([bigint("a", a.clone()), tinyint("b", b.clone())]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            modulo(column(t, "a", &accessor), column(t, "b", &accessor)),
+            "r",
+        )],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([tinyint(
+        "r",
+        a.iter()
+            .zip(&b)
+            .map(|(&a, &b)| i8::try_from(a % i64::from(b)).unwrap())
+            .collect::<Vec<_>>(),
+    )]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_prove_modulo_by_a_column_containing_zero() {
+    let data = owned_table([bigint("a", [7_i64, 8, 9]), bigint("b", [2_i64, 0, 4])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            modulo(column(t, "a", &accessor), column(t, "b", &accessor)),
+            "r",
+        )],
+        tab(t),
+    );
+    assert!(matches!(
+        VerifiableQueryResult::<InnerProductProof>::try_new_with_cancellation(
+            &ast,
+            &accessor,
+            &(),
+            &[],
+            &|| true
+        ),
+        Err(ProofError::DivisionByZero)
+    ));
+}
+
+#[test]
+fn we_cannot_create_modulo_by_a_zero_literal_or_of_non_integer_types() {
+    let data = owned_table([
+        bigint("a", [1_i64, 2]),
+        int128("b", [1_i128, 2]),
+        varchar("c", ["a", "b"]),
+        decimal75("d", 10, 2, [1, 2]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    assert!(matches!(
+        DynProofExpr::try_new_modulo(column(t, "a", &accessor), const_bigint(0)),
+        Err(ConversionError::ColumnOperationError {
+            source: ColumnOperationError::DivisionByZero
+        })
+    ));
+    for name in ["b", "c", "d"] {
+        assert!(matches!(
+            DynProofExpr::try_new_modulo(column(t, "a", &accessor), column(t, name, &accessor)),
+            Err(ConversionError::DataTypeMismatch { .. })
+        ));
+        assert!(matches!(
+            DynProofExpr::try_new_modulo(column(t, name, &accessor), column(t, "a", &accessor)),
+            Err(ConversionError::DataTypeMismatch { .. })
+        ));
+    }
+}
@@ -14,13 +14,14 @@ use crate::{
 };
 use alloc::{boxed::Box, vec};
 use bumpalo::Bump;
+use core::fmt;
 use serde::{Deserialize, Serialize};
 
 /// Provable numerical * expression
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MultiplyExpr {
-    lhs: Box<DynProofExpr>,
-    rhs: Box<DynProofExpr>,
+    pub(crate) lhs: Box<DynProofExpr>,
+    pub(crate) rhs: Box<DynProofExpr>,
 }
 
 impl MultiplyExpr {
@@ -110,3 +111,9 @@ impl ProofExpr for MultiplyExpr {
         self.rhs.get_column_references(columns);
     }
 }
+
+impl fmt::Display for MultiplyExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({} * {})", self.lhs, self.rhs)
+    }
+}
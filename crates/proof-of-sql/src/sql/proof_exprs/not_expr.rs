@@ -11,6 +11,7 @@ use crate::{
 };
 use alloc::boxed::Box;
 use bumpalo::Bump;
+use core::fmt;
 use serde::{Deserialize, Serialize};
 
 /// Provable logical NOT expression
@@ -80,3 +81,9 @@ impl ProofExpr for NotExpr {
         self.expr.get_column_references(columns);
     }
 }
+
+impl fmt::Display for NotExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(not {})", self.expr)
+    }
+}
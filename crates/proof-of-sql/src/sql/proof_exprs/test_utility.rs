@@ -1,10 +1,10 @@
 use super::{AliasedDynProofExpr, ColumnExpr, DynProofExpr, TableExpr};
 use crate::base::{
-    database::{ColumnRef, LiteralValue, SchemaAccessor, TableRef},
+    database::{ColumnRef, ColumnType, LiteralValue, SchemaAccessor, TableRef},
     math::{decimal::Precision, i256::I256},
     scalar::Scalar,
 };
-use proof_of_sql_parser::intermediate_ast::AggregationOperator;
+use proof_of_sql_parser::{intermediate_ast::AggregationOperator, posql_time::PoSQLTimeZone};
 use sqlparser::ast::Ident;
 
 pub fn col_ref(tab: TableRef, name: &str, accessor: &impl SchemaAccessor) -> ColumnRef {
@@ -85,6 +85,71 @@ pub fn multiply(left: DynProofExpr, right: DynProofExpr) -> DynProofExpr {
     DynProofExpr::try_new_multiply(left, right).unwrap()
 }
 
+/// # Panics
+/// Panics if:
+/// - `DynProofExpr::try_new_modulo()` returns an error.
+pub fn modulo(left: DynProofExpr, right: DynProofExpr) -> DynProofExpr {
+    DynProofExpr::try_new_modulo(left, right).unwrap()
+}
+
+/// # Panics
+/// Panics if:
+/// - `DynProofExpr::try_new_divide()` returns an error.
+pub fn divide(left: DynProofExpr, right: DynProofExpr) -> DynProofExpr {
+    DynProofExpr::try_new_divide(left, right).unwrap()
+}
+
+/// # Panics
+/// Panics if:
+/// - `DynProofExpr::try_new_width_bucket()` returns an error.
+pub fn width_bucket(expr: DynProofExpr, low: i64, high: i64, bucket_count: u32) -> DynProofExpr {
+    DynProofExpr::try_new_width_bucket(expr, low, high, bucket_count).unwrap()
+}
+
+/// # Panics
+/// Panics if:
+/// - `DynProofExpr::try_new_at_time_zone()` returns an error.
+pub fn at_time_zone(expr: DynProofExpr, time_zone: PoSQLTimeZone) -> DynProofExpr {
+    DynProofExpr::try_new_at_time_zone(expr, time_zone).unwrap()
+}
+
+/// # Panics
+/// Panics if:
+/// - `granularity` is not a supported granularity.
+/// - `DynProofExpr::try_new_date_trunc()` returns an error.
+pub fn date_trunc(granularity: &str, expr: DynProofExpr) -> DynProofExpr {
+    DynProofExpr::try_new_date_trunc(granularity.parse().unwrap(), expr).unwrap()
+}
+
+/// # Panics
+/// Panics if:
+/// - `field` is not a supported field.
+/// - `DynProofExpr::try_new_extract()` returns an error.
+pub fn extract(field: &str, expr: DynProofExpr) -> DynProofExpr {
+    DynProofExpr::try_new_extract(field.parse().unwrap(), expr).unwrap()
+}
+
+/// # Panics
+/// Panics if:
+/// - `DynProofExpr::try_new_abs()` returns an error.
+pub fn abs(expr: DynProofExpr) -> DynProofExpr {
+    DynProofExpr::try_new_abs(expr).unwrap()
+}
+
+/// # Panics
+/// Panics if:
+/// - `DynProofExpr::try_new_cast()` returns an error.
+pub fn cast(expr: DynProofExpr, to_type: ColumnType) -> DynProofExpr {
+    DynProofExpr::try_new_cast(expr, to_type).unwrap()
+}
+
+/// # Panics
+/// Panics if:
+/// - `DynProofExpr::try_new_case()` returns an error.
+pub fn case(when_then: Vec<(DynProofExpr, DynProofExpr)>, else_expr: DynProofExpr) -> DynProofExpr {
+    DynProofExpr::try_new_case(when_then, else_expr).unwrap()
+}
+
 pub fn const_bool(val: bool) -> DynProofExpr {
     DynProofExpr::new_literal(LiteralValue::Boolean(val))
 }
@@ -105,6 +170,10 @@ pub fn const_int128(val: i128) -> DynProofExpr {
     DynProofExpr::new_literal(LiteralValue::Int128(val))
 }
 
+pub fn const_uint64(val: u64) -> DynProofExpr {
+    DynProofExpr::new_literal(LiteralValue::UInt64(val))
+}
+
 pub fn const_varchar(val: &str) -> DynProofExpr {
     DynProofExpr::new_literal(LiteralValue::VarChar(val.to_string()))
 }
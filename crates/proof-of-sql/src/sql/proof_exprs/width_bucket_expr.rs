@@ -0,0 +1,171 @@
+use super::{DynProofExpr, ProofExpr};
+use crate::{
+    base::{
+        database::{Column, ColumnRef, ColumnType, Table},
+        map::{IndexMap, IndexSet},
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::{
+        proof::{FinalRoundBuilder, VerificationBuilder},
+        proof_gadgets::{prover_evaluate_sign, result_evaluate_sign, verifier_evaluate_sign},
+    },
+    utils::log,
+};
+use alloc::{boxed::Box, vec::Vec};
+use bumpalo::Bump;
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+/// The maximum number of buckets of a `WIDTH_BUCKET` expression
+///
+/// Every bucket boundary is proven with its own sign decomposition, so the cost of proving grows
+/// linearly with the number of buckets.
+pub(crate) const MAX_BUCKET_COUNT: u32 = 1024;
+
+/// Provable `WIDTH_BUCKET(expr, low, high, bucket_count)` expression
+///
+/// Splits `[low, high)` into `bucket_count` buckets of equal width and returns the 1-based
+/// index of the bucket containing `expr`. Following SQL, values below `low` are assigned
+/// bucket `0` and values greater than or equal to `high` are assigned `bucket_count + 1`.
+///
+/// Writing `n = bucket_count` and `w = high - low`, the bucket index of `x` is the number of
+/// `i` in `0..=n` with `n * (x - low) - i * w >= 0`, so the result is proven with one sign
+/// decomposition per bucket boundary.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WidthBucketExpr {
+    expr: Box<DynProofExpr>,
+    low: i64,
+    high: i64,
+    bucket_count: u32,
+}
+
+impl WidthBucketExpr {
+    /// Create a new `WIDTH_BUCKET` expression
+    pub fn new(expr: Box<DynProofExpr>, low: i64, high: i64, bucket_count: u32) -> Self {
+        Self {
+            expr,
+            low,
+            high,
+            bucket_count,
+        }
+    }
+
+    /// Compute `n * (x - low) - i * w` for every bucket boundary `i` in `0..=n`
+    fn boundary_differences<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        column: Column<'a, S>,
+    ) -> Vec<&'a [S]> {
+        let values = column.to_scalar_with_scaling(0);
+        let count = S::from(i64::from(self.bucket_count));
+        let low = S::from(self.low);
+        let width = S::from(self.high) - low;
+        (0..=self.bucket_count)
+            .map(|i| {
+                let offset = S::from(i64::from(i)) * width;
+                alloc.alloc_slice_fill_with(values.len(), |j| count * (values[j] - low) - offset)
+                    as &[_]
+            })
+            .collect()
+    }
+
+    /// Count the non-negative boundary differences of every row
+    fn count_buckets<'a>(&self, alloc: &'a Bump, length: usize, signs: &[&[bool]]) -> &'a [i64] {
+        alloc.alloc_slice_fill_with(length, |j| {
+            i64::from(self.bucket_count) + 1
+                - signs.iter().map(|sign| i64::from(sign[j])).sum::<i64>()
+        })
+    }
+}
+
+impl ProofExpr for WidthBucketExpr {
+    fn data_type(&self) -> ColumnType {
+        ColumnType::BigInt
+    }
+
+    #[tracing::instrument(name = "WidthBucketExpr::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        log::log_memory_usage("Start");
+
+        let expr_column = self.expr.result_evaluate(alloc, table);
+        let table_length = table.num_rows();
+        let signs = self
+            .boundary_differences(alloc, expr_column)
+            .into_iter()
+            .map(|diff| result_evaluate_sign(table_length, alloc, diff))
+            .collect::<Vec<_>>();
+        let res = Column::BigInt(self.count_buckets(alloc, table_length, &signs));
+
+        log::log_memory_usage("End");
+
+        res
+    }
+
+    #[tracing::instrument(name = "WidthBucketExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        log::log_memory_usage("Start");
+
+        let expr_column = self.expr.prover_evaluate(builder, alloc, table);
+        let signs = self
+            .boundary_differences(alloc, expr_column)
+            .into_iter()
+            .map(|diff| {
+                prover_evaluate_sign(
+                    builder,
+                    alloc,
+                    diff,
+                    #[cfg(test)]
+                    false,
+                )
+            })
+            .collect::<Vec<_>>();
+        let res = Column::BigInt(self.count_buckets(alloc, table.num_rows(), &signs));
+
+        log::log_memory_usage("End");
+
+        res
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut VerificationBuilder<S>,
+        accessor: &IndexMap<ColumnRef, S>,
+        one_eval: S,
+    ) -> Result<S, ProofError> {
+        let expr_eval = self.expr.verifier_evaluate(builder, accessor, one_eval)?;
+        let count = S::from(i64::from(self.bucket_count));
+        let low = S::from(self.low);
+        let width = S::from(self.high) - low;
+        let mut res = (count + S::one()) * one_eval;
+        for i in 0..=self.bucket_count {
+            let offset = S::from(i64::from(i)) * width;
+            let diff_eval = count * (expr_eval - low * one_eval) - offset * one_eval;
+            res -= verifier_evaluate_sign(builder, diff_eval, one_eval)?;
+        }
+        Ok(res)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.expr.get_column_references(columns);
+    }
+}
+
+impl fmt::Display for WidthBucketExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "width_bucket({}, {}, {}, {})",
+            self.expr, self.low, self.high, self.bucket_count
+        )
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, OwnedTableTestAccessor},
+    },
+    sql::{
+        parse::ConversionError,
+        postprocessing::{apply_postprocessing_steps, test_utility::*, OwnedTablePostprocessing},
+        proof::{exercise_verification, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, DynProofExpr},
+        proof_plans::test_utility::*,
+    },
+};
+use proof_of_sql_parser::utility::{aliased_expr, col, count};
+use rand::{
+    distributions::{Distribution, Uniform},
+    rngs::StdRng,
+};
+use rand_core::SeedableRng;
+
+fn expected_bucket(x: i64, low: i64, high: i64, bucket_count: u32) -> i64 {
+    if x < low {
+        0
+    } else if x >= high {
+        i64::from(bucket_count) + 1
+    } else {
+        (x - low) * i64::from(bucket_count) / (high - low) + 1
+    }
+}
+
+// select width_bucket(a, 0, 50, 5) as bucket from sxt.t
+// followed by select bucket, count(bucket) as frequency group by bucket
+#[test]
+fn we_can_prove_a_histogram_of_width_buckets() {
+    let data = owned_table([bigint("a", [-5_i64, 0, 9, 10, 24, 25, 49, 50, 77])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            width_bucket(column(t, "a", &accessor), 0, 50, 5),
+            "bucket",
+        )],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("bucket", [0_i64, 1, 1, 2, 3, 3, 5, 6, 6])]);
+    assert_eq!(res, expected_res);
+
+    let postprocessing: [OwnedTablePostprocessing; 1] = [group_by_postprocessing(
+        &["bucket"],
+        &[
+            aliased_expr(col("bucket"), "bucket"),
+            aliased_expr(count(col("bucket")), "frequency"),
+        ],
+    )];
+    let histogram = apply_postprocessing_steps(res, &postprocessing).unwrap();
+    let expected_histogram = owned_table([
+        bigint("bucket", [0_i64, 1, 2, 3, 5, 6]),
+        bigint("frequency", [1_i64, 2, 1, 2, 1, 2]),
+    ]);
+    assert_eq!(histogram, expected_histogram);
+}
+
+// select a, width_bucket(b, -10, 10, 4) as bucket from sxt.t where width_bucket(b, -10, 10, 4) = 2
+#[test]
+fn we_can_prove_a_filter_on_a_width_bucket() {
+    let data = owned_table([
+        smallint("a", [1_i16, 2, 3, 4, 5]),
+        int("b", [-11_i32, -5, -1, 0, 10]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        vec![
+            col_expr_plan(t, "a", &accessor),
+            aliased_plan(
+                width_bucket(column(t, "b", &accessor), -10, 10, 4),
+                "bucket",
+            ),
+        ],
+        tab(t),
+        equal(
+            width_bucket(column(t, "b", &accessor), -10, 10, 4),
+            const_bigint(2),
+        ),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([smallint("a", [2_i16, 3]), bigint("bucket", [2_i64, 2])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_width_buckets_of_random_data() {
+    let dist = Uniform::new(-300_i64, 300);
+    let mut rng = StdRng::from_seed([0u8; 32]);
+    for (low, high, bucket_count) in [(-100_i64, 100_i64, 7_u32), (0, 1, 1), (-250, 3, 10)] {
+        let values: Vec<i64> = dist.sample_iter(&mut rng).take(40).collect();
+        let data = owned_table([bigint("a", values.clone())]);
+        let t = "sxt.t".parse().unwrap();
+        let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+        let ast = projection(
+            vec![aliased_plan(
+                width_bucket(column(t, "a", &accessor), low, high, bucket_count),
+                "bucket",
+            )],
+            tab(t),
+        );
+        let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+        exercise_verification(&verifiable_res, &ast, &accessor, t);
+        let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+        let expected_res = owned_table([bigint(
+            "bucket",
+            values
+                .into_iter()
+                .map(|x| expected_bucket(x, low, high, bucket_count))
+                .collect::<Vec<_>>(),
+        )]);
+        assert_eq!(res, expected_res);
+    }
+}
+
+#[test]
+fn we_cannot_create_width_buckets_with_invalid_arguments() {
+    let data = owned_table([
+        bigint("a", [1_i64, 2]),
+        int128("b", [1_i128, 2]),
+        varchar("c", ["a", "b"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    for name in ["b", "c"] {
+        assert!(matches!(
+            DynProofExpr::try_new_width_bucket(column(t, name, &accessor), 0, 10, 2),
+            Err(ConversionError::InvalidExpression { .. })
+        ));
+    }
+    for (low, high, bucket_count) in [
+        (10, 10, 2),
+        (10, 0, 2),
+        (0, 10, 0),
+        (0, 10, 1025),
+        (0, 10, u32::MAX),
+    ] {
+        assert!(matches!(
+            DynProofExpr::try_new_width_bucket(column(t, "a", &accessor), low, high, bucket_count),
+            Err(ConversionError::InvalidExpression { .. })
+        ));
+    }
+    assert!(DynProofExpr::try_new_width_bucket(column(t, "a", &accessor), 0, 10, 1024).is_ok());
+}
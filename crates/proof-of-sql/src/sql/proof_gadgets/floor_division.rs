@@ -0,0 +1,78 @@
+use super::{prover_evaluate_sign, verifier_evaluate_sign};
+use crate::{
+    base::{proof::ProofError, scalar::Scalar},
+    sql::proof::{FinalRoundBuilder, VerificationBuilder},
+};
+use bumpalo::Bump;
+
+/// Prove the floor of the division of a column of integers by a positive constant.
+///
+/// # Panics
+/// Panics if `divisor` is not positive.
+///
+/// If n1, ..., nm denotes the data and d the divisor, commit to the quotients q1, ..., qm and
+/// prove that `qi = floor(ni / d)` by proving that
+/// - `ni - qi * d >= 0`,
+/// - `d - 1 - (ni - qi * d) >= 0`,
+/// - and that `qi` is small, by decomposing it into bits, so that the bounds hold over the
+///   integers and not only in the field.
+pub fn prover_evaluate_floor_division<'a, S: Scalar + 'a>(
+    builder: &mut FinalRoundBuilder<'a, S>,
+    alloc: &'a Bump,
+    numerator: &[i128],
+    divisor: i128,
+) -> &'a [i128] {
+    assert!(divisor > 0, "the divisor must be positive");
+    let quotients = alloc.alloc_slice_fill_iter(numerator.iter().map(|n| n.div_euclid(divisor)));
+    let quotient: &[S] = alloc.alloc_slice_fill_iter(quotients.iter().map(|&q| S::from(q)));
+    let remainder: &[S] = alloc.alloc_slice_fill_iter(
+        numerator
+            .iter()
+            .zip(quotients.iter())
+            .map(|(&n, &q)| S::from(n - q * divisor)),
+    );
+    let remainder_bound: &[S] = alloc.alloc_slice_fill_iter(
+        numerator
+            .iter()
+            .zip(quotients.iter())
+            .map(|(&n, &q)| S::from(divisor - 1 - (n - q * divisor))),
+    );
+    builder.produce_intermediate_mle(quotient);
+    for column in [quotient, remainder, remainder_bound] {
+        prover_evaluate_sign(
+            builder,
+            alloc,
+            column,
+            #[cfg(test)]
+            false,
+        );
+    }
+    quotients
+}
+
+/// Verify the floor of the division of a column of integers by a positive constant, returning
+/// the evaluation of the quotients.
+///
+/// See [`prover_evaluate_floor_division`].
+pub fn verifier_evaluate_floor_division<S: Scalar>(
+    builder: &mut VerificationBuilder<S>,
+    numerator_eval: S,
+    divisor: i128,
+    one_eval: S,
+) -> Result<S, ProofError> {
+    let quotient_eval = builder.try_consume_final_round_mle_evaluation()?;
+    let divisor = S::from(divisor);
+    let remainder_eval = numerator_eval - quotient_eval * divisor;
+    let remainder_bound_eval = (divisor - S::one()) * one_eval - remainder_eval;
+
+    // The sign of the quotient is not needed, only that it decomposes into few enough bits.
+    verifier_evaluate_sign(builder, quotient_eval, one_eval)?;
+    for eval in [remainder_eval, remainder_bound_eval] {
+        if verifier_evaluate_sign(builder, eval, one_eval)? != S::zero() {
+            return Err(ProofError::VerificationError {
+                error: "floor division remainder is out of range",
+            });
+        }
+    }
+    Ok(quotient_eval)
+}
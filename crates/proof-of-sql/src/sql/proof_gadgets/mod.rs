@@ -5,6 +5,12 @@ use bitwise_verification::{verify_constant_abs_decomposition, verify_constant_si
 mod bitwise_verification_test;
 mod sign_expr;
 pub(crate) use sign_expr::{prover_evaluate_sign, result_evaluate_sign, verifier_evaluate_sign};
+mod floor_division;
+pub(crate) use floor_division::{prover_evaluate_floor_division, verifier_evaluate_floor_division};
+mod truncated_division;
+pub(crate) use truncated_division::{
+    prover_evaluate_truncated_division, truncated_division, verifier_evaluate_truncated_division,
+};
 pub mod range_check;
 #[cfg(all(test, feature = "blitzar"))]
 pub mod range_check_test;
@@ -0,0 +1,254 @@
+use super::{prover_evaluate_sign, verifier_evaluate_sign};
+use crate::{
+    base::{
+        database::{Column, ColumnType},
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::proof::{FinalRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder},
+};
+use alloc::{boxed::Box, vec};
+use bumpalo::Bump;
+
+/// The values of an integer column, widened to `i128`
+///
+/// # Panics
+/// Panics if the column is not an integer column no wider than `BIGINT`.
+fn integer_values<'a, S: Scalar>(
+    column: &'a Column<'_, S>,
+) -> impl ExactSizeIterator<Item = i128> + 'a {
+    (0..column.len()).map(move |i| match column {
+        Column::TinyInt(col) => i128::from(col[i]),
+        Column::SmallInt(col) => i128::from(col[i]),
+        Column::Int(col) => i128::from(col[i]),
+        Column::BigInt(col) => i128::from(col[i]),
+        _ => panic!("only integer columns no wider than BIGINT are supported"),
+    })
+}
+
+/// Whether `value` fits in the integer type `column_type`
+fn fits_in(value: i128, column_type: ColumnType) -> bool {
+    match column_type {
+        ColumnType::TinyInt => i8::try_from(value).is_ok(),
+        ColumnType::SmallInt => i16::try_from(value).is_ok(),
+        ColumnType::Int => i32::try_from(value).is_ok(),
+        ColumnType::BigInt => i64::try_from(value).is_ok(),
+        ColumnType::Int128 => true,
+        _ => false,
+    }
+}
+
+/// Compute the truncated quotient and remainder of every row
+///
+/// A zero divisor, or a quotient that does not fit in `quotient_type` (e.g. `i64::MIN / -1` for a
+/// `BIGINT` quotient), yields `(0, 0)`.
+pub fn truncated_division<'a, S: Scalar>(
+    alloc: &'a Bump,
+    lhs: &Column<'a, S>,
+    rhs: &Column<'a, S>,
+    quotient_type: ColumnType,
+) -> (&'a [i128], &'a [i128]) {
+    let division = |(a, b): (i128, i128)| {
+        a.checked_div(b)
+            .filter(|&q| fits_in(q, quotient_type))
+            .map_or((0, 0), |q| (q, a - q * b))
+    };
+    let quotients = alloc.alloc_slice_fill_iter(
+        integer_values(lhs)
+            .zip(integer_values(rhs))
+            .map(|pair| division(pair).0),
+    );
+    let remainders = alloc.alloc_slice_fill_iter(
+        integer_values(lhs)
+            .zip(integer_values(rhs))
+            .map(|pair| division(pair).1),
+    );
+    (quotients, remainders)
+}
+
+/// Prove the truncated division of `lhs` by `rhs`, returning the quotients and remainders.
+///
+/// The quotient is truncated toward zero and the remainder has the sign of the dividend, as with
+/// Rust's `/` and `%` on integers. A zero divisor is recorded on `builder` as
+/// [`ProofError::DivisionByZero`], which the prover returns. A quotient that does not fit in
+/// `quotient_type` yields a quotient and remainder of zero, which cannot be verified.
+///
+/// If a1, ..., an and b1, ..., bn denote the data, commit to the quotients q1, ..., qn and the
+/// remainders r1, ..., rn and prove that
+/// - `ai - qi * bi - ri = 0`,
+/// - `qi` and `ri` are small, by proving their signs, so that the identity above and the bounds
+///   below hold over the integers and not only in the field,
+/// - `ui = ri * (1 - 2 * sign(ai)) >= 0`, i.e. that `ri` is zero or has the sign of `ai`, which
+///   makes `ui = |ri|`,
+/// - and `bi * (1 - 2 * sign(bi)) - ui - 1 >= 0`, i.e. `|ri| < |bi|`, which also rules out
+///   `bi = 0`.
+///
+/// `sign(ai)` and `sign(bi)` are the proven sign bits of the operands, so `|bi|` and `|ri|` are
+/// linear in the committed columns and their signs.
+pub fn prover_evaluate_truncated_division<'a, S: Scalar>(
+    builder: &mut FinalRoundBuilder<'a, S>,
+    alloc: &'a Bump,
+    lhs: Column<'a, S>,
+    rhs: Column<'a, S>,
+    quotient_type: ColumnType,
+    #[cfg(test)] quotient_shift: i128,
+) -> (&'a [i128], &'a [i128]) {
+    if integer_values(&rhs).any(|b| b == 0) {
+        builder.record_error(ProofError::DivisionByZero);
+    }
+    let (quotients, remainders) = truncated_division(alloc, &lhs, &rhs, quotient_type);
+    #[cfg(test)]
+    let (quotients, remainders): (&[i128], &[i128]) = (
+        alloc.alloc_slice_fill_iter(quotients.iter().map(|&q| q + quotient_shift)),
+        alloc.alloc_slice_fill_iter(
+            remainders
+                .iter()
+                .zip(integer_values(&rhs))
+                .map(|(&r, b)| r - quotient_shift * b),
+        ),
+    );
+    let lhs_values: &[S] = alloc.alloc_slice_fill_iter(integer_values(&lhs).map(S::from));
+    let rhs_values: &[S] = alloc.alloc_slice_fill_iter(integer_values(&rhs).map(S::from));
+    let quotient: &[S] = alloc.alloc_slice_fill_iter(quotients.iter().map(|&q| S::from(q)));
+    let remainder: &[S] = alloc.alloc_slice_fill_iter(remainders.iter().map(|&r| S::from(r)));
+    builder.produce_intermediate_mle(quotient);
+    builder.produce_intermediate_mle(remainder);
+
+    // subpolynomial: lhs - quotient * rhs - remainder
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (S::one(), vec![Box::new(lhs)]),
+            (-S::one(), vec![Box::new(quotient), Box::new(rhs)]),
+            (-S::one(), vec![Box::new(remainder)]),
+        ],
+    );
+
+    for column in [quotient, remainder] {
+        prover_evaluate_sign(
+            builder,
+            alloc,
+            column,
+            #[cfg(test)]
+            false,
+        );
+    }
+    let lhs_sign = prover_evaluate_sign(
+        builder,
+        alloc,
+        lhs_values,
+        #[cfg(test)]
+        false,
+    );
+    let rhs_sign = prover_evaluate_sign(
+        builder,
+        alloc,
+        rhs_values,
+        #[cfg(test)]
+        false,
+    );
+    let abs_remainder: &[S] =
+        alloc.alloc_slice_fill_iter(remainder.iter().zip(lhs_sign).map(|(&r, &is_negative)| {
+            if is_negative {
+                -r
+            } else {
+                r
+            }
+        }));
+    let bound: &[S] = alloc.alloc_slice_fill_iter(
+        rhs_values
+            .iter()
+            .zip(rhs_sign)
+            .zip(abs_remainder)
+            .map(|((&b, &is_negative), &u)| (if is_negative { -b } else { b }) - u - S::one()),
+    );
+    let ones: &[bool] = alloc.alloc_slice_fill_copy(lhs.len(), true);
+    builder.produce_intermediate_mle(abs_remainder);
+    builder.produce_intermediate_mle(bound);
+
+    // subpolynomial: abs_remainder - remainder + 2 * remainder * lhs_sign
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (S::one(), vec![Box::new(abs_remainder)]),
+            (-S::one(), vec![Box::new(remainder)]),
+            (S::TWO, vec![Box::new(remainder), Box::new(lhs_sign)]),
+        ],
+    );
+    // subpolynomial: bound - rhs + 2 * rhs * rhs_sign + abs_remainder + 1
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (S::one(), vec![Box::new(bound)]),
+            (-S::one(), vec![Box::new(rhs)]),
+            (S::TWO, vec![Box::new(rhs), Box::new(rhs_sign)]),
+            (S::one(), vec![Box::new(abs_remainder)]),
+            (S::one(), vec![Box::new(ones)]),
+        ],
+    );
+
+    for column in [abs_remainder, bound] {
+        prover_evaluate_sign(
+            builder,
+            alloc,
+            column,
+            #[cfg(test)]
+            false,
+        );
+    }
+
+    (quotients, remainders)
+}
+
+/// Verify the truncated division of `lhs` by `rhs`, returning the evaluations of the quotients
+/// and remainders.
+///
+/// See [`prover_evaluate_truncated_division`].
+pub fn verifier_evaluate_truncated_division<S: Scalar>(
+    builder: &mut VerificationBuilder<S>,
+    lhs_eval: S,
+    rhs_eval: S,
+    one_eval: S,
+) -> Result<(S, S), ProofError> {
+    let quotient_eval = builder.try_consume_final_round_mle_evaluation()?;
+    let remainder_eval = builder.try_consume_final_round_mle_evaluation()?;
+
+    // subpolynomial: lhs - quotient * rhs - remainder
+    builder.try_produce_sumcheck_subpolynomial_evaluation(
+        SumcheckSubpolynomialType::Identity,
+        lhs_eval - quotient_eval * rhs_eval - remainder_eval,
+        2,
+    )?;
+
+    // The signs of the quotient and the remainder are not needed, only that they decompose into
+    // few enough bits.
+    verifier_evaluate_sign(builder, quotient_eval, one_eval)?;
+    verifier_evaluate_sign(builder, remainder_eval, one_eval)?;
+    let lhs_sign_eval = verifier_evaluate_sign(builder, lhs_eval, one_eval)?;
+    let rhs_sign_eval = verifier_evaluate_sign(builder, rhs_eval, one_eval)?;
+    let abs_remainder_eval = builder.try_consume_final_round_mle_evaluation()?;
+    let bound_eval = builder.try_consume_final_round_mle_evaluation()?;
+
+    // subpolynomial: abs_remainder - remainder + 2 * remainder * lhs_sign
+    builder.try_produce_sumcheck_subpolynomial_evaluation(
+        SumcheckSubpolynomialType::Identity,
+        abs_remainder_eval - remainder_eval + S::TWO * remainder_eval * lhs_sign_eval,
+        2,
+    )?;
+    // subpolynomial: bound - rhs + 2 * rhs * rhs_sign + abs_remainder + 1
+    builder.try_produce_sumcheck_subpolynomial_evaluation(
+        SumcheckSubpolynomialType::Identity,
+        bound_eval - rhs_eval + S::TWO * rhs_eval * rhs_sign_eval + abs_remainder_eval + one_eval,
+        2,
+    )?;
+
+    for eval in [abs_remainder_eval, bound_eval] {
+        if verifier_evaluate_sign(builder, eval, one_eval)? != S::zero() {
+            return Err(ProofError::VerificationError {
+                error: "division remainder is out of range",
+            });
+        }
+    }
+
+    Ok((quotient_eval, remainder_eval))
+}
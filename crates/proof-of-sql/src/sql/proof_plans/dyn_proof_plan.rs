@@ -10,8 +10,9 @@ use crate::{
         FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate, VerificationBuilder,
     },
 };
-use alloc::vec::Vec;
+use alloc::{string::ToString, vec::Vec};
 use bumpalo::Bump;
+use core::fmt;
 use serde::{Deserialize, Serialize};
 
 /// The query plan for proving a query
@@ -58,3 +59,96 @@ pub enum DynProofPlan {
     /// ```
     Union(UnionExec),
 }
+
+/// Write `items` separated by commas and enclosed in brackets
+fn fmt_list<T: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    items: impl IntoIterator<Item = T>,
+) -> fmt::Result {
+    write!(f, "[")?;
+    for (i, item) in items.into_iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{item}")?;
+    }
+    write!(f, "]")
+}
+
+/// Write the fields of a schema as `name: TYPE`
+fn fmt_schema(f: &mut fmt::Formatter<'_>, schema: &[ColumnField]) -> fmt::Result {
+    fmt_list(
+        f,
+        schema
+            .iter()
+            .map(|field| alloc::format!("{}: {}", field.name(), field.data_type())),
+    )
+}
+
+impl DynProofPlan {
+    /// Write this plan node on one line, and its inputs below it with one more level of indentation
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        write!(f, "{:indent$}", "", indent = 2 * depth)?;
+        match self {
+            DynProofPlan::Empty(_) => writeln!(f, "Empty"),
+            DynProofPlan::Table(table) => {
+                write!(f, "Table: table={}, schema=", table.table_ref)?;
+                fmt_schema(f, &table.schema)?;
+                writeln!(f)
+            }
+            DynProofPlan::Projection(projection) => {
+                write!(
+                    f,
+                    "Projection: table={}, output=",
+                    projection.table.table_ref
+                )?;
+                fmt_list(f, &projection.aliased_results)?;
+                writeln!(f)
+            }
+            DynProofPlan::GroupBy(group_by) => {
+                write!(f, "GroupBy: table={}, group_by=", group_by.table.table_ref)?;
+                fmt_list(f, &group_by.group_by_exprs)?;
+                write!(f, ", aggregates=")?;
+                fmt_list(
+                    f,
+                    group_by
+                        .sum_expr
+                        .iter()
+                        .map(ToString::to_string)
+                        .chain([alloc::format!("count(*) as {}", group_by.count_alias)]),
+                )?;
+                writeln!(f, ", predicate={}", group_by.where_clause)
+            }
+            DynProofPlan::Filter(filter) => {
+                write!(f, "Filter: table={}, output=", filter.table.table_ref)?;
+                fmt_list(f, &filter.aliased_results)?;
+                writeln!(f, ", predicate={}", filter.where_clause)
+            }
+            DynProofPlan::Slice(slice) => {
+                write!(f, "Slice: skip={}, fetch=", slice.skip)?;
+                match slice.fetch {
+                    Some(fetch) => writeln!(f, "{fetch}")?,
+                    None => writeln!(f, "all")?,
+                }
+                slice.input.fmt_indented(f, depth + 1)
+            }
+            DynProofPlan::Union(union) => {
+                write!(f, "Union: schema=")?;
+                fmt_schema(f, &union.schema)?;
+                writeln!(f)?;
+                for input in &union.inputs {
+                    input.fmt_indented(f, depth + 1)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Renders the plan as an indented tree, similar to an `EXPLAIN`, with one line per plan node
+/// and the inputs of a node indented below it.
+impl fmt::Display for DynProofPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{
+    base::{
+        database::{ColumnType, TestSchemaAccessor},
+        map::indexmap,
+    },
+    sql::{proof::ProofPlan, proof_exprs::test_utility::*, proof_plans::test_utility::*},
+};
+
+fn accessor() -> TestSchemaAccessor {
+    TestSchemaAccessor::new(indexmap! {
+        "sxt.t".parse().unwrap() => indexmap! {
+            "a".into() => ColumnType::BigInt,
+            "b".into() => ColumnType::VarChar,
+            "c".into() => ColumnType::Int,
+        },
+    })
+}
+
+#[test]
+fn we_can_display_a_table_plan() {
+    let t = "sxt.t".parse().unwrap();
+    let plan = table_exec(
+        t,
+        vec![
+            column_field("a", ColumnType::BigInt),
+            column_field("b", ColumnType::VarChar),
+        ],
+    );
+    assert_eq!(
+        plan.to_string(),
+        "Table: table=sxt.t, schema=[a: BIGINT, b: VARCHAR]\n"
+    );
+}
+
+#[test]
+fn we_can_display_an_empty_plan() {
+    assert_eq!(empty_exec().to_string(), "Empty\n");
+}
+
+#[test]
+fn we_can_display_a_projection_plan() {
+    let t = "sxt.t".parse().unwrap();
+    let accessor = accessor();
+    let plan = projection(
+        vec![
+            col_expr_plan(t, "a", &accessor),
+            aliased_plan(
+                multiply(column(t, "a", &accessor), const_decimal75(3, 2, 150)),
+                "d",
+            ),
+        ],
+        tab(t),
+    );
+    assert_eq!(
+        plan.to_string(),
+        "Projection: table=sxt.t, output=[a, (a * 1.50) as d]\n"
+    );
+}
+
+#[test]
+fn we_can_display_a_filter_plan_with_a_compound_predicate() {
+    let t = "sxt.t".parse().unwrap();
+    let accessor = accessor();
+    let plan = filter(
+        vec![
+            aliased_col_expr_plan(t, "b", "x", &accessor),
+            aliased_plan(abs(column(t, "c", &accessor)), "c"),
+        ],
+        tab(t),
+        or(
+            equal(column(t, "b", &accessor), const_varchar("it's")),
+            not(gte(column(t, "a", &accessor), const_bigint(-2))),
+        ),
+    );
+    assert_eq!(
+        plan.to_string(),
+        "Filter: table=sxt.t, output=[b as x, abs(c) as c], \
+         predicate=((b = 'it''s') or (not (a >= -2)))\n"
+    );
+}
+
+#[test]
+fn we_can_display_a_group_by_plan() {
+    let t = "sxt.t".parse().unwrap();
+    let accessor = accessor();
+    let plan = group_by(
+        cols_expr(t, &["b"], &accessor),
+        vec![sum_expr(column(t, "a", &accessor), "sum_a")],
+        "__count__",
+        tab(t),
+        const_bool(true),
+    );
+    assert_eq!(
+        plan.to_string(),
+        "GroupBy: table=sxt.t, group_by=[b], \
+         aggregates=[sum(a) as sum_a, count(*) as __count__], predicate=true\n"
+    );
+}
+
+#[test]
+fn we_can_display_nested_plans_with_indentation() {
+    let t = "sxt.t".parse().unwrap();
+    let accessor = accessor();
+    let plan = slice_exec(
+        union_exec(
+            vec![
+                filter(
+                    cols_expr_plan(t, &["a"], &accessor),
+                    tab(t),
+                    lte(column(t, "a", &accessor), const_bigint(5)),
+                ),
+                slice_exec(
+                    projection(cols_expr_plan(t, &["a"], &accessor), tab(t)),
+                    2,
+                    None,
+                ),
+            ],
+            vec![column_field("a", ColumnType::BigInt)],
+        ),
+        1,
+        Some(3),
+    );
+    assert_eq!(
+        plan.to_string(),
+        "Slice: skip=1, fetch=3\n  \
+           Union: schema=[a: BIGINT]\n    \
+             Filter: table=sxt.t, output=[a], predicate=(a <= 5)\n    \
+             Slice: skip=2, fetch=all\n      \
+               Projection: table=sxt.t, output=[a]\n"
+    );
+}
+
+#[test]
+fn we_get_each_column_reference_once_sorted_by_table_and_column() {
+    let t = "sxt.t".parse().unwrap();
+    let u = "sxt.u".parse().unwrap();
+    let accessor = TestSchemaAccessor::new(indexmap! {
+        t => indexmap! {
+            "c".into() => ColumnType::Int,
+            "a".into() => ColumnType::BigInt,
+        },
+        u => indexmap! {
+            "b".into() => ColumnType::BigInt,
+        },
+    });
+    let plan = union_exec(
+        vec![
+            filter(
+                cols_expr_plan(u, &["b"], &accessor),
+                tab(u),
+                equal(column(u, "b", &accessor), const_bigint(1)),
+            ),
+            filter(
+                vec![aliased_plan(column(t, "a", &accessor), "b")],
+                tab(t),
+                and(
+                    equal(column(t, "c", &accessor), const_int(2)),
+                    equal(column(t, "a", &accessor), const_bigint(3)),
+                ),
+            ),
+            filter(
+                vec![aliased_plan(column(t, "a", &accessor), "b")],
+                tab(t),
+                equal(column(t, "c", &accessor), const_int(4)),
+            ),
+        ],
+        vec![column_field("b", ColumnType::BigInt)],
+    );
+    let column_references: Vec<_> = plan.get_column_references().into_iter().collect();
+    assert_eq!(
+        column_references,
+        vec![
+            col_ref(t, "a", &accessor),
+            col_ref(t, "c", &accessor),
+            col_ref(u, "b", &accessor),
+        ]
+    );
+}
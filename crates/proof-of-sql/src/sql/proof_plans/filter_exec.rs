@@ -12,8 +12,8 @@ use crate::{
     },
     sql::{
         proof::{
-            FinalRoundBuilder, FirstRoundBuilder, HonestProver, ProofPlan, ProverEvaluate,
-            ProverHonestyMarker, SumcheckSubpolynomialType, VerificationBuilder,
+            sort_column_references, FinalRoundBuilder, FirstRoundBuilder, HonestProver, ProofPlan,
+            ProverEvaluate, ProverHonestyMarker, SumcheckSubpolynomialType, VerificationBuilder,
         },
         proof_exprs::{AliasedDynProofExpr, DynProofExpr, ProofExpr, TableExpr},
     },
@@ -130,7 +130,7 @@ where
 
         self.where_clause.get_column_references(&mut columns);
 
-        columns
+        sort_column_references(columns)
     }
 
     fn get_table_references(&self) -> IndexSet<TableRef> {
@@ -440,3 +440,30 @@ fn we_can_prove_a_filter() {
     ]);
     assert_eq!(res, expected);
 }
+
+#[test]
+fn we_can_get_the_schema_of_a_filter_result_before_proving() {
+    let data = owned_table([
+        bigint("a", [1_i64, 4, 5, 2, 5]),
+        int("b", [1_i32, 2, 3, 4, 5]),
+        varchar("c", ["1", "2", "3", "4", "5"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        vec![
+            col_expr_plan(t, "c", &accessor),
+            aliased_plan(
+                add(column(t, "a", &accessor), column(t, "b", &accessor)),
+                "sum",
+            ),
+            aliased_plan(equal(column(t, "a", &accessor), const_bigint(5)), "is_five"),
+        ],
+        tab(t),
+        gte(column(t, "b", &accessor), const_int(2)),
+    );
+    let schema = ast.get_column_result_fields();
+    let verifiable_res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    assert_eq!(res.schema(), schema);
+}
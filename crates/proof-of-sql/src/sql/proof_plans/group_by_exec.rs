@@ -8,16 +8,20 @@ use crate::{
             TableRef,
         },
         map::{IndexMap, IndexSet},
+        polynomial::MultilinearExtension,
         proof::ProofError,
         scalar::Scalar,
         slice_ops,
     },
     sql::{
         proof::{
-            FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate,
-            SumcheckSubpolynomialType, VerificationBuilder,
+            sort_column_references, FinalRoundBuilder, FirstRoundBuilder, ProofPlan,
+            ProverEvaluate, SumcheckSubpolynomialTerm, SumcheckSubpolynomialType,
+            VerificationBuilder,
+        },
+        proof_exprs::{
+            multiply_columns, AliasedDynProofExpr, ColumnExpr, DynProofExpr, ProofExpr, TableExpr,
         },
-        proof_exprs::{AliasedDynProofExpr, ColumnExpr, DynProofExpr, ProofExpr, TableExpr},
     },
     utils::log,
 };
@@ -39,6 +43,9 @@ use sqlparser::ast::Ident;
 /// ```
 ///
 /// Note: if `group_by_exprs` is empty, then the query is equivalent to removing the `GROUP BY` clause.
+///
+/// If a sum expression is a product, e.g. `SUM(a * b)`, the product is fused into the group by
+/// sumcheck instead of being committed to as an intermediate column.
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
 pub struct GroupByExec {
     pub(super) group_by_exprs: Vec<ColumnExpr>,
@@ -89,13 +96,16 @@ impl ProofPlan for GroupByExec {
             .iter()
             .map(|expr| expr.verifier_evaluate(builder, accessor, input_one_eval))
             .collect::<Result<Vec<_>, _>>()?;
-        let aggregate_evals = self
+        let aggregate_factor_evals = self
             .sum_expr
             .iter()
             .map(|aliased_expr| {
                 aliased_expr
                     .expr
-                    .verifier_evaluate(builder, accessor, input_one_eval)
+                    .product_factors()
+                    .into_iter()
+                    .map(|factor| factor.verifier_evaluate(builder, accessor, input_one_eval))
+                    .collect::<Result<Vec<_>, _>>()
             })
             .collect::<Result<Vec<_>, _>>()?;
         // 3. filtered_columns
@@ -115,7 +125,7 @@ impl ProofPlan for GroupByExec {
             beta,
             input_one_eval,
             output_one_eval,
-            (group_by_evals, aggregate_evals, where_eval),
+            (group_by_evals, aggregate_factor_evals, where_eval),
             (
                 group_by_result_columns_evals.clone(),
                 sum_result_columns_evals.clone(),
@@ -182,7 +192,7 @@ impl ProofPlan for GroupByExec {
 
         self.where_clause.get_column_references(&mut columns);
 
-        columns
+        sort_column_references(columns)
     }
 
     fn get_table_references(&self) -> IndexSet<TableRef> {
@@ -276,10 +286,21 @@ impl ProverEvaluate for GroupByExec {
             .iter()
             .map(|expr| expr.prover_evaluate(builder, alloc, table))
             .collect::<Vec<_>>();
-        let sum_columns = self
+        let sum_factor_columns = self
             .sum_expr
             .iter()
-            .map(|aliased_expr| aliased_expr.expr.prover_evaluate(builder, alloc, table))
+            .map(|aliased_expr| {
+                aliased_expr
+                    .expr
+                    .product_factors()
+                    .into_iter()
+                    .map(|factor| factor.prover_evaluate(builder, alloc, table))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let sum_columns = sum_factor_columns
+            .iter()
+            .map(|factors| multiply_factors(alloc, factors))
             .collect::<Vec<_>>();
         // 3. Compute filtered_columns
         let AggregatedColumns {
@@ -317,7 +338,7 @@ impl ProverEvaluate for GroupByExec {
             alloc,
             alpha,
             beta,
-            (&group_by_columns, &sum_columns, selection),
+            (&group_by_columns, &sum_factor_columns, selection),
             (&group_by_result_columns, &sum_result_columns, count_column),
             table.num_rows(),
         );
@@ -328,6 +349,19 @@ impl ProverEvaluate for GroupByExec {
     }
 }
 
+/// Multiply the factors of a sum expression together without committing to the product
+#[allow(
+    clippy::missing_panics_doc,
+    reason = "product_factors always returns at least one factor"
+)]
+fn multiply_factors<'a, S: Scalar>(alloc: &'a Bump, factors: &[Column<'a, S>]) -> Column<'a, S> {
+    factors
+        .iter()
+        .copied()
+        .reduce(|product, factor| Column::Scalar(multiply_columns(&product, &factor, alloc)))
+        .expect("every sum expression has at least one factor")
+}
+
 #[allow(clippy::unnecessary_wraps)]
 fn verify_group_by<S: Scalar>(
     builder: &mut VerificationBuilder<S>,
@@ -335,15 +369,21 @@ fn verify_group_by<S: Scalar>(
     beta: S,
     input_one_eval: S,
     output_one_eval: S,
-    (g_in_evals, sum_in_evals, sel_in_eval): (Vec<S>, Vec<S>, S),
+    (g_in_evals, sum_in_factor_evals, sel_in_eval): (Vec<S>, Vec<Vec<S>>, S),
     (g_out_evals, sum_out_evals, count_out_eval): (Vec<S>, Vec<S>, S),
 ) -> Result<(), ProofError> {
     // g_in_fold = alpha * sum beta^j * g_in[j]
     let g_in_fold_eval = alpha * fold_vals(beta, &g_in_evals);
     // g_out_fold = alpha * sum beta^j * g_out[j]
     let g_out_fold_eval = alpha * fold_vals(beta, &g_out_evals);
+    // sum_in[j] = prod_k sum_in_factors[j][k]
+    let sum_in_evals = sum_in_factor_evals
+        .iter()
+        .map(|factor_evals| factor_evals.iter().copied().product())
+        .collect::<Vec<S>>();
     // sum_in_fold = input_ones + sum beta^(j+1) * sum_in[j]
     let sum_in_fold_eval = input_one_eval + beta * fold_vals(beta, &sum_in_evals);
+    let max_num_factors = sum_in_factor_evals.iter().map(Vec::len).max().unwrap_or(1);
     // sum_out_fold = count_out + sum beta^(j+1) * sum_out[j]
     let sum_out_fold_eval = count_out_eval + beta * fold_vals(beta, &sum_out_evals);
 
@@ -354,7 +394,7 @@ fn verify_group_by<S: Scalar>(
     builder.try_produce_sumcheck_subpolynomial_evaluation(
         SumcheckSubpolynomialType::ZeroSum,
         g_in_star_eval * sel_in_eval * sum_in_fold_eval - g_out_star_eval * sum_out_fold_eval,
-        3,
+        2 + max_num_factors.max(1),
     )?;
 
     // g_in_star + g_in_star * g_in_fold - input_ones = 0
@@ -378,12 +418,13 @@ fn verify_group_by<S: Scalar>(
     clippy::missing_panics_doc,
     reason = "alpha is guaranteed to not be zero in this context"
 )]
+#[allow(clippy::type_complexity)]
 pub fn prove_group_by<'a, S: Scalar>(
     builder: &mut FinalRoundBuilder<'a, S>,
     alloc: &'a Bump,
     alpha: S,
     beta: S,
-    (g_in, sum_in, sel_in): (&[Column<S>], &[Column<S>], &'a [bool]),
+    (g_in, sum_in_factors, sel_in): (&[Column<S>], &[Vec<Column<'a, S>>], &'a [bool]),
     (g_out, sum_out, count_out): (&[Column<S>], &[&'a [S]], &'a [i64]),
     n: usize,
 ) {
@@ -399,10 +440,6 @@ pub fn prove_group_by<'a, S: Scalar>(
     let g_out_fold = alloc.alloc_slice_fill_copy(m, Zero::zero());
     fold_columns(g_out_fold, alpha, beta, g_out);
 
-    // sum_in_fold = 1 + sum beta^(j+1) * sum_in[j]
-    let sum_in_fold = alloc.alloc_slice_fill_copy(n, One::one());
-    fold_columns(sum_in_fold, beta, beta, sum_in);
-
     // sum_out_fold = count_out + sum beta^(j+1) * sum_out[j]
     let sum_out_fold = alloc.alloc_slice_fill_default(m);
     slice_ops::slice_cast_mut(count_out, sum_out_fold);
@@ -421,24 +458,32 @@ pub fn prove_group_by<'a, S: Scalar>(
     builder.produce_intermediate_mle(g_in_star as &[_]);
     builder.produce_intermediate_mle(g_out_star as &[_]);
 
-    // sum g_in_star * sel_in * sum_in_fold - g_out_star * sum_out_fold = 0
-    builder.produce_sumcheck_subpolynomial(
-        SumcheckSubpolynomialType::ZeroSum,
+    // sum g_in_star * sel_in * sum_in_fold - g_out_star * sum_out_fold = 0,
+    // where sum_in_fold = input_ones + sum beta^(j+1) * prod_k sum_in_factors[j][k]
+    // is expanded so that products never have to be committed to
+    let mut terms: Vec<SumcheckSubpolynomialTerm<'a, S>> = vec![(
+        S::one(),
         vec![
-            (
-                S::one(),
-                vec![
-                    Box::new(g_in_star as &[_]),
-                    Box::new(sel_in),
-                    Box::new(sum_in_fold as &[_]),
-                ],
-            ),
-            (
-                -S::one(),
-                vec![Box::new(g_out_star as &[_]), Box::new(sum_out_fold as &[_])],
-            ),
+            Box::new(g_in_star as &[_]),
+            Box::new(sel_in),
+            Box::new(input_ones as &[_]),
         ],
-    );
+    )];
+    let mut beta_power = S::one();
+    for factors in sum_in_factors {
+        beta_power *= beta;
+        let mut multiplicands: Vec<Box<dyn MultilinearExtension<S> + 'a>> =
+            vec![Box::new(g_in_star as &[_]), Box::new(sel_in)];
+        for &factor in factors {
+            multiplicands.push(Box::new(factor));
+        }
+        terms.push((beta_power, multiplicands));
+    }
+    terms.push((
+        -S::one(),
+        vec![Box::new(g_out_star as &[_]), Box::new(sum_out_fold as &[_])],
+    ));
+    builder.produce_sumcheck_subpolynomial(SumcheckSubpolynomialType::ZeroSum, terms);
 
     // g_in_star + g_in_star * g_in_fold - input_ones = 0
     builder.produce_sumcheck_subpolynomial(
@@ -6,10 +6,12 @@ use crate::{
         scalar::Curve25519Scalar,
     },
     sql::{
-        proof::{exercise_verification, VerifiableQueryResult},
+        postprocessing::{apply_postprocessing_steps, test_utility::*},
+        proof::{exercise_verification, ProofPlan, VerifiableQueryResult},
         proof_exprs::test_utility::*,
     },
 };
+use proof_of_sql_parser::utility::{aliased_expr, col, sum};
 
 /// `select a, sum(c) as sum_c, count(*) as __count__ from sxt.t where b = 99 group by a`
 #[test]
@@ -230,3 +232,105 @@ fn we_can_prove_a_complex_group_by_query_with_many_columns() {
     ]);
     assert_eq!(res, expected);
 }
+
+/// `select g, sum(a * b * c) as abc, sum(a * b) as ab, sum(c) as c, count(*) as __count__ from sxt.t where c >= 0 group by g`
+/// proven with the fused products must match proving the products first and summing them afterwards
+#[test]
+fn we_can_prove_a_group_by_with_fused_sums_of_products() {
+    let data = owned_table([
+        bigint("g", [1, 2, 2, 1, 2, 3, 1]),
+        bigint("a", [3, -2, 5, 7, 1, 4, -6]),
+        int("b", [2, 8, -1, 0, 9, 3, 5]),
+        smallint("c", [4_i16, 1, 6, 2, -3, 5, 7]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let a_times_b = || multiply(column(t, "a", &accessor), column(t, "b", &accessor));
+    let where_clause = || gte(column(t, "c", &accessor), const_bigint(0));
+
+    let fused_expr = group_by(
+        cols_expr(t, &["g"], &accessor),
+        vec![
+            sum_expr(multiply(a_times_b(), column(t, "c", &accessor)), "abc"),
+            sum_expr(a_times_b(), "ab"),
+            sum_expr(column(t, "c", &accessor), "c"),
+        ],
+        "__count__",
+        tab(t),
+        where_clause(),
+    );
+    let res = VerifiableQueryResult::new(&fused_expr, &accessor, &());
+    exercise_verification(&res, &fused_expr, &accessor, t);
+    let fused_res = res.verify(&fused_expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([
+        bigint("g", [1, 2, 3]),
+        bigint("abc", [24 - 210, -16 - 30, 60]),
+        bigint("ab", [6 - 30, -16 - 5, 12]),
+        smallint("c", [13_i16, 7, 5]),
+        bigint("__count__", [3, 2, 1]),
+    ]);
+    assert_eq!(fused_res, expected);
+
+    let naive_expr = filter(
+        vec![
+            col_expr_plan(t, "g", &accessor),
+            aliased_plan(multiply(a_times_b(), column(t, "c", &accessor)), "abc"),
+            aliased_plan(a_times_b(), "ab"),
+            col_expr_plan(t, "c", &accessor),
+        ],
+        tab(t),
+        where_clause(),
+    );
+    let res = VerifiableQueryResult::new(&naive_expr, &accessor, &());
+    exercise_verification(&res, &naive_expr, &accessor, t);
+    let naive_res = res.verify(&naive_expr, &accessor, &()).unwrap().table;
+    let naive_res = apply_postprocessing_steps(
+        naive_res,
+        &[group_by_postprocessing(
+            &["g"],
+            &[
+                aliased_expr(col("g"), "g"),
+                aliased_expr(sum(col("abc")), "abc"),
+                aliased_expr(sum(col("ab")), "ab"),
+                aliased_expr(sum(col("c")), "c"),
+            ],
+        )],
+    )
+    .unwrap();
+    assert_eq!(
+        naive_res,
+        owned_table([
+            bigint("g", [1, 2, 3]),
+            bigint("abc", [24 - 210, -16 - 30, 60]),
+            bigint("ab", [6 - 30, -16 - 5, 12]),
+            smallint("c", [13_i16, 7, 5]),
+        ])
+    );
+}
+
+/// `select a, sum(b * 2) as sum_b, count(*) as __count__ from sxt.t where c = 'x' group by a`
+#[test]
+fn we_can_get_the_schema_of_a_group_by_result_before_proving() {
+    let data = owned_table([
+        int("a", [1_i32, 2, 2, 1, 2]),
+        smallint("b", [1_i16, 2, 3, 4, 5]),
+        varchar("c", ["x", "x", "y", "x", "x"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let expr = group_by(
+        cols_expr(t, &["a"], &accessor),
+        vec![sum_expr(
+            multiply(column(t, "b", &accessor), const_smallint(2)),
+            "sum_b",
+        )],
+        "__count__",
+        tab(t),
+        equal(column(t, "c", &accessor), const_varchar("x")),
+    );
+    let schema = expr.get_column_result_fields();
+    let res = VerifiableQueryResult::<InnerProductProof>::new(&expr, &accessor, &());
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res.schema(), schema);
+}
@@ -47,6 +47,13 @@ mod union_exec_test;
 
 mod dyn_proof_plan;
 pub use dyn_proof_plan::DynProofPlan;
+#[cfg(test)]
+mod dyn_proof_plan_test;
+
+mod proof_plan_builder;
+pub use proof_plan_builder::ProofPlanBuilder;
+#[cfg(test)]
+mod proof_plan_builder_test;
 
 #[cfg(test)]
 mod demo_mock_plan;
@@ -9,7 +9,8 @@ use crate::{
     },
     sql::{
         proof::{
-            FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate, VerificationBuilder,
+            sort_column_references, FinalRoundBuilder, FirstRoundBuilder, ProofPlan,
+            ProverEvaluate, VerificationBuilder,
         },
         proof_exprs::{AliasedDynProofExpr, ProofExpr, TableExpr},
     },
@@ -79,7 +80,7 @@ impl ProofPlan for ProjectionExec {
         self.aliased_results.iter().for_each(|aliased_expr| {
             aliased_expr.expr.get_column_references(&mut columns);
         });
-        columns
+        sort_column_references(columns)
     }
 
     fn get_table_references(&self) -> IndexSet<TableRef> {
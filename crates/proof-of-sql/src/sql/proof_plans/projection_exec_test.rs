@@ -355,3 +355,32 @@ fn we_can_prove_a_projection() {
     ]);
     assert_eq!(res, expected);
 }
+
+#[test]
+fn we_can_get_the_schema_of_a_projection_result_before_proving() {
+    let data = owned_table([
+        smallint("a", [1_i16, 4, 5]),
+        bigint("b", [1_i64, 2, 3]),
+        decimal75("c", 10, 2, [100, 250, -3]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![
+            aliased_plan(
+                multiply(column(t, "a", &accessor), column(t, "b", &accessor)),
+                "product",
+            ),
+            aliased_plan(
+                subtract(column(t, "c", &accessor), column(t, "a", &accessor)),
+                "difference",
+            ),
+            aliased_plan(const_varchar("x"), "literal"),
+        ],
+        tab(t),
+    );
+    let schema = ast.get_column_result_fields();
+    let verifiable_res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    assert_eq!(res.schema(), schema);
+}
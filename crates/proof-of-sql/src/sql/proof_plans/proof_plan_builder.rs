@@ -0,0 +1,251 @@
+use super::{DynProofPlan, FilterExec, GroupByExec, ProjectionExec, TableExec};
+use crate::{
+    base::{
+        database::{ColumnField, ColumnRef, LiteralValue, SchemaAccessor, TableRef},
+        map::IndexMap,
+    },
+    sql::{
+        parse::{ConversionError, ConversionResult, DynProofExprBuilder, WhereExprBuilder},
+        proof_exprs::{AliasedDynProofExpr, ColumnExpr, DynProofExpr, TableExpr},
+    },
+};
+use alloc::{borrow::ToOwned, boxed::Box, format, string::ToString, vec::Vec};
+use proof_of_sql_parser::intermediate_ast::{AggregationOperator, AliasedResultExpr, Expression};
+use sqlparser::ast::Ident;
+
+/// Builder for constructing a [`DynProofPlan`] directly, without going through SQL.
+///
+/// ```ignore
+///     ProofPlanBuilder::new(&accessor)
+///         .scan(table_ref, vec!["a".into(), "b".into()])
+///         .filter(equal(col("b"), lit(1)))
+///         .project(vec![aliased_expr(col("a"), "a")])
+///         .build()?
+/// ```
+///
+/// Nothing is checked until [`ProofPlanBuilder::build`] is called, which type checks every
+/// expression against the scanned columns and returns the same [`ConversionError`]s as the
+/// equivalent SQL query.
+pub struct ProofPlanBuilder<'a> {
+    schema_accessor: &'a dyn SchemaAccessor,
+    table_ref: Option<TableRef>,
+    columns: Vec<Ident>,
+    where_expr: Option<Box<Expression>>,
+    result_exprs: Option<Vec<AliasedResultExpr>>,
+    group_by: Option<(Vec<Ident>, Vec<AliasedResultExpr>)>,
+}
+
+// Public interface
+impl<'a> ProofPlanBuilder<'a> {
+    /// Creates a new `ProofPlanBuilder` resolving tables and columns with `schema_accessor`.
+    #[must_use]
+    pub fn new(schema_accessor: &'a dyn SchemaAccessor) -> Self {
+        Self {
+            schema_accessor,
+            table_ref: None,
+            columns: Vec::new(),
+            where_expr: None,
+            result_exprs: None,
+            group_by: None,
+        }
+    }
+
+    /// Reads `columns` from the table `table_ref`. Only these columns can be referenced by the
+    /// other expressions of the plan.
+    #[must_use]
+    pub fn scan(mut self, table_ref: TableRef, columns: Vec<Ident>) -> Self {
+        self.table_ref = Some(table_ref);
+        self.columns = columns;
+        self
+    }
+
+    /// Keeps only the rows for which `expr` is true, i.e. `WHERE <expr>`.
+    #[must_use]
+    pub fn filter(mut self, expr: Box<Expression>) -> Self {
+        self.where_expr = Some(expr);
+        self
+    }
+
+    /// Outputs `exprs` instead of the scanned columns, i.e. `SELECT <expr1> AS <alias1>, ...`.
+    #[must_use]
+    pub fn project(mut self, exprs: Vec<AliasedResultExpr>) -> Self {
+        self.result_exprs = Some(exprs);
+        self
+    }
+
+    /// Groups the rows by the `keys` columns and outputs the keys followed by `aggs`.
+    ///
+    /// As with SQL, only `SUM` aggregations followed by a single `COUNT` can be proven.
+    #[must_use]
+    pub fn group_by(mut self, keys: Vec<Ident>, aggs: Vec<AliasedResultExpr>) -> Self {
+        self.group_by = Some((keys, aggs));
+        self
+    }
+
+    /// Validates the plan and builds it.
+    ///
+    /// Without a projection or grouping the scanned columns are output. A filter produces a
+    /// [`DynProofPlan::Filter`], just like the SQL path does.
+    pub fn build(self) -> ConversionResult<DynProofPlan> {
+        let table_ref = self
+            .table_ref
+            .ok_or_else(|| ConversionError::InvalidExpression {
+                expression: "ProofPlanBuilder has no table scan".to_owned(),
+            })?;
+        let column_mapping = self.column_mapping(table_ref)?;
+        let where_clause = WhereExprBuilder::new(&column_mapping).build(self.where_expr)?;
+        let table = TableExpr { table_ref };
+        match (self.result_exprs, self.group_by) {
+            (Some(_), Some(_)) => Err(ConversionError::InvalidExpression {
+                expression: "a plan can not be both projected and grouped".to_owned(),
+            }),
+            (None, Some((keys, aggs))) => {
+                build_group_by(&column_mapping, table, where_clause, &keys, &aggs)
+            }
+            (Some(result_exprs), None) => {
+                let aliased_results = build_aliased_results(&column_mapping, &result_exprs)?;
+                Ok(match where_clause {
+                    Some(where_clause) => {
+                        DynProofPlan::Filter(FilterExec::new(aliased_results, table, where_clause))
+                    }
+                    None => DynProofPlan::Projection(ProjectionExec::new(aliased_results, table)),
+                })
+            }
+            (None, None) => Ok(match where_clause {
+                Some(where_clause) => {
+                    let aliased_results = column_mapping
+                        .iter()
+                        .map(|(alias, column_ref)| AliasedDynProofExpr {
+                            expr: DynProofExpr::new_column(column_ref.clone()),
+                            alias: alias.clone(),
+                        })
+                        .collect();
+                    DynProofPlan::Filter(FilterExec::new(aliased_results, table, where_clause))
+                }
+                None => DynProofPlan::Table(TableExec::new(
+                    table_ref,
+                    column_mapping
+                        .values()
+                        .map(|column_ref| {
+                            ColumnField::new(column_ref.column_id(), *column_ref.column_type())
+                        })
+                        .collect(),
+                )),
+            }),
+        }
+    }
+}
+
+// Private interface
+impl ProofPlanBuilder<'_> {
+    /// Resolves the scanned columns, in the order they were given.
+    fn column_mapping(&self, table_ref: TableRef) -> ConversionResult<IndexMap<Ident, ColumnRef>> {
+        let tables = self.schema_accessor.lookup_tables();
+        // Accessors that do not list their tables are trusted to know the table
+        if !tables.is_empty() && !tables.contains(&table_ref) {
+            return Err(ConversionError::UnknownTable {
+                resource_id: Box::new(table_ref.resource_id()),
+                available_tables: tables
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            });
+        }
+        self.columns
+            .iter()
+            .map(|column_id| {
+                let column_type = self
+                    .schema_accessor
+                    .lookup_column(table_ref, column_id.clone())
+                    .ok_or_else(|| ConversionError::MissingColumn {
+                        identifier: Box::new(column_id.clone()),
+                        resource_id: Box::new(table_ref.resource_id()),
+                    })?;
+                Ok((
+                    column_id.clone(),
+                    ColumnRef::new(table_ref, column_id.clone(), column_type),
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Builds the provable result expressions of a projection. Aggregations are rejected.
+fn build_aliased_results(
+    column_mapping: &IndexMap<Ident, ColumnRef>,
+    result_exprs: &[AliasedResultExpr],
+) -> ConversionResult<Vec<AliasedDynProofExpr>> {
+    result_exprs
+        .iter()
+        .map(|result_expr| {
+            Ok(AliasedDynProofExpr {
+                expr: DynProofExprBuilder::new_agg(column_mapping).build(&result_expr.expr)?,
+                alias: result_expr.alias.into(),
+            })
+        })
+        .collect()
+}
+
+/// Builds a [`GroupByExec`] from the group by columns and `SUM`s followed by a `COUNT`.
+fn build_group_by(
+    column_mapping: &IndexMap<Ident, ColumnRef>,
+    table: TableExpr,
+    where_clause: Option<DynProofExpr>,
+    keys: &[Ident],
+    aggs: &[AliasedResultExpr],
+) -> ConversionResult<DynProofPlan> {
+    let group_by_exprs = keys
+        .iter()
+        .map(|key| {
+            column_mapping
+                .get(key)
+                .map(|column_ref| ColumnExpr::new(column_ref.clone()))
+                .ok_or_else(|| ConversionError::MissingColumn {
+                    identifier: Box::new(key.clone()),
+                    resource_id: Box::new(table.table_ref.resource_id()),
+                })
+        })
+        .collect::<ConversionResult<Vec<_>>>()?;
+    let Some((count, sums)) = aggs.split_last() else {
+        return Err(ConversionError::Unprovable {
+            error: "a group by must end with a COUNT aggregation".to_owned(),
+        });
+    };
+    if !matches!(
+        *count.expr,
+        Expression::Aggregation {
+            op: AggregationOperator::Count,
+            ..
+        }
+    ) {
+        return Err(ConversionError::Unprovable {
+            error: "a group by must end with a COUNT aggregation".to_owned(),
+        });
+    }
+    let sum_expr = sums
+        .iter()
+        .map(|sum| match *sum.expr {
+            Expression::Aggregation {
+                op: AggregationOperator::Sum,
+                ..
+            } => Ok(AliasedDynProofExpr {
+                expr: DynProofExprBuilder::new(column_mapping).build(&sum.expr)?,
+                alias: sum.alias.into(),
+            }),
+            _ => Err(ConversionError::Unprovable {
+                error: format!(
+                    "only SUM aggregations can be proven in a group by, found {:?}",
+                    sum.expr
+                ),
+            }),
+        })
+        .collect::<ConversionResult<Vec<_>>>()?;
+    Ok(DynProofPlan::GroupBy(GroupByExec::new(
+        group_by_exprs,
+        sum_expr,
+        count.alias.into(),
+        table,
+        where_clause.unwrap_or_else(|| DynProofExpr::new_literal(LiteralValue::Boolean(true))),
+    )))
+}
@@ -0,0 +1,216 @@
+use super::ProofPlanBuilder;
+use crate::{
+    base::{
+        commitment::naive_evaluation_proof::NaiveEvaluationProof,
+        database::{owned_table_utility::*, ColumnField, ColumnType, OwnedTableTestAccessor},
+    },
+    sql::{
+        parse::{ConversionError, QueryExpr},
+        proof::VerifiableQueryResult,
+        proof_plans::DynProofPlan,
+    },
+};
+use proof_of_sql_parser::utility::{add, aliased_expr, col, count_all, equal, lit, max, mul, sum};
+
+fn accessor() -> OwnedTableTestAccessor<'static, NaiveEvaluationProof> {
+    OwnedTableTestAccessor::<NaiveEvaluationProof>::new_from_table(
+        "sxt.t".parse().unwrap(),
+        owned_table([
+            bigint("a", [1, 2, 3, 4, 5]),
+            varchar("b", ["x", "y", "x", "y", "x"]),
+            bigint("c", [1, 0, 1, 1, 0]),
+        ]),
+        0,
+        (),
+    )
+}
+
+fn sql_plan(sql: &str, accessor: &OwnedTableTestAccessor<NaiveEvaluationProof>) -> DynProofPlan {
+    QueryExpr::try_new(sql.parse().unwrap(), "sxt".into(), accessor)
+        .unwrap()
+        .proof_expr()
+        .clone()
+}
+
+fn sql_error(
+    sql: &str,
+    accessor: &OwnedTableTestAccessor<NaiveEvaluationProof>,
+) -> ConversionError {
+    QueryExpr::try_new(sql.parse().unwrap(), "sxt".into(), accessor).unwrap_err()
+}
+
+#[test]
+fn we_can_build_a_filter_with_a_projection_equal_to_the_sql_plan() {
+    let accessor = accessor();
+    let plan = ProofPlanBuilder::new(&accessor)
+        .scan(
+            "sxt.t".parse().unwrap(),
+            vec!["a".into(), "b".into(), "c".into()],
+        )
+        .filter(equal(col("c"), lit(1_i64)))
+        .project(vec![
+            aliased_expr(col("a"), "a"),
+            aliased_expr(col("b"), "b"),
+            aliased_expr(mul(col("a"), col("c")), "ac"),
+        ])
+        .build()
+        .unwrap();
+    assert_eq!(
+        plan,
+        sql_plan("SELECT a, b, a * c AS ac FROM t WHERE c = 1", &accessor)
+    );
+
+    let verifiable_result =
+        VerifiableQueryResult::<NaiveEvaluationProof>::new(&plan, &accessor, &());
+    let result = verifiable_result
+        .verify(&plan, &accessor, &())
+        .unwrap()
+        .table;
+    assert_eq!(
+        result,
+        owned_table([
+            bigint("a", [1, 3, 4]),
+            varchar("b", ["x", "x", "y"]),
+            bigint("ac", [1, 3, 4]),
+        ])
+    );
+}
+
+#[test]
+fn we_can_build_a_group_by_equal_to_the_sql_plan() {
+    let accessor = accessor();
+    let plan = ProofPlanBuilder::new(&accessor)
+        .scan("sxt.t".parse().unwrap(), vec!["a".into(), "b".into()])
+        .group_by(
+            vec!["b".into()],
+            vec![
+                aliased_expr(sum(col("a")), "s"),
+                aliased_expr(count_all(), "n"),
+            ],
+        )
+        .build()
+        .unwrap();
+    assert_eq!(
+        plan,
+        sql_plan(
+            "SELECT b, SUM(a) AS s, COUNT(*) AS n FROM t GROUP BY b",
+            &accessor
+        )
+    );
+    assert!(matches!(plan, DynProofPlan::GroupBy(_)));
+}
+
+#[test]
+fn we_can_build_plans_without_a_filter() {
+    let accessor = accessor();
+    let t = "sxt.t".parse().unwrap();
+    let plan = ProofPlanBuilder::new(&accessor)
+        .scan(t, vec!["c".into(), "a".into()])
+        .build()
+        .unwrap();
+    assert_eq!(
+        plan,
+        DynProofPlan::Table(super::TableExec::new(
+            t,
+            vec![
+                ColumnField::new("c".into(), ColumnType::BigInt),
+                ColumnField::new("a".into(), ColumnType::BigInt),
+            ]
+        ))
+    );
+
+    let plan = ProofPlanBuilder::new(&accessor)
+        .scan(t, vec!["a".into()])
+        .project(vec![aliased_expr(add(col("a"), lit(1_i64)), "a1")])
+        .build()
+        .unwrap();
+    assert!(matches!(plan, DynProofPlan::Projection(_)));
+    let verifiable_result =
+        VerifiableQueryResult::<NaiveEvaluationProof>::new(&plan, &accessor, &());
+    let result = verifiable_result
+        .verify(&plan, &accessor, &())
+        .unwrap()
+        .table;
+    assert_eq!(result, owned_table([bigint("a1", [2, 3, 4, 5, 6])]));
+}
+
+#[test]
+fn we_cannot_build_plans_with_the_type_errors_rejected_by_sql() {
+    let accessor = accessor();
+    let t = "sxt.t".parse().unwrap();
+    let error = ProofPlanBuilder::new(&accessor)
+        .scan(t, vec!["a".into()])
+        .filter(col("a"))
+        .build()
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        ConversionError::NonbooleanWhereClause {
+            datatype: ColumnType::BigInt
+        }
+    ));
+    assert_eq!(error, sql_error("SELECT a FROM t WHERE a", &accessor));
+
+    let error = ProofPlanBuilder::new(&accessor)
+        .scan(t, vec!["a".into(), "b".into()])
+        .project(vec![aliased_expr(add(col("a"), col("b")), "ab")])
+        .build()
+        .unwrap_err();
+    assert!(matches!(error, ConversionError::DataTypeMismatch { .. }));
+    assert_eq!(error, sql_error("SELECT a + b AS ab FROM t", &accessor));
+}
+
+#[test]
+fn we_cannot_build_plans_over_unknown_tables_or_columns() {
+    let accessor = accessor();
+    assert!(matches!(
+        ProofPlanBuilder::new(&accessor)
+            .scan("sxt.u".parse().unwrap(), vec!["a".into()])
+            .build(),
+        Err(ConversionError::UnknownTable { .. })
+    ));
+    assert!(matches!(
+        ProofPlanBuilder::new(&accessor)
+            .scan("sxt.t".parse().unwrap(), vec!["d".into()])
+            .build(),
+        Err(ConversionError::MissingColumn { .. })
+    ));
+    assert!(matches!(
+        ProofPlanBuilder::new(&accessor).build(),
+        Err(ConversionError::InvalidExpression { .. })
+    ));
+}
+
+#[test]
+fn we_cannot_build_group_bys_that_are_not_provable() {
+    let accessor = accessor();
+    let t = "sxt.t".parse().unwrap();
+    assert!(matches!(
+        ProofPlanBuilder::new(&accessor)
+            .scan(t, vec!["a".into(), "b".into()])
+            .group_by(
+                vec!["b".into()],
+                vec![
+                    aliased_expr(max(col("a")), "m"),
+                    aliased_expr(count_all(), "n"),
+                ],
+            )
+            .build(),
+        Err(ConversionError::Unprovable { .. })
+    ));
+    assert!(matches!(
+        ProofPlanBuilder::new(&accessor)
+            .scan(t, vec!["a".into(), "b".into()])
+            .group_by(vec!["b".into()], vec![aliased_expr(sum(col("a")), "s")])
+            .build(),
+        Err(ConversionError::Unprovable { .. })
+    ));
+    assert!(matches!(
+        ProofPlanBuilder::new(&accessor)
+            .scan(t, vec!["a".into(), "b".into()])
+            .project(vec![aliased_expr(col("a"), "a")])
+            .group_by(vec!["b".into()], vec![aliased_expr(count_all(), "n")])
+            .build(),
+        Err(ConversionError::InvalidExpression { .. })
+    ));
+}
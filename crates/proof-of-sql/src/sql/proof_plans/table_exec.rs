@@ -1,12 +1,15 @@
 use crate::{
     base::{
-        database::{ColumnField, ColumnRef, OwnedTable, Table, TableEvaluation, TableRef},
+        database::{
+            ColumnField, ColumnRef, OwnedTable, Table, TableEvaluation, TableOptions, TableRef,
+        },
         map::{indexset, IndexMap, IndexSet},
         proof::ProofError,
         scalar::Scalar,
     },
     sql::proof::{
-        FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate, VerificationBuilder,
+        sort_column_references, FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate,
+        VerificationBuilder,
     },
     utils::log,
 };
@@ -31,6 +34,31 @@ impl TableExec {
     pub fn new(table_ref: TableRef, schema: Vec<ColumnField>) -> Self {
         Self { table_ref, schema }
     }
+
+    /// Get the table from `table_map` with its columns in the order of the schema.
+    ///
+    /// The columns in `table_map` are in the order of [`ProofPlan::get_column_references`], which
+    /// need not be the order of the schema.
+    ///
+    /// # Panics
+    /// Panics if the table or one of its columns is missing from `table_map`.
+    fn table_in_schema_order<'a, S: Scalar>(
+        &self,
+        table_map: &IndexMap<TableRef, Table<'a, S>>,
+    ) -> Table<'a, S> {
+        let table = table_map.get(&self.table_ref).expect("Table not found");
+        Table::try_from_iter_with_options(
+            self.schema.iter().map(|field| {
+                let column = *table
+                    .inner_table()
+                    .get(&field.name())
+                    .expect("Column not found");
+                (field.name(), column)
+            }),
+            TableOptions::new(Some(table.num_rows())),
+        )
+        .expect("Columns of the same table have the same length")
+    }
 }
 
 impl ProofPlan for TableExec {
@@ -61,10 +89,12 @@ impl ProofPlan for TableExec {
     }
 
     fn get_column_references(&self) -> IndexSet<ColumnRef> {
-        self.schema
-            .iter()
-            .map(|field| ColumnRef::new(self.table_ref, field.name(), field.data_type()))
-            .collect()
+        sort_column_references(
+            self.schema
+                .iter()
+                .map(|field| ColumnRef::new(self.table_ref, field.name(), field.data_type()))
+                .collect(),
+        )
     }
 
     fn get_table_references(&self) -> IndexSet<TableRef> {
@@ -82,10 +112,7 @@ impl ProverEvaluate for TableExec {
     ) -> Table<'a, S> {
         log::log_memory_usage("Start");
 
-        let first_round_table = table_map
-            .get(&self.table_ref)
-            .expect("Table not found")
-            .clone();
+        let first_round_table = self.table_in_schema_order(table_map);
 
         log::log_memory_usage("End");
 
@@ -102,10 +129,7 @@ impl ProverEvaluate for TableExec {
     ) -> Table<'a, S> {
         log::log_memory_usage("Start");
 
-        let final_round_table = table_map
-            .get(&self.table_ref)
-            .expect("Table not found")
-            .clone();
+        let final_round_table = self.table_in_schema_order(table_map);
 
         log::log_memory_usage("End");
 
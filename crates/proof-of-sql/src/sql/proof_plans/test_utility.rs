@@ -55,5 +55,5 @@ pub fn slice_exec(input: DynProofPlan, skip: usize, fetch: Option<usize>) -> Dyn
 }
 
 pub fn union_exec(inputs: Vec<DynProofPlan>, schema: Vec<ColumnField>) -> DynProofPlan {
-    DynProofPlan::Union(UnionExec::new(inputs, schema))
+    DynProofPlan::Union(UnionExec::try_new(inputs, schema).unwrap())
 }
@@ -2,8 +2,9 @@ use super::{fold_columns, fold_vals, DynProofPlan};
 use crate::{
     base::{
         database::{
-            union_util::table_union, Column, ColumnField, ColumnRef, OwnedTable, Table,
-            TableEvaluation, TableRef,
+            union_util::{are_schemas_compatible, table_union},
+            Column, ColumnField, ColumnRef, OwnedTable, Table, TableEvaluation,
+            TableOperationError, TableOperationResult, TableRef,
         },
         map::{IndexMap, IndexSet},
         polynomial::MultilinearExtension,
@@ -12,8 +13,8 @@ use crate::{
         slice_ops,
     },
     sql::proof::{
-        FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate, SumcheckSubpolynomialType,
-        VerificationBuilder,
+        sort_column_references, FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate,
+        SumcheckSubpolynomialType, VerificationBuilder,
     },
 };
 use alloc::{boxed::Box, vec, vec::Vec};
@@ -41,6 +42,29 @@ impl UnionExec {
     pub fn new(inputs: Vec<DynProofPlan>, schema: Vec<ColumnField>) -> Self {
         Self { inputs, schema }
     }
+
+    /// Creates a new union execution plan, checking that every input has the column types of
+    /// `schema`.
+    ///
+    /// # Errors
+    /// Returns [`TableOperationError::UnionIncompatibleSchemas`] for the first input whose result
+    /// columns do not have the types of `schema`. Column names may differ.
+    pub fn try_new(
+        inputs: Vec<DynProofPlan>,
+        schema: Vec<ColumnField>,
+    ) -> TableOperationResult<Self> {
+        if let Some(actual_schema) = inputs
+            .iter()
+            .map(ProofPlan::get_column_result_fields)
+            .find(|input_schema| !are_schemas_compatible(&schema, input_schema))
+        {
+            return Err(TableOperationError::UnionIncompatibleSchemas {
+                correct_schema: schema,
+                actual_schema,
+            });
+        }
+        Ok(Self::new(inputs, schema))
+    }
 }
 
 impl ProofPlan for UnionExec
@@ -91,10 +115,12 @@ where
     }
 
     fn get_column_references(&self) -> IndexSet<ColumnRef> {
-        self.inputs
-            .iter()
-            .flat_map(ProofPlan::get_column_references)
-            .collect()
+        sort_column_references(
+            self.inputs
+                .iter()
+                .flat_map(ProofPlan::get_column_references)
+                .collect(),
+        )
     }
 
     fn get_table_references(&self) -> IndexSet<TableRef> {
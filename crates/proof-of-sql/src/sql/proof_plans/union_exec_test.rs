@@ -1,17 +1,17 @@
-use super::test_utility::*;
+use super::{test_utility::*, UnionExec};
 use crate::{
     base::{
         database::{
             owned_table_utility::*, table_utility::*, ColumnType, OwnedTable,
-            OwnedTableTestAccessor, TableTestAccessor, TestAccessor,
+            OwnedTableTestAccessor, TableOperationError, TableTestAccessor, TestAccessor,
         },
         map::indexmap,
         scalar::Curve25519Scalar,
     },
     sql::{
         proof::{
-            exercise_verification, FirstRoundBuilder, ProvableQueryResult, ProverEvaluate,
-            VerifiableQueryResult,
+            exercise_verification, FirstRoundBuilder, ProofPlan, ProvableQueryResult,
+            ProverEvaluate, VerifiableQueryResult,
         },
         proof_exprs::test_utility::*,
     },
@@ -293,3 +293,115 @@ fn we_can_get_result_from_union_using_first_round_evaluate() {
 
     assert_eq!(res, expected);
 }
+
+#[test]
+fn we_can_prove_a_union_of_filters_over_tables_with_matching_column_types() {
+    let data0 = owned_table([
+        bigint("a0", [1_i64, 2, 3, 4, 5]),
+        varchar("b0", ["1", "2", "3", "4", "5"]),
+    ]);
+    let t0 = "sxt.t0".parse().unwrap();
+    let data1 = owned_table([
+        bigint("a1", [10_i64, 20, 30]),
+        varchar("b1", ["10", "20", "30"]),
+    ]);
+    let t1 = "sxt.t1".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t0, data0, 0);
+    accessor.add_table(t1, data1, 0);
+    let ast = union_exec(
+        vec![
+            filter(
+                cols_expr_plan(t0, &["a0", "b0"], &accessor),
+                tab(t0),
+                gte(column(t0, "a0", &accessor), const_bigint(4)),
+            ),
+            filter(
+                cols_expr_plan(t1, &["a1", "b1"], &accessor),
+                tab(t1),
+                lte(column(t1, "a1", &accessor), const_bigint(20)),
+            ),
+        ],
+        vec![
+            column_field("a", ColumnType::BigInt),
+            column_field("b", ColumnType::VarChar),
+        ],
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t0);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        bigint("a", [4_i64, 5, 10, 20]),
+        varchar("b", ["4", "5", "10", "20"]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_create_a_union_of_plans_with_mismatched_column_types() {
+    let data0 = owned_table([bigint("a0", [1_i64, 2]), varchar("b0", ["1", "2"])]);
+    let t0 = "sxt.t0".parse().unwrap();
+    let data1 = owned_table([int("a1", [1_i32, 2]), varchar("b1", ["1", "2"])]);
+    let t1 = "sxt.t1".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t0, data0, 0);
+    accessor.add_table(t1, data1, 0);
+    let schema = vec![
+        column_field("a", ColumnType::BigInt),
+        column_field("b", ColumnType::VarChar),
+    ];
+    // Mismatched column types
+    assert!(matches!(
+        UnionExec::try_new(
+            vec![
+                projection(cols_expr_plan(t0, &["a0", "b0"], &accessor), tab(t0)),
+                projection(cols_expr_plan(t1, &["a1", "b1"], &accessor), tab(t1)),
+            ],
+            schema.clone(),
+        ),
+        Err(TableOperationError::UnionIncompatibleSchemas { .. })
+    ));
+    // Mismatched number of columns
+    assert!(matches!(
+        UnionExec::try_new(
+            vec![projection(cols_expr_plan(t0, &["a0"], &accessor), tab(t0))],
+            schema,
+        ),
+        Err(TableOperationError::UnionIncompatibleSchemas { .. })
+    ));
+}
+
+#[test]
+fn we_can_get_the_schema_of_a_union_result_before_proving() {
+    let data0 = owned_table([bigint("a0", [1_i64, 2, 3]), varchar("b0", ["1", "2", "3"])]);
+    let t0 = "sxt.t0".parse().unwrap();
+    let data1 = owned_table([bigint("a1", [4_i64, 5]), varchar("b1", ["4", "5"])]);
+    let t1 = "sxt.t1".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t0, data0, 0);
+    accessor.add_table(t1, data1, 0);
+    let ast = union_exec(
+        vec![
+            projection(
+                vec![
+                    aliased_plan(add(column(t0, "a0", &accessor), const_bigint(1)), "a0"),
+                    col_expr_plan(t0, "b0", &accessor),
+                ],
+                tab(t0),
+            ),
+            filter(
+                cols_expr_plan(t1, &["a1", "b1"], &accessor),
+                tab(t1),
+                equal(column(t1, "a1", &accessor), const_bigint(5)),
+            ),
+        ],
+        vec![
+            column_field("a", ColumnType::BigInt),
+            column_field("b", ColumnType::VarChar),
+        ],
+    );
+    let schema = ast.get_column_result_fields();
+    let verifiable_res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    assert_eq!(res.schema(), schema);
+}
@@ -5,11 +5,15 @@ use ark_std::test_rng;
 use proof_of_sql::base::commitment::InnerProductProof;
 use proof_of_sql::{
     base::{
-        database::{owned_table_utility::*, OwnedTable, OwnedTableTestAccessor, TestAccessor},
+        database::{
+            owned_table_from_json_records, owned_table_utility::*, ColumnField, ColumnType,
+            LiteralValue, OwnedColumn, OwnedTable, OwnedTableTestAccessor, TestAccessor,
+        },
+        math::decimal::Precision,
         scalar::Curve25519Scalar,
     },
     proof_primitive::dory::{
-        DoryEvaluationProof, DoryProverPublicSetup, DoryVerifierPublicSetup,
+        DoryEvaluationProof, DoryProverPublicSetup, DoryScalar, DoryVerifierPublicSetup,
         DynamicDoryEvaluationProof, ProverSetup, PublicParameters, VerifierSetup,
     },
     sql::{
@@ -18,6 +22,8 @@ use proof_of_sql::{
         proof::{QueryError, VerifiableQueryResult},
     },
 };
+use proof_of_sql_parser::posql_time::{PoSQLTimeUnit, PoSQLTimeZone};
+use std::sync::Arc;
 
 #[test]
 #[cfg(feature = "blitzar")]
@@ -78,6 +84,54 @@ fn we_can_prove_a_minimal_filter_query_with_dory() {
     assert_eq!(owned_table_result, expected_result);
 }
 
+#[test]
+fn we_can_verify_a_result_in_canonical_row_order_regardless_of_storage_order_with_dory() {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let verified_bytes = |table: OwnedTable<DoryScalar>| {
+        let mut accessor =
+            OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+        accessor.add_table("sxt.table".parse().unwrap(), table, 0);
+        let query = QueryExpr::try_new(
+            "SELECT a, b FROM table WHERE c >= 0".parse().unwrap(),
+            "sxt".into(),
+            &accessor,
+        )
+        .unwrap();
+        let verifiable_result = VerifiableQueryResult::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        let table = verifiable_result
+            .verify_in_canonical_order(query.proof_expr(), &accessor, &dory_verifier_setup)
+            .unwrap()
+            .table;
+        postcard::to_allocvec(&table).unwrap()
+    };
+
+    let bytes = verified_bytes(owned_table([
+        bigint("a", [2, 1, 2, 3, 1]),
+        varchar("b", ["y", "x", "x", "z", "w"]),
+        bigint("c", [0, 1, -1, 2, 3]),
+    ]));
+    let reordered_bytes = verified_bytes(owned_table([
+        bigint("a", [1, 3, 2, 2, 1]),
+        varchar("b", ["w", "z", "x", "y", "x"]),
+        bigint("c", [3, 2, -1, 0, 1]),
+    ]));
+    assert_eq!(bytes, reordered_bytes);
+    let expected_result: OwnedTable<DoryScalar> = owned_table([
+        bigint("a", [1, 1, 2, 3]),
+        varchar("b", ["w", "x", "y", "z"]),
+    ]);
+    assert_eq!(bytes, postcard::to_allocvec(&expected_result).unwrap());
+}
+
 #[test]
 fn we_can_prove_a_minimal_filter_query_with_dynamic_dory() {
     let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
@@ -110,6 +164,123 @@ fn we_can_prove_a_minimal_filter_query_with_dynamic_dory() {
     assert_eq!(owned_table_result, expected_result);
 }
 
+#[test]
+fn we_can_prove_concurrent_queries_with_a_shared_prover_setup_with_dynamic_dory() {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = Arc::new(ProverSetup::from(&public_parameters));
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let table = owned_table([
+        bigint("a", [1, 2, 3, 4, 5, 6]),
+        varchar("b", ["x", "y", "x", "z", "y", "x"]),
+    ]);
+    let queries = [
+        "SELECT a FROM table WHERE b = 'x'",
+        "SELECT b, a * 2 AS c FROM table WHERE a >= 4",
+        "SELECT b, SUM(a) AS s, COUNT(*) AS n FROM table GROUP BY b",
+        "SELECT a > 3 AS big FROM table",
+    ];
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DynamicDoryEvaluationProof>::new_empty_with_setup(&prover_setup);
+    accessor.add_table("sxt.table".parse().unwrap(), table.clone(), 0);
+    let query_exprs = queries
+        .map(|query| QueryExpr::try_new(query.parse().unwrap(), "sxt".into(), &accessor).unwrap());
+
+    let verifiable_results = std::thread::scope(|scope| {
+        let handles = query_exprs
+            .iter()
+            .map(|query| {
+                let prover_setup = Arc::clone(&prover_setup);
+                let table = table.clone();
+                scope.spawn(move || {
+                    let mut accessor =
+                        OwnedTableTestAccessor::<DynamicDoryEvaluationProof>::new_empty_with_setup(
+                            &prover_setup,
+                        );
+                    accessor.add_table("sxt.table".parse().unwrap(), table, 0);
+                    VerifiableQueryResult::<DynamicDoryEvaluationProof>::new(
+                        query.proof_expr(),
+                        &accessor,
+                        &&*prover_setup,
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    let expected_results = [
+        owned_table([bigint("a", [1, 3, 6])]),
+        owned_table([varchar("b", ["z", "y", "x"]), bigint("c", [8, 10, 12])]),
+        owned_table([
+            varchar("b", ["x", "y", "z"]),
+            bigint("s", [10, 7, 4]),
+            bigint("n", [3, 2, 1]),
+        ]),
+        owned_table([boolean("big", [false, false, false, true, true, true])]),
+    ];
+    for ((query, verifiable_result), expected_result) in query_exprs
+        .iter()
+        .zip(verifiable_results)
+        .zip(expected_results)
+    {
+        let owned_table_result = verifiable_result
+            .verify(query.proof_expr(), &accessor, &&verifier_setup)
+            .unwrap()
+            .table;
+        let owned_table_result =
+            apply_postprocessing_steps(owned_table_result, query.postprocessing()).unwrap();
+        assert_eq!(owned_table_result, expected_result);
+    }
+}
+
+#[test]
+fn we_can_bind_a_parameterized_limit_without_replanning_with_dynamic_dory() {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DynamicDoryEvaluationProof>::new_empty_with_setup(&prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", 0..10_i64)]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT * FROM table WHERE a >= 2 ORDER BY a LIMIT $1 OFFSET $2"
+            .parse()
+            .unwrap(),
+        "sxt".into(),
+        &accessor,
+    )
+    .unwrap();
+    for (limit, offset) in [(3_i64, 0_i64), (5, 1)] {
+        let bound_query = query
+            .try_bind_parameters(&[LiteralValue::BigInt(limit), LiteralValue::BigInt(offset)])
+            .unwrap();
+        assert_eq!(bound_query.proof_expr(), query.proof_expr());
+        let verifiable_result = VerifiableQueryResult::<DynamicDoryEvaluationProof>::new(
+            bound_query.proof_expr(),
+            &accessor,
+            &&prover_setup,
+        );
+        let owned_table_result = apply_postprocessing_steps(
+            verifiable_result
+                .verify(bound_query.proof_expr(), &accessor, &&verifier_setup)
+                .unwrap()
+                .table,
+            bound_query.postprocessing(),
+        )
+        .unwrap();
+        let expected_result = owned_table([bigint("a", (2 + offset)..(2 + offset + limit))]);
+        assert_eq!(owned_table_result, expected_result);
+    }
+}
+
 #[test]
 #[cfg(feature = "blitzar")]
 fn we_can_prove_a_basic_equality_query_with_curve25519() {
@@ -169,6 +340,136 @@ fn we_can_prove_a_basic_equality_query_with_dory() {
     assert_eq!(owned_table_result, expected_result);
 }
 
+#[test]
+fn we_can_prove_an_in_list_query_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.countries".parse().unwrap(),
+        owned_table([
+            varchar("country", ["China", "Brazil", "India", "Japan", "Chile"]),
+            bigint("population", [1411, 216, 1428, 125, 20]),
+        ]),
+        0,
+    );
+    for (query_text, expected_result) in [
+        (
+            "SELECT * FROM countries WHERE country IN ('China', 'India', 'Japan')",
+            owned_table([
+                varchar("country", ["China", "India", "Japan"]),
+                bigint("population", [1411, 1428, 125]),
+            ]),
+        ),
+        (
+            "SELECT country FROM countries WHERE country NOT IN ('China', 'India', 'Japan')",
+            owned_table([varchar("country", ["Brazil", "Chile"])]),
+        ),
+        (
+            "SELECT country FROM countries WHERE population IN (20)",
+            owned_table([varchar("country", ["Chile"])]),
+        ),
+    ] {
+        let query =
+            QueryExpr::try_new(query_text.parse().unwrap(), "sxt".into(), &accessor).unwrap();
+        let verifiable_result = VerifiableQueryResult::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        let owned_table_result = verifiable_result
+            .verify(query.proof_expr(), &accessor, &dory_verifier_setup)
+            .unwrap()
+            .table;
+        assert_eq!(owned_table_result, expected_result);
+    }
+}
+
+#[test]
+fn we_can_prove_a_date_filter_query_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("a", [1, 2, 3, 4]),
+            date("d", [18_262, 18_263, -365, 19_000]),
+        ]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT * FROM table WHERE d > DATE '2020-01-01'"
+            .parse()
+            .unwrap(),
+        "sxt".into(),
+        &accessor,
+    )
+    .unwrap();
+    let verifiable_result = VerifiableQueryResult::<DoryEvaluationProof>::new(
+        query.proof_expr(),
+        &accessor,
+        &dory_prover_setup,
+    );
+    let owned_table_result = verifiable_result
+        .verify(query.proof_expr(), &accessor, &dory_verifier_setup)
+        .unwrap()
+        .table;
+    let expected_result = owned_table([bigint("a", [2, 4]), date("d", [18_263, 19_000])]);
+    assert_eq!(owned_table_result, expected_result);
+}
+
+#[test]
+fn we_can_prove_a_count_of_true_values_in_a_large_boolean_column_with_dory() {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 5);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 5);
+
+    let flags: Vec<bool> = (0..1000).map(|i| i % 3 == 0 || i % 7 == 0).collect();
+    let expected_count = flags.iter().filter(|&&flag| flag).count();
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("k", [0; 1000]), boolean("flag", flags)]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT k, count(*) as c FROM table WHERE flag group by k"
+            .parse()
+            .unwrap(),
+        "sxt".into(),
+        &accessor,
+    )
+    .unwrap();
+    let verifiable_result = VerifiableQueryResult::<DoryEvaluationProof>::new(
+        query.proof_expr(),
+        &accessor,
+        &dory_prover_setup,
+    );
+    let owned_table_result = verifiable_result
+        .verify(query.proof_expr(), &accessor, &dory_verifier_setup)
+        .unwrap()
+        .table;
+    let expected_result = owned_table([
+        bigint("k", [0]),
+        bigint("c", [i64::try_from(expected_count).unwrap()]),
+    ]);
+    assert_eq!(owned_table_result, expected_result);
+}
+
 #[test]
 #[cfg(feature = "blitzar")]
 fn we_can_prove_a_basic_inequality_query_with_curve25519() {
@@ -511,6 +812,92 @@ fn we_can_prove_a_complex_query_with_dory() {
     assert_eq!(owned_table_result, expected_result);
 }
 
+#[test]
+fn we_can_prove_a_query_projecting_comparisons_as_boolean_columns_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.prices".parse().unwrap(),
+        owned_table([
+            varchar("item", ["pen", "book", "lamp", "desk"]),
+            bigint("price", [5, 100, 150, 400]),
+            decimal75("discount", 5, 2, [0, 50, 1500, 12000]),
+        ]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT item, price > 100 AS is_expensive, price - discount <= 100 AS is_affordable FROM prices"
+            .parse()
+            .unwrap(),
+        "sxt".into(),
+        &accessor,
+    )
+    .unwrap();
+    let verifiable_result = VerifiableQueryResult::<DoryEvaluationProof>::new(
+        query.proof_expr(),
+        &accessor,
+        &dory_prover_setup,
+    );
+    let owned_table_result = verifiable_result
+        .verify(query.proof_expr(), &accessor, &dory_verifier_setup)
+        .unwrap()
+        .table;
+    let expected_result = owned_table([
+        varchar("item", ["pen", "book", "lamp", "desk"]),
+        boolean("is_expensive", [false, false, true, true]),
+        boolean("is_affordable", [true, true, false, false]),
+    ]);
+    assert_eq!(owned_table_result, expected_result);
+}
+
+#[test]
+fn we_can_prove_a_query_projecting_a_compound_boolean_expression_with_dynamic_dory() {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DynamicDoryEvaluationProof>::new_empty_with_setup(&prover_setup);
+    accessor.add_table(
+        "sxt.prices".parse().unwrap(),
+        owned_table([
+            varchar("item", ["pen", "book", "lamp", "desk", "chair"]),
+            bigint("price", [5, 100, 150, 400, 80]),
+            int("stock", [0, 3, 12, 1, 7]),
+            boolean("on_sale", [true, false, false, true, false]),
+        ]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT item, (price > 100 AND stock >= 1) OR NOT (on_sale = false) AS is_featured FROM prices WHERE price >= 80"
+            .parse()
+            .unwrap(),
+        "sxt".into(),
+        &accessor,
+    )
+    .unwrap();
+    let verifiable_result = VerifiableQueryResult::<DynamicDoryEvaluationProof>::new(
+        query.proof_expr(),
+        &accessor,
+        &&prover_setup,
+    );
+    let owned_table_result = verifiable_result
+        .verify(query.proof_expr(), &accessor, &&verifier_setup)
+        .unwrap()
+        .table;
+    let expected_result = owned_table([
+        varchar("item", ["book", "lamp", "desk", "chair"]),
+        boolean("is_featured", [false, true, true, false]),
+    ]);
+    assert_eq!(owned_table_result, expected_result);
+}
+
 //TODO: This test uses postprocessing now. Check proof results once PROOF-765 is done.
 #[test]
 #[cfg(feature = "blitzar")]
@@ -758,6 +1145,404 @@ fn we_can_prove_a_basic_group_by_query_with_dory() {
     assert_eq!(owned_table_result, expected_result);
 }
 
+#[test]
+fn we_can_prove_a_population_variance_query_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let sectors = [
+        "energy", "tech", "energy", "health", "tech", "energy", "tech",
+    ];
+    let prices = [1025_i64, 30_050, 990, 7_777, 29_900, 1_150, 31_225];
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.prices".parse().unwrap(),
+        owned_table([
+            varchar("sector", sectors),
+            decimal75("price", 10, 2, prices),
+        ]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT sector, VAR_POP(price) AS variance FROM prices GROUP BY sector"
+            .parse()
+            .unwrap(),
+        "sxt".into(),
+        &accessor,
+    )
+    .unwrap();
+    let verifiable_result = VerifiableQueryResult::<DoryEvaluationProof>::new(
+        query.proof_expr(),
+        &accessor,
+        &dory_prover_setup,
+    );
+    let owned_table_result = verifiable_result
+        .verify(query.proof_expr(), &accessor, &dory_verifier_setup)
+        .unwrap()
+        .table;
+    let owned_table_result: OwnedTable<DoryScalar> =
+        apply_postprocessing_steps(owned_table_result, query.postprocessing()).unwrap();
+
+    // The exact variance of a sector is `(n * sum(p^2) - sum(p)^2) / n^2` in units of 10^-4, so
+    // in units of 10^-10 it is the ratio of the following numerator and denominator
+    let reference_variance = |sector: &str| {
+        let values = sectors
+            .iter()
+            .zip(prices)
+            .filter(|(s, _)| **s == sector)
+            .map(|(_, price)| i128::from(price))
+            .collect::<Vec<_>>();
+        let n = i128::try_from(values.len()).unwrap();
+        let sum = values.iter().sum::<i128>();
+        let sum_of_squares = values.iter().map(|v| v * v).sum::<i128>();
+        ((n * sum_of_squares - sum * sum) * 1_000_000, n * n)
+    };
+    assert_eq!(
+        owned_table_result.inner_table()[0],
+        OwnedColumn::VarChar(vec!["energy".into(), "health".into(), "tech".into()])
+    );
+    let OwnedColumn::Decimal75(_, scale, variances) = &owned_table_result.inner_table()[1] else {
+        panic!("VAR_POP should produce a decimal column");
+    };
+    assert_eq!(*scale, 10);
+    for (sector, variance) in ["energy", "health", "tech"].into_iter().zip(variances) {
+        let (numerator, denominator) = reference_variance(sector);
+        // The result is rounded to the nearest unit of 10^-10
+        let variance = i128::try_from(*variance).unwrap();
+        assert!(2 * (variance * denominator - numerator).abs() <= denominator);
+    }
+}
+
+#[test]
+fn we_can_apply_string_transformations_to_a_verified_result_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.users".parse().unwrap(),
+        owned_table([
+            bigint("id", [1, 2, 3, 4]),
+            varchar("name", [" Alice", "BOB ", "Çelik", "dana"]),
+        ]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT id, LOWER(TRIM(name)) AS name FROM users WHERE id >= 2"
+            .parse()
+            .unwrap(),
+        "sxt".into(),
+        &accessor,
+    )
+    .unwrap();
+    let verifiable_result = VerifiableQueryResult::<DoryEvaluationProof>::new(
+        query.proof_expr(),
+        &accessor,
+        &dory_prover_setup,
+    );
+    let owned_table_result = verifiable_result
+        .verify(query.proof_expr(), &accessor, &dory_verifier_setup)
+        .unwrap()
+        .table;
+    // The proof covers the untransformed names
+    let expected_verified_result: OwnedTable<DoryScalar> = owned_table([
+        bigint("id", [2, 3, 4]),
+        varchar("name", ["BOB ", "Çelik", "dana"]),
+    ]);
+    assert_eq!(owned_table_result, expected_verified_result);
+    let owned_table_result: OwnedTable<DoryScalar> =
+        apply_postprocessing_steps(owned_table_result, query.postprocessing()).unwrap();
+    let expected_result: OwnedTable<DoryScalar> = owned_table([
+        bigint("id", [2, 3, 4]),
+        varchar("name", ["bob", "çelik", "dana"]),
+    ]);
+    assert_eq!(owned_table_result, expected_result);
+}
+
+#[test]
+fn we_can_apply_substrings_to_a_verified_result_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.cards".parse().unwrap(),
+        owned_table([
+            bigint("id", [1, 2, 3]),
+            varchar(
+                "card_number",
+                ["4111111111111111", "5500005555555559", "3782"],
+            ),
+            varchar("holder", ["Zoë", "Łukasz", "李"]),
+        ]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT id, SUBSTRING(card_number, 1, 4) AS prefix, SUBSTRING(holder, 2, 10) AS rest FROM cards WHERE id >= 2"
+            .parse()
+            .unwrap(),
+        "sxt".into(),
+        &accessor,
+    )
+    .unwrap();
+    let verifiable_result = VerifiableQueryResult::<DoryEvaluationProof>::new(
+        query.proof_expr(),
+        &accessor,
+        &dory_prover_setup,
+    );
+    let owned_table_result = verifiable_result
+        .verify(query.proof_expr(), &accessor, &dory_verifier_setup)
+        .unwrap()
+        .table;
+    let owned_table_result: OwnedTable<DoryScalar> =
+        apply_postprocessing_steps(owned_table_result, query.postprocessing()).unwrap();
+    let expected_result: OwnedTable<DoryScalar> = owned_table([
+        bigint("id", [2, 3]),
+        varchar("prefix", ["5500", "3782"]),
+        varchar("rest", ["ukasz", ""]),
+    ]);
+    assert_eq!(owned_table_result, expected_result);
+}
+
+#[test]
+fn we_can_order_a_verified_result_by_date_and_timestamp_columns_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.events".parse().unwrap(),
+        owned_table([
+            bigint("id", [1, 2, 3, 4, 5]),
+            // 2024-02-29, 1969-12-31, 1970-01-01, 1900-01-01 and 2024-02-29
+            date("day", [19_782, -1, 0, -25_567, 19_782]),
+            timestamptz(
+                "at",
+                PoSQLTimeUnit::Second,
+                PoSQLTimeZone::utc(),
+                [1_709_200_000, -10, 5, -2_208_988_800, 1_709_164_800],
+            ),
+        ]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT id, day, at FROM events WHERE NOT id = 2 ORDER BY day DESC, at"
+            .parse()
+            .unwrap(),
+        "sxt".into(),
+        &accessor,
+    )
+    .unwrap();
+    let verifiable_result = VerifiableQueryResult::<DoryEvaluationProof>::new(
+        query.proof_expr(),
+        &accessor,
+        &dory_prover_setup,
+    );
+    let owned_table_result = verifiable_result
+        .verify(query.proof_expr(), &accessor, &dory_verifier_setup)
+        .unwrap()
+        .table;
+    let owned_table_result: OwnedTable<DoryScalar> =
+        apply_postprocessing_steps(owned_table_result, query.postprocessing()).unwrap();
+    let expected_result: OwnedTable<DoryScalar> = owned_table([
+        bigint("id", [5, 1, 3, 4]),
+        date("day", [19_782, 19_782, 0, -25_567]),
+        timestamptz(
+            "at",
+            PoSQLTimeUnit::Second,
+            PoSQLTimeZone::utc(),
+            [1_709_164_800, 1_709_200_000, 5, -2_208_988_800],
+        ),
+    ]);
+    assert_eq!(owned_table_result, expected_result);
+}
+
+#[test]
+fn we_can_prove_select_distinct_queries_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("a", [1, 2, 1, 3, 2, 1]),
+            varchar("b", ["x", "y", "x", "x", "z", "y"]),
+            bigint("c", [10, 20, 30, 40, 50, 60]),
+        ]),
+        0,
+    );
+    let verified_result = |sql: &str| -> OwnedTable<DoryScalar> {
+        let query = QueryExpr::try_new(sql.parse().unwrap(), "sxt".into(), &accessor).unwrap();
+        let verifiable_result = VerifiableQueryResult::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        let owned_table_result = verifiable_result
+            .verify(query.proof_expr(), &accessor, &dory_verifier_setup)
+            .unwrap()
+            .table;
+        apply_postprocessing_steps(owned_table_result, query.postprocessing()).unwrap()
+    };
+
+    assert_eq!(
+        verified_result("SELECT DISTINCT a FROM table ORDER BY a"),
+        owned_table([bigint("a", [1, 2, 3])])
+    );
+    assert_eq!(
+        verified_result(
+            "SELECT DISTINCT b AS letter, a FROM table WHERE c > 10 ORDER BY letter, a"
+        ),
+        owned_table([
+            varchar("letter", ["x", "x", "y", "y", "z"]),
+            bigint("a", [1, 3, 1, 2, 2]),
+        ])
+    );
+}
+
+#[test]
+fn we_can_prove_queries_on_uint64_columns_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("id", [1, 2, 3, 4, 5]),
+            uint64(
+                "balance",
+                [
+                    u64::MAX,
+                    0,
+                    1 << 63,
+                    u64::MAX - 1,
+                    9_223_372_036_854_775_807,
+                ],
+            ),
+        ]),
+        0,
+    );
+    let verified_result = |sql: &str| -> OwnedTable<DoryScalar> {
+        let query = QueryExpr::try_new(sql.parse().unwrap(), "sxt".into(), &accessor).unwrap();
+        let verifiable_result = VerifiableQueryResult::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        let owned_table_result = verifiable_result
+            .verify(query.proof_expr(), &accessor, &dory_verifier_setup)
+            .unwrap()
+            .table;
+        apply_postprocessing_steps(owned_table_result, query.postprocessing()).unwrap()
+    };
+
+    assert_eq!(
+        verified_result("SELECT id, balance FROM table ORDER BY balance DESC"),
+        owned_table([
+            bigint("id", [1, 4, 3, 5, 2]),
+            uint64(
+                "balance",
+                [
+                    u64::MAX,
+                    u64::MAX - 1,
+                    1 << 63,
+                    9_223_372_036_854_775_807,
+                    0
+                ],
+            ),
+        ])
+    );
+    assert_eq!(
+        verified_result("SELECT id FROM table WHERE balance >= 9223372036854775808 ORDER BY id"),
+        owned_table([bigint("id", [1, 3, 4])])
+    );
+    assert_eq!(
+        verified_result("SELECT id FROM table WHERE balance = 18446744073709551615"),
+        owned_table([bigint("id", [1])])
+    );
+}
+
+#[test]
+fn we_can_prove_a_group_by_query_with_a_sum_of_products_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("a", [1, 1, 2, 3, 2, 1]),
+            bigint("b", [1, 0, 4, 2, 3, -5]),
+            int("c", [-2, 2, 1, 0, 1, 3]),
+            decimal75("d", 5, 1, [15, -3, 20, 7, 11, 4]),
+        ]),
+        0,
+    );
+    // With a count the products are fused into the group by, without one they are proven
+    // as a filter and summed in postprocessing
+    let results = [
+        "SELECT a, sum(b * c * d) as s, sum(b * c) as t, count(*) as n FROM table WHERE c >= 0 group by a",
+        "SELECT a, sum(b * c * d) as s, sum(b * c) as t FROM table WHERE c >= 0 group by a",
+    ]
+    .map(|sql| {
+        let query = QueryExpr::try_new(sql.parse().unwrap(), "sxt".into(), &accessor).unwrap();
+        let verifiable_result = VerifiableQueryResult::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        let owned_table_result = verifiable_result
+            .verify(query.proof_expr(), &accessor, &dory_verifier_setup)
+            .unwrap()
+            .table;
+        apply_postprocessing_steps(owned_table_result, query.postprocessing()).unwrap()
+    });
+    let [fused_result, naive_result] = results;
+    let expected_result = owned_table([
+        bigint("a", [1, 2, 3]),
+        decimal75("s", 25, 1, [-60, 80 + 33, 0]),
+        bigint("t", [-15, 4 + 3, 0]),
+    ]);
+    assert_eq!(naive_result, expected_result);
+    let expected_result = owned_table([
+        bigint("a", [1, 2, 3]),
+        decimal75("s", 25, 1, [-60, 80 + 33, 0]),
+        bigint("t", [-15, 4 + 3, 0]),
+        bigint("n", [2, 2, 1]),
+    ]);
+    assert_eq!(fused_result, expected_result);
+}
+
 // Overflow checks
 #[test]
 #[cfg(feature = "blitzar")]
@@ -844,3 +1629,135 @@ fn we_can_perform_arithmetic_and_conditional_operations_on_tinyint() {
     let expected_result = owned_table([tinyint("result", [9_i8, 10])]);
     assert_eq!(owned_table_result, expected_result);
 }
+
+#[test]
+fn we_can_prove_column_to_column_comparisons_with_dory() {
+    type RowValue<'a> = &'a dyn Fn(usize) -> i64;
+    type Comparison = fn(&i64, &i64) -> bool;
+
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let a_data = [-3_i64, -1, 0, 2, 2, 5, 7, 9];
+    let b_data = [-3_i64, 1, 0, 1, 4, 5, 8, -2];
+    let c_data = [-4_i32, -1, 1, 2, 3, 5, 6, 9];
+    let d_data = [-30_i64, -5, 0, 25, 20, 50, 71, 90];
+    let e_data = [-300_i64, -50, 1, 250, 199, 500, 710, 899];
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("i", 0..8),
+            bigint("a", a_data),
+            bigint("b", b_data),
+            int("c", c_data),
+            decimal75("d", 10, 1, d_data),
+            decimal75("e", 10, 2, e_data),
+        ]),
+        0,
+    );
+
+    // Each operand is given as SQL together with its value in hundredths for every row
+    let a_value = |i: usize| a_data[i] * 100;
+    let b_value = |i: usize| b_data[i] * 100;
+    let c_value = |i: usize| i64::from(c_data[i]) * 100;
+    let d_value = |i: usize| d_data[i] * 10;
+    let e_value = |i: usize| e_data[i];
+    let operand_pairs: [(&str, RowValue, &str, RowValue); 7] = [
+        ("a", &a_value, "b", &b_value),
+        ("a", &a_value, "c", &c_value),
+        ("d", &d_value, "e", &e_value),
+        ("c", &c_value, "d", &d_value),
+        ("b", &b_value, "a + c", &|i| a_value(i) + c_value(i)),
+        ("e", &e_value, "a - b", &|i| a_value(i) - b_value(i)),
+        (
+            "a * c",
+            &|i| a_value(i) * i64::from(c_data[i]),
+            "d",
+            &d_value,
+        ),
+    ];
+    let comparisons: [(&str, Comparison); 7] = [
+        ("=", i64::eq),
+        ("!=", i64::ne),
+        ("<>", i64::ne),
+        ("<", i64::lt),
+        ("<=", i64::le),
+        (">", i64::gt),
+        (">=", i64::ge),
+    ];
+    for (lhs, lhs_value, rhs, rhs_value) in operand_pairs {
+        for (op, compare) in comparisons {
+            let sql = format!("SELECT i FROM table WHERE {lhs} {op} {rhs}");
+            let query = QueryExpr::try_new(sql.parse().unwrap(), "sxt".into(), &accessor).unwrap();
+            let verifiable_result = VerifiableQueryResult::<DoryEvaluationProof>::new(
+                query.proof_expr(),
+                &accessor,
+                &dory_prover_setup,
+            );
+            let owned_table_result = verifiable_result
+                .verify(query.proof_expr(), &accessor, &dory_verifier_setup)
+                .unwrap()
+                .table;
+            let expected_result = owned_table([bigint(
+                "i",
+                (0..8)
+                    .filter(|&i| compare(&lhs_value(i), &rhs_value(i)))
+                    .map(|i| i64::try_from(i).unwrap())
+                    .collect::<Vec<_>>(),
+            )]);
+            assert_eq!(owned_table_result, expected_result, "{sql}");
+        }
+    }
+}
+
+#[test]
+fn we_can_prove_a_query_over_json_records_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let records = [
+        serde_json::json!({"id": 1, "name": "apple", "price": 1.25, "in_stock": true}),
+        serde_json::json!({"id": "2", "name": "pear", "price": "0.5", "in_stock": "false"}),
+        serde_json::json!({"id": 3, "name": "plum", "price": 2, "in_stock": true, "note": null}),
+    ];
+    let schema = [
+        ColumnField::new("id".into(), ColumnType::BigInt),
+        ColumnField::new("name".into(), ColumnType::VarChar),
+        ColumnField::new(
+            "price".into(),
+            ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+        ),
+        ColumnField::new("in_stock".into(), ColumnType::Boolean),
+    ];
+    let table = owned_table_from_json_records(&records, &schema).unwrap();
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table("sxt.table".parse().unwrap(), table, 0);
+    let query = QueryExpr::try_new(
+        "SELECT id, name FROM table WHERE in_stock and price >= 1"
+            .parse()
+            .unwrap(),
+        "sxt".into(),
+        &accessor,
+    )
+    .unwrap();
+    let verifiable_result = VerifiableQueryResult::<DoryEvaluationProof>::new(
+        query.proof_expr(),
+        &accessor,
+        &dory_prover_setup,
+    );
+    let owned_table_result = verifiable_result
+        .verify(query.proof_expr(), &accessor, &dory_verifier_setup)
+        .unwrap()
+        .table;
+    let expected_result = owned_table([bigint("id", [1, 3]), varchar("name", ["apple", "plum"])]);
+    assert_eq!(owned_table_result, expected_result);
+}
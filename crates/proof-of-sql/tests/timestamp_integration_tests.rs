@@ -66,6 +66,68 @@ fn we_can_prove_a_basic_query_containing_rfc3339_timestamp_with_dory() {
     assert_eq!(owned_table_result, expected_result);
 }
 
+#[test]
+fn we_can_compare_timestamps_with_different_offsets_as_instants_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    // +05:30
+    let ist = PoSQLTimeZone::new(19_800);
+    // 2024-06-20T05:30:00Z, i.e. 2024-06-20T11:00:00+05:30 and 2024-06-19T21:30:00-08:00
+    let instant = 1_718_861_400;
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([timestamptz(
+            "times",
+            PoSQLTimeUnit::Second,
+            ist,
+            [instant - 1, instant, instant + 1],
+        )]),
+        0,
+    );
+    for (query, expected_timestamps) in [
+        (
+            "SELECT times FROM table WHERE times = timestamp '2024-06-19T21:30:00-08:00';",
+            vec![instant],
+        ),
+        (
+            "SELECT times FROM table WHERE times = timestamp '2024-06-20T11:00:00+05:30';",
+            vec![instant],
+        ),
+        (
+            "SELECT times FROM table WHERE times >= timestamp '2024-06-19T21:30:00-08:00';",
+            vec![instant, instant + 1],
+        ),
+        (
+            "SELECT times FROM table WHERE times < timestamp '2024-06-20T11:00:00+05:30';",
+            vec![instant - 1],
+        ),
+    ] {
+        let query = QueryExpr::try_new(query.parse().unwrap(), "sxt".into(), &accessor).unwrap();
+        let verifiable_result = VerifiableQueryResult::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        let owned_table_result = verifiable_result
+            .verify(query.proof_expr(), &accessor, &dory_verifier_setup)
+            .unwrap()
+            .table;
+        let expected_result = owned_table([timestamptz(
+            "times",
+            PoSQLTimeUnit::Second,
+            ist,
+            expected_timestamps,
+        )]);
+        assert_eq!(owned_table_result, expected_result);
+    }
+}
+
 /// Runs a timestamp query test.
 #[cfg(feature = "blitzar")]
 fn run_timestamp_query_test(
@@ -0,0 +1,267 @@
+//! Reading a CSV file as a stream of bounded [`OwnedTable`] chunks.
+use csv::{Reader, StringRecord};
+use proof_of_sql::base::{
+    commitment::{Commitment, TableCommitment},
+    database::{ColumnType, OwnedColumn, OwnedTable},
+    scalar::Scalar,
+};
+use proof_of_sql_parser::posql_time::PoSQLDate;
+use snafu::Snafu;
+use sqlparser::ast::Ident;
+use std::{io::Read, marker::PhantomData, num::NonZeroUsize};
+
+/// Errors that can occur while reading a CSV file as table chunks.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Snafu)]
+pub enum CsvChunksError {
+    #[snafu(display("Invalid schema entry '{entry}', expected `name:type`"))]
+    /// A schema entry is not of the form `name:type`.
+    InvalidSchemaEntry {
+        /// The invalid entry
+        entry: String,
+    },
+
+    #[snafu(display("Unsupported column type '{column_type}'"))]
+    /// A schema entry has a type that cannot be read from CSV.
+    UnsupportedColumnType {
+        /// The unsupported type
+        column_type: String,
+    },
+
+    #[snafu(display("Column '{column}' is not in the CSV header"))]
+    /// A schema column is missing from the CSV header.
+    MissingColumn {
+        /// The missing column
+        column: String,
+    },
+
+    #[snafu(display("Failed to read CSV: {source}"))]
+    /// The CSV reader failed.
+    Csv {
+        /// The underlying error
+        source: csv::Error,
+    },
+
+    #[snafu(display("Invalid {column_type} value '{value}' in column '{column}'"))]
+    /// A field could not be parsed as the type of its column.
+    InvalidValue {
+        /// The column of the field
+        column: String,
+        /// The type of the column
+        column_type: ColumnType,
+        /// The unparsable field
+        value: String,
+    },
+}
+
+/// Result type for reading CSV chunks.
+#[allow(clippy::module_name_repetitions)]
+pub type CsvChunksResult<T> = Result<T, CsvChunksError>;
+
+/// Parses a schema of the form `name:type,name:type,...`.
+///
+/// Supported types are `BOOLEAN`, `TINYINT`, `SMALLINT`, `INT`, `BIGINT`, `INT128`, `UINT64`,
+/// `VARCHAR` and `DATE` (as `YYYY-MM-DD`), matched case-insensitively.
+pub fn parse_schema(schema: &str) -> CsvChunksResult<Vec<(Ident, ColumnType)>> {
+    schema
+        .split(',')
+        .map(|entry| {
+            let (name, column_type) =
+                entry
+                    .split_once(':')
+                    .ok_or_else(|| CsvChunksError::InvalidSchemaEntry {
+                        entry: entry.to_string(),
+                    })?;
+            let column_type = match column_type.trim().to_ascii_uppercase().as_str() {
+                "BOOLEAN" => ColumnType::Boolean,
+                "TINYINT" => ColumnType::TinyInt,
+                "SMALLINT" => ColumnType::SmallInt,
+                "INT" => ColumnType::Int,
+                "BIGINT" => ColumnType::BigInt,
+                "INT128" => ColumnType::Int128,
+                "UINT64" => ColumnType::UInt64,
+                "VARCHAR" => ColumnType::VarChar,
+                "DATE" => ColumnType::Date,
+                _ => Err(CsvChunksError::UnsupportedColumnType {
+                    column_type: column_type.to_string(),
+                })?,
+            };
+            Ok((Ident::new(name.trim()), column_type))
+        })
+        .collect()
+}
+
+/// Returns an empty column of `column_type`, or `None` if the type cannot be read from CSV.
+fn empty_column<S: Scalar>(column_type: ColumnType) -> Option<OwnedColumn<S>> {
+    Some(match column_type {
+        ColumnType::Boolean => OwnedColumn::Boolean(Vec::new()),
+        ColumnType::TinyInt => OwnedColumn::TinyInt(Vec::new()),
+        ColumnType::SmallInt => OwnedColumn::SmallInt(Vec::new()),
+        ColumnType::Int => OwnedColumn::Int(Vec::new()),
+        ColumnType::BigInt => OwnedColumn::BigInt(Vec::new()),
+        ColumnType::Int128 => OwnedColumn::Int128(Vec::new()),
+        ColumnType::UInt64 => OwnedColumn::UInt64(Vec::new()),
+        ColumnType::VarChar => OwnedColumn::VarChar(Vec::new()),
+        ColumnType::Date => OwnedColumn::Date(Vec::new()),
+        _ => None?,
+    })
+}
+
+/// Appends the parsed `value` to `column`, returning `None` if it does not parse.
+fn push_value<S: Scalar>(column: &mut OwnedColumn<S>, value: &str) -> Option<()> {
+    match column {
+        OwnedColumn::Boolean(col) => col.push(value.trim().to_ascii_lowercase().parse().ok()?),
+        OwnedColumn::TinyInt(col) => col.push(value.trim().parse().ok()?),
+        OwnedColumn::SmallInt(col) => col.push(value.trim().parse().ok()?),
+        OwnedColumn::Int(col) => col.push(value.trim().parse().ok()?),
+        OwnedColumn::BigInt(col) => col.push(value.trim().parse().ok()?),
+        OwnedColumn::Int128(col) => col.push(value.trim().parse().ok()?),
+        OwnedColumn::UInt64(col) => col.push(value.trim().parse().ok()?),
+        OwnedColumn::VarChar(col) => col.push(value.to_string()),
+        OwnedColumn::Date(col) => {
+            col.push(PoSQLDate::try_from(value.trim()).ok()?.days_since_epoch());
+        }
+        _ => return None,
+    }
+    Some(())
+}
+
+/// An iterator over a CSV file as [`OwnedTable`]s of at most `chunk_size` rows each.
+///
+/// Only one chunk is held in memory at a time, so memory use is bounded by the chunk size
+/// rather than by the size of the file.
+pub struct CsvChunks<R: Read, S: Scalar> {
+    reader: Reader<R>,
+    schema: Vec<(Ident, ColumnType)>,
+    header_indices: Vec<usize>,
+    chunk_size: NonZeroUsize,
+    record: StringRecord,
+    _scalar: PhantomData<S>,
+}
+
+impl<R: Read, S: Scalar> CsvChunks<R, S> {
+    /// Creates a [`CsvChunks`] reading the columns of `schema` from a CSV with a header row.
+    pub fn try_new(
+        reader: R,
+        schema: Vec<(Ident, ColumnType)>,
+        chunk_size: NonZeroUsize,
+    ) -> CsvChunksResult<Self> {
+        if let Some((_, column_type)) = schema
+            .iter()
+            .find(|(_, column_type)| empty_column::<S>(*column_type).is_none())
+        {
+            return Err(CsvChunksError::UnsupportedColumnType {
+                column_type: column_type.to_string(),
+            });
+        }
+        let mut reader = Reader::from_reader(reader);
+        let headers = reader
+            .headers()
+            .map_err(|source| CsvChunksError::Csv { source })?;
+        let header_indices = schema
+            .iter()
+            .map(|(name, _)| {
+                headers
+                    .iter()
+                    .position(|header| header.trim() == name.value)
+                    .ok_or_else(|| CsvChunksError::MissingColumn {
+                        column: name.value.clone(),
+                    })
+            })
+            .collect::<CsvChunksResult<_>>()?;
+        Ok(Self {
+            reader,
+            schema,
+            header_indices,
+            chunk_size,
+            record: StringRecord::new(),
+            _scalar: PhantomData,
+        })
+    }
+
+    #[allow(clippy::missing_panics_doc)]
+    fn empty_columns(&self) -> Vec<OwnedColumn<S>> {
+        self.schema
+            .iter()
+            .map(|(_, column_type)| {
+                empty_column(*column_type).expect("the schema only has supported column types")
+            })
+            .collect()
+    }
+
+    #[allow(clippy::missing_panics_doc)]
+    fn table_from_columns(&self, columns: Vec<OwnedColumn<S>>) -> OwnedTable<S> {
+        OwnedTable::try_from_iter(
+            self.schema
+                .iter()
+                .map(|(name, _)| name.clone())
+                .zip(columns),
+        )
+        .expect("the columns of a chunk have equal length")
+    }
+
+    /// Returns a table with the columns of the schema and no rows.
+    #[allow(
+        clippy::missing_panics_doc,
+        reason = "the schema is checked to only have supported column types on construction"
+    )]
+    pub fn empty_table(&self) -> OwnedTable<S> {
+        self.table_from_columns(self.empty_columns())
+    }
+
+    fn read_chunk(&mut self) -> CsvChunksResult<Option<OwnedTable<S>>> {
+        let mut columns = self.empty_columns();
+        let mut num_rows = 0;
+        while num_rows < self.chunk_size.get()
+            && self
+                .reader
+                .read_record(&mut self.record)
+                .map_err(|source| CsvChunksError::Csv { source })?
+        {
+            for ((column, (name, column_type)), &index) in columns
+                .iter_mut()
+                .zip(&self.schema)
+                .zip(&self.header_indices)
+            {
+                let value = self.record.get(index).unwrap_or_default();
+                push_value(column, value).ok_or_else(|| CsvChunksError::InvalidValue {
+                    column: name.value.clone(),
+                    column_type: *column_type,
+                    value: value.to_string(),
+                })?;
+            }
+            num_rows += 1;
+        }
+        Ok((num_rows > 0).then(|| self.table_from_columns(columns)))
+    }
+}
+
+impl<R: Read, S: Scalar> Iterator for CsvChunks<R, S> {
+    type Item = CsvChunksResult<OwnedTable<S>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_chunk().transpose()
+    }
+}
+
+/// Commits to a CSV file one chunk at a time, appending each chunk to the [`TableCommitment`].
+#[allow(
+    clippy::missing_panics_doc,
+    reason = "every chunk has the columns and types of the empty table the commitment starts from"
+)]
+pub fn commit_csv<C: Commitment, R: Read>(
+    reader: R,
+    schema: Vec<(Ident, ColumnType)>,
+    chunk_size: NonZeroUsize,
+    setup: &C::PublicSetup<'_>,
+) -> CsvChunksResult<TableCommitment<C>> {
+    let chunks = CsvChunks::<R, C::Scalar>::try_new(reader, schema, chunk_size)?;
+    let mut commitment =
+        TableCommitment::from_owned_table_with_offset(&chunks.empty_table(), 0, setup);
+    for chunk in chunks {
+        commitment
+            .append_owned_table(&chunk?, setup)
+            .expect("every chunk has the schema of the empty table");
+    }
+    Ok(commitment)
+}
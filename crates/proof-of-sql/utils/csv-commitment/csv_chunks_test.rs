@@ -0,0 +1,147 @@
+use crate::csv_chunks::{commit_csv, parse_schema, CsvChunks, CsvChunksError};
+use ark_std::test_rng;
+use proof_of_sql::{
+    base::{
+        commitment::TableCommitment,
+        database::{owned_table_utility::*, ColumnType, OwnedTable},
+    },
+    proof_primitive::dory::{DoryScalar, DynamicDoryCommitment, ProverSetup, PublicParameters},
+};
+use std::{fmt::Write, num::NonZeroUsize};
+
+const NUM_ROWS: usize = 1000;
+
+fn csv_and_table() -> (String, OwnedTable<DoryScalar>) {
+    let mut csv = String::from("name,id,flag,joined\n");
+    for i in 0..NUM_ROWS {
+        writeln!(
+            csv,
+            "user_{i},{},{},2024-01-{:02}",
+            i * 7,
+            i % 3 == 0,
+            i % 28 + 1
+        )
+        .unwrap();
+    }
+    let table = owned_table([
+        bigint("id", (0..NUM_ROWS).map(|i| i64::try_from(i * 7).unwrap())),
+        varchar("name", (0..NUM_ROWS).map(|i| format!("user_{i}"))),
+        boolean("flag", (0..NUM_ROWS).map(|i| i % 3 == 0)),
+        date(
+            "joined",
+            (0..NUM_ROWS).map(|i| 19_723 + i32::try_from(i % 28).unwrap()),
+        ),
+    ]);
+    (csv, table)
+}
+
+const SCHEMA: &str = "id:bigint,name:varchar,flag:boolean,joined:date";
+
+#[test]
+fn we_can_parse_a_schema() {
+    let schema = parse_schema("a:BigInt, b : varchar,c:DATE").unwrap();
+    assert_eq!(
+        schema,
+        vec![
+            ("a".into(), ColumnType::BigInt),
+            ("b".into(), ColumnType::VarChar),
+            ("c".into(), ColumnType::Date),
+        ]
+    );
+    assert!(matches!(
+        parse_schema("a"),
+        Err(CsvChunksError::InvalidSchemaEntry { .. })
+    ));
+    assert!(matches!(
+        parse_schema("a:scalar"),
+        Err(CsvChunksError::UnsupportedColumnType { .. })
+    ));
+}
+
+#[test]
+fn we_can_read_a_csv_in_chunks_of_bounded_size() {
+    let (csv, table) = csv_and_table();
+    let chunks: Vec<OwnedTable<DoryScalar>> = CsvChunks::try_new(
+        csv.as_bytes(),
+        parse_schema(SCHEMA).unwrap(),
+        NonZeroUsize::new(64).unwrap(),
+    )
+    .unwrap()
+    .collect::<Result<_, _>>()
+    .unwrap();
+    assert_eq!(chunks.len(), NUM_ROWS.div_ceil(64));
+    assert!(chunks.iter().all(|chunk| chunk.num_rows() <= 64));
+    assert_eq!(
+        chunks.iter().map(OwnedTable::num_rows).sum::<usize>(),
+        NUM_ROWS
+    );
+    assert_eq!(
+        chunks[0].inner_table()[1],
+        table.inner_table()[1].slice(0, 64)
+    );
+}
+
+#[test]
+fn we_can_commit_to_a_csv_in_chunks() {
+    let public_parameters = PublicParameters::test_rand(6, &mut test_rng());
+    let setup = ProverSetup::from(&public_parameters);
+    let (csv, table) = csv_and_table();
+    let expected =
+        TableCommitment::<DynamicDoryCommitment>::from_owned_table_with_offset(&table, 0, &&setup);
+    for chunk_size in [64, 333, NUM_ROWS, 2 * NUM_ROWS] {
+        let commitment = commit_csv::<DynamicDoryCommitment, _>(
+            csv.as_bytes(),
+            parse_schema(SCHEMA).unwrap(),
+            NonZeroUsize::new(chunk_size).unwrap(),
+            &&setup,
+        )
+        .unwrap();
+        assert_eq!(commitment, expected);
+    }
+}
+
+#[test]
+fn we_can_commit_to_a_csv_with_no_rows() {
+    let public_parameters = PublicParameters::test_rand(2, &mut test_rng());
+    let setup = ProverSetup::from(&public_parameters);
+    let commitment = commit_csv::<DynamicDoryCommitment, _>(
+        "name,id,flag,joined\n".as_bytes(),
+        parse_schema(SCHEMA).unwrap(),
+        NonZeroUsize::new(64).unwrap(),
+        &&setup,
+    )
+    .unwrap();
+    assert_eq!(commitment.num_rows(), 0);
+    assert_eq!(commitment.num_columns(), 4);
+}
+
+#[test]
+fn we_cannot_read_a_csv_with_missing_columns_unsupported_types_or_invalid_values() {
+    assert!(matches!(
+        CsvChunks::<_, DoryScalar>::try_new(
+            "a,b\n1,2\n".as_bytes(),
+            parse_schema("c:bigint").unwrap(),
+            NonZeroUsize::new(1).unwrap(),
+        ),
+        Err(CsvChunksError::MissingColumn { .. })
+    ));
+    assert!(matches!(
+        CsvChunks::<_, DoryScalar>::try_new(
+            "a,b\n1,2\n".as_bytes(),
+            vec![("a".into(), ColumnType::Scalar)],
+            NonZeroUsize::new(1).unwrap(),
+        ),
+        Err(CsvChunksError::UnsupportedColumnType { .. })
+    ));
+    let mut chunks = CsvChunks::<_, DoryScalar>::try_new(
+        "a,b\n1,2\nx,3\n".as_bytes(),
+        parse_schema("a:bigint").unwrap(),
+        NonZeroUsize::new(1).unwrap(),
+    )
+    .unwrap();
+    assert!(chunks.next().unwrap().is_ok());
+    assert!(matches!(
+        chunks.next().unwrap(),
+        Err(CsvChunksError::InvalidValue { .. })
+    ));
+}
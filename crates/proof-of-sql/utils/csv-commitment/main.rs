@@ -0,0 +1,124 @@
+//! Utility to compute a Dynamic Dory table commitment to a CSV file without loading it into
+//! memory.
+//!
+//! The CSV is read in chunks of `--chunk-size` rows, and each chunk is appended to the
+//! commitment before the next is read. The commitment is written serialized with `postcard`,
+//! so it can be inspected with `commitment-utility`.
+#![cfg_attr(test, allow(clippy::missing_panics_doc))]
+
+mod csv_chunks;
+#[cfg(test)]
+mod csv_chunks_test;
+
+use clap::Parser;
+use csv_chunks::{commit_csv, parse_schema, CsvChunksError};
+use proof_of_sql::{
+    base::commitment::TableCommitment,
+    proof_primitive::dory::{DynamicDoryCommitment, ProverSetup, PublicParameters},
+};
+use snafu::Snafu;
+use std::{
+    fs::File,
+    io::{self, BufReader, Write},
+    num::NonZeroUsize,
+    path::PathBuf,
+};
+
+#[derive(Parser)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    /// Input CSV file with a header row (defaults to None which is stdin)
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Output file (defaults to None which is stdout)
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// File containing the serialized public parameters
+    #[arg(short, long)]
+    params: PathBuf,
+
+    /// Columns to commit to, as `name:type,name:type,...`
+    /// (types: boolean, tinyint, smallint, int, bigint, int128, varchar, date)
+    #[arg(short, long)]
+    schema: String,
+
+    /// Number of rows held in memory at a time
+    #[arg(long, default_value = "10000")]
+    chunk_size: NonZeroUsize,
+}
+
+#[derive(Debug, Snafu)]
+enum CsvCommitmentError {
+    #[snafu(display("Failed to open input file '{:?}'", filename))]
+    OpenInputFile { filename: PathBuf },
+
+    #[snafu(display("Failed to load public parameters from '{:?}'", filename))]
+    LoadPublicParameters { filename: PathBuf },
+
+    #[snafu(display("Failed to commit to CSV: {source}"))]
+    CommitCsv { source: CsvChunksError },
+
+    #[snafu(display("Failed to serialize commitment"))]
+    SerializationError,
+
+    #[snafu(display("Failed to create output file '{:?}'", filename))]
+    CreateOutputFile { filename: PathBuf },
+
+    #[snafu(display("Failed to write to output file '{:?}'", filename))]
+    WriteOutputFile { filename: PathBuf },
+
+    #[snafu(display("Failed to write to stdout"))]
+    WriteStdout,
+}
+
+type CsvCommitmentResult<T, E = CsvCommitmentError> = std::result::Result<T, E>;
+
+fn main() -> CsvCommitmentResult<()> {
+    let cli = Cli::parse();
+
+    let schema =
+        parse_schema(&cli.schema).map_err(|source| CsvCommitmentError::CommitCsv { source })?;
+    let public_parameters = PublicParameters::load_from_file(&cli.params).map_err(|_| {
+        CsvCommitmentError::LoadPublicParameters {
+            filename: cli.params.clone(),
+        }
+    })?;
+    let setup = ProverSetup::from(&public_parameters);
+
+    // Commit to the input one chunk at a time
+    let commitment: TableCommitment<DynamicDoryCommitment> = match &cli.input {
+        Some(input_file) => {
+            let file = File::open(input_file).map_err(|_| CsvCommitmentError::OpenInputFile {
+                filename: input_file.clone(),
+            })?;
+            commit_csv(BufReader::new(file), schema, cli.chunk_size, &&setup)
+        }
+        None => commit_csv(io::stdin().lock(), schema, cli.chunk_size, &&setup),
+    }
+    .map_err(|source| CsvCommitmentError::CommitCsv { source })?;
+    let output_data =
+        postcard::to_allocvec(&commitment).map_err(|_| CsvCommitmentError::SerializationError)?;
+
+    // Write output data
+    match &cli.output {
+        Some(output_file) => {
+            let mut file =
+                File::create(output_file).map_err(|_| CsvCommitmentError::CreateOutputFile {
+                    filename: output_file.clone(),
+                })?;
+            file.write_all(&output_data)
+                .map_err(|_| CsvCommitmentError::WriteOutputFile {
+                    filename: output_file.clone(),
+                })?;
+        }
+        None => {
+            io::stdout()
+                .write_all(&output_data)
+                .map_err(|_| CsvCommitmentError::WriteStdout)?;
+        }
+    }
+
+    Ok(())
+}
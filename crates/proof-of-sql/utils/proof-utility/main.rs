@@ -0,0 +1,112 @@
+//! Utility to deserialize a verifiable query result written by `VerifiableQueryResult::to_bytes`,
+//! from a file or stdin, and print its format and structure.
+use clap::{Parser, ValueEnum};
+use proof_of_sql::{
+    base::commitment::{CommitmentEvaluationProof, InnerProductProof},
+    proof_primitive::dory::{DoryEvaluationProof, DynamicDoryEvaluationProof},
+    sql::proof::{ProofCompression, ProofSerializationError, VerifiableQueryResult},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use snafu::Snafu;
+use std::{
+    fs,
+    io::{self, Read, Write},
+    path::PathBuf,
+};
+
+#[derive(ValueEnum, Clone, Debug)]
+/// Supported commitment schemes.
+enum CommitmentScheme {
+    /// Inner Product Argument (IPA) commitment scheme.
+    Ipa,
+    /// Dory commitment scheme.
+    Dory,
+    /// Dynamic Dory commitment scheme.
+    DynamicDory,
+}
+
+#[derive(Parser)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    /// Input file (defaults to None which is stdin)
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Output file (defaults to None which is stdout)
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Commitment scheme (e.g. `ipa`, `dynamic_dory`, `dory`)
+    #[arg(long, value_enum, default_value_t = CommitmentScheme::DynamicDory)]
+    scheme: CommitmentScheme,
+}
+
+#[derive(Debug, Snafu)]
+enum ProofUtilityError {
+    #[snafu(display("Failed to read from input file '{:?}'", filename))]
+    ReadInputFile { filename: PathBuf },
+
+    #[snafu(display("Failed to read from stdin"))]
+    ReadStdin,
+
+    #[snafu(display("Failed to write to output file '{:?}'", filename))]
+    WriteOutputFile { filename: PathBuf },
+
+    #[snafu(display("Failed to write to stdout"))]
+    WriteStdout,
+
+    #[snafu(display("Failed to deserialize verifiable query result: {source}"))]
+    DeserializationError { source: ProofSerializationError },
+}
+
+type ProofUtilityResult<T, E = ProofUtilityError> = std::result::Result<T, E>;
+
+/// Deserializes bytes written by [`VerifiableQueryResult::to_bytes`] and describes them.
+fn summarize<CP: CommitmentEvaluationProof + Serialize + DeserializeOwned>(
+    bytes: &[u8],
+) -> ProofUtilityResult<String> {
+    let compression = ProofCompression::detect(bytes)
+        .map_err(|source| ProofUtilityError::DeserializationError { source })?;
+    let summary = VerifiableQueryResult::<CP>::from_bytes(bytes)
+        .map_err(|source| ProofUtilityError::DeserializationError { source })?
+        .summary();
+    Ok(format!(
+        "format: postcard (unversioned)\ncompression: {compression}\n{summary}"
+    ))
+}
+
+fn main() -> ProofUtilityResult<()> {
+    let cli = Cli::parse();
+
+    let input_data = match &cli.input {
+        Some(input_file) => fs::read(input_file).map_err(|_| ProofUtilityError::ReadInputFile {
+            filename: input_file.clone(),
+        })?,
+        None => {
+            let mut buffer = Vec::new();
+            io::stdin()
+                .read_to_end(&mut buffer)
+                .map_err(|_| ProofUtilityError::ReadStdin)?;
+            buffer
+        }
+    };
+
+    let human_readable = match cli.scheme {
+        CommitmentScheme::DynamicDory => summarize::<DynamicDoryEvaluationProof>(&input_data)?,
+        CommitmentScheme::Dory => summarize::<DoryEvaluationProof>(&input_data)?,
+        CommitmentScheme::Ipa => summarize::<InnerProductProof>(&input_data)?,
+    };
+
+    match &cli.output {
+        Some(output_file) => fs::write(output_file, human_readable).map_err(|_| {
+            ProofUtilityError::WriteOutputFile {
+                filename: output_file.clone(),
+            }
+        })?,
+        None => io::stdout()
+            .write_all(human_readable.as_bytes())
+            .map_err(|_| ProofUtilityError::WriteStdout)?,
+    }
+
+    Ok(())
+}